@@ -11,12 +11,14 @@ use crate::dangi::Dangi;
 use crate::ethiopian::{Ethiopian, EthiopianEraStyle};
 use crate::gregorian::Gregorian;
 use crate::hebrew::Hebrew;
+use crate::hindu::{HinduLunar, HinduLunarEraStyle, HinduSolar, HinduSolarRegion};
 use crate::indian::Indian;
 use crate::islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura};
 use crate::iso::Iso;
 use crate::japanese::{Japanese, JapaneseExtended};
 use crate::persian::Persian;
 use crate::roc::Roc;
+use crate::vietnamese::Vietnamese;
 use crate::{
     types, AsCalendar, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime, Ref,
 };
@@ -90,6 +92,10 @@ pub enum AnyCalendar {
     Gregorian(Gregorian),
     /// A [`Hebrew`] calendar
     Hebrew(Hebrew),
+    /// A [`HinduLunar`] calendar
+    HinduLunar(HinduLunar),
+    /// A [`HinduSolar`] calendar
+    HinduSolar(HinduSolar),
     /// An [`Indian`] calendar
     Indian(Indian),
     /// An [`IslamicCivil`] calendar
@@ -110,6 +116,8 @@ pub enum AnyCalendar {
     Persian(Persian),
     /// A [`Roc`] calendar
     Roc(Roc),
+    /// A [`Vietnamese`] calendar
+    Vietnamese(Vietnamese),
 }
 
 // TODO(#3469): Decide on the best way to implement Ord.
@@ -131,6 +139,10 @@ pub enum AnyDateInner {
     Gregorian(<Gregorian as Calendar>::DateInner),
     /// A date for a [`Hebrew`] calendar
     Hebrew(<Hebrew as Calendar>::DateInner),
+    /// A date for a [`HinduLunar`] calendar
+    HinduLunar(<HinduLunar as Calendar>::DateInner),
+    /// A date for a [`HinduSolar`] calendar
+    HinduSolar(<HinduSolar as Calendar>::DateInner),
     /// A date for an [`Indian`] calendar
     Indian(<Indian as Calendar>::DateInner),
     /// A date for an [`IslamicCivil`] calendar
@@ -151,6 +163,8 @@ pub enum AnyDateInner {
     Persian(<Persian as Calendar>::DateInner),
     /// A date for a [`Roc`] calendar
     Roc(<Roc as Calendar>::DateInner),
+    /// A date for a [`Vietnamese`] calendar
+    Vietnamese(<Vietnamese as Calendar>::DateInner),
 }
 
 macro_rules! match_cal_and_date {
@@ -163,6 +177,14 @@ macro_rules! match_cal_and_date {
             (&Self::Ethiopian(ref $cal_matched), &AnyDateInner::Ethiopian(ref $date_matched)) => $e,
             (&Self::Gregorian(ref $cal_matched), &AnyDateInner::Gregorian(ref $date_matched)) => $e,
             (&Self::Hebrew(ref $cal_matched), &AnyDateInner::Hebrew(ref $date_matched)) => $e,
+            (&Self::HinduLunar(ref $cal_matched), &AnyDateInner::HinduLunar(ref $date_matched)) => {
+                $e
+            }
+            (&Self::HinduSolar(ref $cal_matched), &AnyDateInner::HinduSolar(ref $date_matched))
+                if $cal_matched.region() == $date_matched.region() =>
+            {
+                $e
+            }
             (&Self::Indian(ref $cal_matched), &AnyDateInner::Indian(ref $date_matched)) => $e,
             (
                 &Self::IslamicCivil(ref $cal_matched),
@@ -188,6 +210,9 @@ macro_rules! match_cal_and_date {
             ) => $e,
             (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
             (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
+            (&Self::Vietnamese(ref $cal_matched), &AnyDateInner::Vietnamese(ref $date_matched)) => {
+                $e
+            }
             _ => panic!(
                 "Found AnyCalendar with mixed calendar type {:?} and date type {:?}!",
                 $cal.kind().debug_name(),
@@ -228,6 +253,12 @@ impl Calendar for AnyCalendar {
             Self::Hebrew(ref c) => {
                 AnyDateInner::Hebrew(c.date_from_codes(era, year, month_code, day)?)
             }
+            Self::HinduLunar(ref c) => {
+                AnyDateInner::HinduLunar(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::HinduSolar(ref c) => {
+                AnyDateInner::HinduSolar(c.date_from_codes(era, year, month_code, day)?)
+            }
             Self::Indian(ref c) => {
                 AnyDateInner::Indian(c.date_from_codes(era, year, month_code, day)?)
             }
@@ -254,6 +285,9 @@ impl Calendar for AnyCalendar {
                 AnyDateInner::Persian(c.date_from_codes(era, year, month_code, day)?)
             }
             Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_codes(era, year, month_code, day)?),
+            Self::Vietnamese(ref c) => {
+                AnyDateInner::Vietnamese(c.date_from_codes(era, year, month_code, day)?)
+            }
         };
         Ok(ret)
     }
@@ -266,6 +300,8 @@ impl Calendar for AnyCalendar {
             Self::Ethiopian(ref c) => AnyDateInner::Ethiopian(c.date_from_iso(iso)),
             Self::Gregorian(ref c) => AnyDateInner::Gregorian(c.date_from_iso(iso)),
             Self::Hebrew(ref c) => AnyDateInner::Hebrew(c.date_from_iso(iso)),
+            Self::HinduLunar(ref c) => AnyDateInner::HinduLunar(c.date_from_iso(iso)),
+            Self::HinduSolar(ref c) => AnyDateInner::HinduSolar(c.date_from_iso(iso)),
             Self::Indian(ref c) => AnyDateInner::Indian(c.date_from_iso(iso)),
             Self::IslamicCivil(ref c) => AnyDateInner::IslamicCivil(c.date_from_iso(iso)),
             Self::IslamicObservational(ref c) => {
@@ -278,6 +314,7 @@ impl Calendar for AnyCalendar {
             Self::JapaneseExtended(ref c) => AnyDateInner::JapaneseExtended(c.date_from_iso(iso)),
             Self::Persian(ref c) => AnyDateInner::Persian(c.date_from_iso(iso)),
             Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_iso(iso)),
+            Self::Vietnamese(ref c) => AnyDateInner::Vietnamese(c.date_from_iso(iso)),
         }
     }
 
@@ -320,6 +357,14 @@ impl Calendar for AnyCalendar {
             (Self::Hebrew(c), &mut AnyDateInner::Hebrew(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (Self::HinduLunar(c), &mut AnyDateInner::HinduLunar(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (Self::HinduSolar(c), &mut AnyDateInner::HinduSolar(ref mut d))
+                if c.region() == d.region() =>
+            {
+                c.offset_date(d, offset.cast_unit())
+            }
             (Self::Indian(c), &mut AnyDateInner::Indian(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
@@ -350,6 +395,9 @@ impl Calendar for AnyCalendar {
             (Self::Roc(c), &mut AnyDateInner::Roc(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (Self::Vietnamese(c), &mut AnyDateInner::Vietnamese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             // This is only reached from misuse of from_raw, a semi-internal api
             #[allow(clippy::panic)]
             (_, d) => panic!(
@@ -425,6 +473,22 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::HinduLunar(c1),
+                Self::HinduLunar(c2),
+                AnyDateInner::HinduLunar(d1),
+                AnyDateInner::HinduLunar(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                Self::HinduSolar(c1),
+                Self::HinduSolar(c2),
+                AnyDateInner::HinduSolar(d1),
+                AnyDateInner::HinduSolar(d2),
+            ) if c1 == c2 && c1.region() == d1.region() && c2.region() == d2.region() => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 Self::Indian(c1),
                 Self::Indian(c2),
@@ -495,6 +559,14 @@ impl Calendar for AnyCalendar {
             (Self::Roc(c1), Self::Roc(c2), AnyDateInner::Roc(d1), AnyDateInner::Roc(d2)) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::Vietnamese(c1),
+                Self::Vietnamese(c2),
+                AnyDateInner::Vietnamese(d1),
+                AnyDateInner::Vietnamese(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             _ => {
                 // attempt to convert
                 let iso = calendar2.date_to_iso(date2);
@@ -544,6 +616,8 @@ impl Calendar for AnyCalendar {
             Self::Ethiopian(_) => "AnyCalendar (Ethiopian)",
             Self::Gregorian(_) => "AnyCalendar (Gregorian)",
             Self::Hebrew(_) => "AnyCalendar (Hebrew)",
+            Self::HinduLunar(_) => "AnyCalendar (Hindu lunisolar)",
+            Self::HinduSolar(_) => "AnyCalendar (Hindu solar)",
             Self::Indian(_) => "AnyCalendar (Indian)",
             Self::IslamicCivil(_) => "AnyCalendar (Islamic, civil)",
             Self::IslamicObservational(_) => "AnyCalendar (Islamic, observational)",
@@ -554,6 +628,7 @@ impl Calendar for AnyCalendar {
             Self::JapaneseExtended(_) => "AnyCalendar (Japanese, historical era data)",
             Self::Persian(_) => "AnyCalendar (Persian)",
             Self::Roc(_) => "AnyCalendar (Roc)",
+            Self::Vietnamese(_) => "AnyCalendar (Vietnamese)",
         }
     }
 
@@ -586,6 +661,18 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduVikramSamvat => AnyCalendar::HinduLunar(
+                HinduLunar::new_with_era_style(HinduLunarEraStyle::VikramSamvat),
+            ),
+            AnyCalendarKind::HinduShaka => {
+                AnyCalendar::HinduLunar(HinduLunar::new_with_era_style(HinduLunarEraStyle::Shaka))
+            }
+            AnyCalendarKind::HinduTamil => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Tamil))
+            }
+            AnyCalendarKind::HinduBengali => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Bengali))
+            }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
             AnyCalendarKind::IslamicObservational => {
@@ -600,6 +687,7 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => AnyCalendar::Vietnamese(Vietnamese::new()),
         }
     }
 
@@ -628,6 +716,18 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduVikramSamvat => AnyCalendar::HinduLunar(
+                HinduLunar::new_with_era_style(HinduLunarEraStyle::VikramSamvat),
+            ),
+            AnyCalendarKind::HinduShaka => {
+                AnyCalendar::HinduLunar(HinduLunar::new_with_era_style(HinduLunarEraStyle::Shaka))
+            }
+            AnyCalendarKind::HinduTamil => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Tamil))
+            }
+            AnyCalendarKind::HinduBengali => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Bengali))
+            }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
             AnyCalendarKind::IslamicObservational => {
@@ -644,6 +744,9 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => {
+                AnyCalendar::Vietnamese(Vietnamese::try_new_with_any_provider(provider)?)
+            }
         })
     }
 
@@ -673,6 +776,18 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduVikramSamvat => AnyCalendar::HinduLunar(
+                HinduLunar::new_with_era_style(HinduLunarEraStyle::VikramSamvat),
+            ),
+            AnyCalendarKind::HinduShaka => {
+                AnyCalendar::HinduLunar(HinduLunar::new_with_era_style(HinduLunarEraStyle::Shaka))
+            }
+            AnyCalendarKind::HinduTamil => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Tamil))
+            }
+            AnyCalendarKind::HinduBengali => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Bengali))
+            }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
             AnyCalendarKind::IslamicObservational => {
@@ -689,6 +804,9 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => {
+                AnyCalendar::Vietnamese(Vietnamese::try_new_with_buffer_provider(provider)?)
+            }
         })
     }
 
@@ -699,6 +817,7 @@ impl AnyCalendar {
             + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
             + DataProvider<crate::provider::ChineseCacheV1Marker>
             + DataProvider<crate::provider::DangiCacheV1Marker>
+            + DataProvider<crate::provider::VietnameseCacheV1Marker>
             + ?Sized,
     {
         Ok(match kind {
@@ -714,6 +833,18 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Gregorian => AnyCalendar::Gregorian(Gregorian),
             AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::HinduVikramSamvat => AnyCalendar::HinduLunar(
+                HinduLunar::new_with_era_style(HinduLunarEraStyle::VikramSamvat),
+            ),
+            AnyCalendarKind::HinduShaka => {
+                AnyCalendar::HinduLunar(HinduLunar::new_with_era_style(HinduLunarEraStyle::Shaka))
+            }
+            AnyCalendarKind::HinduTamil => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Tamil))
+            }
+            AnyCalendarKind::HinduBengali => {
+                AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Bengali))
+            }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
             AnyCalendarKind::IslamicObservational => {
@@ -730,6 +861,9 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => {
+                AnyCalendar::Vietnamese(Vietnamese::try_new_unstable(provider)?)
+            }
         })
     }
 
@@ -771,6 +905,7 @@ impl AnyCalendar {
             + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
             + DataProvider<crate::provider::ChineseCacheV1Marker>
             + DataProvider<crate::provider::DangiCacheV1Marker>
+            + DataProvider<crate::provider::VietnameseCacheV1Marker>
             + ?Sized,
     {
         let kind = AnyCalendarKind::from_data_locale_with_fallback(locale);
@@ -790,6 +925,14 @@ impl AnyCalendar {
                 .expect("Ethiopian calendar known to have an AnyCalendarKind"),
             Self::Gregorian(_) => AnyCalendarKind::Gregorian,
             Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            #[allow(clippy::expect_used)] // Invariant known at compile time
+            Self::HinduLunar(ref c) => c
+                .any_calendar_kind()
+                .expect("Hindu lunisolar calendar known to have an AnyCalendarKind"),
+            #[allow(clippy::expect_used)] // Invariant known at compile time
+            Self::HinduSolar(ref c) => c
+                .any_calendar_kind()
+                .expect("Hindu solar calendar known to have an AnyCalendarKind"),
             Self::Indian(_) => AnyCalendarKind::Indian,
             Self::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
             Self::IslamicObservational(_) => AnyCalendarKind::IslamicObservational,
@@ -800,6 +943,7 @@ impl AnyCalendar {
             Self::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
             Self::Persian(_) => AnyCalendarKind::Persian,
             Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
 
@@ -842,6 +986,14 @@ impl AnyDateInner {
             AnyDateInner::Ethiopian(_) => AnyCalendarKind::Ethiopian,
             AnyDateInner::Gregorian(_) => AnyCalendarKind::Gregorian,
             AnyDateInner::Hebrew(_) => AnyCalendarKind::Hebrew,
+            AnyDateInner::HinduLunar(ref d) => match d.era_style() {
+                HinduLunarEraStyle::VikramSamvat => AnyCalendarKind::HinduVikramSamvat,
+                HinduLunarEraStyle::Shaka => AnyCalendarKind::HinduShaka,
+            },
+            AnyDateInner::HinduSolar(ref d) => match d.region() {
+                HinduSolarRegion::Tamil => AnyCalendarKind::HinduTamil,
+                HinduSolarRegion::Bengali => AnyCalendarKind::HinduBengali,
+            },
             AnyDateInner::Indian(_) => AnyCalendarKind::Indian,
             AnyDateInner::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
             AnyDateInner::IslamicObservational(_) => AnyCalendarKind::IslamicObservational,
@@ -852,6 +1004,7 @@ impl AnyDateInner {
             AnyDateInner::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
             AnyDateInner::Persian(_) => AnyCalendarKind::Persian,
             AnyDateInner::Roc(_) => AnyCalendarKind::Roc,
+            AnyDateInner::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
 }
@@ -876,6 +1029,26 @@ pub enum AnyCalendarKind {
     Gregorian,
     /// The kind of a [`Hebrew`] calendar
     Hebrew,
+    /// The kind of a [`HinduLunar`] calendar, with Vikram Samvat era
+    ///
+    /// CLDR defines no BCP-47 value for this calendar, so it cannot be selected with the `-u-ca`
+    /// keyword, and [`AnyCalendarKind::as_bcp47_string()`] returns `None`.
+    HinduVikramSamvat,
+    /// The kind of a [`HinduLunar`] calendar, with Shaka era
+    ///
+    /// CLDR defines no BCP-47 value for this calendar, so it cannot be selected with the `-u-ca`
+    /// keyword, and [`AnyCalendarKind::as_bcp47_string()`] returns `None`.
+    HinduShaka,
+    /// The kind of a [`HinduSolar`] calendar, with Tamil rules
+    ///
+    /// CLDR defines no BCP-47 value for this calendar, so it cannot be selected with the `-u-ca`
+    /// keyword, and [`AnyCalendarKind::as_bcp47_string()`] returns `None`.
+    HinduTamil,
+    /// The kind of a [`HinduSolar`] calendar, with Bengali rules
+    ///
+    /// CLDR defines no BCP-47 value for this calendar, so it cannot be selected with the `-u-ca`
+    /// keyword, and [`AnyCalendarKind::as_bcp47_string()`] returns `None`.
+    HinduBengali,
     /// The kind of a [`Indian`] calendar
     Indian,
     /// The kind of an [`IslamicCivil`] calendar
@@ -896,6 +1069,11 @@ pub enum AnyCalendarKind {
     Persian,
     /// The kind of a [`Roc`] calendar
     Roc,
+    /// The kind of a [`Vietnamese`] calendar
+    ///
+    /// CLDR defines no BCP-47 value for this calendar, so it cannot be selected with the `-u-ca`
+    /// keyword, and [`AnyCalendarKind::as_bcp47_string()`] returns `None`.
+    Vietnamese,
 }
 
 impl AnyCalendarKind {
@@ -978,8 +1156,10 @@ impl AnyCalendarKind {
     }
 
     /// Convert to a BCP-47 string
-    pub fn as_bcp47_string(self) -> &'static str {
-        match self {
+    ///
+    /// Returns `None` if CLDR defines no BCP-47 value for this calendar.
+    pub fn as_bcp47_string(self) -> Option<&'static str> {
+        Some(match self {
             AnyCalendarKind::Buddhist => "buddhist",
             AnyCalendarKind::Chinese => "chinese",
             AnyCalendarKind::Coptic => "coptic",
@@ -988,6 +1168,11 @@ impl AnyCalendarKind {
             AnyCalendarKind::EthiopianAmeteAlem => "ethioaa",
             AnyCalendarKind::Gregorian => "gregory",
             AnyCalendarKind::Hebrew => "hebrew",
+            AnyCalendarKind::HinduVikramSamvat
+            | AnyCalendarKind::HinduShaka
+            | AnyCalendarKind::HinduTamil
+            | AnyCalendarKind::HinduBengali
+            | AnyCalendarKind::Vietnamese => return None,
             AnyCalendarKind::Indian => "indian",
             AnyCalendarKind::IslamicCivil => "islamic-civil",
             AnyCalendarKind::IslamicObservational => "islamic",
@@ -998,13 +1183,15 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => "japanext",
             AnyCalendarKind::Persian => "persian",
             AnyCalendarKind::Roc => "roc",
-        }
+        })
     }
 
     /// Convert to a BCP-47 `Value`
+    ///
+    /// Returns `None` if CLDR defines no BCP-47 value for this calendar.
     #[allow(clippy::unwrap_used)] // these are known-good BCP47 unicode extension values
-    pub fn as_bcp47_value(self) -> Value {
-        match self {
+    pub fn as_bcp47_value(self) -> Option<Value> {
+        Some(match self {
             AnyCalendarKind::Buddhist => value!("buddhist"),
             AnyCalendarKind::Chinese => value!("chinese"),
             AnyCalendarKind::Coptic => value!("coptic"),
@@ -1013,6 +1200,11 @@ impl AnyCalendarKind {
             AnyCalendarKind::EthiopianAmeteAlem => value!("ethioaa"),
             AnyCalendarKind::Gregorian => value!("gregory"),
             AnyCalendarKind::Hebrew => value!("hebrew"),
+            AnyCalendarKind::HinduVikramSamvat
+            | AnyCalendarKind::HinduShaka
+            | AnyCalendarKind::HinduTamil
+            | AnyCalendarKind::HinduBengali
+            | AnyCalendarKind::Vietnamese => return None,
             AnyCalendarKind::Indian => value!("indian"),
             AnyCalendarKind::IslamicCivil => Value::try_from_bytes(b"islamic-civil").unwrap(),
             AnyCalendarKind::IslamicObservational => value!("islamic"),
@@ -1025,7 +1217,7 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => value!("japanext"),
             AnyCalendarKind::Persian => value!("persian"),
             AnyCalendarKind::Roc => value!("roc"),
-        }
+        })
     }
 
     fn debug_name(self) -> &'static str {
@@ -1038,6 +1230,15 @@ impl AnyCalendarKind {
             AnyCalendarKind::EthiopianAmeteAlem => Ethiopian(true).debug_name(),
             AnyCalendarKind::Gregorian => Gregorian.debug_name(),
             AnyCalendarKind::Hebrew => Hebrew.debug_name(),
+            AnyCalendarKind::HinduVikramSamvat | AnyCalendarKind::HinduShaka => {
+                HinduLunar::new().debug_name()
+            }
+            AnyCalendarKind::HinduTamil => {
+                HinduSolar::new_with_region(HinduSolarRegion::Tamil).debug_name()
+            }
+            AnyCalendarKind::HinduBengali => {
+                HinduSolar::new_with_region(HinduSolarRegion::Bengali).debug_name()
+            }
             AnyCalendarKind::Indian => Indian.debug_name(),
             AnyCalendarKind::IslamicCivil => IslamicCivil.debug_name(),
             AnyCalendarKind::IslamicObservational => IslamicObservational.debug_name(),
@@ -1048,6 +1249,7 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => JapaneseExtended::DEBUG_NAME,
             AnyCalendarKind::Persian => Persian.debug_name(),
             AnyCalendarKind::Roc => Roc.debug_name(),
+            AnyCalendarKind::Vietnamese => Vietnamese::DEBUG_NAME,
         }
     }
 
@@ -1207,6 +1409,30 @@ impl IntoAnyCalendar for Hebrew {
     }
 }
 
+impl IntoAnyCalendar for HinduLunar {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::HinduLunar(self)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::HinduLunar(*self)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::HinduLunar(*d)
+    }
+}
+
+impl IntoAnyCalendar for HinduSolar {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::HinduSolar(self)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::HinduSolar(*self)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::HinduSolar(*d)
+    }
+}
+
 impl IntoAnyCalendar for Indian {
     fn to_any(self) -> AnyCalendar {
         AnyCalendar::Indian(Indian)
@@ -1327,6 +1553,18 @@ impl IntoAnyCalendar for Roc {
     }
 }
 
+impl IntoAnyCalendar for Vietnamese {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Vietnamese(self)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Vietnamese(self.clone())
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Vietnamese(*d)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1410,6 +1648,10 @@ mod tests {
         let ethiopian = AnyCalendar::new(AnyCalendarKind::Ethiopian);
         let gregorian = AnyCalendar::new(AnyCalendarKind::Gregorian);
         let hebrew = AnyCalendar::new(AnyCalendarKind::Hebrew);
+        let hindu_vikram = AnyCalendar::new(AnyCalendarKind::HinduVikramSamvat);
+        let hindu_shaka = AnyCalendar::new(AnyCalendarKind::HinduShaka);
+        let hindu_tamil = AnyCalendar::new(AnyCalendarKind::HinduTamil);
+        let hindu_bengali = AnyCalendar::new(AnyCalendarKind::HinduBengali);
         let indian = AnyCalendar::new(AnyCalendarKind::Indian);
         let islamic_civil: AnyCalendar = AnyCalendar::new(AnyCalendarKind::IslamicCivil);
         let islamic_observational: AnyCalendar =
@@ -1420,6 +1662,7 @@ mod tests {
         let japanext = AnyCalendar::new(AnyCalendarKind::JapaneseExtended);
         let persian = AnyCalendar::new(AnyCalendarKind::Persian);
         let roc = AnyCalendar::new(AnyCalendarKind::Roc);
+        let vietnamese = AnyCalendar::new(AnyCalendarKind::Vietnamese);
         let buddhist = Ref(&buddhist);
         let chinese = Ref(&chinese);
         let coptic = Ref(&coptic);
@@ -1428,6 +1671,10 @@ mod tests {
         let ethiopian = Ref(&ethiopian);
         let gregorian = Ref(&gregorian);
         let hebrew = Ref(&hebrew);
+        let hindu_vikram = Ref(&hindu_vikram);
+        let hindu_shaka = Ref(&hindu_shaka);
+        let hindu_tamil = Ref(&hindu_tamil);
+        let hindu_bengali = Ref(&hindu_bengali);
         let indian = Ref(&indian);
         let islamic_civil = Ref(&islamic_civil);
         let islamic_observational = Ref(&islamic_observational);
@@ -1437,6 +1684,7 @@ mod tests {
         let japanext = Ref(&japanext);
         let persian = Ref(&persian);
         let roc = Ref(&roc);
+        let vietnamese = Ref(&vietnamese);
 
         single_test_roundtrip(buddhist, "be", 100, "M03", 1);
         single_test_roundtrip(buddhist, "be", 2000, "M03", 1);
@@ -1649,10 +1897,81 @@ mod tests {
             ),
         );
 
+        single_test_roundtrip(hindu_vikram, "vikram", 2081, "M01", 1);
+        single_test_roundtrip(hindu_vikram, "vikram", 2080, "M05L", 1);
+        single_test_roundtrip(hindu_vikram, "vikram", 2080, "M12", 15);
+        single_test_error(
+            hindu_vikram,
+            "vikram",
+            2081,
+            "M05L",
+            1,
+            CalendarError::UnknownMonthCode(
+                "M05L".parse().unwrap(),
+                AnyCalendarKind::HinduVikramSamvat.debug_name(),
+            ),
+        );
+
+        single_test_roundtrip(hindu_shaka, "saka", 1946, "M01", 1);
+        single_test_error(
+            hindu_shaka,
+            "saka",
+            1946,
+            "M01",
+            31,
+            CalendarError::Overflow {
+                field: "day",
+                max: 30,
+            },
+        );
+
+        single_test_roundtrip(hindu_tamil, "saka", 1946, "M01", 1);
+        single_test_roundtrip(hindu_tamil, "saka", 1945, "M10", 1);
+        single_test_error(
+            hindu_tamil,
+            "saka",
+            1946,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode(
+                "M13".parse().unwrap(),
+                AnyCalendarKind::HinduTamil.debug_name(),
+            ),
+        );
+
+        single_test_roundtrip(hindu_bengali, "bangabda", 1431, "M01", 1);
+        single_test_roundtrip(hindu_bengali, "bangabda", 1430, "M10", 1);
+        single_test_error(
+            hindu_bengali,
+            "saka",
+            1946,
+            "M01",
+            1,
+            CalendarError::UnknownEra(
+                "saka".parse().unwrap(),
+                AnyCalendarKind::HinduBengali.debug_name(),
+            ),
+        );
+
         single_test_roundtrip(roc, "roc", 10, "M05", 3);
         single_test_roundtrip(roc, "roc-inverse", 15, "M01", 10);
         single_test_roundtrip(roc, "roc", 100, "M10", 30);
 
+        single_test_roundtrip(vietnamese, "vietnam", 4682, "M01", 1);
+        single_test_roundtrip(vietnamese, "vietnam", 4660, "M02L", 15);
+        single_test_roundtrip(vietnamese, "vietnam", -1300, "M11", 12);
+        single_test_error(
+            vietnamese,
+            "chinese",
+            4660,
+            "M01",
+            1,
+            CalendarError::UnknownEra(
+                "chinese".parse().unwrap(),
+                AnyCalendarKind::Vietnamese.debug_name(),
+            ),
+        );
+
         single_test_roundtrip(islamic_observational, "islamic", 477, "M03", 1);
         single_test_roundtrip(islamic_observational, "islamic", 2083, "M07", 21);
         single_test_roundtrip(islamic_observational, "islamic", 1600, "M12", 20);
@@ -1713,4 +2032,29 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn test_hindu_kinds() {
+        let iso = Date::try_new_iso_date(2024, 4, 14).unwrap();
+        for kind in [
+            AnyCalendarKind::HinduVikramSamvat,
+            AnyCalendarKind::HinduShaka,
+            AnyCalendarKind::HinduTamil,
+            AnyCalendarKind::HinduBengali,
+        ] {
+            let calendar = AnyCalendar::new(kind);
+            assert_eq!(calendar.date_from_iso(iso).kind(), kind);
+            assert_eq!(kind.as_bcp47_string(), None);
+            assert_eq!(kind.as_bcp47_value(), None);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mixed calendar type")]
+    fn test_hindu_solar_region_mismatch() {
+        let tamil = AnyCalendar::new(AnyCalendarKind::HinduTamil);
+        let bengali = AnyCalendar::new(AnyCalendarKind::HinduBengali);
+        let date = tamil.date_from_iso(Date::try_new_iso_date(2024, 4, 14).unwrap());
+        bengali.day_of_month(&date);
+    }
 }
@@ -218,6 +218,11 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
             // For offset_date to work with lunar calendars, need to handle an edge case where the original month is not valid in the future year.
             self.year += offset.years;
             self.year_info = data.load_or_compute_info(self.year);
+            // The last month of a leap year may not exist in the future year; use the
+            // last month of that year instead
+            self.month = self
+                .month
+                .min(C::months_for_every_year(self.year, self.year_info));
         }
 
         self.offset_months(offset.months, data);
@@ -415,7 +420,8 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Iso;
+    use crate::hebrew::Hebrew;
+    use crate::{Date, Iso};
 
     #[test]
     fn test_ord() {
@@ -445,4 +451,19 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_offset_years_from_last_month_of_leap_year() {
+        // Elul is the 13th month of the leap year 5782, but the 12th month of 5783
+        let elul_5782 = Date::try_new_hebrew_date_with_calendar(5782, 13, 29, Hebrew).unwrap();
+        let elul_5783 = Date::try_new_hebrew_date_with_calendar(5783, 12, 29, Hebrew).unwrap();
+        assert_eq!(
+            elul_5782.clone().added(DateDuration::new(1, 0, 0, 0)),
+            elul_5783
+        );
+
+        // Adding months afterwards starts from the clamped month
+        let tishrei_5784 = Date::try_new_hebrew_date_with_calendar(5784, 1, 29, Hebrew).unwrap();
+        assert_eq!(elul_5782.added(DateDuration::new(1, 1, 0, 0)), tishrei_5784);
+    }
 }
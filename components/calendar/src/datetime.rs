@@ -3,8 +3,12 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IntoAnyCalendar};
+use crate::duration::{
+    ArithmeticOverflow, DateTimeDifferenceOptions, DateTimeDuration, DateTimeDurationUnit,
+    TimeDuration,
+};
 use crate::types::{self, Time};
-use crate::{AsCalendar, Calendar, CalendarError, Date, Iso};
+use crate::{AsCalendar, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, Iso};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
 
@@ -89,6 +93,135 @@ impl<A: AsCalendar> DateTime<A> {
             time: self.time,
         }
     }
+
+    /// Add a `duration` to this datetime, mutating it
+    ///
+    /// This follows the semantics of `PlainDateTime.prototype.add` in TC39 Temporal:
+    /// the time part of the duration is added first, carrying over into days, and then
+    /// the years and months are added, followed by the weeks and days.
+    ///
+    /// If adding years and months lands on a day that does not exist in the resulting month,
+    /// `overflow` decides whether to clamp it to the end of the month or to return an error.
+    /// `self` is unchanged if an error is returned.
+    ///
+    /// ```rust
+    /// use icu::calendar::{
+    ///     ArithmeticOverflow, DateDuration, DateTime, DateTimeDuration,
+    ///     TimeDuration,
+    /// };
+    ///
+    /// let mut datetime = DateTime::try_new_iso_datetime(2024, 1, 31, 22, 0, 0)
+    ///     .expect("Failed to initialize ISO DateTime instance.");
+    ///
+    /// datetime
+    ///     .add(
+    ///         DateTimeDuration::new(
+    ///             DateDuration::new(0, 1, 0, 0),
+    ///             TimeDuration::new(2, 30, 0, 0),
+    ///         ),
+    ///         ArithmeticOverflow::Constrain,
+    ///     )
+    ///     .expect("Constrained addition cannot fail");
+    ///
+    /// // The time wraps around into one extra day, which is added after the month:
+    /// // 2024-01-31 + 1 month = 2024-02-29 (constrained), + 1 day = 2024-03-01
+    /// assert_eq!(
+    ///     datetime,
+    ///     DateTime::try_new_iso_datetime(2024, 3, 1, 0, 30, 0).unwrap()
+    /// );
+    /// ```
+    pub fn add(
+        &mut self,
+        duration: DateTimeDuration<A::Calendar>,
+        overflow: ArithmeticOverflow,
+    ) -> Result<(), CalendarError> {
+        let (time, extra_days) = Time::from_nanosecond_with_remainder_days(
+            self.time.nanoseconds_since_midnight() + duration.time.total_nanoseconds(),
+        );
+        let days = i32::try_from(i128::from(duration.date.days) + extra_days)
+            .map_err(|_| CalendarError::OutOfRange)?;
+        add_date_duration(
+            self.date.calendar.as_calendar(),
+            &mut self.date.inner,
+            DateDuration::new(
+                duration.date.years,
+                duration.date.months,
+                duration.date.weeks,
+                days,
+            ),
+            overflow,
+        )?;
+        self.time = time;
+        Ok(())
+    }
+
+    /// Add a `duration` to this datetime, returning the new one
+    ///
+    /// See [`Self::add()`] for details.
+    pub fn added(
+        mut self,
+        duration: DateTimeDuration<A::Calendar>,
+        overflow: ArithmeticOverflow,
+    ) -> Result<Self, CalendarError> {
+        self.add(duration, overflow)?;
+        Ok(self)
+    }
+
+    /// Calculate the duration `other - self`
+    ///
+    /// This follows the semantics of `PlainDateTime.prototype.until` in TC39 Temporal:
+    /// the result contains no fields larger than `options.largest_unit` or smaller than
+    /// `options.smallest_unit`, and the remainder is rounded with `options.rounding_mode`.
+    /// Adding the result to `self` (with [`ArithmeticOverflow::Constrain`]) yields `other`
+    /// if no rounding took place.
+    ///
+    /// Returns an error if `options.largest_unit` is smaller than `options.smallest_unit`,
+    /// or if the result does not fit into the duration fields.
+    ///
+    /// ```rust
+    /// use icu::calendar::{
+    ///     DateTime, DateTimeDifferenceOptions, DateTimeDurationUnit, RoundingMode,
+    /// };
+    ///
+    /// let start = DateTime::try_new_iso_datetime(2024, 1, 15, 22, 0, 0).unwrap();
+    /// let end = DateTime::try_new_iso_datetime(2024, 2, 16, 0, 30, 0).unwrap();
+    ///
+    /// let duration = start
+    ///     .until(
+    ///         &end,
+    ///         DateTimeDifferenceOptions::new(
+    ///             DateTimeDurationUnit::Months,
+    ///             DateTimeDurationUnit::Minutes,
+    ///         ),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(duration.date.months, 1);
+    /// assert_eq!(duration.date.days, 0);
+    /// assert_eq!(duration.time.hours, 2);
+    /// assert_eq!(duration.time.minutes, 30);
+    ///
+    /// let mut options = DateTimeDifferenceOptions::new(
+    ///     DateTimeDurationUnit::Hours,
+    ///     DateTimeDurationUnit::Hours,
+    /// );
+    /// options.rounding_mode = RoundingMode::HalfExpand;
+    /// let duration = start.until(&end, options).unwrap();
+    /// assert_eq!(duration.date.days, 0);
+    /// assert_eq!(duration.time.hours, 747);
+    /// ```
+    pub fn until<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &DateTime<B>,
+        options: DateTimeDifferenceOptions,
+    ) -> Result<DateTimeDuration<A::Calendar>, CalendarError> {
+        let calendar = self.date.calendar.as_calendar();
+        datetime_until(
+            calendar,
+            (self.date.inner.clone(), self.time),
+            (calendar.date_from_iso(other.date.to_iso()), other.time),
+            options,
+        )
+    }
 }
 
 impl<C: IntoAnyCalendar, A: AsCalendar<Calendar = C>> DateTime<A> {
@@ -179,9 +312,544 @@ where
 {
 }
 
+const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The fixed (Rata Die) day number of a date in any calendar
+fn fixed_day<C: Calendar>(calendar: &C, date: &C::DateInner) -> i128 {
+    i128::from(Iso::fixed_from_iso(*calendar.date_to_iso(date).inner()).to_i64_date())
+}
+
+/// Add `years` and `months` to `date`, clamping the day to the end of the resulting month.
+///
+/// Returns the number of days in the resulting month if the day had to be clamped.
+fn offset_years_months<C: Calendar>(
+    calendar: &C,
+    date: &mut C::DateInner,
+    years: i32,
+    months: i32,
+) -> Option<u32> {
+    if years == 0 && months == 0 {
+        return None;
+    }
+    let day = calendar.day_of_month(date).0;
+    calendar.offset_date(date, DateDuration::new(years, months, 0, 0));
+    let overflowed_day = calendar.day_of_month(date).0;
+    if overflowed_day == day {
+        None
+    } else {
+        // The day does not exist in the resulting month, and `offset_date` spilled it over
+        // into the next month. Move back to the last day of the intended month.
+        calendar.offset_date(date, DateDuration::new(0, 0, 0, -(overflowed_day as i32)));
+        Some(calendar.day_of_month(date).0)
+    }
+}
+
+/// Add `duration` to `date` following Temporal's `CalendarDateAdd`
+fn add_date_duration<C: Calendar>(
+    calendar: &C,
+    date: &mut C::DateInner,
+    duration: DateDuration<C>,
+    overflow: ArithmeticOverflow,
+) -> Result<(), CalendarError> {
+    let mut result = date.clone();
+    if let Some(max) = offset_years_months(calendar, &mut result, duration.years, duration.months) {
+        if overflow == ArithmeticOverflow::Reject {
+            return Err(CalendarError::Overflow {
+                field: "day",
+                max: max as usize,
+            });
+        }
+    }
+    if duration.weeks != 0 || duration.days != 0 {
+        calendar.offset_date(
+            &mut result,
+            DateDuration::new(0, 0, duration.weeks, duration.days),
+        );
+    }
+    *date = result;
+    Ok(())
+}
+
+/// `date + duration`, with clamping
+fn added_date<C: Calendar>(
+    calendar: &C,
+    date: &C::DateInner,
+    duration: DateDuration<C>,
+) -> C::DateInner {
+    let mut result = date.clone();
+    #[allow(clippy::unwrap_used)] // constrained addition does not fail
+    add_date_duration(
+        calendar,
+        &mut result,
+        duration,
+        ArithmeticOverflow::Constrain,
+    )
+    .unwrap();
+    result
+}
+
+/// Calculate `date2 - date1` following Temporal's `CalendarDateUntil`, truncating
+/// at days
+///
+/// Fails if the resulting fields do not fit into a [`DateDuration`].
+fn date_until<C: Calendar>(
+    calendar: &C,
+    date1: &C::DateInner,
+    date2: &C::DateInner,
+    largest_unit: DateDurationUnit,
+) -> Result<DateDuration<C>, CalendarError> {
+    let fixed2 = fixed_day(calendar, date2);
+    let days = fixed2 - fixed_day(calendar, date1);
+    let field = |value: i128| i32::try_from(value).map_err(|_| CalendarError::OutOfRange);
+    match largest_unit {
+        DateDurationUnit::Days => return Ok(DateDuration::new(0, 0, 0, field(days)?)),
+        DateDurationUnit::Weeks => {
+            return Ok(DateDuration::new(0, 0, field(days / 7)?, field(days % 7)?))
+        }
+        DateDurationUnit::Years | DateDurationUnit::Months => {}
+    }
+    if days == 0 {
+        return Ok(DateDuration::default());
+    }
+    let sign = if days > 0 { 1 } else { -1 };
+
+    // Whether `date1 + (years, months)` is past `date2`. A date that had to be clamped
+    // to the end of its month counts as past the end of that month.
+    let surpasses = |years: i32, months: i32| {
+        let mut candidate = date1.clone();
+        let clamped = offset_years_months(calendar, &mut candidate, years, months).is_some();
+        let remaining = fixed2 - fixed_day(calendar, &candidate);
+        if sign > 0 {
+            remaining < 0 || (remaining == 0 && clamped)
+        } else {
+            remaining > 0
+        }
+    };
+
+    // No calendar has a year longer than 390 days or a month longer than 33 days,
+    // so these estimates never overshoot.
+    let mut years = 0;
+    if largest_unit == DateDurationUnit::Years {
+        years = field(days / 390)?;
+        while !surpasses(years + sign, 0) {
+            years += sign;
+        }
+    }
+    let mut months = 0;
+    if largest_unit == DateDurationUnit::Months {
+        months = field(days / 33)?;
+    }
+    while !surpasses(years, months + sign) {
+        months += sign;
+    }
+
+    let intermediate = added_date(calendar, date1, DateDuration::new(years, months, 0, 0));
+    let days = field(fixed2 - fixed_day(calendar, &intermediate))?;
+    Ok(DateDuration::new(years, months, 0, days))
+}
+
+/// `duration` with all fields smaller than `unit` set to zero
+fn truncate_date_duration<C: Calendar>(
+    duration: &DateDuration<C>,
+    unit: DateDurationUnit,
+) -> DateDuration<C> {
+    match unit {
+        DateDurationUnit::Years => DateDuration::new(duration.years, 0, 0, 0),
+        DateDurationUnit::Months => DateDuration::new(duration.years, duration.months, 0, 0),
+        DateDurationUnit::Weeks => {
+            DateDuration::new(duration.years, duration.months, duration.weeks, 0)
+        }
+        DateDurationUnit::Days => *duration,
+    }
+}
+
+/// A mutable reference to the field of `duration` corresponding to `unit`
+fn date_duration_field<C: Calendar>(
+    duration: &mut DateDuration<C>,
+    unit: DateDurationUnit,
+) -> &mut i32 {
+    match unit {
+        DateDurationUnit::Years => &mut duration.years,
+        DateDurationUnit::Months => &mut duration.months,
+        DateDurationUnit::Weeks => &mut duration.weeks,
+        DateDurationUnit::Days => &mut duration.days,
+    }
+}
+
+/// Calculate `end - start` following Temporal's `DifferencePlainDateTimeWithRounding`
+fn datetime_until<C: Calendar>(
+    calendar: &C,
+    start: (C::DateInner, Time),
+    end: (C::DateInner, Time),
+    options: DateTimeDifferenceOptions,
+) -> Result<DateTimeDuration<C>, CalendarError> {
+    let DateTimeDifferenceOptions {
+        largest_unit,
+        smallest_unit,
+        rounding_mode,
+    } = options;
+    if largest_unit > smallest_unit {
+        return Err(CalendarError::OutOfRange);
+    }
+
+    // Normalize end-of-day times such as 24:00 into the next day
+    let normalize = |(mut date, time): (C::DateInner, Time)| {
+        let (time, extra_days) =
+            Time::from_nanosecond_with_remainder_days(time.nanoseconds_since_midnight());
+        if extra_days != 0 {
+            let extra_days = i32::try_from(extra_days).map_err(|_| CalendarError::OutOfRange)?;
+            calendar.offset_date(&mut date, DateDuration::new(0, 0, 0, extra_days));
+        }
+        Ok::<_, CalendarError>((date, time.nanoseconds_since_midnight()))
+    };
+    let (date1, time1) = normalize(start)?;
+    let (date2, time2) = normalize(end)?;
+
+    // Positions on a timeline of nanoseconds, where dates reached by adding a
+    // date duration to `date1` keep the time of day of `start`
+    let position = |date: &C::DateInner| fixed_day(calendar, date) * NANOSECONDS_PER_DAY + time1;
+    let target = fixed_day(calendar, &date2) * NANOSECONDS_PER_DAY + time2;
+    let total = target - position(&date1);
+    if total == 0 {
+        return Ok(DateTimeDuration::default());
+    }
+    let sign = total.signum();
+    let negative = sign < 0;
+
+    // If the time of day difference points the other way than the date difference,
+    // borrow a day from the dates
+    let mut time_difference = time2 - time1;
+    let date_sign = (fixed_day(calendar, &date2) - fixed_day(calendar, &date1)).signum();
+    let mut adjusted = date2;
+    if time_difference != 0 && time_difference.signum() == -date_sign {
+        calendar.offset_date(
+            &mut adjusted,
+            DateDuration::new(0, 0, 0, time_difference.signum() as i32),
+        );
+        time_difference -= time_difference.signum() * NANOSECONDS_PER_DAY;
+    }
+
+    let (mut date_duration, mut time_nanoseconds) = match largest_unit.date_unit() {
+        Some(unit) => (
+            date_until(calendar, &date1, &adjusted, unit)?,
+            time_difference,
+        ),
+        None => (DateDuration::default(), total),
+    };
+
+    // The smallest unit whose rounding may have carried over into a larger unit
+    let mut carried_from = None;
+    match smallest_unit.date_unit() {
+        None => {
+            let increment = smallest_unit.nanoseconds().unwrap_or(1);
+            let magnitude = time_nanoseconds.abs();
+            let mut whole = magnitude / increment;
+            if rounding_mode.rounds_away_from_zero(
+                negative,
+                whole,
+                magnitude % increment,
+                increment,
+            ) {
+                whole += 1;
+            }
+            time_nanoseconds = sign * whole * increment;
+            if largest_unit.date_unit().is_some() && time_nanoseconds.abs() >= NANOSECONDS_PER_DAY {
+                date_duration.days += sign as i32;
+                time_nanoseconds -= sign * NANOSECONDS_PER_DAY;
+                carried_from = Some(DateDurationUnit::Days);
+            }
+        }
+        Some(unit) => {
+            if unit == DateDurationUnit::Weeks {
+                date_duration.weeks += date_duration.days / 7;
+                date_duration.days %= 7;
+            }
+            // Round relative to `start` by finding where the truncated duration and the
+            // duration one `unit` further land, and how far `end` is between the two
+            let mut lower = truncate_date_duration(&date_duration, unit);
+            let mut upper = lower;
+            *date_duration_field(&mut upper, unit) += sign as i32;
+            let lower_position = position(&added_date(calendar, &date1, lower));
+            let upper_position = position(&added_date(calendar, &date1, upper));
+            let whole = i128::from(date_duration_field(&mut lower, unit).abs());
+            if rounding_mode.rounds_away_from_zero(
+                negative,
+                whole,
+                (target - lower_position).abs(),
+                (upper_position - lower_position).abs(),
+            ) {
+                date_duration = upper;
+                carried_from = Some(unit);
+            } else {
+                date_duration = lower;
+            }
+            time_nanoseconds = 0;
+        }
+    }
+
+    // Rounding up may have produced a whole larger unit, e.g. 7 days or 12 months
+    if let Some(carried_from) = carried_from {
+        let rounded_position = position(&added_date(calendar, &date1, date_duration));
+        for unit in [
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Months,
+            DateDurationUnit::Years,
+        ] {
+            let unit_as_datetime = DateTimeDurationUnit::from(unit);
+            if unit_as_datetime >= DateTimeDurationUnit::from(carried_from)
+                || unit_as_datetime < largest_unit
+                || (unit == DateDurationUnit::Weeks && largest_unit != DateTimeDurationUnit::Weeks)
+            {
+                continue;
+            }
+            let mut candidate = truncate_date_duration(&date_duration, unit);
+            *date_duration_field(&mut candidate, unit) += sign as i32;
+            let candidate_position = position(&added_date(calendar, &date1, candidate));
+            if sign * (rounded_position - candidate_position) >= 0 {
+                date_duration = candidate;
+            } else {
+                break;
+            }
+        }
+    }
+
+    Ok(DateTimeDuration::new(
+        date_duration,
+        TimeDuration::from_nanoseconds(time_nanoseconds, largest_unit)
+            .ok_or(CalendarError::OutOfRange)?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hebrew::Hebrew;
+    use crate::RoundingMode;
+
+    fn iso_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        nanosecond: u32,
+    ) -> DateTime<Iso> {
+        DateTime::new(
+            Date::try_new_iso_date(year, month, day).unwrap(),
+            Time::try_new(hour, minute, 0, nanosecond).unwrap(),
+        )
+    }
+
+    fn until(
+        start: &DateTime<Iso>,
+        end: &DateTime<Iso>,
+        largest_unit: DateTimeDurationUnit,
+        smallest_unit: DateTimeDurationUnit,
+        rounding_mode: RoundingMode,
+    ) -> DateTimeDuration<Iso> {
+        let mut options = DateTimeDifferenceOptions::new(largest_unit, smallest_unit);
+        options.rounding_mode = rounding_mode;
+        start.until(end, options).unwrap()
+    }
+
+    #[test]
+    fn test_add_across_day_boundary() {
+        let mut datetime = DateTime::new_from_iso(iso_datetime(2024, 3, 10, 23, 0, 0), Hebrew);
+        datetime
+            .add(
+                DateTimeDuration::new(Default::default(), TimeDuration::new(2, 30, 0, 0)),
+                ArithmeticOverflow::Reject,
+            )
+            .unwrap();
+        assert_eq!(datetime.to_iso(), iso_datetime(2024, 3, 11, 1, 30, 0));
+
+        datetime
+            .add(
+                DateTimeDuration::new(Default::default(), TimeDuration::new(-49, 0, 0, 1)),
+                ArithmeticOverflow::Reject,
+            )
+            .unwrap();
+        assert_eq!(datetime.to_iso(), iso_datetime(2024, 3, 9, 0, 30, 1));
+    }
+
+    #[test]
+    fn test_add_overflow() {
+        let start = iso_datetime(2023, 1, 31, 12, 0, 0);
+        let one_month = DateTimeDuration::new(DateDuration::new(0, 1, 0, 0), Default::default());
+        assert_eq!(
+            start.added(one_month, ArithmeticOverflow::Constrain),
+            Ok(iso_datetime(2023, 2, 28, 12, 0, 0))
+        );
+        assert_eq!(
+            start.added(one_month, ArithmeticOverflow::Reject),
+            Err(CalendarError::Overflow {
+                field: "day",
+                max: 28
+            })
+        );
+    }
+
+    #[test]
+    fn test_until_balancing() {
+        use DateTimeDurationUnit::*;
+        use RoundingMode::*;
+
+        let start = iso_datetime(2024, 1, 1, 10, 0, 0);
+        let end = iso_datetime(2024, 1, 2, 9, 0, 0);
+        let duration = until(&start, &end, Days, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::default());
+        assert_eq!(duration.time, TimeDuration::new(23, 0, 0, 0));
+        let duration = until(&end, &start, Days, Nanoseconds, Trunc);
+        assert_eq!(duration.time, TimeDuration::new(-23, 0, 0, 0));
+        let duration = until(&start, &end, Minutes, Nanoseconds, Trunc);
+        assert_eq!(duration.time, TimeDuration::new(0, 23 * 60, 0, 0));
+
+        // January 31 plus one month does not surpass March 1
+        let start = iso_datetime(2023, 1, 31, 0, 0, 0);
+        let end = iso_datetime(2023, 3, 1, 0, 0, 0);
+        let duration = until(&start, &end, Years, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, 1, 0, 1));
+
+        // ...but it does surpass February 28
+        let end = iso_datetime(2023, 2, 28, 0, 0, 0);
+        let duration = until(&start, &end, Years, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 0, 28));
+
+        let start = iso_datetime(2023, 3, 31, 0, 0, 0);
+        let duration = until(&start, &end, Years, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, -1, 0, 0));
+
+        let start = iso_datetime(2020, 2, 29, 0, 0, 0);
+        let end = iso_datetime(2024, 2, 28, 0, 0, 0);
+        let duration = until(&start, &end, Years, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::new(3, 11, 0, 30));
+        let duration = until(&start, &end, Weeks, Nanoseconds, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 208, 4));
+
+        assert_eq!(
+            start.until(&end, DateTimeDifferenceOptions::new(Hours, Days)),
+            Err(CalendarError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_until_rounding() {
+        use DateTimeDurationUnit::*;
+        use RoundingMode::*;
+
+        let start = iso_datetime(2024, 1, 1, 0, 0, 0);
+        let end = iso_datetime(2024, 1, 31, 12, 0, 0);
+        let duration = until(&start, &end, Months, Days, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 0, 30));
+        assert_eq!(duration.time, TimeDuration::default());
+        let duration = until(&start, &end, Months, Days, HalfTrunc);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 0, 30));
+        // Rounding up to 31 days reaches a whole month
+        let duration = until(&start, &end, Months, Days, HalfExpand);
+        assert_eq!(duration.date, DateDuration::new(0, 1, 0, 0));
+        let duration = until(&end, &start, Months, Days, Floor);
+        assert_eq!(duration.date, DateDuration::new(0, -1, 0, 0));
+        let duration = until(&end, &start, Months, Days, Ceil);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 0, -30));
+
+        let end = iso_datetime(2024, 1, 20, 12, 0, 0);
+        let duration = until(&start, &end, Weeks, Weeks, HalfExpand);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 3, 0));
+        let duration = until(&start, &end, Weeks, Weeks, Trunc);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 2, 0));
+
+        let start = iso_datetime(2024, 1, 1, 23, 59, 999_999_999);
+        let end = iso_datetime(2024, 1, 1, 23, 59, 499_999_999);
+        let duration = until(&start, &end, Hours, Seconds, HalfEven);
+        assert_eq!(duration.time, TimeDuration::new(0, 0, 0, 0));
+        let duration = until(&start, &end, Hours, Seconds, Expand);
+        assert_eq!(duration.time, TimeDuration::new(0, 0, -1, 0));
+        let duration = until(&start, &end, Hours, Milliseconds, Trunc);
+        assert_eq!(duration.time.milliseconds, -500);
+
+        // Rounding the time up carries over into days
+        let start = iso_datetime(2024, 1, 1, 0, 0, 0);
+        let end = iso_datetime(2024, 1, 7, 23, 30, 0);
+        let duration = until(&start, &end, Weeks, Hours, HalfExpand);
+        assert_eq!(duration.date, DateDuration::new(0, 0, 1, 0));
+        assert_eq!(duration.time, TimeDuration::default());
+    }
+
+    #[test]
+    fn test_until_out_of_range() {
+        use DateTimeDurationUnit::*;
+
+        // More than i32::MAX days apart
+        let start = iso_datetime(-3_000_000, 1, 1, 0, 0, 0);
+        let end = iso_datetime(3_000_000, 1, 1, 0, 0, 0);
+        assert_eq!(
+            start.until(&end, DateTimeDifferenceOptions::new(Days, Days)),
+            Err(CalendarError::OutOfRange)
+        );
+        let duration = until(&start, &end, Years, Days, RoundingMode::Trunc);
+        assert_eq!(duration.date.years, 6_000_000);
+    }
+
+    #[test]
+    fn test_until_then_add_roundtrips() {
+        use crate::AnyCalendarKind;
+
+        let pairs = [
+            (
+                iso_datetime(2021, 5, 17, 23, 10, 5),
+                iso_datetime(2024, 3, 11, 1, 30, 0),
+            ),
+            (
+                iso_datetime(1999, 12, 31, 0, 0, 0),
+                iso_datetime(2000, 1, 1, 0, 0, 1),
+            ),
+            (
+                iso_datetime(2023, 9, 16, 12, 0, 0),
+                iso_datetime(2023, 9, 16, 11, 0, 0),
+            ),
+        ];
+        for kind in [
+            AnyCalendarKind::Buddhist,
+            AnyCalendarKind::Chinese,
+            AnyCalendarKind::Coptic,
+            AnyCalendarKind::Ethiopian,
+            AnyCalendarKind::Gregorian,
+            AnyCalendarKind::Hebrew,
+            AnyCalendarKind::Indian,
+            AnyCalendarKind::IslamicCivil,
+            AnyCalendarKind::Japanese,
+            AnyCalendarKind::Persian,
+            AnyCalendarKind::Roc,
+        ] {
+            let calendar = Rc::new(AnyCalendar::new(kind));
+            for (start, end) in &pairs {
+                let start = start.to_calendar(calendar.clone());
+                let end = end.to_calendar(calendar.clone());
+                for largest_unit in [
+                    DateTimeDurationUnit::Years,
+                    DateTimeDurationUnit::Months,
+                    DateTimeDurationUnit::Weeks,
+                    DateTimeDurationUnit::Days,
+                    DateTimeDurationUnit::Seconds,
+                ] {
+                    let duration = start
+                        .until(
+                            &end,
+                            DateTimeDifferenceOptions::new(
+                                largest_unit,
+                                DateTimeDurationUnit::Nanoseconds,
+                            ),
+                        )
+                        .unwrap();
+                    let added = start
+                        .clone()
+                        .added(duration, ArithmeticOverflow::Constrain)
+                        .unwrap();
+                    assert_eq!(added, end, "{kind:?} {largest_unit:?} {duration:?}");
+                }
+            }
+        }
+    }
 
     #[test]
     fn test_ord() {
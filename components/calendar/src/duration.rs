@@ -8,7 +8,7 @@ use core::marker::PhantomData;
 
 /// A duration between two dates
 ///
-/// Can be used to perform date arithmetic
+/// Can be used to perform date arithmetic, and forms the date part of a [`DateTimeDuration`]
 ///
 /// # Example
 ///
@@ -62,7 +62,7 @@ use core::marker::PhantomData;
 /// assert_eq!(mutated_date_iso.month().ordinal, 11);
 /// assert_eq!(mutated_date_iso.day_of_month().0, 27);
 /// ```
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
 #[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
 pub struct DateDuration<C: Calendar + ?Sized> {
     /// The number of years
@@ -92,6 +92,14 @@ pub enum DateDurationUnit {
     Days,
 }
 
+// Manual impls since the derive will introduce a C: Copy bound
+impl<C: Calendar + ?Sized> Copy for DateDuration<C> {}
+impl<C: Calendar + ?Sized> Clone for DateDuration<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 impl<C: Calendar + ?Sized> Default for DateDuration<C> {
     fn default() -> Self {
         Self {
@@ -144,3 +152,333 @@ impl<C: Calendar> fmt::Debug for DateDuration<C> {
             .finish()
     }
 }
+
+/// A duration of clock time, as used in [`DateTimeDuration`]
+///
+/// The fields are not required to be balanced; for example, a [`TimeDuration`] of
+/// 90 minutes is valid and is treated the same as one of 1 hour and 30 minutes.
+///
+/// # Example
+///
+/// ```rust
+/// use icu_calendar::TimeDuration;
+///
+/// // two and a half hours
+/// let duration = TimeDuration::new(2, 30, 0, 0);
+/// assert_eq!(duration.total_nanoseconds(), 9_000_000_000_000);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+pub struct TimeDuration {
+    /// The number of hours
+    pub hours: i64,
+    /// The number of minutes
+    pub minutes: i64,
+    /// The number of seconds
+    pub seconds: i64,
+    /// The number of milliseconds
+    pub milliseconds: i64,
+    /// The number of microseconds
+    pub microseconds: i64,
+    /// The number of nanoseconds
+    pub nanoseconds: i64,
+}
+
+impl TimeDuration {
+    /// Construct a [`TimeDuration`] from hours, minutes, seconds, and nanoseconds
+    pub const fn new(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            milliseconds: 0,
+            microseconds: 0,
+            nanoseconds,
+        }
+    }
+
+    /// The total length of this duration in nanoseconds
+    pub const fn total_nanoseconds(&self) -> i128 {
+        self.hours as i128 * 3_600_000_000_000
+            + self.minutes as i128 * 60_000_000_000
+            + self.seconds as i128 * 1_000_000_000
+            + self.milliseconds as i128 * 1_000_000
+            + self.microseconds as i128 * 1_000
+            + self.nanoseconds as i128
+    }
+
+    /// Balance a number of nanoseconds into a [`TimeDuration`] whose largest
+    /// nonzero field is at most `largest_unit`.
+    ///
+    /// Date units are treated as [`DateTimeDurationUnit::Hours`], since a day is not
+    /// a fixed amount of clock time in every context.
+    ///
+    /// Returns `None` if a field would not fit into an `i64`.
+    pub fn from_nanoseconds(nanoseconds: i128, largest_unit: DateTimeDurationUnit) -> Option<Self> {
+        let mut remainder = nanoseconds;
+        let mut result = Self::default();
+        let fields: [(DateTimeDurationUnit, &mut i64); 6] = [
+            (DateTimeDurationUnit::Hours, &mut result.hours),
+            (DateTimeDurationUnit::Minutes, &mut result.minutes),
+            (DateTimeDurationUnit::Seconds, &mut result.seconds),
+            (DateTimeDurationUnit::Milliseconds, &mut result.milliseconds),
+            (DateTimeDurationUnit::Microseconds, &mut result.microseconds),
+            (DateTimeDurationUnit::Nanoseconds, &mut result.nanoseconds),
+        ];
+        for (unit, field) in fields {
+            if unit < largest_unit {
+                continue;
+            }
+            let unit_nanoseconds = unit.nanoseconds()?;
+            *field = i64::try_from(remainder / unit_nanoseconds).ok()?;
+            remainder %= unit_nanoseconds;
+        }
+        Some(result)
+    }
+}
+
+/// A duration consisting of both a calendar-dependent date part and a clock time part
+///
+/// Can be used to perform date-time arithmetic with [`DateTime::add()`](crate::DateTime::add)
+/// and [`DateTime::until()`](crate::DateTime::until).
+///
+/// # Example
+///
+/// ```rust
+/// use icu_calendar::{DateDuration, DateTimeDuration, TimeDuration};
+///
+/// // one month, two hours and thirty minutes
+/// let duration: DateTimeDuration<icu_calendar::Iso> = DateTimeDuration::new(
+///     DateDuration::new(0, 1, 0, 0),
+///     TimeDuration::new(2, 30, 0, 0),
+/// );
+/// assert_eq!(duration.date.months, 1);
+/// assert_eq!(duration.time.minutes, 30);
+/// ```
+#[derive(Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+pub struct DateTimeDuration<C: Calendar + ?Sized> {
+    /// The date part of the duration
+    pub date: DateDuration<C>,
+    /// The time part of the duration
+    pub time: TimeDuration,
+}
+
+// Manual impls since the derive will introduce a C: Copy bound
+impl<C: Calendar + ?Sized> Copy for DateTimeDuration<C> {}
+impl<C: Calendar + ?Sized> Clone for DateTimeDuration<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Calendar + ?Sized> Default for DateTimeDuration<C> {
+    fn default() -> Self {
+        Self {
+            date: Default::default(),
+            time: Default::default(),
+        }
+    }
+}
+
+impl<C: Calendar + ?Sized> DateTimeDuration<C> {
+    /// Construct a [`DateTimeDuration`] from its date and time parts
+    pub fn new(date: DateDuration<C>, time: TimeDuration) -> Self {
+        Self { date, time }
+    }
+
+    /// Explicitly cast duration to one for a different calendar
+    pub fn cast_unit<C2: Calendar + ?Sized>(self) -> DateTimeDuration<C2> {
+        DateTimeDuration {
+            date: self.date.cast_unit(),
+            time: self.time,
+        }
+    }
+}
+
+impl<C: Calendar> fmt::Debug for DateTimeDuration<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("DateTimeDuration")
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+/// A "duration unit" for [`DateTimeDuration`]s, used to specify the largest and smallest
+/// units of a difference between two [`DateTime`](crate::DateTime)s
+///
+/// Units are ordered from largest to smallest, so `Years < Nanoseconds`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)] // this type should be stable
+pub enum DateTimeDurationUnit {
+    /// Duration in years
+    Years,
+    /// Duration in months
+    Months,
+    /// Duration in weeks
+    Weeks,
+    /// Duration in days
+    Days,
+    /// Duration in hours
+    Hours,
+    /// Duration in minutes
+    Minutes,
+    /// Duration in seconds
+    Seconds,
+    /// Duration in milliseconds
+    Milliseconds,
+    /// Duration in microseconds
+    Microseconds,
+    /// Duration in nanoseconds
+    Nanoseconds,
+}
+
+impl DateTimeDurationUnit {
+    /// The number of nanoseconds in this unit, or `None` for date units
+    pub(crate) fn nanoseconds(self) -> Option<i128> {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => None,
+            Self::Hours => Some(3_600_000_000_000),
+            Self::Minutes => Some(60_000_000_000),
+            Self::Seconds => Some(1_000_000_000),
+            Self::Milliseconds => Some(1_000_000),
+            Self::Microseconds => Some(1_000),
+            Self::Nanoseconds => Some(1),
+        }
+    }
+
+    /// The corresponding [`DateDurationUnit`], or `None` for time units
+    pub(crate) fn date_unit(self) -> Option<DateDurationUnit> {
+        match self {
+            Self::Years => Some(DateDurationUnit::Years),
+            Self::Months => Some(DateDurationUnit::Months),
+            Self::Weeks => Some(DateDurationUnit::Weeks),
+            Self::Days => Some(DateDurationUnit::Days),
+            _ => None,
+        }
+    }
+}
+
+impl From<DateDurationUnit> for DateTimeDurationUnit {
+    fn from(other: DateDurationUnit) -> Self {
+        match other {
+            DateDurationUnit::Years => Self::Years,
+            DateDurationUnit::Months => Self::Months,
+            DateDurationUnit::Weeks => Self::Weeks,
+            DateDurationUnit::Days => Self::Days,
+        }
+    }
+}
+
+/// How to handle adding a duration that lands on a day that does not exist
+/// in the resulting month, such as January 31 plus one month.
+///
+/// This corresponds to the `overflow` option in Temporal.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[allow(clippy::exhaustive_enums)] // this type should be stable
+pub enum ArithmeticOverflow {
+    /// Clamp the day to the last day of the resulting month
+    #[default]
+    Constrain,
+    /// Return an error
+    Reject,
+}
+
+/// How to round a duration to its smallest unit
+///
+/// This corresponds to the `roundingMode` option in Temporal.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[allow(clippy::exhaustive_enums)] // this type should be stable
+pub enum RoundingMode {
+    /// Round towards positive infinity
+    Ceil,
+    /// Round towards negative infinity
+    Floor,
+    /// Round away from zero
+    Expand,
+    /// Round towards zero
+    #[default]
+    Trunc,
+    /// Round to the nearest value, with ties towards positive infinity
+    HalfCeil,
+    /// Round to the nearest value, with ties towards negative infinity
+    HalfFloor,
+    /// Round to the nearest value, with ties away from zero
+    HalfExpand,
+    /// Round to the nearest value, with ties towards zero
+    HalfTrunc,
+    /// Round to the nearest value, with ties towards the even value
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Whether a value of magnitude `whole + numerator / denominator` (with
+    /// `0 <= numerator < denominator`) and the given sign should be rounded away
+    /// from zero to `whole + 1`, as opposed to towards zero to `whole`.
+    pub(crate) fn rounds_away_from_zero(
+        self,
+        negative: bool,
+        whole: i128,
+        numerator: i128,
+        denominator: i128,
+    ) -> bool {
+        if numerator == 0 {
+            return false;
+        }
+        let half = (2 * numerator).cmp(&denominator);
+        match self {
+            Self::Ceil => !negative,
+            Self::Floor => negative,
+            Self::Expand => true,
+            Self::Trunc => false,
+            _ if half != core::cmp::Ordering::Equal => half == core::cmp::Ordering::Greater,
+            Self::HalfCeil => !negative,
+            Self::HalfFloor => negative,
+            Self::HalfExpand => true,
+            Self::HalfTrunc => false,
+            Self::HalfEven => whole % 2 != 0,
+        }
+    }
+}
+
+/// Options for [`DateTime::until()`](crate::DateTime::until)
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct DateTimeDifferenceOptions {
+    /// The largest unit of the resulting duration. All larger fields will be zero.
+    ///
+    /// Default is [`DateTimeDurationUnit::Days`]
+    pub largest_unit: DateTimeDurationUnit,
+    /// The smallest unit of the resulting duration. All smaller fields will be zero,
+    /// with the remainder rounded according to [`Self::rounding_mode`].
+    ///
+    /// Default is [`DateTimeDurationUnit::Nanoseconds`]
+    pub smallest_unit: DateTimeDurationUnit,
+    /// How to round the remainder below [`Self::smallest_unit`].
+    ///
+    /// Default is [`RoundingMode::Trunc`]
+    pub rounding_mode: RoundingMode,
+}
+
+impl Default for DateTimeDifferenceOptions {
+    fn default() -> Self {
+        Self {
+            largest_unit: DateTimeDurationUnit::Days,
+            smallest_unit: DateTimeDurationUnit::Nanoseconds,
+            rounding_mode: RoundingMode::Trunc,
+        }
+    }
+}
+
+impl DateTimeDifferenceOptions {
+    /// Construct options with the given largest and smallest units and the default
+    /// rounding mode
+    pub fn new(largest_unit: DateTimeDurationUnit, smallest_unit: DateTimeDurationUnit) -> Self {
+        Self {
+            largest_unit,
+            smallest_unit,
+            ..Default::default()
+        }
+    }
+}
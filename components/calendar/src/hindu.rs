@@ -0,0 +1,1045 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hindu lunisolar and solar calendars.
+//!
+//! ```rust
+//! use icu::calendar::hindu::{HinduLunar, HinduSolar, HinduSolarRegion};
+//! use icu::calendar::Date;
+//!
+//! let date_iso = Date::try_new_iso_date(2024, 4, 9)
+//!     .expect("Failed to initialize ISO Date instance.");
+//!
+//! // Gudi Padwa / Ugadi, the first day of Chaitra
+//! let date_lunar = Date::new_from_iso(date_iso, HinduLunar::new());
+//! assert_eq!(date_lunar.year().number, 2081);
+//! assert_eq!(date_lunar.month().code.0, "M01");
+//! assert_eq!(date_lunar.day_of_month().0, 1);
+//! assert_eq!(date_lunar.tithi(), 1);
+//!
+//! let date_tamil =
+//!     Date::new_from_iso(date_iso, HinduSolar::new_with_region(HinduSolarRegion::Tamil));
+//! assert_eq!(date_tamil.year().number, 1945);
+//! assert_eq!(date_tamil.month().code.0, "M12");
+//! assert_eq!(date_tamil.day_of_month().0, 27);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic, PrecomputedDataSource};
+use crate::iso::Iso;
+use crate::{types, AsCalendar, Calendar, CalendarError, Date, DateDuration, DateDurationUnit};
+use calendrical_calculations::hindu;
+use calendrical_calculations::rata_die::RataDie;
+use core::cell::Cell;
+use tinystr::{tinystr, TinyAsciiStr};
+
+/// The number of years the Vikram Samvat era precedes the Shaka era
+const SHAKA_OFFSET: i32 = hindu::SHAKA_ERA - hindu::VIKRAM_SAMVAT_ERA;
+
+/// Which era style the Hindu lunisolar calendar uses
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
+pub enum HinduLunarEraStyle {
+    /// Count years in the Vikram Samvat era, as is common in North and West India
+    VikramSamvat,
+    /// Count years in the Shaka era, as is common in South India
+    Shaka,
+}
+
+/// The Hindu lunisolar calendar
+///
+/// The [Hindu lunisolar calendar] is the calendar used for most Hindu festivals. Its months run from
+/// one new moon to the next (the _amanta_ scheme), and are named after the sidereal zodiac sign the
+/// sun enters during the month. A month in which the sun does not enter a new sign is an _adhika_
+/// (leap) month, and takes the name of the month that follows it. Traditionally, days are numbered
+/// by the lunar day (_tithi_) current at sunrise, so a tithi can be skipped (_kshaya_) or repeated
+/// (_adhika_).
+///
+/// This implementation follows the astronomical rules of _Calendrical Calculations_ by Reingold &
+/// Dershowitz, with sunrise computed at Ujjain.
+///
+/// This type can be used with [`Date`] or [`DateTime`](crate::DateTime) to represent dates in this calendar.
+///
+/// It can be constructed in two modes: using the Vikram Samvat era (the default), or the Shaka era,
+/// see [`HinduLunarEraStyle`] for more info.
+///
+/// [Hindu lunisolar calendar]: https://en.wikipedia.org/wiki/Hindu_calendar
+///
+/// # Era codes
+///
+/// This calendar supports two era codes: `"vikram"`, where 1 Vikram Samvat is 57 BCE, and `"saka"`,
+/// where 1 Shaka is 78 CE. Dates are output in the era of the calendar's era style. Dates before the
+/// era use negative year numbers.
+///
+/// # Month codes
+///
+/// This calendar is a lunisolar calendar. It supports regular month codes `"M01" - "M12"`, where
+/// `"M01"` is Chaitra, as well as leap month codes `"M01L" - "M12L"` for adhika months. An adhika
+/// month precedes the regular month of the same name.
+///
+/// # Days
+///
+/// Like in other calendars, the day of month counts civil days from the start of the month, so
+/// that it can be used with month codes to construct dates. The tithi of a date is available
+/// from [`Date::tithi()`] and [`Date::is_adhika_day()`], and dates can be constructed from a
+/// tithi with [`Date::try_new_hindu_lunar_date_with_calendar()`].
+// The bool specifies whether dates should be in the Shaka era
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduLunar(pub(crate) bool);
+
+/// The inner date type used for representing [`Date`]s of [`HinduLunar`]. See [`Date`] and [`HinduLunar`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduLunarDateInner(ArithmeticDate<HinduLunar>, HinduLunarEraStyle);
+
+impl HinduLunarDateInner {
+    /// The era style of the calendar this date belongs to
+    pub(crate) fn era_style(&self) -> HinduLunarEraStyle {
+        self.1
+    }
+}
+
+/// Information about a Hindu lunisolar year, in the Vikram Samvat era
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct HinduLunarYearInfo {
+    new_year: RataDie,
+    /// Bit `n` is set if the month with ordinal `n + 1` has 30 days rather than 29
+    long_months: u16,
+    /// Bit `n` is set if the month with ordinal `n + 1` is an adhika month
+    leap_months: u16,
+    /// The name of each month, where 1 is Chaitra
+    month_names: [u8; 13],
+    months: u8,
+}
+
+impl HinduLunarYearInfo {
+    fn compute(year: i32) -> Self {
+        let new_year = hindu::hindu_lunar_new_year(year);
+        let mut info = Self {
+            new_year,
+            long_months: 0,
+            leap_months: 0,
+            month_names: [0; 13],
+            months: 0,
+        };
+        let mut start = new_year;
+        loop {
+            let first = hindu::hindu_lunar_from_fixed(start);
+            let Some(name) = info.month_names.get_mut(info.months as usize) else {
+                break;
+            };
+            if first.year != year {
+                break;
+            }
+            *name = first.month;
+            let next = hindu::hindu_lunar_next_month_start(start);
+            if first.leap_month {
+                info.leap_months |= 1 << info.months;
+            }
+            if next - start > 29 {
+                info.long_months |= 1 << info.months;
+            }
+            info.months += 1;
+            start = next;
+        }
+        info
+    }
+
+    fn month_days(self, month: u8) -> u8 {
+        if month == 0 || month > self.months {
+            return 0;
+        }
+        29 + ((self.long_months >> (month - 1)) & 1) as u8
+    }
+
+    fn month_start(self, month: u8) -> RataDie {
+        self.new_year
+            + (1..month)
+                .map(|m| i64::from(self.month_days(m)))
+                .sum::<i64>()
+    }
+
+    /// The ordinal of the month with the given name and leap status, if it exists in this year
+    fn ordinal(self, name: u8, leap: bool) -> Option<u8> {
+        (1..=self.months).find(|&m| {
+            self.month_names.get(m as usize - 1) == Some(&name)
+                && ((self.leap_months >> (m - 1)) & 1 == 1) == leap
+        })
+    }
+
+    /// The ordinal of the month with the given code, if it exists in this year
+    fn ordinal_for_code(self, month_code: types::MonthCode) -> Result<u8, CalendarError> {
+        month_code
+            .parsed()
+            .and_then(|(name, leap)| self.ordinal(name, leap))
+            .ok_or(CalendarError::UnknownMonthCode(
+                month_code.0,
+                "Hindu lunisolar",
+            ))
+    }
+
+    fn month_code(self, month: u8) -> types::MonthCode {
+        let name = self
+            .month_names
+            .get(month.wrapping_sub(1) as usize)
+            .copied()
+            .unwrap_or_default();
+        let leap = month > 0 && (self.leap_months >> (month - 1)) & 1 == 1;
+        let bytes = [
+            b'M',
+            b'0' + name / 10,
+            b'0' + name % 10,
+            if leap { b'L' } else { 0 },
+        ];
+        types::MonthCode(TinyAsciiStr::try_from_raw(bytes).unwrap_or(tinystr!(4, "und")))
+    }
+}
+
+impl CalendarArithmetic for HinduLunar {
+    type YearInfo = HinduLunarYearInfo;
+
+    fn month_days(_year: i32, month: u8, info: HinduLunarYearInfo) -> u8 {
+        info.month_days(month)
+    }
+
+    fn months_for_every_year(_year: i32, info: HinduLunarYearInfo) -> u8 {
+        info.months
+    }
+
+    fn is_leap_year(_year: i32, info: HinduLunarYearInfo) -> bool {
+        info.leap_months != 0
+    }
+
+    fn last_month_day_in_year(_year: i32, info: HinduLunarYearInfo) -> (u8, u8) {
+        (info.months, info.month_days(info.months))
+    }
+}
+
+/// A [`PrecomputedDataSource`] that remembers the year info of the last few years it loaded,
+/// as computing it takes many astronomical calculations
+struct YearInfoCache<I, F> {
+    compute: F,
+    entries: Cell<[Option<(i32, I)>; 3]>,
+}
+
+impl<I: Copy, F: Fn(i32) -> I> YearInfoCache<I, F> {
+    /// Creates a cache that already contains the info for `year`
+    fn new(compute: F, year: i32, info: I) -> Self {
+        Self {
+            compute,
+            entries: Cell::new([Some((year, info)), None, None]),
+        }
+    }
+}
+
+impl<I: Copy, F: Fn(i32) -> I> PrecomputedDataSource<I> for YearInfoCache<I, F> {
+    fn load_or_compute_info(&self, year: i32) -> I {
+        let mut entries = self.entries.get();
+        if let Some(&(_, info)) = entries.iter().flatten().find(|&&(y, _)| y == year) {
+            return info;
+        }
+        let info = (self.compute)(year);
+        entries.rotate_right(1);
+        if let Some(first) = entries.first_mut() {
+            *first = Some((year, info));
+        }
+        self.entries.set(entries);
+        info
+    }
+}
+
+impl Calendar for HinduLunar {
+    type DateInner = HinduLunarDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        let year = if era.0 == tinystr!(16, "vikram") {
+            year
+        } else if era.0 == tinystr!(16, "saka") {
+            year + SHAKA_OFFSET
+        } else {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        };
+        let info = HinduLunarYearInfo::compute(year);
+        let month = info.ordinal_for_code(month_code)?;
+        ArithmeticDate::new_from_ordinals_with_info(year, month, day, info)
+            .map(|date| HinduLunarDateInner(date, self.era_style()))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> HinduLunarDateInner {
+        let fixed = Iso::fixed_from_iso(*iso.inner());
+        let year = hindu::hindu_lunar_from_fixed(fixed).year;
+        let info = HinduLunarYearInfo::compute(year);
+        let mut start = info.new_year;
+        for month in 1..=info.months {
+            let days = info.month_days(month);
+            if fixed < start + i64::from(days) {
+                return HinduLunarDateInner(
+                    ArithmeticDate::new_unchecked_with_info(
+                        year,
+                        month,
+                        (fixed - start + 1) as u8,
+                        info,
+                    ),
+                    self.era_style(),
+                );
+            }
+            start += i64::from(days);
+        }
+        // Not reachable: the year contains all days up to the next new year
+        HinduLunarDateInner(
+            ArithmeticDate::new_unchecked_with_info(
+                year,
+                info.months,
+                info.month_days(info.months),
+                info,
+            ),
+            self.era_style(),
+        )
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(Self::fixed_from_hindu_lunar(date.0))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        let cache = YearInfoCache::new(HinduLunarYearInfo::compute, date.0.year, date.0.year_info);
+        date.0.offset_date(offset, &cache);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        Self::year_as_hindu_lunar(date.0.year, self.0)
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::is_leap_year(date.0.year, date.0.year_info)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: date.0.year_info.month_code(date.0.month),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: Self::year_as_hindu_lunar(prev_year, self.0),
+            days_in_prev_year: (date.0.year_info.new_year - hindu::hindu_lunar_new_year(prev_year))
+                as u16,
+            next_year: Self::year_as_hindu_lunar(next_year, self.0),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hindu lunisolar"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        if self.0 {
+            Some(AnyCalendarKind::HinduShaka)
+        } else {
+            Some(AnyCalendarKind::HinduVikramSamvat)
+        }
+    }
+}
+
+impl HinduLunar {
+    /// Construct a new Hindu lunisolar calendar for the Vikram Samvat era
+    pub const fn new() -> Self {
+        Self(false)
+    }
+
+    /// Construct a new Hindu lunisolar calendar with the given era style
+    pub const fn new_with_era_style(era_style: HinduLunarEraStyle) -> Self {
+        Self(matches!(era_style, HinduLunarEraStyle::Shaka))
+    }
+
+    /// Returns the era style of this calendar
+    pub fn era_style(&self) -> HinduLunarEraStyle {
+        if self.0 {
+            HinduLunarEraStyle::Shaka
+        } else {
+            HinduLunarEraStyle::VikramSamvat
+        }
+    }
+
+    fn fixed_from_hindu_lunar(date: ArithmeticDate<HinduLunar>) -> RataDie {
+        date.year_info.month_start(date.month) + i64::from(date.day) - 1
+    }
+
+    /// Construct a date in this calendar from a Vikram Samvat year, a month code, and a tithi
+    fn date_from_tithi(
+        self,
+        year: i32,
+        month_code: types::MonthCode,
+        tithi: u8,
+        leap_day: bool,
+    ) -> Result<HinduLunarDateInner, CalendarError> {
+        let info = HinduLunarYearInfo::compute(year);
+        let month = info.ordinal_for_code(month_code)?;
+        if tithi == 0 || tithi > 30 {
+            return Err(CalendarError::Overflow {
+                field: "day",
+                max: 30,
+            });
+        }
+        let start = info.month_start(month);
+        let key = |day: u8| {
+            let date = hindu::hindu_lunar_from_fixed(start + i64::from(day) - 1);
+            (date.day, date.leap_day)
+        };
+        // Tithis advance by roughly one per day, so start a few days early and search forward
+        let mut day = tithi.saturating_sub(3).max(1);
+        while day > 1 && key(day) > (tithi, leap_day) {
+            day -= 1;
+        }
+        while day <= info.month_days(month) {
+            match key(day).cmp(&(tithi, leap_day)) {
+                core::cmp::Ordering::Less => day += 1,
+                core::cmp::Ordering::Equal => {
+                    return Ok(HinduLunarDateInner(
+                        ArithmeticDate::new_unchecked_with_info(year, month, day, info),
+                        self.era_style(),
+                    ))
+                }
+                // The tithi is skipped (kshaya), or is not repeated
+                core::cmp::Ordering::Greater => break,
+            }
+        }
+        Err(CalendarError::OutOfRange)
+    }
+
+    fn year_as_hindu_lunar(year: i32, shaka: bool) -> types::FormattableYear {
+        if shaka {
+            types::FormattableYear {
+                era: types::Era(tinystr!(16, "saka")),
+                number: year - SHAKA_OFFSET,
+                cyclic: None,
+                related_iso: None,
+            }
+        } else {
+            types::FormattableYear {
+                era: types::Era(tinystr!(16, "vikram")),
+                number: year,
+                cyclic: None,
+                related_iso: None,
+            }
+        }
+    }
+}
+
+impl<A: AsCalendar<Calendar = HinduLunar>> Date<A> {
+    /// Construct a new Hindu lunisolar date from a year in the calendar's era, a month name
+    /// (1 is Chaitra), whether the month is adhika, a tithi, and whether the day is the second day
+    /// with that tithi.
+    ///
+    /// Returns [`CalendarError::OutOfRange`] if the tithi is skipped (kshaya) in that month,
+    /// or is not repeated.
+    ///
+    /// ```rust
+    /// use icu::calendar::hindu::HinduLunar;
+    /// use icu::calendar::Date;
+    ///
+    /// // Adhika Shravana 1, VS 2080
+    /// let date_hindu = Date::try_new_hindu_lunar_date_with_calendar(
+    ///     2080,
+    ///     5,
+    ///     true,
+    ///     1,
+    ///     false,
+    ///     HinduLunar::new(),
+    /// )
+    /// .expect("Failed to initialize Hindu lunisolar Date instance.");
+    ///
+    /// assert_eq!(date_hindu.year().number, 2080);
+    /// assert_eq!(date_hindu.month().code.0, "M05L");
+    /// assert_eq!(date_hindu.month().ordinal, 5);
+    /// assert_eq!(date_hindu.tithi(), 1);
+    /// ```
+    pub fn try_new_hindu_lunar_date_with_calendar(
+        year: i32,
+        month: u8,
+        leap_month: bool,
+        day: u8,
+        leap_day: bool,
+        calendar: A,
+    ) -> Result<Date<A>, CalendarError> {
+        let year = if calendar.as_calendar().0 {
+            year + SHAKA_OFFSET
+        } else {
+            year
+        };
+        let bytes = [
+            b'M',
+            b'0' + month / 10 % 10,
+            b'0' + month % 10,
+            if leap_month { b'L' } else { 0 },
+        ];
+        let month_code =
+            types::MonthCode(TinyAsciiStr::try_from_raw(bytes).unwrap_or(tinystr!(4, "und")));
+        let inner = calendar
+            .as_calendar()
+            .date_from_tithi(year, month_code, day, leap_day)?;
+        Ok(Date::from_raw(inner, calendar))
+    }
+
+    /// Returns the lunar day (tithi) current at sunrise on this date, from 1 to 30
+    ///
+    /// Unlike [`Date::day_of_month()`], this requires astronomical calculations.
+    pub fn tithi(&self) -> u8 {
+        hindu::hindu_lunar_from_fixed(HinduLunar::fixed_from_hindu_lunar(self.inner.0)).day
+    }
+
+    /// Returns whether this is the second day with the same tithi (an adhika day)
+    ///
+    /// Like [`Date::tithi()`], this requires astronomical calculations.
+    pub fn is_adhika_day(&self) -> bool {
+        hindu::hindu_lunar_from_fixed(HinduLunar::fixed_from_hindu_lunar(self.inner.0)).leap_day
+    }
+}
+
+/// The regional variant of the Hindu solar calendar
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum HinduSolarRegion {
+    /// The Tamil calendar, counting years in the Shaka era. A month starts on the day of the
+    /// solar transit if it happens before sunset, and on the following day otherwise.
+    Tamil,
+    /// The Bengali calendar, counting years in the Bangabda era. A month starts on the day
+    /// after the solar transit, where days start at midnight in Indian Standard Time.
+    Bengali,
+}
+
+/// The Hindu solar calendar
+///
+/// The [Hindu solar calendar] has twelve months of 29 to 32 days, each starting when the sun enters
+/// a new sign of the sidereal zodiac (a _samkranti_). The first month, starting at Mesha Samkranti
+/// in April, is Chithirai in Tamil Nadu and Boishakh in Bengal. The regions differ in which day
+/// a month starts on when the samkranti happens late in the day, see [`HinduSolarRegion`].
+///
+/// This implementation follows the astronomical rules of _Calendrical Calculations_ by Reingold &
+/// Dershowitz.
+///
+/// This type can be used with [`Date`] or [`DateTime`](crate::DateTime) to represent dates in this calendar.
+///
+/// [Hindu solar calendar]: https://en.wikipedia.org/wiki/Hindu_calendar#Solar_calendar
+///
+/// # Era codes
+///
+/// The Tamil calendar has a single era: `"saka"`, with Shaka 0 being 78 CE. The Bengali calendar
+/// has a single era: `"bangabda"`, with Bangabda 0 being 593 CE. Dates before the era use negative years.
+///
+/// # Month codes
+///
+/// This calendar supports 12 solar month codes (`"M01" - "M12"`)
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduSolar(pub(crate) HinduSolarRegion);
+
+/// The inner date type used for representing [`Date`]s of [`HinduSolar`]. See [`Date`] and [`HinduSolar`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct HinduSolarDateInner(ArithmeticDate<HinduSolar>, HinduSolarRegion);
+
+impl HinduSolarDateInner {
+    /// The region of the calendar this date belongs to
+    pub(crate) fn region(&self) -> HinduSolarRegion {
+        self.1
+    }
+}
+
+/// Information about a Hindu solar year
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct HinduSolarYearInfo {
+    new_year: RataDie,
+    /// Two bits per month, storing the number of days in the month minus 29
+    month_lengths: u32,
+}
+
+impl HinduSolarYearInfo {
+    fn compute(region: HinduSolarRegion, year: i32) -> Self {
+        let month_start = |year, month| match region {
+            HinduSolarRegion::Tamil => hindu::fixed_from_tamil(year, month, 1),
+            HinduSolarRegion::Bengali => hindu::fixed_from_bengali(year, month, 1),
+        };
+        let new_year = month_start(year, 1);
+        let mut month_lengths = 0;
+        let mut start = new_year;
+        for month in 1..=12 {
+            let next = if month == 12 {
+                month_start(year + 1, 1)
+            } else {
+                month_start(year, month + 1)
+            };
+            month_lengths |= ((next - start - 29).clamp(0, 3) as u32) << (2 * (month - 1));
+            start = next;
+        }
+        Self {
+            new_year,
+            month_lengths,
+        }
+    }
+
+    fn month_days(self, month: u8) -> u8 {
+        if month == 0 || month > 12 {
+            return 0;
+        }
+        29 + ((self.month_lengths >> (2 * (month - 1))) & 3) as u8
+    }
+
+    fn month_start(self, month: u8) -> RataDie {
+        self.new_year
+            + (1..month)
+                .map(|m| i64::from(self.month_days(m)))
+                .sum::<i64>()
+    }
+}
+
+impl CalendarArithmetic for HinduSolar {
+    type YearInfo = HinduSolarYearInfo;
+
+    fn month_days(_year: i32, month: u8, info: HinduSolarYearInfo) -> u8 {
+        info.month_days(month)
+    }
+
+    fn months_for_every_year(_year: i32, _info: HinduSolarYearInfo) -> u8 {
+        12
+    }
+
+    fn is_leap_year(_year: i32, info: HinduSolarYearInfo) -> bool {
+        (1..=12).map(|m| u16::from(info.month_days(m))).sum::<u16>() > 365
+    }
+
+    fn last_month_day_in_year(_year: i32, info: HinduSolarYearInfo) -> (u8, u8) {
+        (12, info.month_days(12))
+    }
+}
+
+impl Calendar for HinduSolar {
+    type DateInner = HinduSolarDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        if era.0 != self.era() {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        }
+        let month = match month_code.parsed() {
+            Some((month, false)) if (1..=12).contains(&month) => month,
+            _ => {
+                return Err(CalendarError::UnknownMonthCode(
+                    month_code.0,
+                    self.debug_name(),
+                ))
+            }
+        };
+        ArithmeticDate::new_from_ordinals_with_info(
+            year,
+            month,
+            day,
+            HinduSolarYearInfo::compute(self.0, year),
+        )
+        .map(|date| HinduSolarDateInner(date, self.0))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> HinduSolarDateInner {
+        let fixed = Iso::fixed_from_iso(*iso.inner());
+        let (year, month, day) = match self.0 {
+            HinduSolarRegion::Tamil => hindu::tamil_from_fixed(fixed),
+            HinduSolarRegion::Bengali => hindu::bengali_from_fixed(fixed),
+        };
+        HinduSolarDateInner(
+            ArithmeticDate::new_unchecked_with_info(
+                year,
+                month,
+                day,
+                HinduSolarYearInfo::compute(self.0, year),
+            ),
+            self.0,
+        )
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(date.0.year_info.month_start(date.0.month) + i64::from(date.0.day) - 1)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        let compute = |year| HinduSolarYearInfo::compute(self.0, year);
+        let cache = YearInfoCache::new(compute, date.0.year, date.0.year_info);
+        date.0.offset_date(offset, &cache);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        self.year_as_hindu_solar(date.0.year)
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::is_leap_year(date.0.year, date.0.year_info)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        let prev_new_year = match self.0 {
+            HinduSolarRegion::Tamil => hindu::fixed_from_tamil(prev_year, 1, 1),
+            HinduSolarRegion::Bengali => hindu::fixed_from_bengali(prev_year, 1, 1),
+        };
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: self.year_as_hindu_solar(prev_year),
+            days_in_prev_year: (date.0.year_info.new_year - prev_new_year) as u16,
+            next_year: self.year_as_hindu_solar(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        match self.0 {
+            HinduSolarRegion::Tamil => "Hindu solar (Tamil)",
+            HinduSolarRegion::Bengali => "Hindu solar (Bengali)",
+        }
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        match self.0 {
+            HinduSolarRegion::Tamil => Some(AnyCalendarKind::HinduTamil),
+            HinduSolarRegion::Bengali => Some(AnyCalendarKind::HinduBengali),
+        }
+    }
+}
+
+impl HinduSolar {
+    /// Construct a new Hindu solar calendar for the given region
+    pub const fn new_with_region(region: HinduSolarRegion) -> Self {
+        Self(region)
+    }
+
+    /// Returns the region of this calendar
+    pub fn region(&self) -> HinduSolarRegion {
+        self.0
+    }
+
+    fn era(&self) -> TinyAsciiStr<16> {
+        match self.0 {
+            HinduSolarRegion::Tamil => tinystr!(16, "saka"),
+            HinduSolarRegion::Bengali => tinystr!(16, "bangabda"),
+        }
+    }
+
+    fn year_as_hindu_solar(&self, year: i32) -> types::FormattableYear {
+        types::FormattableYear {
+            era: types::Era(self.era()),
+            number: year,
+            cyclic: None,
+            related_iso: None,
+        }
+    }
+}
+
+impl<A: AsCalendar<Calendar = HinduSolar>> Date<A> {
+    /// Construct a new Hindu solar date from a year in the calendar's era, a month, and a day.
+    ///
+    /// ```rust
+    /// use icu::calendar::hindu::{HinduSolar, HinduSolarRegion};
+    /// use icu::calendar::Date;
+    ///
+    /// // Pohela Boishakh, 1431 Bangabda
+    /// let date_bengali = Date::try_new_hindu_solar_date_with_calendar(
+    ///     1431,
+    ///     1,
+    ///     1,
+    ///     HinduSolar::new_with_region(HinduSolarRegion::Bengali),
+    /// )
+    /// .expect("Failed to initialize Hindu solar Date instance.");
+    ///
+    /// assert_eq!(date_bengali.to_iso(), Date::try_new_iso_date(2024, 4, 14).unwrap());
+    /// ```
+    pub fn try_new_hindu_solar_date_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Date<A>, CalendarError> {
+        let region = calendar.as_calendar().0;
+        let info = HinduSolarYearInfo::compute(region, year);
+        ArithmeticDate::new_from_ordinals_with_info(year, month, day, info)
+            .map(|date| HinduSolarDateInner(date, region))
+            .map(|inner| Date::from_raw(inner, calendar))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iso(year: i32, month: u8, day: u8) -> Date<Iso> {
+        Date::try_new_iso_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_lunar_new_years() {
+        // Chaitra Shukla Pratipada (Gudi Padwa / Ugadi)
+        let cases = [
+            (2077, iso(2020, 3, 25)),
+            (2078, iso(2021, 4, 13)),
+            (2079, iso(2022, 4, 2)),
+            (2080, iso(2023, 3, 22)),
+            (2081, iso(2024, 4, 9)),
+        ];
+        for (year, iso) in cases {
+            let date = Date::new_from_iso(iso, HinduLunar::new());
+            assert_eq!(date.year().number, year, "{iso:?}");
+            assert_eq!(date.month().code.0, "M01", "{iso:?}");
+            assert_eq!(date.month().ordinal, 1, "{iso:?}");
+            assert_eq!(date.day_of_month().0, 1, "{iso:?}");
+            assert_eq!(date.day_of_year_info().day_of_year, 1, "{iso:?}");
+
+            let shaka = Date::new_from_iso(
+                iso,
+                HinduLunar::new_with_era_style(HinduLunarEraStyle::Shaka),
+            );
+            assert_eq!(shaka.year().number, year - 135, "{iso:?}");
+            assert_eq!(shaka.year().era.0, "saka", "{iso:?}");
+
+            let constructed = Date::try_new_hindu_lunar_date_with_calendar(
+                year,
+                1,
+                false,
+                1,
+                false,
+                HinduLunar::new(),
+            )
+            .unwrap();
+            assert_eq!(constructed.to_iso(), iso);
+        }
+    }
+
+    #[test]
+    fn test_adhika_months() {
+        // Adhika Shravana in VS 2080
+        let date = Date::new_from_iso(iso(2023, 8, 1), HinduLunar::new());
+        assert_eq!(date.year().number, 2080);
+        assert_eq!(date.month().code.0, "M05L");
+        assert_eq!(date.month().ordinal, 5);
+        assert_eq!(date.months_in_year(), 13);
+        assert!(date.is_in_leap_year());
+
+        let date = Date::new_from_iso(iso(2023, 8, 20), HinduLunar::new());
+        assert_eq!(date.month().code.0, "M05");
+        assert_eq!(date.month().ordinal, 6);
+
+        // Adhika Ashvin in VS 2077
+        let date = Date::new_from_iso(iso(2020, 10, 1), HinduLunar::new());
+        assert_eq!(date.year().number, 2077);
+        assert_eq!(date.month().code.0, "M07L");
+
+        let date = Date::new_from_iso(iso(2024, 6, 1), HinduLunar::new());
+        assert_eq!(date.months_in_year(), 12);
+        assert!(!date.is_in_leap_year());
+
+        // There is no adhika month in VS 2081
+        assert!(matches!(
+            Date::try_new_hindu_lunar_date_with_calendar(
+                2081,
+                5,
+                true,
+                1,
+                false,
+                HinduLunar::new()
+            ),
+            Err(CalendarError::UnknownMonthCode(..))
+        ));
+    }
+
+    #[test]
+    fn test_festivals() {
+        // Holi: Phalguna Purnima, VS 2080
+        let date = Date::try_new_hindu_lunar_date_with_calendar(
+            2080,
+            12,
+            false,
+            15,
+            false,
+            HinduLunar::new(),
+        )
+        .unwrap();
+        assert_eq!(date.to_iso(), iso(2024, 3, 25));
+        assert_eq!(date.tithi(), 15);
+        let from_codes = Date::try_new_from_codes(
+            types::Era(tinystr!(16, "vikram")),
+            2080,
+            types::MonthCode(tinystr!(4, "M12")),
+            date.day_of_month().0 as u8,
+            HinduLunar::new(),
+        )
+        .unwrap();
+        assert_eq!(from_codes, date);
+
+        // Thai Pongal, 1 Thai 1945 Shaka
+        let tamil = HinduSolar::new_with_region(HinduSolarRegion::Tamil);
+        let date = Date::new_from_iso(iso(2024, 1, 15), tamil);
+        assert_eq!((date.year().number, date.month().ordinal), (1945, 10));
+        assert_eq!(date.day_of_month().0, 1);
+
+        // Poush Sankranti, 1 Magh 1430 Bangabda
+        let bengali = HinduSolar::new_with_region(HinduSolarRegion::Bengali);
+        let date = Date::new_from_iso(iso(2024, 1, 16), bengali);
+        assert_eq!(date.year().era.0, "bangabda");
+        assert_eq!((date.year().number, date.month().ordinal), (1430, 10));
+        assert_eq!(date.day_of_month().0, 1);
+    }
+
+    #[test]
+    fn test_solar_new_years() {
+        // Puthandu and Pohela Boishakh
+        let cases = [
+            (1942, iso(2020, 4, 14), iso(2020, 4, 14)),
+            (1944, iso(2022, 4, 14), iso(2022, 4, 15)),
+            (1945, iso(2023, 4, 14), iso(2023, 4, 15)),
+            (1946, iso(2024, 4, 14), iso(2024, 4, 14)),
+        ];
+        let tamil = HinduSolar::new_with_region(HinduSolarRegion::Tamil);
+        let bengali = HinduSolar::new_with_region(HinduSolarRegion::Bengali);
+        for (year, tamil_iso, bengali_iso) in cases {
+            let date = Date::try_new_hindu_solar_date_with_calendar(year, 1, 1, tamil).unwrap();
+            assert_eq!(date.to_iso(), tamil_iso);
+            let date =
+                Date::try_new_hindu_solar_date_with_calendar(year - 515, 1, 1, bengali).unwrap();
+            assert_eq!(date.to_iso(), bengali_iso);
+            assert!(matches!(date.days_in_year(), 365 | 366));
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let start = Iso::fixed_from_iso(*iso(2022, 11, 1).inner());
+        let calendars = [
+            crate::AnyCalendar::HinduLunar(HinduLunar::new()),
+            crate::AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Tamil)),
+            crate::AnyCalendar::HinduSolar(HinduSolar::new_with_region(HinduSolarRegion::Bengali)),
+        ];
+        for calendar in calendars {
+            let mut previous: Option<Date<crate::Ref<crate::AnyCalendar>>> = None;
+            for offset in (0..400).step_by(3) {
+                let iso = Iso::iso_from_fixed(start + offset);
+                let date = Date::new_from_iso(iso, crate::Ref(&calendar));
+                assert_eq!(date.to_iso(), iso, "{date:?}");
+                let from_codes = Date::try_new_from_codes(
+                    date.year().era,
+                    date.year().number,
+                    date.month().code,
+                    date.day_of_month().0 as u8,
+                    crate::Ref(&calendar),
+                );
+                assert_eq!(from_codes.unwrap(), date);
+                if let Some(previous) = previous {
+                    assert_eq!(previous.added(DateDuration::new(0, 0, 0, 3)), date);
+                }
+                previous = Some(date);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kshaya_and_adhika_days() {
+        let calendar = HinduLunar::new();
+        let mut skipped = false;
+        let mut repeated = false;
+        for month in 1..=3 {
+            for tithi in 1..=30 {
+                let first = Date::try_new_hindu_lunar_date_with_calendar(
+                    2081, month, false, tithi, false, calendar,
+                );
+                let second = Date::try_new_hindu_lunar_date_with_calendar(
+                    2081, month, false, tithi, true, calendar,
+                );
+                match (first, second) {
+                    (Err(CalendarError::OutOfRange), Err(CalendarError::OutOfRange)) => {
+                        skipped = true
+                    }
+                    (Ok(first), Ok(second)) => {
+                        repeated = true;
+                        assert!(!first.is_adhika_day());
+                        assert!(second.is_adhika_day());
+                        assert_eq!(first.added(DateDuration::new(0, 0, 0, 1)), second);
+                        assert_eq!(second.tithi(), tithi);
+                    }
+                    (Ok(first), Err(CalendarError::OutOfRange)) => {
+                        assert_eq!(first.tithi(), tithi);
+                    }
+                    other => panic!("unexpected result {other:?}"),
+                }
+            }
+        }
+        assert!(skipped);
+        assert!(repeated);
+    }
+}
@@ -0,0 +1,455 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::types::Time;
+use crate::{AsCalendar, CalendarError, Date, DateTime, Iso};
+use calendrical_calculations::rata_die::RataDie;
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_PER_DAY: i128 = 86_400 * NANOSECONDS_PER_SECOND;
+
+/// The Rata Die of 1970-01-01
+const UNIX_EPOCH_RATA_DIE: i64 = 719_163;
+/// The Rata Die of the day starting with Julian Day Number 0 at noon, minus one
+/// (Julian Day Number `n` starts at noon of Rata Die `n - 1_721_425`).
+const JULIAN_DAY_NUMBER_OFFSET: i64 = 1_721_425;
+
+/// The largest number of days an [`Instant`] may be away from the Unix epoch, matching
+/// the range of `Temporal.Instant`.
+const MAX_DAYS: i128 = 100_000_000;
+
+/// A point in time, independent of calendars and time zones, with nanosecond precision.
+///
+/// An [`Instant`] is stored as the number of nanoseconds since the Unix epoch
+/// (1970-01-01T00:00:00Z), ignoring leap seconds. Like `Temporal.Instant`, it supports
+/// instants within 100,000,000 days of the Unix epoch.
+///
+/// It can be converted losslessly to and from Unix timestamps, and from days in
+/// [`RataDie`] and Julian Day Number counts. Given a UTC offset, it can also be converted
+/// to and from a [`DateTime`] in any calendar.
+///
+/// # Example
+///
+/// ```rust
+/// use icu::calendar::{DateTime, Instant};
+///
+/// let instant = Instant::try_from_unix_seconds_and_nanoseconds(1_700_000_000, 123_456_789)
+///     .expect("Instant is within range");
+///
+/// let datetime = instant
+///     .try_to_datetime_iso(-8 * 3600)
+///     .expect("Offset is within range");
+/// assert_eq!(datetime.date.year().number, 2023);
+/// assert_eq!(datetime.date.month().ordinal, 11);
+/// assert_eq!(datetime.date.day_of_month().0, 14);
+/// assert_eq!(datetime.time.hour.number(), 14);
+/// assert_eq!(datetime.time.minute.number(), 13);
+/// assert_eq!(datetime.time.second.number(), 20);
+/// assert_eq!(datetime.time.nanosecond.number(), 123_456_789);
+///
+/// assert_eq!(Instant::try_from_datetime(&datetime, -8 * 3600), Ok(instant));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    /// Nanoseconds since the Unix epoch
+    nanoseconds: i128,
+}
+
+impl Instant {
+    /// The earliest supported instant, 100,000,000 days before the Unix epoch
+    pub const MIN: Self = Self {
+        nanoseconds: -MAX_DAYS * NANOSECONDS_PER_DAY,
+    };
+
+    /// The latest supported instant, 100,000,000 days after the Unix epoch
+    pub const MAX: Self = Self {
+        nanoseconds: MAX_DAYS * NANOSECONDS_PER_DAY,
+    };
+
+    /// The Unix epoch, 1970-01-01T00:00:00Z
+    pub const UNIX_EPOCH: Self = Self { nanoseconds: 0 };
+
+    /// Construct an [`Instant`] from a number of nanoseconds since the Unix epoch.
+    ///
+    /// Returns an error if the result is not between [`Self::MIN`] and [`Self::MAX`].
+    pub fn try_from_unix_nanoseconds(nanoseconds: i128) -> Result<Self, CalendarError> {
+        let result = Self { nanoseconds };
+        if result < Self::MIN || result > Self::MAX {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(result)
+    }
+
+    /// Construct an [`Instant`] from a number of seconds since the Unix epoch.
+    ///
+    /// Returns an error if the result is not between [`Self::MIN`] and [`Self::MAX`].
+    pub fn try_from_unix_seconds(seconds: i64) -> Result<Self, CalendarError> {
+        Self::try_from_unix_nanoseconds(i128::from(seconds) * NANOSECONDS_PER_SECOND)
+    }
+
+    /// Construct an [`Instant`] from a number of seconds since the Unix epoch and
+    /// a number of nanoseconds after that second, as found in a `timespec`.
+    ///
+    /// Returns an error if `nanoseconds` is not less than one second, or if the result is
+    /// not between [`Self::MIN`] and [`Self::MAX`].
+    pub fn try_from_unix_seconds_and_nanoseconds(
+        seconds: i64,
+        nanoseconds: u32,
+    ) -> Result<Self, CalendarError> {
+        if i128::from(nanoseconds) >= NANOSECONDS_PER_SECOND {
+            return Err(CalendarError::Overflow {
+                field: "nanoseconds",
+                max: 999_999_999,
+            });
+        }
+        Self::try_from_unix_nanoseconds(
+            i128::from(seconds) * NANOSECONDS_PER_SECOND + i128::from(nanoseconds),
+        )
+    }
+
+    /// The number of nanoseconds since the Unix epoch
+    pub fn unix_nanoseconds(self) -> i128 {
+        self.nanoseconds
+    }
+
+    /// The number of whole seconds since the Unix epoch, rounded towards negative infinity.
+    ///
+    /// Together with [`Self::subsecond_nanoseconds()`], this is the `timespec`
+    /// representation of this instant.
+    pub fn unix_seconds(self) -> i64 {
+        // MAX_DAYS worth of seconds fits into an i64
+        self.nanoseconds.div_euclid(NANOSECONDS_PER_SECOND) as i64
+    }
+
+    /// The number of nanoseconds after [`Self::unix_seconds()`]
+    pub fn subsecond_nanoseconds(self) -> u32 {
+        self.nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32
+    }
+
+    /// Construct the [`Instant`] at the start (midnight UTC) of the given day.
+    ///
+    /// Returns an error if the result is not between [`Self::MIN`] and [`Self::MAX`].
+    pub fn try_from_rata_die(rata_die: RataDie) -> Result<Self, CalendarError> {
+        Self::try_from_unix_nanoseconds(
+            i128::from(rata_die.to_i64_date() - UNIX_EPOCH_RATA_DIE) * NANOSECONDS_PER_DAY,
+        )
+    }
+
+    /// The day (in UTC) containing this instant.
+    ///
+    /// Together with [`Self::nanoseconds_since_midnight()`], this losslessly represents
+    /// this instant.
+    pub fn rata_die(self) -> RataDie {
+        // MAX_DAYS fits into an i64
+        RataDie::new(self.nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64 + UNIX_EPOCH_RATA_DIE)
+    }
+
+    /// The number of nanoseconds since midnight UTC of [`Self::rata_die()`]
+    pub fn nanoseconds_since_midnight(self) -> u64 {
+        self.nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64
+    }
+
+    /// Construct the [`Instant`] at the start of the given Julian Day Number, which
+    /// is noon UTC.
+    ///
+    /// Returns an error if the result is not between [`Self::MIN`] and [`Self::MAX`].
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, Instant};
+    ///
+    /// let instant = Instant::try_from_julian_day_number(2_451_545).unwrap();
+    /// assert_eq!(
+    ///     instant.try_to_datetime_iso(0),
+    ///     DateTime::try_new_iso_datetime(2000, 1, 1, 12, 0, 0)
+    /// );
+    /// ```
+    pub fn try_from_julian_day_number(julian_day_number: i64) -> Result<Self, CalendarError> {
+        let rata_die = i128::from(julian_day_number) - i128::from(JULIAN_DAY_NUMBER_OFFSET);
+        Self::try_from_unix_nanoseconds(
+            (rata_die - i128::from(UNIX_EPOCH_RATA_DIE)) * NANOSECONDS_PER_DAY
+                + NANOSECONDS_PER_DAY / 2,
+        )
+    }
+
+    /// The Julian Day Number of the Julian day containing this instant.
+    ///
+    /// Julian days start at noon UTC, so this is one larger than the Julian Day Number of
+    /// [`Self::rata_die()`] in the afternoon.
+    ///
+    /// Together with [`Self::nanoseconds_since_noon()`], this losslessly represents
+    /// this instant.
+    pub fn julian_day_number(self) -> i64 {
+        let shifted = self.nanoseconds - NANOSECONDS_PER_DAY / 2;
+        // MAX_DAYS fits into an i64
+        shifted.div_euclid(NANOSECONDS_PER_DAY) as i64
+            + UNIX_EPOCH_RATA_DIE
+            + JULIAN_DAY_NUMBER_OFFSET
+    }
+
+    /// The number of nanoseconds since noon UTC of [`Self::julian_day_number()`]
+    pub fn nanoseconds_since_noon(self) -> u64 {
+        (self.nanoseconds - NANOSECONDS_PER_DAY / 2).rem_euclid(NANOSECONDS_PER_DAY) as u64
+    }
+
+    /// Construct an [`Instant`] from a [`DateTime`] in any calendar, interpreted as a local
+    /// time at `utc_offset_seconds` seconds east of UTC.
+    ///
+    /// Returns an error if the offset is a day or more, or if the result is not between
+    /// [`Self::MIN`] and [`Self::MAX`].
+    pub fn try_from_datetime<A: AsCalendar>(
+        datetime: &DateTime<A>,
+        utc_offset_seconds: i32,
+    ) -> Result<Self, CalendarError> {
+        let offset = validate_utc_offset(utc_offset_seconds)?;
+        let days = i128::from(
+            Iso::fixed_from_iso(*datetime.date.to_iso().inner()).to_i64_date()
+                - UNIX_EPOCH_RATA_DIE,
+        );
+        Self::try_from_unix_nanoseconds(
+            days * NANOSECONDS_PER_DAY + datetime.time.nanoseconds_since_midnight()
+                - offset * NANOSECONDS_PER_SECOND,
+        )
+    }
+
+    /// The local ISO [`DateTime`] of this instant at `utc_offset_seconds` seconds east of UTC.
+    ///
+    /// Returns an error if the offset is a day or more.
+    pub fn try_to_datetime_iso(
+        self,
+        utc_offset_seconds: i32,
+    ) -> Result<DateTime<Iso>, CalendarError> {
+        let offset = validate_utc_offset(utc_offset_seconds)?;
+        let (time, days) = Time::from_nanosecond_with_remainder_days(
+            self.nanoseconds + offset * NANOSECONDS_PER_SECOND,
+        );
+        // MAX_DAYS plus one day fits into an i64
+        let date = Iso::iso_from_fixed(RataDie::new(days as i64 + UNIX_EPOCH_RATA_DIE));
+        Ok(DateTime { date, time })
+    }
+
+    /// The local [`DateTime`] of this instant in `calendar`, at `utc_offset_seconds`
+    /// seconds east of UTC.
+    ///
+    /// Returns an error if the offset is a day or more.
+    ///
+    /// ```rust
+    /// use icu::calendar::hebrew::Hebrew;
+    /// use icu::calendar::Instant;
+    ///
+    /// let instant = Instant::try_from_unix_seconds(1_700_000_000).unwrap();
+    /// let datetime = instant
+    ///     .try_to_datetime(2 * 3600, Hebrew::new_always_calculating())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(datetime.date.year().number, 5784);
+    /// assert_eq!(datetime.date.month().code.0, "M03");
+    /// assert_eq!(datetime.date.day_of_month().0, 2);
+    /// assert_eq!(datetime.time.hour.number(), 0);
+    /// ```
+    pub fn try_to_datetime<A: AsCalendar>(
+        self,
+        utc_offset_seconds: i32,
+        calendar: A,
+    ) -> Result<DateTime<A>, CalendarError> {
+        let iso = self.try_to_datetime_iso(utc_offset_seconds)?;
+        Ok(DateTime::new(
+            Date::new_from_iso(iso.date, calendar),
+            iso.time,
+        ))
+    }
+}
+
+fn validate_utc_offset(utc_offset_seconds: i32) -> Result<i128, CalendarError> {
+    if utc_offset_seconds >= 86_400 {
+        Err(CalendarError::Overflow {
+            field: "utc_offset_seconds",
+            max: 86_399,
+        })
+    } else if utc_offset_seconds <= -86_400 {
+        Err(CalendarError::Underflow {
+            field: "utc_offset_seconds",
+            min: -86_399,
+        })
+    } else {
+        Ok(i128::from(utc_offset_seconds))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for Instant {
+    type Error = CalendarError;
+
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let nanoseconds = match time.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(after) => i128::try_from(after.as_nanos()).map_err(|_| CalendarError::OutOfRange)?,
+            Err(before) => -i128::try_from(before.duration().as_nanos())
+                .map_err(|_| CalendarError::OutOfRange)?,
+        };
+        Self::try_from_unix_nanoseconds(nanoseconds)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<Instant> for std::time::SystemTime {
+    type Error = CalendarError;
+
+    fn try_from(instant: Instant) -> Result<Self, Self::Error> {
+        let duration = std::time::Duration::new(
+            instant.nanoseconds.unsigned_abs().div_euclid(1_000_000_000) as u64,
+            instant.nanoseconds.unsigned_abs().rem_euclid(1_000_000_000) as u32,
+        );
+        if instant.nanoseconds >= 0 {
+            std::time::SystemTime::UNIX_EPOCH.checked_add(duration)
+        } else {
+            std::time::SystemTime::UNIX_EPOCH.checked_sub(duration)
+        }
+        .ok_or(CalendarError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unix_roundtrip() {
+        for (seconds, nanoseconds) in [
+            (0, 0),
+            (1, 1),
+            (-1, 999_999_999),
+            (1_700_000_000, 123_456_789),
+            (-62_135_596_800, 0),
+        ] {
+            let instant =
+                Instant::try_from_unix_seconds_and_nanoseconds(seconds, nanoseconds).unwrap();
+            assert_eq!(instant.unix_seconds(), seconds);
+            assert_eq!(instant.subsecond_nanoseconds(), nanoseconds);
+            assert_eq!(
+                Instant::try_from_unix_nanoseconds(instant.unix_nanoseconds()),
+                Ok(instant)
+            );
+        }
+        assert_eq!(
+            Instant::try_from_unix_nanoseconds(-1)
+                .unwrap()
+                .unix_seconds(),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(
+            Instant::try_from_unix_seconds(8_640_000_000_000),
+            Ok(Instant::MAX)
+        );
+        assert_eq!(
+            Instant::try_from_unix_seconds(8_640_000_000_001),
+            Err(CalendarError::OutOfRange)
+        );
+        assert_eq!(
+            Instant::try_from_unix_seconds(-8_640_000_000_000),
+            Ok(Instant::MIN)
+        );
+        assert_eq!(
+            Instant::try_from_unix_seconds(i64::MIN),
+            Err(CalendarError::OutOfRange)
+        );
+        assert!(Instant::try_from_unix_seconds_and_nanoseconds(0, 1_000_000_000).is_err());
+        assert!(Instant::try_from_rata_die(RataDie::big_negative()).is_err());
+        assert!(Instant::try_from_julian_day_number(i64::MIN).is_err());
+        assert!(Instant::UNIX_EPOCH.try_to_datetime_iso(86_400).is_err());
+        assert!(Instant::UNIX_EPOCH.try_to_datetime_iso(-86_400).is_err());
+
+        // Extreme instants are representable as dates in any offset
+        let datetime = Instant::MAX.try_to_datetime_iso(86_399).unwrap();
+        assert_eq!(datetime.date.year().number, 275_760);
+        assert_eq!(
+            Instant::try_from_datetime(&datetime, 86_399),
+            Ok(Instant::MAX)
+        );
+        // ... but not every date is representable as an instant
+        assert_eq!(
+            Instant::try_from_datetime(&datetime, 0),
+            Err(CalendarError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_rata_die_and_julian_day_number() {
+        let epoch = Instant::UNIX_EPOCH;
+        assert_eq!(epoch.rata_die(), RataDie::new(719_163));
+        assert_eq!(epoch.nanoseconds_since_midnight(), 0);
+        assert_eq!(epoch.julian_day_number(), 2_440_587);
+        assert_eq!(epoch.nanoseconds_since_noon(), 43_200_000_000_000);
+        assert_eq!(
+            Instant::try_from_rata_die(RataDie::new(719_163)),
+            Ok(Instant::UNIX_EPOCH)
+        );
+
+        // 2000-01-01T12:00:00Z is the J2000 epoch
+        let j2000 = Instant::try_from_unix_seconds(946_728_000).unwrap();
+        assert_eq!(j2000.julian_day_number(), 2_451_545);
+        assert_eq!(j2000.nanoseconds_since_noon(), 0);
+        assert_eq!(Instant::try_from_julian_day_number(2_451_545), Ok(j2000));
+        assert_eq!(
+            j2000.rata_die(),
+            Iso::fixed_from_iso(*Date::try_new_iso_date(2000, 1, 1).unwrap().inner())
+        );
+
+        // Just before noon, the Julian day started the previous day
+        let before_noon = Instant::try_from_unix_nanoseconds(j2000.unix_nanoseconds() - 1).unwrap();
+        assert_eq!(before_noon.julian_day_number(), 2_451_544);
+        assert_eq!(before_noon.nanoseconds_since_noon(), 86_399_999_999_999);
+        assert_eq!(before_noon.rata_die(), j2000.rata_die());
+    }
+
+    #[test]
+    fn test_datetime() {
+        let datetime = DateTime::new(
+            Date::try_new_iso_date(1969, 12, 31).unwrap(),
+            Time::try_new(23, 59, 59, 999_999_999).unwrap(),
+        );
+        let instant = Instant::try_from_datetime(&datetime, 0).unwrap();
+        assert_eq!(instant.unix_nanoseconds(), -1);
+        assert_eq!(instant.try_to_datetime_iso(0), Ok(datetime));
+
+        // 24:00 is the start of the next day
+        let end_of_day = DateTime::new(
+            Date::try_new_iso_date(1969, 12, 31).unwrap(),
+            Time::try_new(24, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            Instant::try_from_datetime(&end_of_day, 0),
+            Ok(Instant::UNIX_EPOCH)
+        );
+
+        let tokyo = Instant::UNIX_EPOCH.try_to_datetime_iso(9 * 3600).unwrap();
+        assert_eq!(
+            tokyo,
+            DateTime::try_new_iso_datetime(1970, 1, 1, 9, 0, 0).unwrap()
+        );
+        let honolulu = Instant::UNIX_EPOCH.try_to_datetime_iso(-10 * 3600).unwrap();
+        assert_eq!(
+            honolulu,
+            DateTime::try_new_iso_datetime(1969, 12, 31, 14, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time() {
+        use std::time::{Duration, SystemTime};
+        let now = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5);
+        let instant = Instant::try_from(now).unwrap();
+        assert_eq!(instant.unix_seconds(), 1_700_000_000);
+        assert_eq!(instant.subsecond_nanoseconds(), 5);
+        assert_eq!(SystemTime::try_from(instant), Ok(now));
+
+        let before = SystemTime::UNIX_EPOCH - Duration::new(1, 1);
+        let instant = Instant::try_from(before).unwrap();
+        assert_eq!(instant.unix_nanoseconds(), -1_000_000_001);
+        assert_eq!(SystemTime::try_from(instant), Ok(before));
+    }
+}
@@ -31,6 +31,7 @@
 
 use crate::any_calendar::AnyCalendarKind;
 use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::week::WeekCalculator;
 use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
 use calendrical_calculations::helpers::{i64_to_saturated_i32, I32CastError};
 use calendrical_calculations::rata_die::RataDie;
@@ -250,6 +251,80 @@ impl Date<Iso> {
             .map(|inner| Date::from_raw(inner, Iso))
     }
 
+    /// Construct a new ISO date from an ISO 8601 week date: a week-based year, a week
+    /// of that year, and a weekday.
+    ///
+    /// ISO 8601 weeks start on Monday, and the first week of a year is the one containing
+    /// at least four days of that year.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::Date;
+    ///
+    /// // 2024-W09-3
+    /// let date_iso = Date::try_new_iso_week_date(2024, 9, IsoWeekday::Wednesday)
+    ///     .expect("Failed to initialize ISO Date instance.");
+    /// assert_eq!(date_iso, Date::try_new_iso_date(2024, 2, 28).unwrap());
+    ///
+    /// // 2020-W53-5 is in the next calendar year
+    /// let date_iso = Date::try_new_iso_week_date(2020, 53, IsoWeekday::Friday)
+    ///     .expect("Failed to initialize ISO Date instance.");
+    /// assert_eq!(date_iso, Date::try_new_iso_date(2021, 1, 1).unwrap());
+    ///
+    /// // 2021 only has 52 weeks
+    /// assert!(Date::try_new_iso_week_date(2021, 53, IsoWeekday::Monday).is_err());
+    /// ```
+    pub fn try_new_iso_week_date(
+        week_year: i32,
+        week: u16,
+        weekday: types::IsoWeekday,
+    ) -> Result<Date<Iso>, CalendarError> {
+        Self::try_new_iso_week_date_with_calculator(
+            week_year,
+            week,
+            weekday,
+            &WeekCalculator {
+                first_weekday: types::IsoWeekday::Monday,
+                min_week_days: 4,
+                weekend: None,
+            },
+        )
+    }
+
+    /// Construct a new ISO date from a week-based year, a week of that year, and a weekday,
+    /// using the week rules of the given [`WeekCalculator`].
+    ///
+    /// This is the inverse of [`Date::week_of_year()`].
+    ///
+    /// ```rust
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::week::WeekCalculator;
+    /// use icu::calendar::Date;
+    /// use icu::locid::locale;
+    ///
+    /// let week_calculator = WeekCalculator::try_new(&locale!("en-US").into())
+    ///     .expect("locale should be present");
+    ///
+    /// // In the US, week 1 of 2022 starts on Sunday, December 26, 2021
+    /// let date_iso = Date::try_new_iso_week_date_with_calculator(
+    ///     2022,
+    ///     1,
+    ///     IsoWeekday::Sunday,
+    ///     &week_calculator,
+    /// )
+    /// .expect("Failed to initialize ISO Date instance.");
+    /// assert_eq!(date_iso, Date::try_new_iso_date(2021, 12, 26).unwrap());
+    /// ```
+    pub fn try_new_iso_week_date_with_calculator(
+        week_year: i32,
+        week: u16,
+        weekday: types::IsoWeekday,
+        calculator: &WeekCalculator,
+    ) -> Result<Date<Iso>, CalendarError> {
+        let date_in_year = Date::try_new_iso_date(week_year, 1, 1)?;
+        calculator.date_from_week_of_year(&date_in_year, week, weekday)
+    }
+
     /// Constructs an ISO date representing the UNIX epoch on January 1, 1970.
     pub fn unix_epoch() -> Self {
         Date::from_raw(IsoDateInner(ArithmeticDate::new_unchecked(1970, 1, 1)), Iso)
@@ -128,7 +128,9 @@ mod error;
 pub mod ethiopian;
 pub mod gregorian;
 pub mod hebrew;
+pub mod hindu;
 pub mod indian;
+mod instant;
 pub mod islamic;
 pub mod iso;
 pub mod japanese;
@@ -137,6 +139,7 @@ pub mod persian;
 pub mod provider;
 pub mod roc;
 pub mod types;
+pub mod vietnamese;
 mod week_of;
 
 pub mod week {
@@ -152,11 +155,14 @@ pub use any_calendar::{AnyCalendar, AnyCalendarKind};
 pub use calendar::Calendar;
 pub use date::{AsCalendar, Date, Ref};
 pub use datetime::DateTime;
-#[doc(hidden)]
-pub use duration::{DateDuration, DateDurationUnit};
+pub use duration::{
+    ArithmeticOverflow, DateDuration, DateDurationUnit, DateTimeDifferenceOptions,
+    DateTimeDuration, DateTimeDurationUnit, RoundingMode, TimeDuration,
+};
 pub use error::CalendarError;
 #[doc(no_inline)]
 pub use gregorian::Gregorian;
+pub use instant::Instant;
 #[doc(no_inline)]
 pub use iso::Iso;
 
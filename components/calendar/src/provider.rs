@@ -16,7 +16,7 @@
 #![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
 
 pub mod chinese_based;
-pub use chinese_based::{ChineseCacheV1Marker, DangiCacheV1Marker};
+pub use chinese_based::{ChineseCacheV1Marker, DangiCacheV1Marker, VietnameseCacheV1Marker};
 
 use crate::types::IsoWeekday;
 use core::str::FromStr;
@@ -46,6 +46,7 @@ const _: () = {
     icu_calendar_data::impl_calendar_dangicache_v1!(Baked);
     icu_calendar_data::impl_calendar_japanese_v1!(Baked);
     icu_calendar_data::impl_calendar_japanext_v1!(Baked);
+    icu_calendar_data::impl_calendar_vietnamesecache_v1!(Baked);
     icu_calendar_data::impl_datetime_week_data_v1!(Baked);
     icu_calendar_data::impl_datetime_week_data_v2!(Baked);
 };
@@ -57,6 +58,7 @@ pub const KEYS: &[DataKey] = &[
     DangiCacheV1Marker::KEY,
     JapaneseErasV1Marker::KEY,
     JapaneseExtendedErasV1Marker::KEY,
+    VietnameseCacheV1Marker::KEY,
     WeekDataV2Marker::KEY,
     // We include the duplicate data for now, as icu_datetime loads it directly
     // https://github.com/unicode-org/icu4x/pull/4364#discussion_r1419877997
@@ -27,7 +27,8 @@ use zerovec::ZeroVec;
 /// operations.
 #[icu_provider::data_struct(
     marker(ChineseCacheV1Marker, "calendar/chinesecache@1", singleton),
-    marker(DangiCacheV1Marker, "calendar/dangicache@1", singleton)
+    marker(DangiCacheV1Marker, "calendar/dangicache@1", singleton),
+    marker(VietnameseCacheV1Marker, "calendar/vietnamesecache@1", singleton)
 )]
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(
@@ -608,6 +608,44 @@ impl Time {
             extra_days,
         )
     }
+
+    /// The number of nanoseconds since midnight represented by this time.
+    ///
+    /// This may be a day or longer for end-of-day times such as 24:00.
+    pub(crate) fn nanoseconds_since_midnight(self) -> i128 {
+        i128::from(self.hour.number()) * 3_600_000_000_000
+            + i128::from(self.minute.number()) * 60_000_000_000
+            + i128::from(self.second.number()) * 1_000_000_000
+            + i128::from(self.nanosecond.number())
+    }
+
+    /// Takes a number of nanoseconds, which could be positive or negative, and returns the Time
+    /// and the day number, which could be positive or negative.
+    pub(crate) fn from_nanosecond_with_remainder_days(nanosecond: i128) -> (Time, i128) {
+        let (extra_days, nanosecond_in_day) = (
+            nanosecond.div_euclid(86_400_000_000_000),
+            nanosecond.rem_euclid(86_400_000_000_000),
+        );
+        let (second_in_day, nanoseconds) = (
+            nanosecond_in_day / 1_000_000_000,
+            nanosecond_in_day % 1_000_000_000,
+        );
+        let (hours, minutes, seconds) = (
+            second_in_day / 3600,
+            second_in_day % 3600 / 60,
+            second_in_day % 60,
+        );
+        #[allow(clippy::unwrap_used)] // values are moduloed to be in range
+        (
+            Self {
+                hour: (hours as u8).try_into().unwrap(),
+                minute: (minutes as u8).try_into().unwrap(),
+                second: (seconds as u8).try_into().unwrap(),
+                nanosecond: (nanoseconds as u32).try_into().unwrap(),
+            },
+            extra_days,
+        )
+    }
 }
 
 #[test]
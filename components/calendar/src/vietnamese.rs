@@ -0,0 +1,575 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Vietnamese lunar calendar.
+//!
+//! ```rust
+//! use icu::calendar::vietnamese::Vietnamese;
+//! use icu::calendar::{Date, DateTime, Ref};
+//!
+//! let vietnamese = Vietnamese::new();
+//! let vietnamese = Ref(&vietnamese); // to avoid cloning
+//!
+//! // `Date` type
+//! let vietnamese_date = Date::try_new_vietnamese_date_with_calendar(4660, 6, 6, vietnamese)
+//!     .expect("Failed to initialize Vietnamese Date instance.");
+//!
+//! // `DateTime` type
+//! let vietnamese_datetime = DateTime::try_new_vietnamese_datetime_with_calendar(
+//!     4660, 6, 6, 13, 1, 0, vietnamese,
+//! )
+//! .expect("Failed to initialize Vietnamese DateTime instance.");
+//!
+//! // `Date` checks
+//! assert_eq!(vietnamese_date.year().number, 4660);
+//! assert_eq!(vietnamese_date.year().related_iso, Some(2023));
+//! assert_eq!(vietnamese_date.year().cyclic.unwrap().get(), 40);
+//! assert_eq!(vietnamese_date.month().ordinal, 6);
+//! assert_eq!(vietnamese_date.day_of_month().0, 6);
+//!
+//! // `DateTime` checks
+//! assert_eq!(vietnamese_datetime.date.year().number, 4660);
+//! assert_eq!(vietnamese_datetime.date.year().related_iso, Some(2023));
+//! assert_eq!(vietnamese_datetime.date.year().cyclic.unwrap().get(), 40);
+//! assert_eq!(vietnamese_datetime.date.month().ordinal, 6);
+//! assert_eq!(vietnamese_datetime.date.day_of_month().0, 6);
+//! assert_eq!(vietnamese_datetime.time.hour.number(), 13);
+//! assert_eq!(vietnamese_datetime.time.minute.number(), 1);
+//! assert_eq!(vietnamese_datetime.time.second.number(), 0);
+//! ```
+
+use crate::calendar_arithmetic::CalendarArithmetic;
+use crate::calendar_arithmetic::PrecomputedDataSource;
+use crate::chinese_based::{
+    chinese_based_ordinal_lunar_month_from_code, ChineseBasedPrecomputedData,
+    ChineseBasedWithDataLoading, ChineseBasedYearInfo,
+};
+use crate::provider::chinese_based::VietnameseCacheV1Marker;
+use crate::AsCalendar;
+use crate::{
+    chinese_based::ChineseBasedDateInner,
+    types::{self, Era, FormattableYear},
+    AnyCalendarKind, Calendar, CalendarError, Date, DateTime, Iso,
+};
+use core::cmp::Ordering;
+use core::num::NonZeroU8;
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+
+/// The Vietnamese Calendar
+///
+/// The Vietnamese Calendar (Âm lịch) is a lunisolar calendar used traditionally in Vietnam.
+/// It is often used today to track important cultural events and holidays like Tết
+/// (Vietnamese lunar new year). It is similar to the Chinese lunar calendar (see `Chinese`),
+/// except that since 1968 observations are based in Vietnam (UTC+7) rather than China (UTC+8).
+/// This can cause some differences; for example, in 1985 Tết fell on January 21, a full month
+/// before the Chinese new year on February 20, and in 2007 Tết fell one day before it.
+///
+/// CLDR has no data for this calendar, so it has no `-u-ca` value and cannot be formatted by
+/// `icu_datetime`.
+///
+/// ```rust
+/// use icu::calendar::{chinese::Chinese, vietnamese::Vietnamese, Date};
+/// use tinystr::tinystr;
+///
+/// let iso = Date::try_new_iso_date(1985, 2, 1).unwrap();
+/// let vietnamese = iso.to_calendar(Vietnamese::new());
+/// let chinese = iso.to_calendar(Chinese::new());
+///
+/// assert_eq!(vietnamese.year().related_iso, Some(1985));
+/// assert_eq!(vietnamese.month().code.0, tinystr!(4, "M01"));
+/// assert_eq!(vietnamese.day_of_month().0, 12);
+///
+/// assert_eq!(chinese.year().related_iso, Some(1984));
+/// assert_eq!(chinese.month().code.0, tinystr!(4, "M12"));
+/// assert_eq!(chinese.day_of_month().0, 12);
+/// ```
+/// # Era codes
+///
+/// This Calendar supports a single era code "vietnam", counting years the same way as the
+/// Chinese calendar, with -2636 ISO (2637 BCE) as year 1. Typically years will be formatted
+/// using cyclic years and the related ISO year.
+///
+/// # Month codes
+///
+/// This calendar is a lunisolar calendar. It supports regular month codes `"M01" - "M12"` as well
+/// as leap month codes `"M01L" - "M12L"`.
+#[derive(Clone, Debug, Default)]
+pub struct Vietnamese {
+    data: Option<DataPayload<VietnameseCacheV1Marker>>,
+}
+
+/// The inner date type used for representing [`Date`]s of [`Vietnamese`]. See [`Date`] and [`Vietnamese`] for more detail.
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct VietnameseDateInner(ChineseBasedDateInner<Vietnamese>);
+
+type Inner = ChineseBasedDateInner<Vietnamese>;
+
+// we want these impls without the `C: Copy/Clone` bounds
+impl Copy for VietnameseDateInner {}
+impl Clone for VietnameseDateInner {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// These impls just make custom derives on types containing C
+// work. They're basically no-ops
+impl PartialEq for Vietnamese {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Vietnamese {}
+#[allow(clippy::non_canonical_partial_ord_impl)] // this is intentional
+impl PartialOrd for Vietnamese {
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl Ord for Vietnamese {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Vietnamese {
+    /// Creates a new [`Vietnamese`] with some precomputed calendrical calculations.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            data: Some(DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_CALENDAR_VIETNAMESECACHE_V1,
+            )),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: CalendarError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+    ]);
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D: DataProvider<VietnameseCacheV1Marker> + ?Sized>(
+        provider: &D,
+    ) -> Result<Self, CalendarError> {
+        Ok(Self {
+            data: Some(provider.load(Default::default())?.take_payload()?),
+        })
+    }
+
+    /// Construct a new [`Vietnamese`] without any precomputed calendrical calculations.
+    pub fn new_always_calculating() -> Self {
+        Vietnamese { data: None }
+    }
+
+    pub(crate) const DEBUG_NAME: &'static str = "Vietnamese";
+}
+
+impl Calendar for Vietnamese {
+    type DateInner = VietnameseDateInner;
+
+    fn date_from_codes(
+        &self,
+        era: crate::types::Era,
+        year: i32,
+        month_code: crate::types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, crate::Error> {
+        let year_info = self.get_precomputed_data().load_or_compute_info(year);
+
+        let month = if let Some(ordinal) =
+            chinese_based_ordinal_lunar_month_from_code(month_code, year_info)
+        {
+            ordinal
+        } else {
+            return Err(CalendarError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ));
+        };
+
+        if era.0 != tinystr!(16, "vietnam") {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let arithmetic = Inner::new_from_ordinals(year, month, day, year_info);
+        Ok(VietnameseDateInner(ChineseBasedDateInner(arithmetic?)))
+    }
+
+    fn date_from_iso(&self, iso: Date<crate::Iso>) -> Self::DateInner {
+        let fixed = Iso::fixed_from_iso(iso.inner);
+        VietnameseDateInner(Inner::chinese_based_date_from_fixed(
+            self,
+            fixed,
+            iso.inner.0,
+        ))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<crate::Iso> {
+        let fixed = Inner::fixed_from_chinese_based_date_inner(date.0);
+        Iso::iso_from_fixed(fixed)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year_inner()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year_inner()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month_inner()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: crate::DateDuration<Self>) {
+        date.0 .0.offset_date(offset, &self.get_precomputed_data());
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: crate::DateDurationUnit,
+        smallest_unit: crate::DateDurationUnit,
+    ) -> crate::DateDuration<Self> {
+        date1.0 .0.until(date2.0 .0, largest_unit, smallest_unit)
+    }
+
+    fn debug_name(&self) -> &'static str {
+        Self::DEBUG_NAME
+    }
+
+    fn year(&self, date: &Self::DateInner) -> crate::types::FormattableYear {
+        Self::format_vietnamese_year(date.0 .0.year, Some(date.0 .0.year_info))
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::is_leap_year(date.0 .0.year, date.0 .0.year_info)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> crate::types::FormattableMonth {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> crate::types::DayOfMonth {
+        types::DayOfMonth(date.0 .0.day as u32)
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> crate::types::DayOfYearInfo {
+        let prev_year = date.0 .0.year.saturating_sub(1);
+        let next_year = date.0 .0.year.saturating_add(1);
+        types::DayOfYearInfo {
+            day_of_year: date.0 .0.day_of_year(),
+            days_in_year: date.0.days_in_year_inner(),
+            prev_year: Self::format_vietnamese_year(prev_year, None),
+            days_in_prev_year: date.0.days_in_prev_year(),
+            next_year: Self::format_vietnamese_year(next_year, None),
+        }
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> crate::types::IsoWeekday {
+        self.date_to_iso(date).day_of_week()
+    }
+
+    fn any_calendar_kind(&self) -> Option<crate::AnyCalendarKind> {
+        Some(AnyCalendarKind::Vietnamese)
+    }
+}
+
+impl<A: AsCalendar<Calendar = Vietnamese>> Date<A> {
+    /// Construct a new Vietnamese date from a `year`, `month`, and `day`.
+    /// `year` represents the Vietnamese year counted infinitely with -2636 (2637 BCE) as year 1;
+    /// `month` represents the month of the year ordinally (ex. if it is a leap year, the last month will be 13, not 12);
+    /// `day` indicates day of month.
+    ///
+    /// ```rust
+    /// use icu::calendar::vietnamese::Vietnamese;
+    /// use icu::calendar::Date;
+    ///
+    /// let vietnamese = Vietnamese::new();
+    ///
+    /// let date_vietnamese = Date::try_new_vietnamese_date_with_calendar(4660, 6, 18, vietnamese)
+    ///     .expect("Failed to initialize Vietnamese Date instance.");
+    ///
+    /// assert_eq!(date_vietnamese.year().number, 4660);
+    /// assert_eq!(date_vietnamese.year().cyclic.unwrap().get(), 40);
+    /// assert_eq!(date_vietnamese.year().related_iso, Some(2023));
+    /// assert_eq!(date_vietnamese.month().ordinal, 6);
+    /// assert_eq!(date_vietnamese.day_of_month().0, 18);
+    /// ```
+    pub fn try_new_vietnamese_date_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Date<A>, CalendarError> {
+        let year_info = calendar
+            .as_calendar()
+            .get_precomputed_data()
+            .load_or_compute_info(year);
+        let arithmetic = Inner::new_from_ordinals(year, month, day, year_info);
+        Ok(Date::from_raw(
+            VietnameseDateInner(ChineseBasedDateInner(arithmetic?)),
+            calendar,
+        ))
+    }
+}
+
+impl<A: AsCalendar<Calendar = Vietnamese>> DateTime<A> {
+    /// Construct a new Vietnamese DateTime from integers. See `try_new_vietnamese_date_with_calendar`.
+    ///
+    /// ```rust
+    /// use icu::calendar::vietnamese::Vietnamese;
+    /// use icu::calendar::DateTime;
+    ///
+    /// let vietnamese = Vietnamese::new();
+    ///
+    /// let vietnamese_datetime = DateTime::try_new_vietnamese_datetime_with_calendar(
+    ///     4660, 6, 6, 13, 1, 0, vietnamese,
+    /// )
+    /// .expect("Failed to initialize Vietnamese DateTime instance.");
+    ///
+    /// assert_eq!(vietnamese_datetime.date.year().number, 4660);
+    /// assert_eq!(vietnamese_datetime.date.year().related_iso, Some(2023));
+    /// assert_eq!(vietnamese_datetime.date.year().cyclic.unwrap().get(), 40);
+    /// assert_eq!(vietnamese_datetime.date.month().ordinal, 6);
+    /// assert_eq!(vietnamese_datetime.date.day_of_month().0, 6);
+    /// assert_eq!(vietnamese_datetime.time.hour.number(), 13);
+    /// assert_eq!(vietnamese_datetime.time.minute.number(), 1);
+    /// assert_eq!(vietnamese_datetime.time.second.number(), 0);
+    /// ```
+    pub fn try_new_vietnamese_datetime_with_calendar(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        calendar: A,
+    ) -> Result<DateTime<A>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_vietnamese_date_with_calendar(year, month, day, calendar)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+type VietnameseCB = calendrical_calculations::chinese_based::Vietnamese;
+impl ChineseBasedWithDataLoading for Vietnamese {
+    type CB = VietnameseCB;
+    fn get_precomputed_data(&self) -> ChineseBasedPrecomputedData<Self::CB> {
+        ChineseBasedPrecomputedData::new(self.data.as_ref().map(|d| d.get()))
+    }
+}
+
+impl Vietnamese {
+    /// Get a `FormattableYear` from an integer Vietnamese year; optionally, a `ChineseBasedYearInfo`
+    /// can be passed in for faster results.
+    fn format_vietnamese_year(
+        year: i32,
+        year_info_option: Option<ChineseBasedYearInfo>,
+    ) -> FormattableYear {
+        let era = Era(tinystr!(16, "vietnam"));
+        let number = year;
+        let cyclic = (number - 1).rem_euclid(60) as u8;
+        let cyclic = NonZeroU8::new(cyclic + 1); // 1-indexed
+        let rata_die_in_year = if let Some(info) = year_info_option {
+            info.new_year::<VietnameseCB>(year)
+        } else {
+            Inner::fixed_mid_year_from_year(number)
+        };
+        let iso_formattable_year = Iso::iso_from_fixed(rata_die_in_year).year();
+        let related_iso = Some(iso_formattable_year.number);
+        types::FormattableYear {
+            era,
+            number,
+            cyclic,
+            related_iso,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::chinese::Chinese;
+    use calendrical_calculations::chinese_based::ChineseBased;
+    use calendrical_calculations::rata_die::RataDie;
+
+    /// Run a test twice, with two calendars
+    fn do_twice(
+        vietnamese_calculating: &Vietnamese,
+        vietnamese_cached: &Vietnamese,
+        test: impl Fn(crate::Ref<Vietnamese>, &'static str),
+    ) {
+        test(crate::Ref(vietnamese_calculating), "calculating");
+        test(crate::Ref(vietnamese_cached), "cached");
+    }
+
+    #[test]
+    fn test_cyclic_same_as_chinese_near_present_day() {
+        for year in 1923..=2123 {
+            let iso = Date::try_new_iso_date(year, 6, 6).unwrap();
+            let chinese = iso.to_calendar(Chinese::new_always_calculating());
+            let vietnamese = iso.to_calendar(Vietnamese::new_always_calculating());
+            assert_eq!(
+                chinese.year().cyclic,
+                vietnamese.year().cyclic,
+                "Cyclic year failed for year: {year}"
+            );
+            assert_eq!(
+                vietnamese.year().related_iso,
+                Some(year),
+                "Vietnamese Rel. ISO failed for year: {year}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_iso_to_vietnamese_roundtrip() {
+        let mut fixed = -1963020;
+        let max_fixed = 1963020;
+        let mut iters = 0;
+        let max_iters = 560;
+        let vietnamese_calculating = Vietnamese::new_always_calculating();
+        let vietnamese_cached = Vietnamese::new();
+        while fixed < max_fixed && iters < max_iters {
+            let rata_die = RataDie::new(fixed);
+            let iso = Iso::iso_from_fixed(rata_die);
+            do_twice(
+                &vietnamese_calculating,
+                &vietnamese_cached,
+                |vietnamese, calendar_type| {
+                    let date = iso.to_calendar(vietnamese);
+                    let result = date.to_calendar(Iso);
+                    assert_eq!(
+                        iso, result,
+                        "[{calendar_type}] Failed roundtrip ISO -> Vietnamese -> ISO for fixed: {fixed}"
+                    );
+                },
+            );
+
+            fixed += 7043;
+            iters += 1;
+        }
+    }
+
+    #[test]
+    fn test_tet_divergent_from_chinese_new_year() {
+        #[derive(Debug)]
+        struct TestCase {
+            related_iso: i32,
+            tet: (u8, u8),
+            chinese_new_year: (u8, u8),
+        }
+
+        let cases = [
+            TestCase {
+                // Vietnam switched to UTC+7 in 1968
+                related_iso: 1968,
+                tet: (1, 29),
+                chinese_new_year: (1, 30),
+            },
+            TestCase {
+                // The winter solstice month differs, so Tết is a whole month early
+                related_iso: 1985,
+                tet: (1, 21),
+                chinese_new_year: (2, 20),
+            },
+            TestCase {
+                related_iso: 2007,
+                tet: (2, 17),
+                chinese_new_year: (2, 18),
+            },
+            TestCase {
+                related_iso: 2024,
+                tet: (2, 10),
+                chinese_new_year: (2, 10),
+            },
+        ];
+
+        let vietnamese_calculating = Vietnamese::new_always_calculating();
+        let vietnamese_cached = Vietnamese::new();
+        let chinese = Chinese::new();
+        for case in cases {
+            let year = VietnameseCB::extended_from_iso(case.related_iso);
+            do_twice(
+                &vietnamese_calculating,
+                &vietnamese_cached,
+                |vietnamese, calendar_type| {
+                    let tet = Date::try_new_vietnamese_date_with_calendar(year, 1, 1, vietnamese)
+                        .unwrap()
+                        .to_iso();
+                    assert_eq!(
+                        (tet.month().ordinal as u8, tet.day_of_month().0 as u8),
+                        case.tet,
+                        "[{calendar_type}] Tết failed for {case:?}"
+                    );
+                    assert_eq!(tet.year().number, case.related_iso);
+                },
+            );
+            let new_year =
+                Date::try_new_chinese_date_with_calendar(year, 1, 1, crate::Ref(&chinese))
+                    .unwrap()
+                    .to_iso();
+            assert_eq!(
+                (
+                    new_year.month().ordinal as u8,
+                    new_year.day_of_month().0 as u8
+                ),
+                case.chinese_new_year,
+                "Chinese new year failed for {case:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_vietnamese_from_codes() {
+        // In 1987 the leap month was the 7th month in Vietnam, but the 6th in China
+        let vietnamese = Vietnamese::new();
+        let date = Date::try_new_from_codes(
+            Era(tinystr!(16, "vietnam")),
+            4624,
+            types::MonthCode(tinystr!(4, "M07L")),
+            1,
+            crate::Ref(&vietnamese),
+        )
+        .unwrap();
+        assert_eq!(date.month().ordinal, 8);
+        assert_eq!(date.year().related_iso, Some(1987));
+        assert_eq!(date.to_iso(), Date::try_new_iso_date(1987, 8, 24).unwrap());
+
+        let err = Date::try_new_from_codes(
+            Era(tinystr!(16, "vietnam")),
+            4624,
+            types::MonthCode(tinystr!(4, "M06L")),
+            1,
+            crate::Ref(&vietnamese),
+        );
+        assert!(matches!(err, Err(CalendarError::UnknownMonthCode(..))));
+
+        let err = Date::try_new_from_codes(
+            Era(tinystr!(16, "chinese")),
+            4624,
+            types::MonthCode(tinystr!(4, "M01")),
+            1,
+            crate::Ref(&vietnamese),
+        );
+        assert!(matches!(err, Err(CalendarError::UnknownEra(..))));
+    }
+}
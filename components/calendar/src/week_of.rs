@@ -6,7 +6,10 @@ use crate::{
     error::CalendarError,
     provider::*,
     types::{DayOfMonth, DayOfYearInfo, IsoWeekday, WeekOfMonth},
+    AsCalendar, Date, DateDuration, Iso,
 };
+use calendrical_calculations::helpers::i64_to_saturated_i32;
+use calendrical_calculations::rata_die::RataDie;
 use icu_provider::prelude::*;
 
 /// Minimum number of days in a month unit required for using this module
@@ -183,6 +186,336 @@ impl WeekCalculator {
         )
     }
 
+    /// Returns the number of weeks in a week-based year according to this calendar.
+    ///
+    /// The week-based year is identified by `date_in_year`: it has the same number as the
+    /// calendar year containing `date_in_year`, even though its first and last days may
+    /// lie in the previous or next calendar year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    ///
+    /// let mut week_calculator = WeekCalculator::default();
+    /// week_calculator.first_weekday = IsoWeekday::Monday;
+    /// week_calculator.min_week_days = 4;
+    ///
+    /// let date_in_2020 = Date::try_new_iso_date(2020, 5, 1).unwrap();
+    /// let date_in_2021 = Date::try_new_iso_date(2021, 5, 1).unwrap();
+    ///
+    /// assert_eq!(week_calculator.weeks_in_year(&date_in_2020), Ok(53));
+    /// assert_eq!(week_calculator.weeks_in_year(&date_in_2021), Ok(52));
+    /// ```
+    pub fn weeks_in_year<A: AsCalendar>(
+        &self,
+        date_in_year: &Date<A>,
+    ) -> Result<u16, CalendarError> {
+        Ok(year_info(date_in_year)?.num_weeks(self))
+    }
+
+    /// Returns the date of the given weekday in the given week of a week-based year
+    /// according to this calendar.
+    ///
+    /// This is the inverse of [`Date::week_of_year()`]. The week-based year is identified by
+    /// `date_in_year` as in [`Self::weeks_in_year()`].
+    ///
+    /// Returns an error if `week` is zero or larger than the number of weeks in the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    ///
+    /// // Weeks start on Sunday, and the first week of the year is the one containing January 1
+    /// let mut week_calculator = WeekCalculator::default();
+    /// week_calculator.first_weekday = IsoWeekday::Sunday;
+    /// week_calculator.min_week_days = 1;
+    ///
+    /// let date_in_2022 = Date::try_new_iso_date(2022, 8, 26).unwrap();
+    ///
+    /// // Week 1 of 2022 starts on Sunday, December 26, 2021
+    /// assert_eq!(
+    ///     week_calculator.date_from_week_of_year(&date_in_2022, 1, IsoWeekday::Sunday),
+    ///     Date::try_new_iso_date(2021, 12, 26)
+    /// );
+    /// assert_eq!(
+    ///     week_calculator.date_from_week_of_year(&date_in_2022, 35, IsoWeekday::Friday),
+    ///     Ok(date_in_2022)
+    /// );
+    /// ```
+    pub fn date_from_week_of_year<A: AsCalendar + Clone>(
+        &self,
+        date_in_year: &Date<A>,
+        week: u16,
+        weekday: IsoWeekday,
+    ) -> Result<Date<A>, CalendarError> {
+        let year = year_info(date_in_year)?;
+        if week == 0 {
+            return Err(CalendarError::Underflow {
+                field: "week",
+                min: 1,
+            });
+        }
+        let num_weeks = year.num_weeks(self);
+        if week > num_weeks {
+            return Err(CalendarError::Overflow {
+                field: "week",
+                max: num_weeks as usize,
+            });
+        }
+        // 1-based day of the year, which may be outside of the year for the first and last weeks
+        let day_of_year = i32::from(year.first_week_offset(self))
+            + 7 * (i32::from(week) - 1)
+            + i32::from(self.weekday_index(weekday))
+            + 1;
+        Ok(date_in_year.clone().added(DateDuration::new(
+            0,
+            0,
+            0,
+            day_of_year - i32::from(date_in_year.day_of_year_info().day_of_year),
+        )))
+    }
+
+    /// Returns the seven dates of the given week of a week-based year according to this
+    /// calendar, starting with [`Self::first_weekday`].
+    ///
+    /// The week-based year is identified by `date_in_year` as in [`Self::weeks_in_year()`].
+    ///
+    /// Returns an error if `week` is zero or larger than the number of weeks in the year.
+    pub fn dates_of_week_of_year<A: AsCalendar + Clone>(
+        &self,
+        date_in_year: &Date<A>,
+        week: u16,
+    ) -> Result<impl Iterator<Item = Date<A>>, CalendarError> {
+        let first_day = self.date_from_week_of_year(date_in_year, week, self.first_weekday)?;
+        Ok(days_from(first_day))
+    }
+
+    /// Returns the seven dates of the week containing `date` according to this calendar,
+    /// starting with [`Self::first_weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    ///
+    /// let mut week_calculator = WeekCalculator::default();
+    /// week_calculator.first_weekday = IsoWeekday::Saturday;
+    ///
+    /// let date = Date::try_new_iso_date(2024, 2, 29).unwrap();
+    /// let week: Vec<_> = week_calculator.dates_of_week(&date).collect();
+    ///
+    /// assert_eq!(week.len(), 7);
+    /// assert_eq!(week[0], Date::try_new_iso_date(2024, 2, 24).unwrap());
+    /// assert_eq!(week[5], date);
+    /// assert_eq!(week[6], Date::try_new_iso_date(2024, 3, 1).unwrap());
+    /// ```
+    pub fn dates_of_week<A: AsCalendar + Clone>(
+        &self,
+        date: &Date<A>,
+    ) -> impl Iterator<Item = Date<A>> {
+        let first_day = date.clone().added(DateDuration::new(
+            0,
+            0,
+            0,
+            -i32::from(self.weekday_index(date.day_of_week())),
+        ));
+        days_from(first_day)
+    }
+
+    /// Returns whether `date` is a business day: a day that is neither part of the
+    /// [weekend](Self::weekend) nor contained in `holidays`.
+    ///
+    /// Holidays are given as ISO dates so that they can be compared against dates in any
+    /// calendar; use [`Date::to_iso()`] to convert them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    ///
+    /// let week_calculator = WeekCalculator::default();
+    /// let holidays = [Date::try_new_iso_date(2024, 1, 1).unwrap()];
+    ///
+    /// let friday = Date::try_new_iso_date(2024, 1, 5).unwrap();
+    /// let saturday = Date::try_new_iso_date(2024, 1, 6).unwrap();
+    ///
+    /// assert!(week_calculator.is_business_day(&friday, None));
+    /// assert!(!week_calculator.is_business_day(&saturday, None));
+    /// assert!(!week_calculator.is_business_day(&holidays[0], Some(&holidays)));
+    /// ```
+    pub fn is_business_day<A: AsCalendar>(
+        &self,
+        date: &Date<A>,
+        holidays: Option<&[Date<Iso>]>,
+    ) -> bool {
+        self.is_business_rata_die(rata_die(date), holidays.unwrap_or_default())
+    }
+
+    /// Returns the date that is `days` business days after `date`, or before it if `days` is
+    /// negative. Weekend days and `holidays` are skipped, see [`Self::is_business_day()`].
+    ///
+    /// If `days` is zero, `date` is returned as is, even if it is not a business day.
+    /// Otherwise, the result is always a business day.
+    ///
+    /// Returns an error if `days` is nonzero and every day of the week is part of the weekend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    /// use icu_calendar::Gregorian;
+    ///
+    /// let week_calculator = WeekCalculator::default();
+    /// let holidays = [Date::try_new_iso_date(2023, 12, 25).unwrap()];
+    ///
+    /// let date = Date::try_new_gregorian_date(2023, 12, 22).unwrap();
+    /// assert_eq!(date.day_of_week(), IsoWeekday::Friday);
+    ///
+    /// assert_eq!(
+    ///     week_calculator.add_business_days(&date, 1, None),
+    ///     Date::try_new_gregorian_date(2023, 12, 25)
+    /// );
+    /// assert_eq!(
+    ///     week_calculator.add_business_days(&date, 1, Some(&holidays)),
+    ///     Date::try_new_gregorian_date(2023, 12, 26)
+    /// );
+    /// assert_eq!(
+    ///     week_calculator.add_business_days(&date, -5, None),
+    ///     Date::try_new_gregorian_date(2023, 12, 15)
+    /// );
+    /// ```
+    pub fn add_business_days<A: AsCalendar + Clone>(
+        &self,
+        date: &Date<A>,
+        days: i32,
+        holidays: Option<&[Date<Iso>]>,
+    ) -> Result<Date<A>, CalendarError> {
+        let holidays = holidays.unwrap_or_default();
+        let business_days_per_week = 7 - self.weekend().count() as u32;
+        if business_days_per_week == 0 && days != 0 {
+            return Err(CalendarError::OutOfRange);
+        }
+
+        let step = if days < 0 { -1 } else { 1 };
+        let mut remaining = days.unsigned_abs();
+        let mut current = rata_die(date);
+        while remaining > 0 {
+            if remaining > business_days_per_week {
+                // Skip whole weeks, keeping at least one business day to go so that the
+                // result lands on a business day.
+                let weeks = (remaining - 1) / business_days_per_week;
+                let next = current + step * 7 * i64::from(weeks);
+                let skipped_holidays = if step > 0 {
+                    self.count_business_holidays(holidays, current + 1, next + 1)
+                } else {
+                    self.count_business_holidays(holidays, next, current)
+                };
+                remaining = remaining - weeks * business_days_per_week + skipped_holidays;
+                current = next;
+            } else {
+                current += step;
+                if self.is_business_rata_die(current, holidays) {
+                    remaining -= 1;
+                }
+            }
+        }
+
+        Ok(Iso::iso_from_fixed(current).to_calendar(date.calendar.clone()))
+    }
+
+    /// Returns the number of business days from `start` (inclusive) to `end` (exclusive).
+    /// Weekend days and `holidays` are not counted, see [`Self::is_business_day()`].
+    ///
+    /// The result is negative if `end` is before `start`, in which case the business days
+    /// from `end` (inclusive) to `start` (exclusive) are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::week::WeekCalculator;
+    /// use icu_calendar::Date;
+    ///
+    /// let week_calculator = WeekCalculator::default();
+    /// let holidays = [Date::try_new_iso_date(2024, 1, 1).unwrap()];
+    ///
+    /// let start = Date::try_new_iso_date(2024, 1, 1).unwrap();
+    /// let end = Date::try_new_iso_date(2024, 2, 1).unwrap();
+    ///
+    /// assert_eq!(week_calculator.business_days_between(&start, &end, None), 23);
+    /// assert_eq!(
+    ///     week_calculator.business_days_between(&start, &end, Some(&holidays)),
+    ///     22
+    /// );
+    /// assert_eq!(week_calculator.business_days_between(&end, &start, None), -23);
+    /// ```
+    pub fn business_days_between<A: AsCalendar, B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        start: &Date<A>,
+        end: &Date<B>,
+        holidays: Option<&[Date<Iso>]>,
+    ) -> i32 {
+        let holidays = holidays.unwrap_or_default();
+        let (start, end) = (rata_die(start), rata_die(end));
+        let (from, to, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+
+        let weeks = (to - from) / 7;
+        let business_days_per_week = 7 - self.weekend().count() as i64;
+        let mut count = weeks * business_days_per_week;
+        let mut day = from + 7 * weeks;
+        while day < to {
+            if !self.is_weekend_rata_die(day) {
+                count += 1;
+            }
+            day += 1;
+        }
+        count -= i64::from(self.count_business_holidays(holidays, from, to));
+
+        i64_to_saturated_i32(sign * count)
+    }
+
+    /// Returns whether the day `date` is part of the weekend.
+    fn is_weekend_rata_die(&self, date: RataDie) -> bool {
+        self.weekend
+            .map_or(false, |weekend| weekend.contains(weekday_of(date)))
+    }
+
+    /// Returns whether the day `date` is neither part of the weekend nor a holiday.
+    fn is_business_rata_die(&self, date: RataDie, holidays: &[Date<Iso>]) -> bool {
+        !self.is_weekend_rata_die(date)
+            && !holidays
+                .iter()
+                .any(|holiday| Iso::fixed_from_iso(holiday.inner) == date)
+    }
+
+    /// Returns the number of distinct holidays in `[from, to)` that are not part of the weekend.
+    fn count_business_holidays(&self, holidays: &[Date<Iso>], from: RataDie, to: RataDie) -> u32 {
+        holidays
+            .iter()
+            .enumerate()
+            .filter(|&(i, holiday)| {
+                let holiday_rd = Iso::fixed_from_iso(holiday.inner);
+                (from..to).contains(&holiday_rd)
+                    && !self.is_weekend_rata_die(holiday_rd)
+                    && !holidays.iter().take(i).any(|h| h == holiday)
+            })
+            .count() as u32
+    }
+
     /// Returns the zero based index of `weekday` vs this calendar's start of week.
     fn weekday_index(&self, weekday: IsoWeekday) -> i8 {
         (7 + (weekday as i8) - (self.first_weekday as i8)) % 7
@@ -208,6 +541,34 @@ impl Default for WeekCalculator {
     }
 }
 
+/// Returns the [`UnitInfo`] for the calendar year containing `date`.
+fn year_info<A: AsCalendar>(date: &Date<A>) -> Result<UnitInfo, CalendarError> {
+    let day_of_year_info = date.day_of_year_info();
+    UnitInfo::new(
+        add_to_weekday(
+            date.day_of_week(),
+            1 - i32::from(day_of_year_info.day_of_year),
+        ),
+        day_of_year_info.days_in_year,
+    )
+}
+
+/// Returns the [`RataDie`] of `date`.
+fn rata_die<A: AsCalendar>(date: &Date<A>) -> RataDie {
+    Iso::fixed_from_iso(date.to_iso().inner)
+}
+
+/// Returns the weekday of the day `date`.
+fn weekday_of(date: RataDie) -> IsoWeekday {
+    // R.D. 1 is a Monday
+    IsoWeekday::from(date.to_i64_date().rem_euclid(7) as usize)
+}
+
+/// Returns the seven consecutive days starting with `first_day`.
+fn days_from<A: AsCalendar + Clone>(first_day: Date<A>) -> impl Iterator<Item = Date<A>> {
+    (0..7).map(move |i| first_day.clone().added(DateDuration::new(0, 0, 0, i)))
+}
+
 /// Returns the weekday that's `num_days` after `weekday`.
 fn add_to_weekday(weekday: IsoWeekday, num_days: i32) -> IsoWeekday {
     let new_weekday = (7 + (weekday as i32) + (num_days % 7)) % 7;
@@ -709,6 +1070,204 @@ fn test_simple_week_of() {
     );
 }
 
+#[test]
+fn test_date_from_week_of_year() {
+    use crate::Iso;
+
+    let iso_calendar = WeekCalculator {
+        first_weekday: IsoWeekday::Monday,
+        min_week_days: 4,
+        weekend: None,
+    };
+    let us_calendar = WeekCalculator {
+        first_weekday: IsoWeekday::Sunday,
+        min_week_days: 1,
+        weekend: None,
+    };
+
+    // Every date round-trips through its week of year
+    for calendar in [&iso_calendar, &us_calendar] {
+        let mut date = Date::try_new_iso_date(1999, 12, 1).unwrap();
+        while date.year().number < 2031 {
+            let week_of = date.week_of_year(calendar).unwrap();
+            let week_year = match week_of.unit {
+                RelativeUnit::Previous => date.year().number - 1,
+                RelativeUnit::Current => date.year().number,
+                RelativeUnit::Next => date.year().number + 1,
+            };
+            assert_eq!(
+                Date::try_new_iso_week_date_with_calculator(
+                    week_year,
+                    week_of.week,
+                    date.day_of_week(),
+                    calendar
+                ),
+                Ok(date),
+                "{calendar:?}"
+            );
+            date.add(DateDuration::new(0, 0, 0, 1));
+        }
+    }
+
+    assert_eq!(
+        Date::try_new_iso_week_date(2026, 1, IsoWeekday::Monday),
+        Date::try_new_iso_date(2025, 12, 29)
+    );
+    assert_eq!(
+        Date::try_new_iso_week_date(2021, 1, IsoWeekday::Monday),
+        Date::try_new_iso_date(2021, 1, 4)
+    );
+    assert_eq!(
+        Date::try_new_iso_week_date(2020, 0, IsoWeekday::Monday),
+        Err(CalendarError::Underflow {
+            field: "week",
+            min: 1
+        })
+    );
+    assert_eq!(
+        Date::try_new_iso_week_date(2021, 53, IsoWeekday::Monday),
+        Err(CalendarError::Overflow {
+            field: "week",
+            max: 52
+        })
+    );
+
+    // The weeks of a year cover it without gaps
+    let date_in_2020 = Date::try_new_iso_date(2020, 6, 1).unwrap();
+    let weeks = iso_calendar.weeks_in_year(&date_in_2020).unwrap();
+    assert_eq!(weeks, 53);
+    let dates: Vec<Date<Iso>> = (1..=weeks)
+        .flat_map(|week| {
+            iso_calendar
+                .dates_of_week_of_year(&date_in_2020, week)
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(dates.len(), 7 * 53);
+    assert_eq!(dates[0], Date::try_new_iso_date(2019, 12, 30).unwrap());
+    assert_eq!(
+        dates[dates.len() - 1],
+        Date::try_new_iso_date(2021, 1, 3).unwrap()
+    );
+    for pair in dates.windows(2) {
+        assert_eq!(pair[0].added(DateDuration::new(0, 0, 0, 1)), pair[1]);
+    }
+
+    // Works for non-ISO calendars
+    let hebrew = Date::try_new_iso_date(2024, 2, 28)
+        .unwrap()
+        .to_calendar(crate::hebrew::Hebrew::new_always_calculating());
+    let week_of = hebrew.week_of_year(&iso_calendar).unwrap();
+    assert_eq!(week_of.unit, RelativeUnit::Current);
+    assert_eq!(
+        iso_calendar.date_from_week_of_year(&hebrew, week_of.week, IsoWeekday::Wednesday),
+        Ok(hebrew)
+    );
+}
+
+#[test]
+fn test_business_days() {
+    use crate::{Gregorian, Iso};
+    use IsoWeekday::*;
+
+    fn naive_add(
+        calendar: &WeekCalculator,
+        date: Date<Iso>,
+        days: i32,
+        holidays: &[Date<Iso>],
+    ) -> Date<Iso> {
+        let mut date = date;
+        let mut remaining = days;
+        while remaining != 0 {
+            date.add(DateDuration::new(0, 0, 0, remaining.signum()));
+            if calendar.is_business_day(&date, Some(holidays)) {
+                remaining -= remaining.signum();
+            }
+        }
+        date
+    }
+
+    let holidays = [
+        Date::try_new_iso_date(2024, 1, 1).unwrap(),
+        Date::try_new_iso_date(2024, 1, 5).unwrap(),
+        Date::try_new_iso_date(2024, 1, 6).unwrap(),
+        Date::try_new_iso_date(2024, 1, 19).unwrap(),
+        Date::try_new_iso_date(2024, 1, 19).unwrap(),
+        Date::try_new_iso_date(2024, 3, 1).unwrap(),
+    ];
+    let weekends = [
+        WeekdaySet::new(&[]),
+        WeekdaySet::new(&[Saturday, Sunday]),
+        WeekdaySet::new(&[Friday, Saturday]),
+        WeekdaySet::new(&[Friday]),
+        WeekdaySet::new(&[Monday, Wednesday, Friday, Sunday]),
+    ];
+
+    for weekend in weekends {
+        let calendar = WeekCalculator {
+            first_weekday: Monday,
+            min_week_days: 1,
+            weekend: Some(weekend),
+        };
+        for holidays in [&[][..], &holidays[..]] {
+            let start = Date::try_new_iso_date(2023, 12, 27).unwrap();
+            for offset in 0..14 {
+                let date = start.added(DateDuration::new(0, 0, 0, offset));
+                for days in -40..=40 {
+                    let expected = naive_add(&calendar, date, days, holidays);
+                    assert_eq!(
+                        calendar.add_business_days(&date, days, Some(holidays)),
+                        Ok(expected),
+                        "{weekend:?} {date:?} {days}"
+                    );
+                    if calendar.is_business_day(&date, Some(holidays)) || days == 0 {
+                        assert_eq!(
+                            calendar.business_days_between(&date, &expected, Some(holidays)),
+                            days,
+                            "{weekend:?} {date:?} {days}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // Works with other calendars
+    let calendar = WeekCalculator::default();
+    let date = Date::try_new_gregorian_date(2024, 2, 28).unwrap();
+    assert_eq!(
+        calendar.add_business_days(&date, 3, Some(&holidays)),
+        Date::try_new_gregorian_date(2024, 3, 5)
+    );
+    assert_eq!(
+        calendar.business_days_between(
+            &date,
+            &Date::try_new_gregorian_date(2024, 3, 5).unwrap(),
+            Some(&holidays)
+        ),
+        3
+    );
+    assert!(!calendar.is_business_day(&Date::new_from_iso(holidays[5], Gregorian), Some(&holidays)));
+
+    // No business days at all
+    let calendar = WeekCalculator {
+        first_weekday: Monday,
+        min_week_days: 1,
+        weekend: Some(WeekdaySet::new(&[
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ])),
+    };
+    assert_eq!(
+        calendar.add_business_days(&date, 1, None),
+        Err(CalendarError::OutOfRange)
+    );
+    assert_eq!(calendar.add_business_days(&date, 0, None), Ok(date));
+    assert_eq!(
+        calendar.business_days_between(&date, &date.added(DateDuration::new(0, 0, 0, 100)), None),
+        0
+    );
+}
+
 #[test]
 fn test_weekend() {
     use icu_locid::langid;
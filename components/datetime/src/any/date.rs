@@ -10,7 +10,7 @@ use alloc::string::String;
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
 use icu_calendar::provider::{
     ChineseCacheV1Marker, DangiCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
-    WeekDataV1Marker,
+    VietnameseCacheV1Marker, WeekDataV1Marker,
 };
 use icu_calendar::Date;
 use icu_decimal::provider::DecimalSymbolsV1Marker;
@@ -183,6 +183,7 @@ impl DateFormatter {
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<ChineseCacheV1Marker>
             + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
             + DataProvider<DangiDateLengthsV1Marker>
             + DataProvider<DangiDateSymbolsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
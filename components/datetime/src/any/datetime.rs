@@ -10,7 +10,7 @@ use alloc::string::String;
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
 use icu_calendar::provider::{
     ChineseCacheV1Marker, DangiCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
-    WeekDataV1Marker,
+    VietnameseCacheV1Marker, WeekDataV1Marker,
 };
 use icu_calendar::{types::Time, DateTime};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
@@ -240,6 +240,7 @@ impl DateTimeFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
             + DataProvider<DangiDateLengthsV1Marker>
             + DataProvider<DangiDateSymbolsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
@@ -271,7 +272,7 @@ impl DateTimeFormatter {
             provider,
             calendar::load_lengths_for_any_calendar_kind(provider, locale, kind)?,
             locale,
-            &kind.as_bcp47_value(),
+            &kind.as_bcp47_value().unwrap_or_default(),
             &options,
         )?;
 
@@ -343,7 +344,7 @@ impl DateTimeFormatter {
             &crate::provider::Baked,
             calendar::load_lengths_for_any_calendar_kind(&crate::provider::Baked, locale, kind)?,
             locale,
-            &kind.as_bcp47_value(),
+            &kind.as_bcp47_value().unwrap_or_default(),
             &options,
         )?;
 
@@ -384,6 +385,7 @@ impl DateTimeFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
             + DataProvider<DangiDateLengthsV1Marker>
             + DataProvider<DangiDateSymbolsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
@@ -15,11 +15,14 @@ use crate::{DateTimeError, FormattedZonedDateTime};
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
 use icu_calendar::provider::{
     ChineseCacheV1Marker, DangiCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
-    WeekDataV1Marker,
+    VietnameseCacheV1Marker, WeekDataV1Marker,
 };
-use icu_calendar::{types::Time, DateTime};
+use icu_calendar::types::{IsoSecond, Time};
+use icu_calendar::{CalendarError, DateTime, Iso};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
+use icu_timezone::provider::TimeZoneBcp47Id;
+use icu_timezone::{CustomTimeZone, MetazoneCalculator, ZoneOffsetCalculator};
 use writeable::Writeable;
 
 size_test!(
@@ -127,6 +130,45 @@ size_test!(
 ///     "Sep 1, 2020, 12:34:28 PM Central Daylight Time");
 /// ```
 ///
+/// Using a time zone specified only by its id, at a given instant:
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::datetime::{options::length, ZonedDateTimeFormatter};
+/// use icu::locid::locale;
+/// use icu::timezone::provider::TimeZoneBcp47Id;
+/// use icu::timezone::{MetazoneCalculator, ZoneOffsetCalculator};
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let options = length::Bag::from_date_time_style(
+///     length::Date::Medium,
+///     length::Time::Full,
+/// );
+/// let zdtf = ZonedDateTimeFormatter::try_new(
+///     &locale!("en").into(),
+///     options.into(),
+///     Default::default(),
+/// )
+/// .expect("Failed to create ZonedDateTimeFormatter instance.");
+///
+/// // Create a DateTime at September 1, 2020 at 5:34 PM UTC
+/// let utc_datetime = DateTime::try_new_iso_datetime(2020, 9, 1, 17, 34, 0)
+///     .expect("Failed to construct DateTime.");
+///
+/// // Format the instant in America/Chicago:
+/// assert_writeable_eq!(
+///     zdtf
+///       .format_in_time_zone(
+///           &utc_datetime,
+///           TimeZoneBcp47Id(tinystr!(8, "uschi")),
+///           &ZoneOffsetCalculator::new(),
+///           &MetazoneCalculator::new(),
+///       )
+///       .expect("Time zone should be known"),
+///     "Sep 1, 2020, 12:34:00 PM Central Daylight Time");
+/// ```
+///
 /// [`TimeZoneFormatter`]: crate::time_zone::TimeZoneFormatter
 #[derive(Debug)]
 pub struct ZonedDateTimeFormatter(raw::ZonedDateTimeFormatter, AnyCalendar);
@@ -199,7 +241,7 @@ impl ZonedDateTimeFormatter {
             &crate::provider::Baked,
             calendar::load_lengths_for_any_calendar_kind(&crate::provider::Baked, locale, kind)?,
             locale,
-            &kind.as_bcp47_value(),
+            &kind.as_bcp47_value().unwrap_or_default(),
             &date_time_format_options,
         )?;
 
@@ -251,6 +293,7 @@ impl ZonedDateTimeFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
             + DataProvider<DangiDateLengthsV1Marker>
             + DataProvider<DangiDateSymbolsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
@@ -282,7 +325,7 @@ impl ZonedDateTimeFormatter {
             provider,
             calendar::load_lengths_for_any_calendar_kind(provider, locale, kind)?,
             locale,
-            &kind.as_bcp47_value(),
+            &kind.as_bcp47_value().unwrap_or_default(),
             &date_time_format_options,
         )?;
 
@@ -405,6 +448,7 @@ impl ZonedDateTimeFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
             + DataProvider<DangiDateLengthsV1Marker>
             + DataProvider<DangiDateSymbolsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
@@ -509,6 +553,95 @@ impl ZonedDateTimeFormatter {
         Ok(self.format(date, time_zone)?.write_to_string().into_owned())
     }
 
+    /// Takes a UTC datetime and a time zone id, and returns an instance of a
+    /// [`FormattedZonedDateTime`] of the local datetime in that time zone at that instant.
+    ///
+    /// The GMT offset, zone variant, and metazone of the time zone are computed with the given
+    /// calculators. This function will fail if the GMT offset cannot be computed, for example
+    /// because the time zone is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::DateTime;
+    /// use icu::datetime::{options::length, ZonedDateTimeFormatter};
+    /// use icu::locid::locale;
+    /// use icu::timezone::provider::TimeZoneBcp47Id;
+    /// use icu::timezone::{MetazoneCalculator, ZoneOffsetCalculator};
+    /// use tinystr::tinystr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let zdtf = ZonedDateTimeFormatter::try_new(
+    ///     &locale!("en").into(),
+    ///     length::Bag::from_date_time_style(length::Date::Medium, length::Time::Long)
+    ///         .into(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    /// let zoc = ZoneOffsetCalculator::new();
+    /// let mzc = MetazoneCalculator::new();
+    ///
+    /// let utc_datetime = DateTime::try_new_iso_datetime(2024, 1, 1, 12, 0, 0).unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     zdtf.format_in_time_zone(
+    ///         &utc_datetime,
+    ///         TimeZoneBcp47Id(tinystr!(8, "uslax")),
+    ///         &zoc,
+    ///         &mzc
+    ///     )
+    ///     .unwrap(),
+    ///     "Jan 1, 2024, 4:00:00 AM PST"
+    /// );
+    /// assert_writeable_eq!(
+    ///     zdtf.format_in_time_zone(
+    ///         &utc_datetime,
+    ///         TimeZoneBcp47Id(tinystr!(8, "inccu")),
+    ///         &zoc,
+    ///         &mzc
+    ///     )
+    ///     .unwrap(),
+    ///     "Jan 1, 2024, 5:30:00 PM GMT+05:30"
+    /// );
+    ///
+    /// // Unknown time zone
+    /// assert!(zdtf
+    ///     .format_in_time_zone(
+    ///         &utc_datetime,
+    ///         TimeZoneBcp47Id(tinystr!(8, "unk")),
+    ///         &zoc,
+    ///         &mzc
+    ///     )
+    ///     .is_err());
+    /// ```
+    pub fn format_in_time_zone<'l>(
+        &'l self,
+        utc_datetime: &DateTime<Iso>,
+        time_zone_id: TimeZoneBcp47Id,
+        zone_offset_calculator: &ZoneOffsetCalculator,
+        metazone_calculator: &MetazoneCalculator,
+    ) -> Result<FormattedZonedDateTime<'l>, DateTimeError> {
+        let mut time_zone = CustomTimeZone::new_empty();
+        time_zone.time_zone_id = Some(time_zone_id);
+        time_zone.maybe_calculate_offset_and_variant(zone_offset_calculator, utc_datetime);
+        let gmt_offset = time_zone
+            .gmt_offset
+            .ok_or(DateTimeError::MissingInputField(Some("gmt_offset")))?;
+
+        // Offsets before the adoption of standard time are not always whole minutes
+        let local_seconds = i64::from(utc_datetime.minutes_since_local_unix_epoch()) * 60
+            + i64::from(utc_datetime.time.second.number())
+            + i64::from(gmt_offset.offset_seconds());
+        let minutes = i32::try_from(local_seconds.div_euclid(60))
+            .map_err(|_| DateTimeError::DateTimeInput(CalendarError::OutOfRange))?;
+        let mut local_datetime = DateTime::from_minutes_since_local_unix_epoch(minutes);
+        local_datetime.time.second = IsoSecond::try_from(local_seconds.rem_euclid(60) as u8)?;
+        local_datetime.time.nanosecond = utc_datetime.time.nanosecond;
+
+        time_zone.maybe_calculate_metazone(metazone_calculator, &local_datetime);
+        self.format(&local_datetime.to_any(), &time_zone)
+    }
+
     /// Converts a date to the correct calendar if necessary
     ///
     /// Returns `Err` if the date is not ISO or compatible with the current calendar, returns `Ok(None)`
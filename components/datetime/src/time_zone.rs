@@ -8,7 +8,11 @@ use crate::provider::time_zones::TimeZoneBcp47Id;
 use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use icu_locid::subtags::Region;
+use icu_timezone::{CustomTimeZone, TimeZoneRegionInfo, TimeZoneRegionMapper};
 use smallvec::SmallVec;
 use tinystr::tinystr;
 
@@ -736,6 +740,69 @@ impl TimeZoneFormatter {
         self.format(value).write_to_string().into_owned()
     }
 
+    /// Returns the time zones of a region, each with its name formatted by this formatter,
+    /// sorted by their names with `compare`, as listed by a time zone picker.
+    ///
+    /// The names are formatted like [`Self::format_to_string()`] does for a time zone with
+    /// only its identifier set, so include the generic location format with
+    /// [`Self::include_generic_location_format()`] to get names such as "Los Angeles Time".
+    /// To sort the names for the user's locale, compare them with a [`Collator`], as in the
+    /// `time_zone_picker` example of the `icu` crate.
+    ///
+    /// [`Collator`]: https://docs.rs/icu_collator/latest/icu_collator/struct.Collator.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::time_zone::{
+    ///     TimeZoneFormatter, TimeZoneFormatterOptions,
+    /// };
+    /// use icu::locid::{locale, subtags::region};
+    /// use icu::timezone::TimeZoneRegionMapper;
+    ///
+    /// let mut tzf = TimeZoneFormatter::try_new(
+    ///     &locale!("en").into(),
+    ///     TimeZoneFormatterOptions::default(),
+    /// )
+    /// .expect("locale should be present");
+    /// tzf.include_generic_location_format()
+    ///     .expect("locale should be present");
+    ///
+    /// let zones = tzf.format_zones_for_region(
+    ///     &TimeZoneRegionMapper::new(),
+    ///     region!("DE"),
+    ///     // Use a `Collator` to compare the names for the user's locale
+    ///     |a, b| a.cmp(b),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     zones
+    ///         .iter()
+    ///         .map(|(info, name)| (name.as_str(), info.is_primary))
+    ///         .collect::<Vec<_>>(),
+    ///     [("Berlin Time", true), ("Busingen Time", false)]
+    /// );
+    /// ```
+    pub fn format_zones_for_region(
+        &self,
+        mapper: &TimeZoneRegionMapper,
+        region: Region,
+        mut compare: impl FnMut(&str, &str) -> Ordering,
+    ) -> Vec<(TimeZoneRegionInfo, String)> {
+        let mut zones: Vec<(TimeZoneRegionInfo, String)> = mapper
+            .as_borrowed()
+            .iter()
+            .filter(|info| info.region == region)
+            .map(|info| {
+                let mut time_zone = CustomTimeZone::new_empty();
+                time_zone.time_zone_id = Some(info.time_zone_id);
+                (info, self.format_to_string(&time_zone))
+            })
+            .collect();
+        zones.sort_by(|(_, a), (_, b)| compare(a, b));
+        zones
+    }
+
     /// Formats a time segment with optional zero-padding.
     fn format_time_segment(n: u8, padding: ZeroPadding) -> String {
         debug_assert!((0..60).contains(&n));
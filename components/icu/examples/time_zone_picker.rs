@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// An example program listing the time zones of a region, as a time zone
+// picker would, using localized location names sorted by a collator.
+
+#![no_main] // https://github.com/unicode-org/icu4x/issues/395
+
+use icu::collator::{Collator, CollatorOptions};
+use icu::datetime::time_zone::{TimeZoneFormatter, TimeZoneFormatterOptions};
+use icu::locid::subtags::{region, Region};
+use icu::locid::{locale, Locale};
+use icu::timezone::TimeZoneRegionMapper;
+use std::env;
+
+fn print<T: AsRef<str>>(_input: T) {
+    #[cfg(debug_assertions)]
+    println!("{}", _input.as_ref());
+}
+
+#[no_mangle]
+fn main(_argc: isize, _argv: *const *const u8) -> isize {
+    let args: Vec<String> = env::args().collect();
+
+    let locale: Locale = args
+        .get(1)
+        .map(|s| s.parse().expect("Failed to parse locale"))
+        .unwrap_or_else(|| locale!("en"));
+
+    let region: Region = args
+        .get(2)
+        .map(|s| s.parse().expect("Failed to parse region"))
+        .unwrap_or(region!("US"));
+
+    let mut tzf =
+        TimeZoneFormatter::try_new(&(&locale).into(), TimeZoneFormatterOptions::default())
+            .expect("Failed to create TimeZoneFormatter");
+    tzf.include_generic_location_format()
+        .expect("Failed to load location format");
+
+    let collator = Collator::try_new(&(&locale).into(), CollatorOptions::new())
+        .expect("Failed to create Collator");
+
+    let zones = tzf.format_zones_for_region(&TimeZoneRegionMapper::new(), region, |a, b| {
+        collator.compare(a, b)
+    });
+
+    print(format!("Time zones in {region} ({locale})"));
+    print("===================================");
+    for (info, name) in zones {
+        if info.is_primary {
+            print(format!("{name} (primary)"));
+        } else {
+            print(name);
+        }
+    }
+
+    0
+}
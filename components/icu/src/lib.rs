@@ -192,6 +192,10 @@ pub use icu_timezone as timezone;
 #[doc(inline)]
 pub use icu_displaynames as displaynames;
 
+#[cfg(feature = "icu_messageformat")]
+#[doc(inline)]
+pub use icu_messageformat as messageformat;
+
 #[cfg(feature = "icu_relativetime")]
 #[doc(inline)]
 pub use icu_relativetime as relativetime;
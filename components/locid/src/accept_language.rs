@@ -0,0 +1,324 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of HTTP `Accept-Language` headers.
+//!
+//! An `Accept-Language` header, as specified in [RFC 9110], is a list of language ranges as
+//! defined in [RFC 4647], each with an optional quality weight:
+//!
+//! ```text
+//! Accept-Language: fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5
+//! ```
+//!
+//! The parser in this module is lenient, as headers sent by real-world clients are often
+//! malformed: invalid entries are skipped, underscores are accepted as subtag separators, and
+//! grandfathered tags such as `i-klingon` are replaced by their preferred values.
+//!
+//! To choose among the locales supported by an application, use `LocaleNegotiator` from
+//! the [`icu_locid_transform`](https://docs.rs/icu_locid_transform/latest/icu_locid_transform/)
+//! crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::accept_language::{AcceptLanguage, LanguageRange};
+//! use icu::locid::locale;
+//!
+//! let accept_language =
+//!     AcceptLanguage::from_bytes(b"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+//!
+//! let ranges = accept_language
+//!     .ranges()
+//!     .iter()
+//!     .map(|r| (&r.range, r.quality))
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     ranges,
+//!     [
+//!         (&LanguageRange::Locale(locale!("fr-CH")), 1000),
+//!         (&LanguageRange::Locale(locale!("fr")), 900),
+//!         (&LanguageRange::Locale(locale!("en")), 800),
+//!         (&LanguageRange::Wildcard, 500),
+//!     ]
+//! );
+//! ```
+//!
+//! [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+//! [RFC 4647]: https://www.rfc-editor.org/rfc/rfc4647
+
+use crate::Locale;
+use alloc::vec::Vec;
+
+/// The maximum quality weight, corresponding to `q=1`.
+pub const MAX_QUALITY: u16 = 1000;
+
+/// A language range in an `Accept-Language` header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageRange {
+    /// The `*` range, which matches any language.
+    Wildcard,
+    /// A range that matches a locale and the locales it is a prefix of, for example `en`
+    /// matches `en` and `en-GB`.
+    Locale(Locale),
+}
+
+/// A [`LanguageRange`] with its quality weight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WeightedLanguageRange {
+    /// The language range.
+    pub range: LanguageRange,
+    /// The quality weight in thousandths, from 0 to [`MAX_QUALITY`]. A weight of 0 means
+    /// that the range is not acceptable.
+    pub quality: u16,
+}
+
+/// A parsed `Accept-Language` header.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct AcceptLanguage {
+    ranges: Vec<WeightedLanguageRange>,
+}
+
+impl AcceptLanguage {
+    /// Parses the value of an `Accept-Language` header.
+    ///
+    /// This never fails: entries that cannot be parsed are skipped. Quality weights larger than
+    /// 1 are clamped, and entries with an invalid quality weight are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::accept_language::{AcceptLanguage, LanguageRange};
+    /// use icu::locid::locale;
+    ///
+    /// let accept_language = AcceptLanguage::from_bytes(
+    ///     b"en_US ; Q = .7,,i-klingon;q=0.5, !!!, de;q=oops, es;q=0",
+    /// );
+    ///
+    /// let ranges = accept_language
+    ///     .ranges()
+    ///     .iter()
+    ///     .map(|r| (&r.range, r.quality))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     ranges,
+    ///     [
+    ///         (&LanguageRange::Locale(locale!("en-US")), 700),
+    ///         (&LanguageRange::Locale(locale!("tlh")), 500),
+    ///         (&LanguageRange::Locale(locale!("es")), 0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_bytes(header: &[u8]) -> Self {
+        let mut ranges = header
+            .split(|&b| b == b',')
+            .filter_map(parse_weighted_range)
+            .collect::<Vec<_>>();
+        // This is a stable sort, so ranges with the same weight keep their order
+        ranges.sort_by(|a, b| b.quality.cmp(&a.quality));
+        Self { ranges }
+    }
+
+    /// Returns the language ranges, ordered from highest to lowest quality weight.
+    ///
+    /// Ranges with the same quality weight are in the order of the header.
+    pub fn ranges(&self) -> &[WeightedLanguageRange] {
+        &self.ranges
+    }
+}
+
+impl FromIterator<WeightedLanguageRange> for AcceptLanguage {
+    fn from_iter<T: IntoIterator<Item = WeightedLanguageRange>>(iter: T) -> Self {
+        let mut ranges = iter.into_iter().collect::<Vec<_>>();
+        ranges.sort_by(|a, b| b.quality.cmp(&a.quality));
+        Self { ranges }
+    }
+}
+
+impl From<LanguageRange> for WeightedLanguageRange {
+    fn from(range: LanguageRange) -> Self {
+        Self {
+            range,
+            quality: MAX_QUALITY,
+        }
+    }
+}
+
+fn parse_weighted_range(entry: &[u8]) -> Option<WeightedLanguageRange> {
+    let mut parts = entry.split(|&b| b == b';');
+    let tag = trim(parts.next()?);
+    if tag.is_empty() {
+        return None;
+    }
+
+    let mut quality = MAX_QUALITY;
+    for parameter in parts {
+        let parameter = trim(parameter);
+        if let Some((name, value)) = split_once(parameter, b'=') {
+            if trim(name).eq_ignore_ascii_case(b"q") {
+                quality = parse_quality(trim(value))?;
+            }
+        }
+    }
+
+    let range = if tag == b"*" {
+        LanguageRange::Wildcard
+    } else if let Some(&(_, preferred)) = GRANDFATHERED
+        .iter()
+        .find(|(grandfathered, _)| eq_tag(tag, grandfathered.as_bytes()))
+    {
+        LanguageRange::Locale(Locale::try_from_bytes(preferred.as_bytes()).ok()?)
+    } else {
+        LanguageRange::Locale(Locale::try_from_bytes(tag).ok()?)
+    };
+
+    Some(WeightedLanguageRange { range, quality })
+}
+
+/// Parses a quality weight such as `1`, `0.8`, or `.25` into thousandths.
+fn parse_quality(value: &[u8]) -> Option<u16> {
+    let (integer, fraction) = split_once(value, b'.').unwrap_or((value, b""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let mut quality: u32 = 0;
+    for &digit in integer {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        quality = quality
+            .saturating_mul(10)
+            .saturating_add(u32::from(digit - b'0'));
+    }
+    quality = quality.saturating_mul(1000);
+    for (i, &digit) in fraction.iter().enumerate() {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        // Only the first three decimals are significant
+        let factor = match i {
+            0 => 100,
+            1 => 10,
+            2 => 1,
+            _ => 0,
+        };
+        quality = quality.saturating_add(u32::from(digit - b'0') * factor);
+    }
+    Some(u16::try_from(quality).map_or(MAX_QUALITY, |q| q.min(MAX_QUALITY)))
+}
+
+fn trim(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if first.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    while let [rest @ .., last] = bytes {
+        if last.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    bytes
+}
+
+fn split_once(bytes: &[u8], separator: u8) -> Option<(&[u8], &[u8])> {
+    let index = bytes.iter().position(|&b| b == separator)?;
+    Some((bytes.get(..index)?, bytes.get(index + 1..)?))
+}
+
+/// Compares tags ASCII-case-insensitively, treating `_` as `-`.
+fn eq_tag(a: &[u8], b: &[u8]) -> bool {
+    let normalize = |b: &u8| match b {
+        b'_' => b'-',
+        b => b.to_ascii_lowercase(),
+    };
+    a.len() == b.len() && a.iter().map(normalize).eq(b.iter().map(normalize))
+}
+
+/// Grandfathered tags from [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646#section-2.2.8)
+/// that have a preferred value in the IANA Language Subtag Registry.
+///
+/// The other grandfathered tags, such as `i-default`, cannot be represented as a [`Locale`]
+/// and are skipped.
+const GRANDFATHERED: &[(&str, &str)] = &[
+    ("art-lojban", "jbo"),
+    ("en-GB-oed", "en-GB-oxendict"),
+    ("i-ami", "ami"),
+    ("i-bnn", "bnn"),
+    ("i-hak", "hak"),
+    ("i-klingon", "tlh"),
+    ("i-lux", "lb"),
+    ("i-navajo", "nv"),
+    ("i-pwn", "pwn"),
+    ("i-tao", "tao"),
+    ("i-tay", "tay"),
+    ("i-tsu", "tsu"),
+    ("no-bok", "nb"),
+    ("no-nyn", "nn"),
+    ("sgn-BE-FR", "sfb"),
+    ("sgn-BE-NL", "vgt"),
+    ("sgn-CH-DE", "sgg"),
+    ("zh-guoyu", "cmn"),
+    ("zh-hakka", "hak"),
+    ("zh-min-nan", "nan"),
+    ("zh-xiang", "hsn"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quality() {
+        assert_eq!(parse_quality(b"1"), Some(1000));
+        assert_eq!(parse_quality(b"1.000"), Some(1000));
+        assert_eq!(parse_quality(b"0"), Some(0));
+        assert_eq!(parse_quality(b"0.5"), Some(500));
+        assert_eq!(parse_quality(b".25"), Some(250));
+        assert_eq!(parse_quality(b"0.123456"), Some(123));
+        assert_eq!(parse_quality(b"2"), Some(1000));
+        assert_eq!(parse_quality(b"1."), Some(1000));
+        assert_eq!(parse_quality(b""), None);
+        assert_eq!(parse_quality(b"."), None);
+        assert_eq!(parse_quality(b"-1"), None);
+        assert_eq!(parse_quality(b"0,5"), None);
+    }
+
+    #[test]
+    fn test_grandfathered() {
+        let accept_language = AcceptLanguage::from_bytes(b"ZH-MIN-NAN, i_navajo, i-default");
+        assert_eq!(
+            accept_language.ranges(),
+            [
+                WeightedLanguageRange::from(LanguageRange::Locale("nan".parse().unwrap())),
+                WeightedLanguageRange::from(LanguageRange::Locale("nv".parse().unwrap())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(AcceptLanguage::from_bytes(b""), AcceptLanguage::default());
+        assert_eq!(
+            AcceptLanguage::from_bytes(b" , ;q=1, \xFF"),
+            AcceptLanguage::default()
+        );
+        assert_eq!(
+            AcceptLanguage::from_bytes(b"en-US;foo=bar;q=0.3\t").ranges(),
+            [WeightedLanguageRange {
+                range: LanguageRange::Locale("en-US".parse().unwrap()),
+                quality: 300,
+            }]
+        );
+    }
+}
@@ -136,6 +136,33 @@ impl Fields {
         self.0.get(key)
     }
 
+    /// Returns an iterator over the fields, ordered by [`Key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::extensions::transform::key;
+    /// use icu::locid::Locale;
+    ///
+    /// let loc: Locale = "en-t-hi-m0-ungegn-d0-fwidth".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     loc.extensions
+    ///         .transform
+    ///         .fields
+    ///         .iter()
+    ///         .map(|(k, v)| (*k, v.to_string()))
+    ///         .collect::<Vec<_>>(),
+    ///     [
+    ///         (key!("d0"), "fwidth".to_string()),
+    ///         (key!("m0"), "ungegn".to_string())
+    ///     ]
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Value)> {
+        self.0.iter()
+    }
+
     /// Sets the specified keyword, returning the old value if it already existed.
     ///
     /// # Examples
@@ -182,6 +182,28 @@ impl Keywords {
         self.0.get_mut(key)
     }
 
+    /// Returns an iterator over the keywords, ordered by [`Key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::extensions::unicode::{key, value};
+    /// use icu::locid::Locale;
+    ///
+    /// let loc: Locale = "und-u-hc-h23-ca-buddhist".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     loc.extensions.unicode.keywords.iter().collect::<Vec<_>>(),
+    ///     [
+    ///         (&key!("ca"), &value!("buddhist")),
+    ///         (&key!("hc"), &value!("h23"))
+    ///     ]
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Value)> {
+        self.0.iter()
+    }
+
     /// Sets the specified keyword, returning the old value if it already existed.
     ///
     /// # Examples
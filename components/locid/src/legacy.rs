@@ -0,0 +1,576 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversions between [`Locale`] and legacy locale identifiers.
+//!
+//! Many systems predate BCP-47 and identify locales with other syntaxes:
+//!
+//! * POSIX locale names, as used by the C library and environment variables such as `LANG`,
+//!   have the form `language[_territory][.codeset][@modifier]`, for example `en_US.UTF-8` or
+//!   `sr_RS@latin`.
+//! * ICU locale IDs separate subtags with underscores, use uppercase variants, and append
+//!   keywords with their legacy names, for example `de_DE@collation=phonebook;calendar=buddhist`.
+//!
+//! The conversions from ICU locale IDs follow the
+//! [BCP 47 Conformance](https://unicode.org/reports/tr35/#BCP_47_Conformance) section of
+//! UTS #35, mapping legacy keyword names and values to `-u-` extension keys and values.
+//!
+//! Conversions to legacy identifiers are provided for interoperability. POSIX locale names
+//! can only express a few of the features of a [`Locale`], so that conversion is lossy.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::legacy;
+//! use icu::locid::locale;
+//!
+//! assert_eq!(
+//!     legacy::from_posix(b"sr_RS.UTF-8@latin"),
+//!     Ok(locale!("sr-Latn-RS"))
+//! );
+//! assert_eq!(
+//!     legacy::from_icu(b"de@collation=phonebook;calendar=buddhist"),
+//!     Ok("de-u-ca-buddhist-co-phonebk".parse().unwrap())
+//! );
+//!
+//! assert_eq!(legacy::to_posix(&locale!("sr-Latn-RS")), "sr_RS@latin");
+//! assert_eq!(
+//!     legacy::to_icu(&"de-u-ca-buddhist-co-phonebk".parse().unwrap()),
+//!     "de@calendar=buddhist;collation=phonebook"
+//! );
+//! ```
+
+use crate::extensions::unicode::{key, value, Key, Value};
+use crate::subtags::{language, region, script, Language, Variant, Variants};
+use crate::{LanguageIdentifier, Locale, ParserError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Converts a POSIX locale name, such as `en_US.UTF-8` or `sr_RS@latin`, to a [`Locale`].
+///
+/// The codeset is ignored. The names `C` and `POSIX` are converted to `en-US-u-va-posix`.
+///
+/// The modifiers `latin`, `cyrillic`, and `devanagari` select a script, `euro` selects the euro
+/// as the currency, `saaho` selects the Saho language, and other modifiers are interpreted as
+/// variants.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::legacy;
+/// use icu::locid::locale;
+///
+/// assert_eq!(legacy::from_posix(b"en_US.UTF-8"), Ok(locale!("en-US")));
+/// assert_eq!(
+///     legacy::from_posix(b"de_DE@euro"),
+///     Ok(locale!("de-DE-u-cu-eur"))
+/// );
+/// assert_eq!(
+///     legacy::from_posix(b"ca_ES.UTF-8@valencia"),
+///     Ok(locale!("ca-ES-valencia"))
+/// );
+/// assert_eq!(legacy::from_posix(b"C"), Ok(locale!("en-US-u-va-posix")));
+/// ```
+pub fn from_posix(id: &[u8]) -> Result<Locale, ParserError> {
+    let (name, modifier) = split_once(id, b'@').map_or((id, None), |(n, m)| (n, Some(m)));
+    let name = split_once(name, b'.').map_or(name, |(n, _)| n);
+
+    let mut locale = if name == b"C" || name == b"POSIX" {
+        posix_locale()
+    } else {
+        Locale::try_from_bytes(name)?
+    };
+
+    if let Some(modifier) = modifier {
+        let modifier = modifier.to_ascii_lowercase();
+        match modifier.as_slice() {
+            b"latin" => locale.id.script = Some(script!("Latn")),
+            b"cyrillic" => locale.id.script = Some(script!("Cyrl")),
+            b"devanagari" => locale.id.script = Some(script!("Deva")),
+            b"euro" => {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .set(key!("cu"), value!("eur"));
+            }
+            b"saaho" => locale.id.language = language!("ssy"),
+            _ => {
+                let variant = Variant::try_from_bytes(&modifier)?;
+                if let Err(index) = locale.id.variants.binary_search(&variant) {
+                    let mut variants = locale.id.variants.to_vec();
+                    variants.insert(index, variant);
+                    locale.id.variants = Variants::from_vec_unchecked(variants);
+                }
+            }
+        }
+    }
+
+    Ok(locale)
+}
+
+/// Converts a [`Locale`] to a POSIX locale name, such as `en_US` or `sr_RS@latin`.
+///
+/// The result does not contain a codeset, which can be appended by the caller, as in
+/// `en_US.UTF-8`.
+///
+/// The language and region are retained. The scripts `Latn`, `Cyrl`, and `Deva` are expressed
+/// with a modifier; otherwise, the first variant or a currency of `eur` is expressed with a
+/// modifier. All other subtags cannot be represented and are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::legacy;
+/// use icu::locid::locale;
+///
+/// assert_eq!(legacy::to_posix(&locale!("en-US")), "en_US");
+/// assert_eq!(legacy::to_posix(&locale!("de-DE-u-cu-eur")), "de_DE@euro");
+/// assert_eq!(legacy::to_posix(&locale!("ca-ES-valencia")), "ca_ES@valencia");
+/// assert_eq!(legacy::to_posix(&locale!("zh-Hant-TW")), "zh_TW");
+/// assert_eq!(legacy::to_posix(&locale!("en-US-u-va-posix")), "C");
+/// ```
+pub fn to_posix(locale: &Locale) -> String {
+    if locale.id.language == language!("en")
+        && locale.id.region == Some(region!("US"))
+        && locale.extensions.unicode.keywords.get(&key!("va")) == Some(&value!("posix"))
+    {
+        return String::from("C");
+    }
+
+    let mut posix = String::from(locale.id.language.as_str());
+    if let Some(region) = locale.id.region {
+        posix.push('_');
+        posix.push_str(region.as_str());
+    }
+
+    let modifier = match locale.id.script {
+        Some(script) if script == script!("Latn") => Some("latin"),
+        Some(script) if script == script!("Cyrl") => Some("cyrillic"),
+        Some(script) if script == script!("Deva") => Some("devanagari"),
+        _ => locale.id.variants.first().map(Variant::as_str).or_else(|| {
+            (locale.extensions.unicode.keywords.get(&key!("cu")) == Some(&value!("eur")))
+                .then_some("euro")
+        }),
+    };
+    if let Some(modifier) = modifier {
+        posix.push('@');
+        posix.push_str(modifier);
+    }
+
+    posix
+}
+
+/// Converts an ICU locale ID, such as `zh_Hant_TW` or `de@collation=phonebook`, to a
+/// [`Locale`].
+///
+/// Subtags may be separated by `_` or `-`, an empty language is interpreted as `und`, and the
+/// `POSIX` variant is converted to `-u-va-posix`. Keywords are converted to `-u-` extension
+/// keywords, mapping their legacy names and values to BCP-47 keys and values. The special
+/// keywords `attribute`, `t`, and `x` are converted to Unicode extension attributes, a
+/// transform extension, and a private use extension, respectively.
+///
+/// Time zone values must be BCP-47 time zone identifiers; IANA time zone identifiers such as
+/// `America/New_York` cannot be converted without data and result in an error. Use
+/// `IanaToBcp47Mapper` from the
+/// [`icu_timezone`](https://docs.rs/icu_timezone/latest/icu_timezone/) crate to convert them
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::legacy;
+/// use icu::locid::locale;
+///
+/// assert_eq!(legacy::from_icu(b"zh_Hant_TW"), Ok(locale!("zh-Hant-TW")));
+/// assert_eq!(
+///     legacy::from_icu(b"en_US_POSIX"),
+///     Ok(locale!("en-US-u-va-posix"))
+/// );
+/// assert_eq!(
+///     legacy::from_icu(b"ja_JP@calendar=japanese;colStrength=primary;colNumeric=yes"),
+///     Ok("ja-JP-u-ca-japanese-kn-ks-level1".parse().unwrap())
+/// );
+/// assert_eq!(
+///     legacy::from_icu(b"_US@x=foo"),
+///     Ok("und-US-x-foo".parse().unwrap())
+/// );
+/// ```
+pub fn from_icu(id: &[u8]) -> Result<Locale, ParserError> {
+    let (base, keywords) = split_once(id, b'@').unwrap_or((id, b""));
+
+    let subtags = base
+        .split(|&b| b == b'_' || b == b'-')
+        .filter(|s| !s.is_empty());
+    let mut tag = Vec::new();
+    if base.first().map_or(true, |&b| b == b'_' || b == b'-') {
+        // An ICU locale ID without a language, such as `_US`
+        tag.extend_from_slice(b"und");
+    }
+    let mut posix_variant = false;
+    for subtag in subtags {
+        if subtag.eq_ignore_ascii_case(b"posix") {
+            posix_variant = true;
+            continue;
+        }
+        if !tag.is_empty() {
+            tag.push(b'-');
+        }
+        tag.extend_from_slice(subtag);
+    }
+    let id = LanguageIdentifier::try_from_bytes(&tag)?;
+
+    let mut unicode = Vec::new();
+    let mut attributes = None;
+    let mut other = Vec::new();
+    let mut private = None;
+    if posix_variant {
+        unicode.push((key!("va"), value!("posix")));
+    }
+    for keyword in keywords
+        .split(|&b| b == b';')
+        .filter(|k| !trim(k).is_empty())
+    {
+        let (name, value) = split_once(keyword, b'=').ok_or(ParserError::InvalidExtension)?;
+        let name = trim(name).to_ascii_lowercase();
+        let value = trim(value).to_ascii_lowercase();
+        match name.as_slice() {
+            b"attribute" => attributes = Some(value),
+            b"x" => private = Some(value),
+            &[singleton] => other.push((singleton, value)),
+            _ => {
+                let key = KEY_ALIASES
+                    .iter()
+                    .find(|(legacy, _)| legacy.as_bytes() == name)
+                    .map_or_else(|| Key::try_from_bytes(&name), |&(_, key)| Ok(key))
+                    .map_err(|_| ParserError::InvalidExtension)?;
+                let value = match VALUE_ALIASES
+                    .iter()
+                    .find(|(k, legacy, _)| *k == key && legacy.as_bytes() == value)
+                {
+                    Some(&(_, _, value)) => Value::try_from_bytes(value.as_bytes()),
+                    None if value == b"yes" => Ok(Value::default()),
+                    None => Value::try_from_bytes(&value),
+                }?;
+                unicode.push((key, value));
+            }
+        }
+    }
+
+    let mut locale = Locale::from(id);
+    if let Some(attributes) = attributes {
+        let mut tag = Vec::from(*b"und-u-");
+        tag.extend_from_slice(&attributes);
+        locale.extensions.unicode.attributes =
+            Locale::try_from_bytes(&tag)?.extensions.unicode.attributes;
+    }
+    for (key, value) in unicode {
+        locale.extensions.unicode.keywords.set(key, value);
+    }
+    let mut extensions = Vec::new();
+    for (singleton, value) in other {
+        extensions.push(singleton);
+        extensions.push(b'-');
+        extensions.extend_from_slice(&value);
+        extensions.push(b'-');
+    }
+    if let Some(private) = private {
+        extensions.extend_from_slice(b"x-");
+        extensions.extend_from_slice(&private);
+    }
+    if !extensions.is_empty() {
+        let mut tag = Vec::from(*b"und-");
+        tag.extend_from_slice(extensions.strip_suffix(b"-").unwrap_or(&extensions));
+        let parsed = Locale::try_from_bytes(&tag)?.extensions;
+        locale.extensions.transform = parsed.transform;
+        locale.extensions.other = parsed.other;
+        locale.extensions.private = parsed.private;
+    }
+
+    Ok(locale)
+}
+
+/// Converts a [`Locale`] to an ICU locale ID, such as `zh_Hant_TW` or
+/// `de@collation=phonebook`.
+///
+/// This is the inverse of [`from_icu`]: `-u-` extension keywords are written with their legacy
+/// names and values where those exist, and keywords are sorted by name.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::legacy;
+/// use icu::locid::locale;
+///
+/// assert_eq!(legacy::to_icu(&locale!("zh-Hant-TW")), "zh_Hant_TW");
+/// assert_eq!(legacy::to_icu(&locale!("en-US-u-va-posix")), "en_US_POSIX");
+/// assert_eq!(legacy::to_icu(&locale!("sl-rozaj")), "sl__ROZAJ");
+/// assert_eq!(
+///     legacy::to_icu(&"ja-JP-u-ca-japanese-kn-ks-level1".parse().unwrap()),
+///     "ja_JP@calendar=japanese;colnumeric=yes;colstrength=primary"
+/// );
+/// assert_eq!(legacy::to_icu(&"und-US-x-foo".parse().unwrap()), "_US@x=foo");
+/// ```
+pub fn to_icu(locale: &Locale) -> String {
+    let mut icu = String::new();
+    if locale.id.language != Language::UND {
+        icu.push_str(locale.id.language.as_str());
+    }
+    if let Some(script) = locale.id.script {
+        icu.push('_');
+        icu.push_str(script.as_str());
+    }
+
+    let posix_variant = locale.id.region.is_some()
+        && locale.extensions.unicode.keywords.get(&key!("va")) == Some(&value!("posix"));
+    let variants = locale
+        .id
+        .variants
+        .iter()
+        .map(Variant::as_str)
+        .chain(posix_variant.then_some("posix"));
+    let mut has_variants = false;
+    for variant in variants {
+        if !has_variants {
+            icu.push('_');
+            if let Some(region) = locale.id.region {
+                icu.push_str(region.as_str());
+            }
+        }
+        has_variants = true;
+        icu.push('_');
+        icu.push_str(&variant.to_ascii_uppercase());
+    }
+    if let (false, Some(region)) = (has_variants, locale.id.region) {
+        icu.push('_');
+        icu.push_str(region.as_str());
+    }
+
+    let mut keywords: Vec<(String, String)> = Vec::new();
+    for (key, value) in locale.extensions.unicode.keywords.iter() {
+        if posix_variant && *key == key!("va") {
+            continue;
+        }
+        let name = KEY_ALIASES
+            .iter()
+            .find(|(_, k)| k == key)
+            .map_or(key.as_str(), |(legacy, _)| legacy);
+        let value = value.to_string();
+        let value = match VALUE_ALIASES
+            .iter()
+            .find(|(k, _, bcp47)| k == key && *bcp47 == value)
+        {
+            Some((_, legacy, _)) => String::from(*legacy),
+            None if value.is_empty() => String::from("yes"),
+            None => value,
+        };
+        keywords.push((String::from(name), value));
+    }
+    if !locale.extensions.unicode.attributes.is_empty() {
+        keywords.push((
+            String::from("attribute"),
+            locale.extensions.unicode.attributes.to_string(),
+        ));
+    }
+    if !locale.extensions.transform.is_empty() {
+        keywords.push((
+            String::from("t"),
+            without_singleton(&locale.extensions.transform),
+        ));
+    }
+    for other in locale.extensions.other.iter() {
+        keywords.push((String::from(other.get_ext_str()), without_singleton(other)));
+    }
+    if !locale.extensions.private.is_empty() {
+        keywords.push((
+            String::from("x"),
+            without_singleton(&locale.extensions.private),
+        ));
+    }
+    keywords.sort();
+
+    for (i, (name, value)) in keywords.iter().enumerate() {
+        icu.push(if i == 0 { '@' } else { ';' });
+        icu.push_str(name);
+        icu.push('=');
+        icu.push_str(value);
+    }
+
+    icu
+}
+
+/// `en-US-u-va-posix`, the equivalent of the POSIX locale.
+fn posix_locale() -> Locale {
+    let mut locale = Locale::from(LanguageIdentifier {
+        language: language!("en"),
+        script: None,
+        region: Some(region!("US")),
+        variants: Default::default(),
+    });
+    locale
+        .extensions
+        .unicode
+        .keywords
+        .set(key!("va"), value!("posix"));
+    locale
+}
+
+/// Writes an extension without its leading singleton, such as `t-` or `x-`.
+fn without_singleton(extension: &impl core::fmt::Display) -> String {
+    let extension = extension.to_string();
+    String::from(extension.get(2..).unwrap_or_default())
+}
+
+fn split_once(bytes: &[u8], separator: u8) -> Option<(&[u8], &[u8])> {
+    let index = bytes.iter().position(|&b| b == separator)?;
+    Some((bytes.get(..index)?, bytes.get(index + 1..)?))
+}
+
+fn trim(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if first.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    while let [rest @ .., last] = bytes {
+        if last.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    bytes
+}
+
+/// Legacy keyword names and their `-u-` extension keys, from CLDR's `bcp47` data.
+const KEY_ALIASES: &[(&str, Key)] = &[
+    ("calendar", key!("ca")),
+    ("colalternate", key!("ka")),
+    ("colbackwards", key!("kb")),
+    ("colcasefirst", key!("kf")),
+    ("colcaselevel", key!("kc")),
+    ("colhiraganaquaternary", key!("kh")),
+    ("collation", key!("co")),
+    ("colnormalization", key!("kk")),
+    ("colnumeric", key!("kn")),
+    ("colreorder", key!("kr")),
+    ("colstrength", key!("ks")),
+    ("currency", key!("cu")),
+    ("measure", key!("ms")),
+    ("numbers", key!("nu")),
+    ("timezone", key!("tz")),
+    ("variabletop", key!("vt")),
+];
+
+/// Legacy keyword values and their `-u-` extension values, from CLDR's `bcp47` data.
+///
+/// The legacy value `yes`, which corresponds to an empty value, is handled separately.
+const VALUE_ALIASES: &[(Key, &str, &str)] = &[
+    (key!("ca"), "ethiopic-amete-alem", "ethioaa"),
+    (key!("ca"), "gregorian", "gregory"),
+    (key!("co"), "dictionary", "dict"),
+    (key!("co"), "gb2312han", "gb2312"),
+    (key!("co"), "phonebook", "phonebk"),
+    (key!("co"), "traditional", "trad"),
+    (key!("ka"), "non-ignorable", "noignore"),
+    (key!("kb"), "no", "false"),
+    (key!("kc"), "no", "false"),
+    (key!("kf"), "no", "false"),
+    (key!("kh"), "no", "false"),
+    (key!("kk"), "no", "false"),
+    (key!("kn"), "no", "false"),
+    (key!("ks"), "primary", "level1"),
+    (key!("ks"), "secondary", "level2"),
+    (key!("ks"), "tertiary", "level3"),
+    (key!("ks"), "quaternary", "level4"),
+    (key!("ks"), "identical", "identic"),
+    (key!("nu"), "traditional", "traditio"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_posix() {
+        for (posix, bcp47) in [
+            ("en_US.UTF-8", "en-US"),
+            ("en_US.UTF-8@euro", "en-US-u-cu-eur"),
+            ("sr_RS@latin", "sr-Latn-RS"),
+            ("sr_RS.UTF-8@Cyrillic", "sr-Cyrl-RS"),
+            ("zh_Hant_TW", "zh-Hant-TW"),
+            ("aa_ER@saaho", "ssy-ER"),
+            ("POSIX", "en-US-u-va-posix"),
+            ("C.UTF-8", "en-US-u-va-posix"),
+            ("de", "de"),
+        ] {
+            assert_eq!(
+                from_posix(posix.as_bytes()),
+                Ok(bcp47.parse().unwrap()),
+                "{posix}"
+            );
+        }
+        assert_eq!(from_posix(b""), Err(ParserError::InvalidLanguage));
+        assert_eq!(from_posix(b"en_US@x"), Err(ParserError::InvalidSubtag));
+    }
+
+    #[test]
+    fn test_from_icu() {
+        for (icu, bcp47) in [
+            ("", "und"),
+            ("de__PINYIN", "de-pinyin"),
+            ("sl_Latn__ROZAJ_BISKE", "sl-Latn-biske-rozaj"),
+            ("en-US_POSIX@colCaseFirst=no", "en-US-u-kf-false-va-posix"),
+            (
+                "de@collation=phonebook;calendar=buddhist",
+                "de-u-ca-buddhist-co-phonebk",
+            ),
+            ("th@numbers=thai; fw=mon", "th-u-fw-mon-nu-thai"),
+            (
+                "en@attribute=foo-bar;ca=gregorian",
+                "en-u-foo-bar-ca-gregory",
+            ),
+            ("en@colReorder=latn-digit", "en-u-kr-latn-digit"),
+            (
+                "ja@t=und-latn-m0-names;x=priv;a=ext",
+                "ja-a-ext-t-und-latn-m0-names-x-priv",
+            ),
+            ("de@timezone=deber", "de-u-tz-deber"),
+        ] {
+            assert_eq!(
+                from_icu(icu.as_bytes()),
+                Ok(bcp47.parse().unwrap()),
+                "{icu}"
+            );
+        }
+        assert_eq!(
+            from_icu(b"de@timezone=Europe/Berlin"),
+            Err(ParserError::InvalidExtension)
+        );
+        assert_eq!(from_icu(b"de@calendar"), Err(ParserError::InvalidExtension));
+        assert_eq!(
+            from_icu(b"de@whatever=x"),
+            Err(ParserError::InvalidExtension)
+        );
+    }
+
+    #[test]
+    fn test_to_icu_round_trip() {
+        for bcp47 in [
+            "und",
+            "de-pinyin",
+            "sl-Latn-biske-rozaj",
+            "en-US-u-kf-false-va-posix",
+            "en-u-va-posix",
+            "de-u-ca-buddhist-co-phonebk",
+            "en-u-foo-bar-ca-gregory-ks-level2",
+            "ja-a-ext-t-und-latn-m0-names-x-priv",
+        ] {
+            let locale: Locale = bcp47.parse().unwrap();
+            assert_eq!(from_icu(to_icu(&locale).as_bytes()), Ok(locale), "{bcp47}");
+        }
+    }
+}
@@ -42,9 +42,14 @@
 //!
 //! For more details, see [`Locale`] and [`LanguageIdentifier`].
 //!
+//! HTTP `Accept-Language` headers can be parsed with the [`accept_language`] module, and POSIX
+//! and ICU legacy locale identifiers can be converted with the [`legacy`] module.
+//!
 //! [`UTS #35: Unicode LDML 3. Unicode Language and Locale Identifiers`]: https://unicode.org/reports/tr35/tr35.html#Unicode_Language_and_Locale_Identifiers
 //! [`ICU4X`]: ../icu/index.html
 //! [`Unicode Extensions`]: extensions
+//! [`accept_language`]: accept_language
+//! [`legacy`]: legacy
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -82,7 +87,9 @@ pub use parser::errors::ParserError;
 #[doc(no_inline)]
 pub use ParserError as Error;
 
+pub mod accept_language;
 pub mod extensions;
+pub mod legacy;
 #[macro_use]
 pub mod subtags;
 pub mod zerovec;
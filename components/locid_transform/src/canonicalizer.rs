@@ -8,6 +8,7 @@ use crate::provider::*;
 use crate::LocaleTransformError;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::str::FromStr;
 
 use crate::LocaleExpander;
 use crate::TransformResult;
@@ -20,6 +21,7 @@ use icu_locid::{
 };
 use icu_provider::prelude::*;
 use tinystr::TinyAsciiStr;
+use writeable::Writeable;
 
 /// Implements the algorithm defined in *[UTS #35: Annex C, LocaleId Canonicalization]*.
 ///
@@ -41,6 +43,8 @@ use tinystr::TinyAsciiStr;
 pub struct LocaleCanonicalizer {
     /// Data to support canonicalization.
     aliases: DataPayload<AliasesV2Marker>,
+    /// Data to support canonicalization of extension values.
+    extension_aliases: DataPayload<ExtensionAliasesV1Marker>,
     /// Likely subtags implementation for delegation.
     expander: LocaleExpander,
 }
@@ -237,6 +241,7 @@ impl LocaleCanonicalizer {
     pub fn try_new_unstable<P>(provider: &P) -> Result<Self, LocaleTransformError>
     where
         P: DataProvider<AliasesV2Marker>
+            + DataProvider<ExtensionAliasesV1Marker>
             + DataProvider<LikelySubtagsForLanguageV1Marker>
             + DataProvider<LikelySubtagsForScriptRegionV1Marker>
             + ?Sized,
@@ -256,6 +261,9 @@ impl LocaleCanonicalizer {
             aliases: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_LOCID_TRANSFORM_ALIASES_V2,
             ),
+            extension_aliases: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_LOCID_TRANSFORM_EXTENSION_ALIASES_V1,
+            ),
             expander,
         }
     }
@@ -265,7 +273,10 @@ impl LocaleCanonicalizer {
         expander: LocaleExpander,
     ) -> Result<Self, LocaleTransformError>
     where
-        P: DataProvider<AliasesV2Marker> + DataProvider<AliasesV1Marker> + ?Sized,
+        P: DataProvider<AliasesV2Marker>
+            + DataProvider<AliasesV1Marker>
+            + DataProvider<ExtensionAliasesV1Marker>
+            + ?Sized,
     {
         let payload_v2: Result<DataPayload<AliasesV2Marker>, _> = provider
             .load(Default::default())
@@ -278,8 +289,23 @@ impl LocaleCanonicalizer {
                 .and_then(DataResponse::take_payload)?;
             payload_v1.try_map_project(|st, _| st.try_into())?
         };
+        // Data generated before extension aliases were added doesn't contain them
+        let extension_aliases = match provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+        {
+            Err(DataError {
+                kind: DataErrorKind::MissingDataKey,
+                ..
+            }) => DataPayload::from_owned(Default::default()),
+            result => result?,
+        };
 
-        Ok(Self { aliases, expander })
+        Ok(Self {
+            aliases,
+            extension_aliases,
+            expander,
+        })
     }
 
     #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new_with_expander)]
@@ -288,12 +314,18 @@ impl LocaleCanonicalizer {
         expander: LocaleExpander,
     ) -> Result<Self, LocaleTransformError>
     where
-        P: DataProvider<AliasesV2Marker> + ?Sized,
+        P: DataProvider<AliasesV2Marker> + DataProvider<ExtensionAliasesV1Marker> + ?Sized,
     {
         let aliases: DataPayload<AliasesV2Marker> =
             provider.load(Default::default())?.take_payload()?;
+        let extension_aliases: DataPayload<ExtensionAliasesV1Marker> =
+            provider.load(Default::default())?.take_payload()?;
 
-        Ok(Self { aliases, expander })
+        Ok(Self {
+            aliases,
+            extension_aliases,
+            expander,
+        })
     }
 
     #[doc = icu_provider::gen_any_buffer_unstable_docs!(ANY, Self::new_with_expander)]
@@ -317,11 +349,9 @@ impl LocaleCanonicalizer {
     /// depending up the results of running the canonicalization algorithm
     /// from <http://unicode.org/reports/tr35/#LocaleId_Canonicalization>.
     ///
-    /// Some BCP47 canonicalization data is not part of the CLDR json package. Because
-    /// of this, some canonicalizations are not performed, e.g. the canonicalization of
-    /// `und-u-ca-islamicc` to `und-u-ca-islamic-civil`. This will be fixed in a future
-    /// release once the missing data has been added to the CLDR json data. See:
-    /// <https://github.com/unicode-org/icu4x/issues/746>
+    /// Deprecated and legacy values of `-u-` and `-t-` extension keywords are replaced
+    /// with their preferred values, e.g. `und-u-ca-islamicc` is canonicalized to
+    /// `und-u-ca-islamic-civil`.
     ///
     /// # Examples
     ///
@@ -334,6 +364,13 @@ impl LocaleCanonicalizer {
     /// let mut locale: Locale = "ja-Latn-fonipa-hepburn-heploc".parse().unwrap();
     /// assert_eq!(lc.canonicalize(&mut locale), TransformResult::Modified);
     /// assert_eq!(locale, "ja-Latn-alalc97-fonipa".parse().unwrap());
+    ///
+    /// let mut locale: Locale = "de-u-ca-islamicc-ms-imperial".parse().unwrap();
+    /// assert_eq!(lc.canonicalize(&mut locale), TransformResult::Modified);
+    /// assert_eq!(
+    ///     locale,
+    ///     "de-u-ca-islamic-civil-ms-uksystem".parse().unwrap()
+    /// );
     /// ```
     pub fn canonicalize(&self, locale: &mut Locale) -> TransformResult {
         let mut result = TransformResult::Unmodified;
@@ -495,6 +532,28 @@ impl LocaleCanonicalizer {
             }
         }
 
+        let mut replacements = Vec::new();
+        for (key, value) in extensions.transform.fields.iter() {
+            if let Some(replacement) = self.extension_value_alias(key.into_tinystr(), value) {
+                replacements.push((*key, replacement));
+            }
+        }
+        for (key, value) in replacements {
+            extensions.transform.fields.set(key, value);
+            *result = TransformResult::Modified;
+        }
+
+        let mut replacements = Vec::new();
+        for (key, value) in extensions.unicode.keywords.iter() {
+            if let Some(replacement) = self.extension_value_alias(key.into_tinystr(), value) {
+                replacements.push((*key, replacement));
+            }
+        }
+        for (key, value) in replacements {
+            extensions.unicode.keywords.set(key, value);
+            *result = TransformResult::Modified;
+        }
+
         if !extensions.unicode.keywords.is_empty() {
             for key in [key!("rg"), key!("sd")] {
                 if let Some(value) = extensions.unicode.keywords.get_mut(&key) {
@@ -516,6 +575,29 @@ impl LocaleCanonicalizer {
         }
     }
 
+    /// Returns the preferred value for a deprecated or legacy extension value.
+    fn extension_value_alias<V: FromStr>(
+        &self,
+        key: TinyAsciiStr<2>,
+        value: &impl Writeable,
+    ) -> Option<V> {
+        // Both `-u-` and `-t-` values omit the default value `true`
+        let is_true = value.write_cmp_bytes(b"") == Ordering::Equal;
+        self.extension_aliases
+            .get()
+            .values
+            .get0(&key.to_unvalidated())?
+            .get1_by(|alias| {
+                if is_true {
+                    alias.as_bytes().cmp(b"true")
+                } else {
+                    value.write_cmp_bytes(alias.as_bytes()).reverse()
+                }
+            })?
+            .parse()
+            .ok()
+    }
+
     fn canonicalize_language_variant_fallbacks(&self, lid: &mut LanguageIdentifier) -> bool {
         // These language/variant comibnations have around 20 rules
         for LanguageStrStrPair(lang, raw_variants, raw_to) in self
@@ -717,4 +799,27 @@ mod tests {
             panic!("should not create: no data present")
         };
     }
+
+    #[test]
+    fn test_extension_aliases_error() {
+        struct BrokenExtensionAliasesProvider;
+
+        impl AnyProvider for BrokenExtensionAliasesProvider {
+            fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
+                if key.hashed() == ExtensionAliasesV1Marker::KEY.hashed() {
+                    return Err(DataErrorKind::Custom.with_str_context("broken"));
+                }
+                RejectByKeyProvider { keys: vec![] }.load_any(key, req)
+            }
+        }
+
+        // Only missing extension aliases are ignored
+        assert!(matches!(
+            LocaleCanonicalizer::try_new_with_any_provider(&BrokenExtensionAliasesProvider),
+            Err(LocaleTransformError::Data(DataError {
+                kind: DataErrorKind::Custom,
+                ..
+            }))
+        ));
+    }
 }
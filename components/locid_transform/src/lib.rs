@@ -19,6 +19,13 @@
 //! This minimize method returns a new Locale that is the result of running the
 //! 'Remove Likely Subtags' algorithm from [`UTS #35: Unicode LDML 3. Likely Subtags`].
 //!
+//! The [`LocaleMatcher`] finds the best supported locale for a list of desired locales,
+//! using the language matching algorithm from [`UTS #35: Language Matching`].
+//! The [`LocaleNegotiator`] builds on it to negotiate locales for HTTP `Accept-Language` headers.
+//!
+//! The [`LocaleValidator`] checks that the subtags of a locale are valid, i.e. defined by CLDR,
+//! and reports subtags that are deprecated or reserved for private use.
+//!
 //! # Examples
 //!
 //! ```
@@ -69,6 +76,7 @@
 //! [`CLDR`]: http://cldr.unicode.org/
 //! [`UTS #35: Unicode LDML 3. Likely Subtags`]: https://www.unicode.org/reports/tr35/#Likely_Subtags.
 //! [`UTS #35: Unicode LDML 3. LocaleId Canonicalization`]: http://unicode.org/reports/tr35/#LocaleId_Canonicalization,
+//! [`UTS #35: Language Matching`]: https://www.unicode.org/reports/tr35/#LanguageMatching
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -93,7 +101,10 @@ mod directionality;
 mod error;
 mod expander;
 pub mod fallback;
+mod matcher;
+mod negotiation;
 pub mod provider;
+mod validator;
 
 pub use canonicalizer::LocaleCanonicalizer;
 pub use directionality::{Direction, LocaleDirectionality};
@@ -101,6 +112,11 @@ pub use error::LocaleTransformError;
 pub use expander::LocaleExpander;
 #[doc(inline)]
 pub use fallback::LocaleFallbacker;
+pub use matcher::{LocaleMatch, LocaleMatcher};
+pub use negotiation::{LocaleNegotiator, NegotiationStrategy};
+pub use validator::{
+    LocaleSubtag, LocaleValidator, SubtagIssue, SubtagStatus, ValidityError, ValidityLevel,
+};
 
 /// Used to track the result of a transformation operation that potentially modifies its argument in place.
 #[derive(Debug, PartialEq)]
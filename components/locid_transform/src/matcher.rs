@@ -0,0 +1,417 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use crate::{LocaleExpander, LocaleTransformError};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_locid::subtags::{Region, Variants};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use tinystr::{tinystr, UnvalidatedTinyAsciiStr};
+
+/// Matches that are at least this distant are rejected by [`LocaleMatcher::find_best_match`].
+///
+/// This is the distance between two locales that only differ in an unrelated script.
+pub(crate) const THRESHOLD: u16 = 50;
+
+/// The distance added for each desired locale after the first one.
+///
+/// This is the distance between `en` and `en-GB`, so that a later desired locale is preferred
+/// over an earlier one only if it matches more closely than a regional variant would.
+pub(crate) const DEMOTION_PER_DESIRED_LOCALE: u16 = 5;
+
+/// The distance used if no rule matches, which should not happen with CLDR data.
+const NO_RULE_DISTANCE: u8 = u8::MAX;
+
+const ANY_LANGUAGE: UnvalidatedTinyAsciiStr<3> = tinystr!(3, "*").to_unvalidated();
+const ANY_SCRIPT: UnvalidatedTinyAsciiStr<4> = tinystr!(4, "*").to_unvalidated();
+
+/// The result of [`LocaleMatcher::find_best_match`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a, S> {
+    /// The best supported locale.
+    pub supported: &'a S,
+    /// The index of [`LocaleMatch::supported`] in the supported locales.
+    pub supported_index: usize,
+    /// The index of the desired locale that matched [`LocaleMatch::supported`].
+    pub desired_index: usize,
+    /// The distance between the desired and the supported locale, including the demotion for
+    /// the position of the desired locale. Lower is better, 0 is a perfect match.
+    pub distance: u16,
+}
+
+/// Finds the best supported locale for a list of desired locales, using the language matching
+/// algorithm from [UTS #35: Language Matching].
+///
+/// Locales are maximized with a [`LocaleExpander`] before comparing their language, script,
+/// and region subtags. The distance between two locales is the sum of the distances between
+/// their subtags, as specified by CLDR rules. For example, Norwegian Bokmål (`nb`) is very close
+/// to Norwegian (`no`), and British English (`en-GB`) is closer to Indian English (`en-IN`)
+/// than American English (`en-US`) is.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::{langid, LanguageIdentifier};
+/// use icu_locid_transform::LocaleMatcher;
+///
+/// let lm = LocaleMatcher::new();
+///
+/// let supported: [LanguageIdentifier; 4] = [
+///     langid!("en-US"),
+///     langid!("en-GB"),
+///     langid!("no"),
+///     langid!("fr"),
+/// ];
+///
+/// let best = lm.find_best_match([langid!("nb")], &supported).unwrap();
+/// assert_eq!(best.supported, &langid!("no"));
+///
+/// let best = lm.find_best_match([langid!("en-IN")], &supported).unwrap();
+/// assert_eq!(best.supported, &langid!("en-GB"));
+///
+/// assert_eq!(lm.find_best_match([langid!("ja")], &supported), None);
+/// ```
+///
+/// [UTS #35: Language Matching]: https://www.unicode.org/reports/tr35/tr35.html#LanguageMatching
+#[derive(Debug)]
+pub struct LocaleMatcher {
+    language_matching: DataPayload<LanguageMatchingV1Marker>,
+    expander: LocaleExpander,
+}
+
+impl LocaleMatcher {
+    /// Creates a [`LocaleMatcher`] from compiled data.
+    ///
+    /// This includes likely subtags data for all locales, see [`LocaleExpander::new_extended()`],
+    /// as locales like `nb` or `gsw` need to be maximized to be matched correctly.
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self::new_with_expander(LocaleExpander::new_extended())
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(ANY, Self::new)]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+    ) -> Result<LocaleMatcher, LocaleTransformError> {
+        let expander = LocaleExpander::try_new_with_any_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_downcasting(), expander)
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(BUFFER, Self::new)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<LocaleMatcher, LocaleTransformError> {
+        let expander = LocaleExpander::try_new_with_buffer_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_deserializing(), expander)
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<LocaleMatcher, LocaleTransformError>
+    where
+        P: DataProvider<LanguageMatchingV1Marker>
+            + DataProvider<LikelySubtagsForLanguageV1Marker>
+            + DataProvider<LikelySubtagsForScriptRegionV1Marker>
+            + DataProvider<LikelySubtagsExtendedV1Marker>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_extended_unstable(provider)?;
+        Self::try_new_with_expander_unstable(provider, expander)
+    }
+
+    /// Creates a [`LocaleMatcher`] with a custom [`LocaleExpander`] and compiled data.
+    ///
+    /// This allows using a [`LocaleExpander`] with custom likely subtags data.
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_with_expander(expander: LocaleExpander) -> Self {
+        LocaleMatcher {
+            language_matching: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_LOCID_TRANSFORM_LANGUAGE_MATCHING_V1,
+            ),
+            expander,
+        }
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new_with_expander)]
+    pub fn try_new_with_expander_unstable<P>(
+        provider: &P,
+        expander: LocaleExpander,
+    ) -> Result<LocaleMatcher, LocaleTransformError>
+    where
+        P: DataProvider<LanguageMatchingV1Marker> + ?Sized,
+    {
+        let language_matching = provider.load(Default::default())?.take_payload()?;
+
+        Ok(LocaleMatcher {
+            language_matching,
+            expander,
+        })
+    }
+
+    /// Returns the distance between a desired and a supported locale.
+    ///
+    /// Both locales are maximized before comparing them, and variants are ignored. Lower is
+    /// better: 0 means that the locales are equivalent, and distances of 50 or more mean that
+    /// the locales are not mutually intelligible.
+    ///
+    /// The distance is not symmetric: some rules only apply in one direction, for example,
+    /// speakers of Swiss German (`gsw`) usually understand German (`de`), but not vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::langid;
+    /// use icu_locid_transform::LocaleMatcher;
+    ///
+    /// let lm = LocaleMatcher::new();
+    ///
+    /// assert_eq!(lm.distance(&langid!("en"), &langid!("en-Latn-US")), 0);
+    /// assert_eq!(lm.distance(&langid!("nb"), &langid!("no")), 1);
+    /// assert_eq!(lm.distance(&langid!("en-IN"), &langid!("en-GB")), 3);
+    /// assert_eq!(lm.distance(&langid!("en-IN"), &langid!("en-US")), 5);
+    /// assert_eq!(lm.distance(&langid!("gsw"), &langid!("de")), 8);
+    /// assert!(lm.distance(&langid!("de"), &langid!("gsw")) >= 50);
+    /// ```
+    pub fn distance(
+        &self,
+        desired: impl AsRef<LanguageIdentifier>,
+        supported: impl AsRef<LanguageIdentifier>,
+    ) -> u16 {
+        self.maximized_distance(
+            &self.maximize(desired.as_ref()),
+            &self.maximize(supported.as_ref()),
+        )
+    }
+
+    /// Returns the supported locale that best matches the desired locales, which are given in
+    /// order of preference.
+    ///
+    /// Each desired locale after the first one is demoted by a small distance, so that a
+    /// more preferred locale wins unless a less preferred one matches much more closely. Among
+    /// equally good matches, CLDR's paradigm locales (such as `en-GB` or `es-419`) are preferred,
+    /// then supported locales that come first.
+    ///
+    /// Returns `None` if no supported locale is close enough to any desired locale, in which case
+    /// callers should use their default locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::{langid, LanguageIdentifier};
+    /// use icu_locid_transform::LocaleMatcher;
+    ///
+    /// let lm = LocaleMatcher::new();
+    ///
+    /// let supported: [LanguageIdentifier; 3] =
+    ///     [langid!("fr"), langid!("de"), langid!("es-ES")];
+    ///
+    /// let best = lm
+    ///     .find_best_match([langid!("es-MX"), langid!("fr")], &supported)
+    ///     .unwrap();
+    ///
+    /// // Spanish as spoken in Spain is close enough to Mexican Spanish
+    /// assert_eq!(best.supported, &langid!("es-ES"));
+    /// assert_eq!(best.supported_index, 2);
+    /// assert_eq!(best.desired_index, 0);
+    /// assert_eq!(best.distance, 5);
+    ///
+    /// let best = lm
+    ///     .find_best_match([langid!("gsw"), langid!("fr")], &supported)
+    ///     .unwrap();
+    ///
+    /// // A perfect match for the second desired locale is better than
+    /// // a close match for the first one
+    /// assert_eq!(best.supported, &langid!("fr"));
+    /// assert_eq!(best.desired_index, 1);
+    /// assert_eq!(best.distance, 5);
+    /// ```
+    pub fn find_best_match<'a, D, S>(
+        &self,
+        desired: impl IntoIterator<Item = D>,
+        supported: &'a [S],
+    ) -> Option<LocaleMatch<'a, S>>
+    where
+        D: AsRef<LanguageIdentifier>,
+        S: AsRef<LanguageIdentifier>,
+    {
+        let supported_maximized = supported
+            .iter()
+            .map(|s| {
+                let s = self.maximize(s.as_ref());
+                let is_paradigm = self.is_paradigm(&s);
+                (s, is_paradigm)
+            })
+            .collect::<Vec<_>>();
+
+        let mut best: Option<(LocaleMatch<'a, S>, bool)> = None;
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let demotion = u16::try_from(desired_index)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(DEMOTION_PER_DESIRED_LOCALE);
+            if demotion >= best.as_ref().map_or(THRESHOLD, |(m, _)| m.distance) {
+                // No later desired locale can beat the current best match
+                break;
+            }
+            let desired = self.maximize(desired.as_ref());
+            for (supported_index, (s, (supported_maximized, is_paradigm))) in
+                supported.iter().zip(&supported_maximized).enumerate()
+            {
+                let distance = self
+                    .maximized_distance(&desired, supported_maximized)
+                    .saturating_add(demotion);
+                let is_better = match &best {
+                    None => distance < THRESHOLD,
+                    Some((best, best_is_paradigm)) => match distance.cmp(&best.distance) {
+                        Ordering::Less => true,
+                        Ordering::Equal => *is_paradigm && !best_is_paradigm,
+                        Ordering::Greater => false,
+                    },
+                };
+                if is_better {
+                    best = Some((
+                        LocaleMatch {
+                            supported: s,
+                            supported_index,
+                            desired_index,
+                            distance,
+                        },
+                        *is_paradigm,
+                    ));
+                }
+            }
+        }
+        best.map(|(m, _)| m)
+    }
+
+    pub(crate) fn maximize(&self, langid: &LanguageIdentifier) -> LanguageIdentifier {
+        let mut maximized = LanguageIdentifier {
+            language: langid.language,
+            script: langid.script,
+            region: langid.region,
+            variants: Variants::new(),
+        };
+        self.expander.maximize(&mut maximized);
+        maximized
+    }
+
+    fn is_paradigm(&self, maximized: &LanguageIdentifier) -> bool {
+        self.language_matching
+            .get()
+            .paradigm_locales
+            .iter()
+            .any(|p| maximized.strict_cmp(p.as_bytes()) == Ordering::Equal)
+    }
+
+    pub(crate) fn maximized_distance(
+        &self,
+        desired: &LanguageIdentifier,
+        supported: &LanguageIdentifier,
+    ) -> u16 {
+        let data = self.language_matching.get();
+        let desired = Subtags::new(desired);
+        let supported = Subtags::new(supported);
+
+        let mut distance = 0;
+        let mut scripts_done = false;
+        if desired.language != supported.language {
+            // Rules between scripts of different languages (such as `am_Ethi` and `en_Latn`)
+            // cover the language distance as well.
+            let explicit_script_rule = data.scripts.iter().find(|rule| {
+                rule.desired.language != ANY_LANGUAGE
+                    && rule.supported.language != ANY_LANGUAGE
+                    && rule_matches(data, rule, &desired, &supported)
+            });
+            if let Some(rule) = explicit_script_rule {
+                distance += u16::from(rule.distance);
+                scripts_done = true;
+            } else {
+                distance += find_distance(data, &data.languages, &desired, &supported);
+            }
+        }
+        if !scripts_done && desired.script != supported.script {
+            distance += find_distance(data, &data.scripts, &desired, &supported);
+        }
+        if desired.region != supported.region {
+            distance += find_distance(data, &data.regions, &desired, &supported);
+        }
+        distance
+    }
+}
+
+/// The subtags of a maximized locale, in the representation of the data.
+struct Subtags {
+    language: UnvalidatedTinyAsciiStr<3>,
+    script: Option<UnvalidatedTinyAsciiStr<4>>,
+    region: Option<Region>,
+}
+
+impl Subtags {
+    fn new(langid: &LanguageIdentifier) -> Self {
+        Self {
+            language: langid.language.into_tinystr().to_unvalidated(),
+            script: langid.script.map(|s| s.into_tinystr().to_unvalidated()),
+            region: langid.region,
+        }
+    }
+}
+
+/// Returns the distance of the first rule in `rules` that matches the locales.
+fn find_distance(
+    data: &LanguageMatchingV1,
+    rules: &zerovec::ZeroSlice<LanguageMatchRule>,
+    desired: &Subtags,
+    supported: &Subtags,
+) -> u16 {
+    u16::from(
+        rules
+            .iter()
+            .find(|rule| rule_matches(data, rule, desired, supported))
+            .map_or(NO_RULE_DISTANCE, |rule| rule.distance),
+    )
+}
+
+fn rule_matches(
+    data: &LanguageMatchingV1,
+    rule: &LanguageMatchRule,
+    desired: &Subtags,
+    supported: &Subtags,
+) -> bool {
+    (pattern_matches(data, &rule.desired, desired)
+        && pattern_matches(data, &rule.supported, supported))
+        || (!rule.oneway
+            && pattern_matches(data, &rule.desired, supported)
+            && pattern_matches(data, &rule.supported, desired))
+}
+
+fn pattern_matches(
+    data: &LanguageMatchingV1,
+    pattern: &LanguageMatchPattern,
+    subtags: &Subtags,
+) -> bool {
+    if pattern.language != ANY_LANGUAGE && pattern.language != subtags.language {
+        return false;
+    }
+    if pattern.script != ANY_SCRIPT && Some(pattern.script) != subtags.script {
+        return false;
+    }
+    if pattern.region_set == LanguageMatchPattern::ANY_REGION {
+        return true;
+    }
+    let Some(region) = subtags.region else {
+        return false;
+    };
+    let in_set = data
+        .region_sets
+        .get(usize::from(pattern.region_set))
+        .map_or(false, |set| {
+            set.binary_search(&region.into_tinystr().to_unvalidated())
+                .is_ok()
+        });
+    in_set != pattern.negated
+}
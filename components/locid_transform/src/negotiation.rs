@@ -0,0 +1,384 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::matcher::{DEMOTION_PER_DESIRED_LOCALE, THRESHOLD};
+use crate::provider::*;
+use crate::{LocaleCanonicalizer, LocaleMatcher, LocaleTransformError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_locid::accept_language::{AcceptLanguage, LanguageRange};
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_provider::prelude::*;
+
+/// The strategy used by [`LocaleNegotiator::negotiate`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NegotiationStrategy {
+    /// Returns all supported locales that are matched by a language range, using the basic
+    /// filtering scheme from [RFC 4647, section 3.3.1].
+    ///
+    /// A range matches a locale if it is equal to the locale or to a prefix of it, so `en`
+    /// matches `en-GB`, but `en-GB` does not match `en`. The locales are ordered by the first
+    /// range that matches them.
+    ///
+    /// [RFC 4647, section 3.3.1]: https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1
+    Filtering,
+    /// Returns at most one supported locale, using the lookup scheme from
+    /// [RFC 4647, section 3.4].
+    ///
+    /// Each range is progressively truncated until it is equal to a supported locale, so
+    /// `de-CH-1996` finds `de-CH` or `de`. Wildcards are ignored.
+    ///
+    /// [RFC 4647, section 3.4]: https://www.rfc-editor.org/rfc/rfc4647#section-3.4
+    Lookup,
+    /// Returns all supported locales that are close to a language range, ordered by their
+    /// distance, as computed by [`LocaleMatcher`].
+    ///
+    /// Unlike the other strategies, this finds supported locales that are not prefixed by a
+    /// range, such as `no` for `nb`, or `en-GB` for `en-IN`.
+    #[default]
+    Matching,
+}
+
+/// Negotiates the locales for an HTTP `Accept-Language` header.
+///
+/// All locales are canonicalized with a [`LocaleCanonicalizer`] before they are compared, so
+/// that deprecated tags like `iw` match current tags like `he`. The returned locales are ranked
+/// by preference. If none of them is suitable, callers should use their default locale.
+///
+/// Ranges with a quality weight of 0 are not acceptable: supported locales that they match,
+/// as in [`NegotiationStrategy::Filtering`], are never returned.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::accept_language::AcceptLanguage;
+/// use icu_locid::{langid, LanguageIdentifier};
+/// use icu_locid_transform::{LocaleNegotiator, NegotiationStrategy};
+///
+/// let negotiator = LocaleNegotiator::new();
+///
+/// let accept_language =
+///     AcceptLanguage::from_bytes(b"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+/// let supported: [LanguageIdentifier; 5] = [
+///     langid!("de"),
+///     langid!("en-GB"),
+///     langid!("en-US"),
+///     langid!("fr"),
+///     langid!("fr-CH"),
+/// ];
+///
+/// assert_eq!(
+///     negotiator.negotiate(
+///         &accept_language,
+///         &supported,
+///         NegotiationStrategy::Filtering
+///     ),
+///     [
+///         &langid!("fr-CH"),
+///         &langid!("fr"),
+///         &langid!("en-GB"),
+///         &langid!("en-US"),
+///         &langid!("de"),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     negotiator.negotiate(
+///         &accept_language,
+///         &supported,
+///         NegotiationStrategy::Lookup
+///     ),
+///     [&langid!("fr-CH")]
+/// );
+///
+/// // `en` is closer to `en-US` than to `en-GB`
+/// assert_eq!(
+///     negotiator.negotiate(
+///         &accept_language,
+///         &supported,
+///         NegotiationStrategy::Matching
+///     ),
+///     [
+///         &langid!("fr-CH"),
+///         &langid!("fr"),
+///         &langid!("en-US"),
+///         &langid!("en-GB"),
+///         &langid!("de"),
+///     ]
+/// );
+/// ```
+///
+/// Deprecated and grandfathered tags are canonicalized:
+///
+/// ```
+/// use icu_locid::accept_language::AcceptLanguage;
+/// use icu_locid::{langid, LanguageIdentifier};
+/// use icu_locid_transform::{LocaleNegotiator, NegotiationStrategy};
+///
+/// let negotiator = LocaleNegotiator::new();
+///
+/// let supported: [LanguageIdentifier; 2] = [langid!("he"), langid!("nb")];
+///
+/// assert_eq!(
+///     negotiator.negotiate(
+///         &AcceptLanguage::from_bytes(b"no-bok, iw;q=0.5"),
+///         &supported,
+///         NegotiationStrategy::Filtering
+///     ),
+///     [&langid!("nb"), &langid!("he")]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LocaleNegotiator {
+    canonicalizer: LocaleCanonicalizer,
+    matcher: LocaleMatcher,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for LocaleNegotiator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocaleNegotiator {
+    /// Creates a [`LocaleNegotiator`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            canonicalizer: LocaleCanonicalizer::new(),
+            matcher: LocaleMatcher::new(),
+        }
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(ANY, Self::new)]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+    ) -> Result<Self, LocaleTransformError> {
+        Ok(Self {
+            canonicalizer: LocaleCanonicalizer::try_new_with_any_provider(provider)?,
+            matcher: LocaleMatcher::try_new_with_any_provider(provider)?,
+        })
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(BUFFER, Self::new)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<Self, LocaleTransformError> {
+        Ok(Self {
+            canonicalizer: LocaleCanonicalizer::try_new_with_buffer_provider(provider)?,
+            matcher: LocaleMatcher::try_new_with_buffer_provider(provider)?,
+        })
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, LocaleTransformError>
+    where
+        P: DataProvider<AliasesV2Marker>
+            + DataProvider<ExtensionAliasesV1Marker>
+            + DataProvider<LanguageMatchingV1Marker>
+            + DataProvider<LikelySubtagsForLanguageV1Marker>
+            + DataProvider<LikelySubtagsForScriptRegionV1Marker>
+            + DataProvider<LikelySubtagsExtendedV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            canonicalizer: LocaleCanonicalizer::try_new_unstable(provider)?,
+            matcher: LocaleMatcher::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Returns the supported locales that are acceptable for an `Accept-Language` header,
+    /// ranked by preference.
+    ///
+    /// See [`NegotiationStrategy`] for the available strategies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::accept_language::AcceptLanguage;
+    /// use icu_locid::{langid, LanguageIdentifier};
+    /// use icu_locid_transform::{LocaleNegotiator, NegotiationStrategy};
+    ///
+    /// let negotiator = LocaleNegotiator::new();
+    ///
+    /// let supported: [LanguageIdentifier; 3] =
+    ///     [langid!("de"), langid!("de-CH"), langid!("en")];
+    ///
+    /// // Lookup truncates ranges
+    /// assert_eq!(
+    ///     negotiator.negotiate(
+    ///         &AcceptLanguage::from_bytes(b"de-CH-1996, en;q=0.5"),
+    ///         &supported,
+    ///         NegotiationStrategy::Lookup
+    ///     ),
+    ///     [&langid!("de-CH")]
+    /// );
+    ///
+    /// // Anything but German
+    /// assert_eq!(
+    ///     negotiator.negotiate(
+    ///         &AcceptLanguage::from_bytes(b"*, de;q=0"),
+    ///         &supported,
+    ///         NegotiationStrategy::Matching
+    ///     ),
+    ///     [&langid!("en")]
+    /// );
+    /// ```
+    pub fn negotiate<'a, S>(
+        &self,
+        accept_language: &AcceptLanguage,
+        supported: &'a [S],
+        strategy: NegotiationStrategy,
+    ) -> Vec<&'a S>
+    where
+        S: AsRef<LanguageIdentifier>,
+    {
+        let supported_canonical = supported
+            .iter()
+            .map(|s| self.canonicalize(Locale::from(s.as_ref().clone())))
+            .collect::<Vec<_>>();
+
+        let mut ranges = Vec::new();
+        let mut rejected = Vec::new();
+        let mut wildcard = false;
+        for weighted in accept_language.ranges() {
+            match (&weighted.range, weighted.quality) {
+                (LanguageRange::Wildcard, 0) => (),
+                (LanguageRange::Wildcard, _) => wildcard = true,
+                (LanguageRange::Locale(locale), 0) => {
+                    rejected.push(self.canonicalize(locale.clone()))
+                }
+                (LanguageRange::Locale(locale), _) => {
+                    ranges.push(self.canonicalize(locale.clone()))
+                }
+                _ => (),
+            }
+        }
+
+        // Indices of supported locales that are not rejected, in supported order
+        let candidates = supported_canonical
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !rejected.iter().any(|r| filter_matches(r, s)))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let mut result = match strategy {
+            NegotiationStrategy::Lookup => {
+                return ranges
+                    .iter()
+                    .find_map(|range| lookup(range, &candidates, &supported_canonical))
+                    .and_then(|i| supported.get(i))
+                    .into_iter()
+                    .collect();
+            }
+            NegotiationStrategy::Filtering => {
+                let mut result = Vec::new();
+                for range in &ranges {
+                    for &i in &candidates {
+                        if !result.contains(&i)
+                            && supported_canonical
+                                .get(i)
+                                .map_or(false, |s| filter_matches(range, s))
+                        {
+                            result.push(i);
+                        }
+                    }
+                }
+                result
+            }
+            NegotiationStrategy::Matching => {
+                let ranges = ranges
+                    .iter()
+                    .map(|range| self.matcher.maximize(&range.id))
+                    .collect::<Vec<_>>();
+                let mut result = Vec::new();
+                for &i in &candidates {
+                    let Some(s) = supported_canonical.get(i) else {
+                        continue;
+                    };
+                    let s = self.matcher.maximize(&s.id);
+                    let distance = ranges
+                        .iter()
+                        .enumerate()
+                        .map(|(index, range)| {
+                            let demotion = u16::try_from(index)
+                                .unwrap_or(u16::MAX)
+                                .saturating_mul(DEMOTION_PER_DESIRED_LOCALE);
+                            self.matcher
+                                .maximized_distance(range, &s)
+                                .saturating_add(demotion)
+                        })
+                        .min()
+                        .unwrap_or(u16::MAX);
+                    if distance < THRESHOLD {
+                        result.push((i, distance));
+                    }
+                }
+                // This is a stable sort, so locales with the same distance keep their order
+                result.sort_by_key(|&(_, distance)| distance);
+                result.into_iter().map(|(i, _)| i).collect()
+            }
+        };
+
+        if wildcard {
+            for i in candidates {
+                if !result.contains(&i) {
+                    result.push(i);
+                }
+            }
+        }
+
+        result
+            .into_iter()
+            .filter_map(|i| supported.get(i))
+            .collect()
+    }
+
+    fn canonicalize(&self, mut locale: Locale) -> Locale {
+        self.canonicalizer.canonicalize(&mut locale);
+        locale
+    }
+}
+
+/// Whether `range` matches `tag` with basic filtering, that is, if it is equal to `tag` or to
+/// a prefix of `tag` that ends before a `-`.
+fn filter_matches(range: &Locale, tag: &Locale) -> bool {
+    let range = range.id.to_string();
+    let tag = tag.id.to_string();
+    tag.strip_prefix(&range)
+        .map_or(false, |rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Returns the index of the supported locale found by looking up `range`.
+fn lookup(range: &Locale, candidates: &[usize], supported: &[Locale]) -> Option<usize> {
+    let mut range: String = range.id.to_string();
+    loop {
+        if let Some(&i) = candidates.iter().find(|&&i| {
+            supported
+                .get(i)
+                .map_or(false, |s| s.id.normalizing_eq(&range))
+        }) {
+            return Some(i);
+        }
+        // Remove the last subtag, as well as a singleton that would end the range
+        range.truncate(range.rfind('-')?);
+        if range
+            .rfind('-')
+            .map_or(range.len(), |i| range.len() - i - 1)
+            == 1
+        {
+            range.truncate(range.rfind('-')?);
+        }
+    }
+}
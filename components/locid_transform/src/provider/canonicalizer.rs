@@ -6,7 +6,8 @@ use super::*;
 use icu_locid::subtags::{Language, Region, Script, Variant};
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
-use zerovec::{VarZeroVec, ZeroMap, ZeroSlice};
+use zerovec::ule::UnvalidatedStr;
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d, ZeroSlice};
 
 #[icu_provider::data_struct(marker(AliasesV1Marker, "locid_transform/aliases@1", singleton))]
 #[derive(PartialEq, Clone, Default)]
@@ -220,3 +221,33 @@ pub struct AliasesV2<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub subdivision: ZeroMap<'data, UnvalidatedSubdivision, SemivalidatedSubdivision>,
 }
+
+#[icu_provider::data_struct(marker(
+    ExtensionAliasesV1Marker,
+    "locid_transform/extension_aliases@1",
+    singleton
+))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This alias data is used to canonicalize the values of `-u-` and `-t-` extension keywords,
+/// based upon the rules in <http://unicode.org/reports/tr35/#LocaleId_Canonicalization>.
+///
+/// It is derived from CLDR's `bcp47` data, and maps deprecated values to their preferred
+/// values, and legacy aliases to their values.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[yoke(prove_covariance_manually)]
+pub struct ExtensionAliasesV1<'data> {
+    /// `[key, value] -> [value]`, where values are subtags joined by `-`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub values: ZeroMap2d<'data, UnvalidatedKey, UnvalidatedStr, str>,
+}
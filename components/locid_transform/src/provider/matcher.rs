@@ -0,0 +1,108 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::*;
+use icu_provider::prelude::*;
+use zerovec::{VarZeroVec, ZeroSlice, ZeroVec};
+
+#[icu_provider::data_struct(marker(
+    LanguageMatchingV1Marker,
+    "locid_transform/language_matching@1",
+    singleton
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This language matching data is used to compute the distance between two locales.
+///
+/// It is derived from CLDR's `languageMatching.xml`, with matching variables expanded
+/// using `territoryContainment`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatchingV1<'data> {
+    /// Rules that compare languages, in priority order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroVec<'data, LanguageMatchRule>,
+    /// Rules that compare languages and scripts, in priority order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scripts: ZeroVec<'data, LanguageMatchRule>,
+    /// Rules that compare languages, scripts, and regions, in priority order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroVec<'data, LanguageMatchRule>,
+    /// Sorted sets of regions, as referenced by [`LanguageMatchPattern::region_set`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region_sets: VarZeroVec<'data, ZeroSlice<UnvalidatedRegion>>,
+    /// The maximized paradigm locales, which are preferred over other locales at the
+    /// same distance.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub paradigm_locales: VarZeroVec<'data, UnvalidatedLanguageIdentifier>,
+}
+
+/// A rule that assigns a distance to pairs of locales, as used in [`LanguageMatchingV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LanguageMatchRule {
+    /// The pattern for the desired locale
+    pub desired: LanguageMatchPattern,
+    /// The pattern for the supported locale
+    pub supported: LanguageMatchPattern,
+    /// The distance between locales matching the patterns
+    pub distance: u8,
+    /// Whether the rule only applies in the given direction, i.e. not with
+    /// desired and supported swapped
+    pub oneway: bool,
+}
+
+/// A pattern matching the subtags of a maximized locale, as used in [`LanguageMatchRule`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchPatternULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LanguageMatchPattern {
+    /// The language, or `*` for any language
+    pub language: UnvalidatedLanguage,
+    /// The script, or `*` for any script
+    pub script: UnvalidatedScript,
+    /// The index of the region set in [`LanguageMatchingV1::region_sets`], or
+    /// [`LanguageMatchPattern::ANY_REGION`] for any region
+    pub region_set: u8,
+    /// Whether the pattern matches the regions that are *not* in the region set
+    pub negated: bool,
+}
+
+impl LanguageMatchPattern {
+    /// The wildcard value of [`LanguageMatchPattern::region_set`].
+    pub const ANY_REGION: u8 = u8::MAX;
+}
@@ -24,6 +24,10 @@ mod expander;
 pub use expander::*;
 mod fallback;
 pub use fallback::*;
+mod matcher;
+pub use matcher::*;
+mod validity;
+pub use validity::*;
 
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
@@ -47,10 +51,13 @@ const _: () = {
     icu_locid_transform_data::impl_fallback_parents_v1!(Baked);
     icu_locid_transform_data::impl_fallback_supplement_co_v1!(Baked);
     icu_locid_transform_data::impl_locid_transform_aliases_v2!(Baked);
+    icu_locid_transform_data::impl_locid_transform_extension_aliases_v1!(Baked);
+    icu_locid_transform_data::impl_locid_transform_language_matching_v1!(Baked);
     icu_locid_transform_data::impl_locid_transform_likelysubtags_ext_v1!(Baked);
     icu_locid_transform_data::impl_locid_transform_likelysubtags_l_v1!(Baked);
     icu_locid_transform_data::impl_locid_transform_likelysubtags_sr_v1!(Baked);
     icu_locid_transform_data::impl_locid_transform_script_dir_v1!(Baked);
+    icu_locid_transform_data::impl_locid_transform_validity_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
@@ -61,12 +68,15 @@ use icu_provider::prelude::*;
 pub const KEYS: &[DataKey] = &[
     AliasesV2Marker::KEY,
     CollationFallbackSupplementV1Marker::KEY,
+    ExtensionAliasesV1Marker::KEY,
+    LanguageMatchingV1Marker::KEY,
     LikelySubtagsExtendedV1Marker::KEY,
     LikelySubtagsForLanguageV1Marker::KEY,
     LikelySubtagsForScriptRegionV1Marker::KEY,
     LocaleFallbackLikelySubtagsV1Marker::KEY,
     LocaleFallbackParentsV1Marker::KEY,
     ScriptDirectionV1Marker::KEY,
+    ValidityV1Marker::KEY,
 ];
 
 use alloc::borrow::Cow;
@@ -80,6 +90,7 @@ type UnvalidatedLanguage = UnvalidatedTinyAsciiStr<3>;
 type UnvalidatedScript = UnvalidatedTinyAsciiStr<4>;
 type UnvalidatedRegion = UnvalidatedTinyAsciiStr<3>;
 type UnvalidatedVariant = UnvalidatedTinyAsciiStr<8>;
+type UnvalidatedKey = UnvalidatedTinyAsciiStr<2>;
 type UnvalidatedSubdivision = UnvalidatedTinyAsciiStr<7>;
 type SemivalidatedSubdivision = TinyAsciiStr<7>;
 
@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::*;
+use icu_provider::prelude::*;
+use zerovec::ule::UnvalidatedStr;
+use zerovec::{ZeroMap, ZeroMap2d, ZeroVec};
+
+#[icu_provider::data_struct(marker(ValidityV1Marker, "locid_transform/validity@1", singleton))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This validity data is used to check whether the subtags of a locale are valid.
+///
+/// It is derived from CLDR's `validity` data for language identifier subtags, and from
+/// the `bcp47` data for `-u-` and `-t-` extension keys and values. Subtags that are
+/// not contained in the data are invalid.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[yoke(prove_covariance_manually)]
+pub struct ValidityV1<'data> {
+    /// Status of valid language subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroMap<'data, UnvalidatedLanguage, IdStatus>,
+    /// Status of valid script subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scripts: ZeroMap<'data, UnvalidatedScript, IdStatus>,
+    /// Status of valid region subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroMap<'data, UnvalidatedRegion, IdStatus>,
+    /// Status of valid variant subtags.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub variants: ZeroMap<'data, UnvalidatedVariant, IdStatus>,
+    /// Status of valid `-u-` and `-t-` extension keys.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub keys: ZeroMap<'data, UnvalidatedKey, IdStatus>,
+    /// Status of valid `-u-` and `-t-` extension values, by key.
+    ///
+    /// Values are stored with their subtags joined by `-`. Keys that accept a class of
+    /// values instead of an enumerated list have an uppercase entry that names the class,
+    /// such as `CODEPOINTS` or `SCRIPT_CODE`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub values: ZeroMap2d<'data, UnvalidatedKey, UnvalidatedStr, IdStatus>,
+    /// Sorted keys whose values are lists of subtags, each of which is validated separately.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub multiple_value_keys: ZeroVec<'data, UnvalidatedKey>,
+}
+
+/// The status of a valid subtag, as used in [`ValidityV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(IdStatusULE)]
+#[repr(u8)]
+pub enum IdStatus {
+    /// A regular subtag, such as `en` or `Latn`.
+    Regular = 0,
+    /// A subtag with a special meaning, such as `mul` or `zxx`.
+    Special = 1,
+    /// A region that contains other regions, such as `001` or `EU`.
+    Macroregion = 2,
+    /// A deprecated subtag, such as `iw` or `SU`.
+    Deprecated = 3,
+    /// A subtag reserved for private use, such as `qaa` or `XZ`.
+    PrivateUse = 4,
+    /// A subtag for unknown values, such as `und` or `Zzzz`.
+    Unknown = 5,
+}
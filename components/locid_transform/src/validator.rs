@@ -0,0 +1,621 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use crate::LocaleTransformError;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use displaydoc::Display;
+use icu_locid::extensions::{transform, unicode};
+use icu_locid::subtags::{Language, Region, Script, Variant};
+use icu_locid::{LanguageIdentifier, Locale, ParserError};
+use icu_provider::prelude::*;
+use tinystr::{TinyAsciiStr, UnvalidatedTinyAsciiStr};
+use zerovec::ule::UnvalidatedStr;
+
+/// The strictness with which [`LocaleValidator::try_from_bytes`] checks a locale.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum ValidityLevel {
+    /// The locale only needs to be syntactically well-formed, as checked when parsing a [`Locale`].
+    WellFormed,
+    /// All subtags of the locale need to be valid, i.e. defined by CLDR.
+    ///
+    /// Deprecated and private-use subtags are valid.
+    Valid,
+}
+
+/// The status of a subtag that is reported by [`LocaleValidator::issues`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum SubtagStatus {
+    /// The subtag is not defined by CLDR.
+    Invalid,
+    /// The subtag is valid, but deprecated.
+    Deprecated,
+    /// The subtag is valid, but reserved for private use.
+    PrivateUse,
+}
+
+/// A subtag of a [`Locale`], as reported by [`LocaleValidator`].
+///
+/// Subtags of the language identifier in a `-t-` extension are reported like those
+/// of the main language identifier.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LocaleSubtag {
+    /// A language subtag.
+    Language(Language),
+    /// A script subtag.
+    Script(Script),
+    /// A region subtag.
+    Region(Region),
+    /// A variant subtag.
+    Variant(Variant),
+    /// The key of a `-u-` extension keyword.
+    UnicodeKey(unicode::Key),
+    /// The value of a `-u-` extension keyword, together with its key.
+    UnicodeValue(unicode::Key, unicode::Value),
+    /// The key of a `-t-` extension field.
+    TransformKey(transform::Key),
+    /// The value of a `-t-` extension field, together with its key.
+    TransformValue(transform::Key, transform::Value),
+}
+
+impl fmt::Display for LocaleSubtag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Language(language) => language.fmt(f),
+            Self::Script(script) => script.fmt(f),
+            Self::Region(region) => region.fmt(f),
+            Self::Variant(variant) => variant.fmt(f),
+            Self::UnicodeKey(key) => write!(f, "u-{key}"),
+            Self::UnicodeValue(key, value) if value.as_tinystr_slice().is_empty() => {
+                write!(f, "u-{key}")
+            }
+            Self::UnicodeValue(key, value) => write!(f, "u-{key}-{value}"),
+            Self::TransformKey(key) => write!(f, "t-{key}"),
+            Self::TransformValue(key, value) => write!(f, "t-{key}-{value}"),
+        }
+    }
+}
+
+/// A subtag that is invalid, deprecated, or reserved for private use, as reported by
+/// [`LocaleValidator::issues`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[allow(clippy::exhaustive_structs)] // this struct is stable
+pub struct SubtagIssue {
+    /// The subtag.
+    pub subtag: LocaleSubtag,
+    /// The status of the subtag.
+    pub status: SubtagStatus,
+}
+
+/// The error returned by [`LocaleValidator::try_from_bytes`].
+#[derive(Display, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ValidityError {
+    /// The input is not a well-formed locale.
+    #[displaydoc("{0}")]
+    Parser(ParserError),
+    /// The locale contains an invalid subtag.
+    #[displaydoc("Invalid subtag: {0}")]
+    InvalidSubtag(LocaleSubtag),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidityError {}
+
+impl From<ParserError> for ValidityError {
+    fn from(e: ParserError) -> Self {
+        Self::Parser(e)
+    }
+}
+
+/// Checks that the subtags of a [`Locale`] are valid, using CLDR's validity data.
+///
+/// Parsing a [`Locale`] only checks that it is syntactically well-formed, so a locale
+/// like `xq-Zzzz-QQ-u-ca-foo` can be parsed even though CLDR doesn't define the language `xq`,
+/// the region `QQ`, or the calendar `foo`. [`LocaleValidator`] checks the language, script,
+/// region, and variant subtags, as well as the keys and values of the `-u-` and `-t-` extensions,
+/// as described in [UTS #35: Validity].
+///
+/// Subtags of other extensions are not checked. Subdivision codes in the `rg` and `sd` keys
+/// are only checked for a valid region.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_locid::subtags::language;
+/// use icu_locid_transform::{
+///     LocaleSubtag, LocaleValidator, SubtagIssue, SubtagStatus,
+/// };
+///
+/// let lv = LocaleValidator::new();
+///
+/// assert!(lv.is_valid(&locale!("en-Latn-US")));
+/// assert!(lv.is_valid(&"de-u-co-phonebk".parse().unwrap()));
+///
+/// assert!(!lv.is_valid(&locale!("xq-Zzzz-QQ")));
+/// assert!(!lv.is_valid(&"de-u-ca-foo".parse().unwrap()));
+///
+/// // Deprecated subtags are valid, but reported
+/// assert!(lv.is_valid(&locale!("iw")));
+/// assert_eq!(
+///     lv.issues(&locale!("iw")),
+///     [SubtagIssue {
+///         subtag: LocaleSubtag::Language(language!("iw")),
+///         status: SubtagStatus::Deprecated,
+///     }]
+/// );
+/// ```
+///
+/// [UTS #35: Validity]: https://www.unicode.org/reports/tr35/#Validity_Data
+#[derive(Debug)]
+pub struct LocaleValidator {
+    validity: DataPayload<ValidityV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for LocaleValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocaleValidator {
+    /// Creates a [`LocaleValidator`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        LocaleValidator {
+            validity: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_LOCID_TRANSFORM_VALIDITY_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: LocaleTransformError,
+        #[cfg(skip)]
+    functions: [
+        new,
+        try_new_with_any_provider,
+        try_new_with_buffer_provider,
+        try_new_unstable,
+        Self,
+    ]);
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<LocaleValidator, LocaleTransformError>
+    where
+        P: DataProvider<ValidityV1Marker> + ?Sized,
+    {
+        let validity = provider.load(Default::default())?.take_payload()?;
+        Ok(LocaleValidator { validity })
+    }
+
+    /// Returns whether all subtags of the locale are valid.
+    ///
+    /// Deprecated and private-use subtags are valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::locale;
+    /// use icu_locid_transform::LocaleValidator;
+    ///
+    /// let lv = LocaleValidator::new();
+    ///
+    /// assert!(lv.is_valid(&locale!("sr-Latn-RS")));
+    /// assert!(lv.is_valid(&locale!("qaa-Qaaa-XZ")));
+    /// assert!(!lv.is_valid(&locale!("en-ZQ")));
+    /// assert!(!lv.is_valid(&locale!("en-fooooo")));
+    /// ```
+    pub fn is_valid(&self, locale: &Locale) -> bool {
+        self.first_invalid(locale).is_none()
+    }
+
+    /// Returns the subtags of the locale that are invalid, deprecated, or reserved for
+    /// private use, in the order in which they appear in the locale.
+    ///
+    /// The value of an extension keyword is only checked if its key is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::extensions::unicode::{key, value};
+    /// use icu_locid::subtags::{language, region, script};
+    /// use icu_locid::Locale;
+    /// use icu_locid_transform::{
+    ///     LocaleSubtag, LocaleValidator, SubtagIssue, SubtagStatus,
+    /// };
+    ///
+    /// let lv = LocaleValidator::new();
+    ///
+    /// let locale: Locale = "xq-Zzzz-QQ-u-ca-foo".parse().unwrap();
+    /// assert_eq!(
+    ///     lv.issues(&locale),
+    ///     [
+    ///         SubtagIssue {
+    ///             subtag: LocaleSubtag::Language(language!("xq")),
+    ///             status: SubtagStatus::Invalid,
+    ///         },
+    ///         SubtagIssue {
+    ///             subtag: LocaleSubtag::Region(region!("QQ")),
+    ///             status: SubtagStatus::PrivateUse,
+    ///         },
+    ///         SubtagIssue {
+    ///             subtag: LocaleSubtag::UnicodeValue(key!("ca"), value!("foo")),
+    ///             status: SubtagStatus::Invalid,
+    ///         },
+    ///     ]
+    /// );
+    ///
+    /// let locale: Locale = "en-BU-u-ca-islamicc".parse().unwrap();
+    /// assert_eq!(
+    ///     lv.issues(&locale),
+    ///     [
+    ///         SubtagIssue {
+    ///             subtag: LocaleSubtag::Region(region!("BU")),
+    ///             status: SubtagStatus::Deprecated,
+    ///         },
+    ///         SubtagIssue {
+    ///             subtag: LocaleSubtag::UnicodeValue(
+    ///                 key!("ca"),
+    ///                 value!("islamicc")
+    ///             ),
+    ///             status: SubtagStatus::Deprecated,
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    pub fn issues(&self, locale: &Locale) -> Vec<SubtagIssue> {
+        let mut issues = Vec::new();
+        self.for_each_issue(locale, &mut |subtag, status| {
+            issues.push(SubtagIssue { subtag, status });
+            true
+        });
+        issues
+    }
+
+    /// Parses a [`Locale`] from a UTF-8 byte slice, and checks it at the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::extensions::unicode::{key, value};
+    /// use icu_locid::locale;
+    /// use icu_locid::ParserError;
+    /// use icu_locid_transform::{
+    ///     LocaleSubtag, LocaleValidator, ValidityError, ValidityLevel,
+    /// };
+    ///
+    /// let lv = LocaleValidator::new();
+    ///
+    /// assert_eq!(
+    ///     lv.try_from_bytes(b"de-u-ca-foo", ValidityLevel::WellFormed)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "de-u-ca-foo"
+    /// );
+    /// assert_eq!(
+    ///     lv.try_from_bytes(b"de-u-ca-foo", ValidityLevel::Valid),
+    ///     Err(ValidityError::InvalidSubtag(LocaleSubtag::UnicodeValue(
+    ///         key!("ca"),
+    ///         value!("foo")
+    ///     )))
+    /// );
+    /// assert_eq!(
+    ///     lv.try_from_bytes(b"de-u-ca-buddhist", ValidityLevel::Valid)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "de-u-ca-buddhist"
+    /// );
+    /// assert_eq!(
+    ///     lv.try_from_bytes(b"de-u-ca-", ValidityLevel::WellFormed),
+    ///     Err(ValidityError::Parser(ParserError::InvalidExtension))
+    /// );
+    /// ```
+    pub fn try_from_bytes(
+        &self,
+        input: &[u8],
+        level: ValidityLevel,
+    ) -> Result<Locale, ValidityError> {
+        let locale = Locale::try_from_bytes(input)?;
+        match level {
+            ValidityLevel::WellFormed => Ok(locale),
+            ValidityLevel::Valid => match self.first_invalid(&locale) {
+                None => Ok(locale),
+                Some(subtag) => Err(ValidityError::InvalidSubtag(subtag)),
+            },
+        }
+    }
+
+    fn first_invalid(&self, locale: &Locale) -> Option<LocaleSubtag> {
+        let mut invalid = None;
+        self.for_each_issue(locale, &mut |subtag, status| {
+            if status == SubtagStatus::Invalid {
+                invalid = Some(subtag);
+                false
+            } else {
+                true
+            }
+        });
+        invalid
+    }
+
+    /// Calls `f` for each issue until it returns `false`. Returns `false` if stopped.
+    fn for_each_issue(
+        &self,
+        locale: &Locale,
+        f: &mut impl FnMut(LocaleSubtag, SubtagStatus) -> bool,
+    ) -> bool {
+        if !self.for_each_langid_issue(&locale.id, f) {
+            return false;
+        }
+        let transform = &locale.extensions.transform;
+        if let Some(lang) = &transform.lang {
+            if !self.for_each_langid_issue(lang, f) {
+                return false;
+            }
+        }
+        for (key, value) in transform.fields.iter() {
+            let key_status = self.key_status(key.into_tinystr());
+            if let Some(status) = issue_status(key_status) {
+                if !f(LocaleSubtag::TransformKey(*key), status) {
+                    return false;
+                }
+            }
+            if key_status.is_none() {
+                continue;
+            }
+            let value_status = self.value_status(key.into_tinystr(), &value.to_string());
+            if let Some(status) = issue_status(value_status) {
+                if !f(LocaleSubtag::TransformValue(*key, value.clone()), status) {
+                    return false;
+                }
+            }
+        }
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            let key_status = self.key_status(key.into_tinystr());
+            if let Some(status) = issue_status(key_status) {
+                if !f(LocaleSubtag::UnicodeKey(*key), status) {
+                    return false;
+                }
+            }
+            if key_status.is_none() {
+                continue;
+            }
+            let value_status = self.value_status(key.into_tinystr(), &value.to_string());
+            if let Some(status) = issue_status(value_status) {
+                if !f(LocaleSubtag::UnicodeValue(*key, value.clone()), status) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn for_each_langid_issue(
+        &self,
+        langid: &LanguageIdentifier,
+        f: &mut impl FnMut(LocaleSubtag, SubtagStatus) -> bool,
+    ) -> bool {
+        let data = self.validity.get();
+        let language = langid.language;
+        if let Some(status) = issue_status(
+            data.languages
+                .get_copied(&language.into_tinystr().to_unvalidated()),
+        ) {
+            if !f(LocaleSubtag::Language(language), status) {
+                return false;
+            }
+        }
+        if let Some(script) = langid.script {
+            if let Some(status) = issue_status(self.script_status(script.into_tinystr())) {
+                if !f(LocaleSubtag::Script(script), status) {
+                    return false;
+                }
+            }
+        }
+        if let Some(region) = langid.region {
+            if let Some(status) = issue_status(self.region_status(region.into_tinystr())) {
+                if !f(LocaleSubtag::Region(region), status) {
+                    return false;
+                }
+            }
+        }
+        for variant in langid.variants.iter() {
+            if let Some(status) = issue_status(
+                data.variants
+                    .get_copied(&variant.into_tinystr().to_unvalidated()),
+            ) {
+                if !f(LocaleSubtag::Variant(*variant), status) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn script_status(&self, script: TinyAsciiStr<4>) -> Option<IdStatus> {
+        self.validity
+            .get()
+            .scripts
+            .get_copied(&script.to_unvalidated())
+    }
+
+    fn region_status(&self, region: TinyAsciiStr<3>) -> Option<IdStatus> {
+        self.validity
+            .get()
+            .regions
+            .get_copied(&region.to_unvalidated())
+    }
+
+    fn key_status(&self, key: TinyAsciiStr<2>) -> Option<IdStatus> {
+        self.validity.get().keys.get_copied(&key.to_unvalidated())
+    }
+
+    /// Returns the status of an extension value, given as its subtags joined by `-`.
+    fn value_status(&self, key: TinyAsciiStr<2>, value: &str) -> Option<IdStatus> {
+        let data = self.validity.get();
+        let key = key.to_unvalidated();
+        let value = if value.is_empty() { "true" } else { value };
+        if let Some(status) = data
+            .values
+            .get_copied_2d(&key, UnvalidatedStr::from_str(value))
+        {
+            return Some(status);
+        }
+        if data.multiple_value_keys.binary_search(&key).is_ok() {
+            // Deprecated or private-use subtags make the whole value deprecated or private-use
+            let mut value_status = IdStatus::Regular;
+            for subtag in value.split('-') {
+                let status = data
+                    .values
+                    .get_copied_2d(&key, UnvalidatedStr::from_str(subtag))
+                    .or_else(|| self.value_class_status(key, subtag))?;
+                if matches!(status, IdStatus::Deprecated | IdStatus::PrivateUse) {
+                    value_status = status;
+                }
+            }
+            Some(value_status)
+        } else if has_class(data, key, PRIVATE_USE) {
+            Some(IdStatus::Regular)
+        } else {
+            self.value_class_status(key, value)
+        }
+    }
+
+    /// Returns the status of a single value subtag for keys that accept a class of values.
+    fn value_class_status(
+        &self,
+        key: UnvalidatedTinyAsciiStr<2>,
+        subtag: &str,
+    ) -> Option<IdStatus> {
+        let data = self.validity.get();
+        if has_class(data, key, CODEPOINTS)
+            && (4..=6).contains(&subtag.len())
+            && subtag.bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            return Some(IdStatus::Regular);
+        }
+        if has_class(data, key, SCRIPT_CODE) || has_class(data, key, REORDER_CODE) {
+            if let Ok(script) = TinyAsciiStr::<4>::from_str(subtag) {
+                if let Some(status) = self.script_status(script.to_ascii_titlecase()) {
+                    return Some(status);
+                }
+            }
+        }
+        let is_subdivision = has_class(data, key, SUBDIVISION_CODE);
+        if (is_subdivision || has_class(data, key, RG_KEY_VALUE)) && (3..=6).contains(&subtag.len())
+        {
+            let (region, suffix) = subtag.split_at(2);
+            if suffix == "zzzz" && is_subdivision {
+                return None;
+            }
+            let region = TinyAsciiStr::<3>::from_str(region).ok()?;
+            if region.is_ascii_alphabetic() && suffix.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return self
+                    .region_status(region.to_ascii_uppercase())
+                    .filter(|&s| s == IdStatus::Regular || s == IdStatus::Deprecated);
+            }
+        }
+        None
+    }
+}
+
+const CODEPOINTS: &str = "CODEPOINTS";
+const PRIVATE_USE: &str = "PRIVATE_USE";
+const REORDER_CODE: &str = "REORDER_CODE";
+const RG_KEY_VALUE: &str = "RG_KEY_VALUE";
+const SCRIPT_CODE: &str = "SCRIPT_CODE";
+const SUBDIVISION_CODE: &str = "SUBDIVISION_CODE";
+
+fn has_class(data: &ValidityV1, key: UnvalidatedTinyAsciiStr<2>, class: &str) -> bool {
+    data.values
+        .get_2d(&key, UnvalidatedStr::from_str(class))
+        .is_some()
+}
+
+/// Maps the status of a subtag in the validity data to the status that is reported.
+fn issue_status(status: Option<IdStatus>) -> Option<SubtagStatus> {
+    match status {
+        None => Some(SubtagStatus::Invalid),
+        Some(IdStatus::Deprecated) => Some(SubtagStatus::Deprecated),
+        Some(IdStatus::PrivateUse) => Some(SubtagStatus::PrivateUse),
+        Some(_) => None,
+    }
+}
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() {
+        let lv = LocaleValidator::new();
+        for (locale, valid) in [
+            ("und", true),
+            ("mul-Zyyy-001", true),
+            ("sh-DD", true),
+            ("en-Qabx-QM", true),
+            ("en-Qaby", false),
+            ("de-1901-fonipa", true),
+            ("en-u-kn", true),
+            ("en-u-kn-false", true),
+            ("en-u-kn-maybe", false),
+            ("en-u-ca", false),
+            ("en-u-zz-true", false),
+            ("en-u-ca-islamic-civil", true),
+            ("en-u-ca-islamic-foo", false),
+            ("en-u-kr-latn-digit", true),
+            ("en-u-kr-latn-foo", false),
+            ("en-u-dx-thai-laoo", true),
+            ("en-u-vt-0061-0062", true),
+            ("en-u-vt-0061-xyz", false),
+            ("en-u-rg-uszzzz", true),
+            ("en-u-rg-zqzzzz", false),
+            ("en-u-sd-usca", true),
+            ("en-u-sd-uszzzz", false),
+            ("en-u-cu-eur-tz-usnyc", true),
+            ("und-Latn-t-ru-m0-ungegn", true),
+            ("und-Latn-t-xq-m0-ungegn", false),
+            ("und-Latn-t-ru-m0-foo", false),
+            ("und-t-k0-windows-extended", true),
+            ("und-t-x0-anything", true),
+            ("en-a-foo-x-bar", true),
+        ] {
+            let locale: Locale = locale.parse().unwrap();
+            assert_eq!(lv.is_valid(&locale), valid, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_issues() {
+        let lv = LocaleValidator::new();
+        let locale: Locale = "und-t-xq-qaaa-m0-names-u-vt-0061-kr-latn-qaai"
+            .parse()
+            .unwrap();
+        let issues = lv.issues(&locale);
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| (issue.subtag.to_string(), issue.status))
+                .collect::<Vec<_>>(),
+            [
+                ("xq".to_string(), SubtagStatus::Invalid),
+                ("Qaaa".to_string(), SubtagStatus::PrivateUse),
+                ("t-m0-names".to_string(), SubtagStatus::Deprecated),
+                ("u-kr-latn-qaai".to_string(), SubtagStatus::Deprecated),
+                ("u-vt".to_string(), SubtagStatus::Deprecated),
+            ]
+        );
+    }
+}
@@ -4,6 +4,8 @@
 
 #[cfg(feature = "experimental")]
 use crate::rules::reference::parser::ParserError;
+#[cfg(feature = "experimental")]
+use crate::PluralCategory;
 use displaydoc::Display;
 use icu_provider::DataError;
 
@@ -17,6 +19,14 @@ pub enum PluralsError {
     #[cfg(feature = "experimental")]
     #[displaydoc("Parser error: {0}")]
     Parser(ParserError),
+    /// A rule is given more than once, has no condition, or cannot be represented.
+    #[cfg(feature = "experimental")]
+    #[displaydoc("Invalid rule for category {0:?}")]
+    InvalidRule(PluralCategory),
+    /// A sample of the first category resolves to the second category.
+    #[cfg(feature = "experimental")]
+    #[displaydoc("Sample for category {0:?} matches category {1:?}")]
+    SampleMismatch(PluralCategory, PluralCategory),
     /// An error originating from [`icu_provider`].
     #[displaydoc("Data provider error: {0}")]
     Data(DataError),
@@ -562,6 +562,187 @@ impl PluralRules {
             .chain(test_rule!(many, Many))
             .chain(Some(PluralCategory::Other))
     }
+
+    /// Constructs a new `PluralRules` from a set of rules in the
+    /// [CLDR plural rule syntax](https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax),
+    /// such as `"n % 10 = 1 and n % 100 != 11 @integer 1, 21, 31, …"`.
+    ///
+    /// Each category may only be given once. The rule for [`PluralCategory::Other`] is optional,
+    /// and may only contain samples. All other rules must have a condition.
+    ///
+    /// The samples of each rule are validated: every sample value must match the rule of its
+    /// own category and no other, and the samples of [`PluralCategory::Other`] must not match
+    /// any rule. Sample ranges are checked in steps of their last digit, for example `0.0~1.5`
+    /// is checked as `0.0`, `0.1`, …, `1.5`, up to a limit of 1000 values per range.
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::{PluralCategory, PluralRules, PluralsError};
+    ///
+    /// let pr = PluralRules::try_from_rules([
+    ///     (
+    ///         PluralCategory::One,
+    ///         "n % 10 = 1 and n % 100 != 11 @integer 1, 21, 31, …",
+    ///     ),
+    ///     (PluralCategory::Other, "@integer 0, 2~20, 100, …"),
+    /// ])
+    /// .expect("rules should be valid");
+    ///
+    /// assert_eq!(pr.category_for(21_usize), PluralCategory::One);
+    /// assert_eq!(pr.category_for(11_usize), PluralCategory::Other);
+    ///
+    /// // The samples of `Other` include 1, which matches the rule for `One`
+    /// assert_eq!(
+    ///     PluralRules::try_from_rules([
+    ///         (PluralCategory::One, "n = 1"),
+    ///         (PluralCategory::Other, "@integer 0~15"),
+    ///     ])
+    ///     .unwrap_err(),
+    ///     PluralsError::SampleMismatch(PluralCategory::Other, PluralCategory::One)
+    /// );
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn try_from_rules<'a>(
+        rules: impl IntoIterator<Item = (PluralCategory, &'a str)>,
+    ) -> Result<Self, PluralsError> {
+        let mut data = provider::PluralRulesV1::default();
+        let mut samples = alloc::vec::Vec::new();
+
+        for (category, rule) in rules {
+            let rule = rules::reference::parse(rule.as_bytes())?;
+            let slot = match category {
+                PluralCategory::Zero => &mut data.zero,
+                PluralCategory::One => &mut data.one,
+                PluralCategory::Two => &mut data.two,
+                PluralCategory::Few => &mut data.few,
+                PluralCategory::Many => &mut data.many,
+                PluralCategory::Other => {
+                    if !rule.condition.0.is_empty()
+                        || samples.iter().any(|(c, _)| *c == PluralCategory::Other)
+                    {
+                        return Err(PluralsError::InvalidRule(category));
+                    }
+                    samples.push((category, rule.samples));
+                    continue;
+                }
+            };
+            if slot.is_some() || rule.condition.0.is_empty() {
+                return Err(PluralsError::InvalidRule(category));
+            }
+            *slot = Some(
+                rules::runtime::ast::Rule::try_from(&rule)
+                    .map_err(|_| PluralsError::InvalidRule(category))?,
+            );
+            samples.push((category, rule.samples));
+        }
+
+        let rules = [
+            (PluralCategory::Zero, &data.zero),
+            (PluralCategory::One, &data.one),
+            (PluralCategory::Two, &data.two),
+            (PluralCategory::Few, &data.few),
+            (PluralCategory::Many, &data.many),
+        ];
+        for (category, samples) in &samples {
+            let values = samples
+                .iter()
+                .flat_map(|s| s.integer.iter().chain(s.decimal.iter()))
+                .flat_map(|list| list.sample_ranges.iter())
+                .flat_map(sample_values);
+            for value in values {
+                let operands =
+                    sample_operands(&value).ok_or(PluralsError::InvalidRule(*category))?;
+                for (other_category, rule) in rules {
+                    let matches = rule.as_ref().map_or(false, |r| test_rule(r, &operands));
+                    if matches != (other_category == *category) {
+                        return Err(PluralsError::SampleMismatch(
+                            *category,
+                            if matches {
+                                other_category
+                            } else {
+                                PluralCategory::Other
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(Self(DataPayload::from_owned(data)))
+    }
+}
+
+/// Parses a sample value, which may have a compact decimal exponent, as in `1.1c6`.
+///
+/// The exponent is written as `e` in the reference AST.
+#[cfg(feature = "experimental")]
+fn sample_operands(value: &str) -> Option<PluralOperands> {
+    let Some((base, exponent)) = value.split_once(['c', 'e']) else {
+        return value.parse().ok();
+    };
+    let exponent: usize = exponent.parse().ok()?;
+    // The operands other than `c` are those of the value with the exponent applied
+    let (integer, fraction) = base.split_once('.').unwrap_or((base, ""));
+    let point = integer.len() + exponent;
+    let digits = alloc::format!("{integer}{fraction:0<width$}", width = exponent);
+    let (integer, fraction) = digits.split_at(point);
+    let mut operands: PluralOperands = if fraction.is_empty() {
+        integer.parse().ok()?
+    } else {
+        alloc::format!("{integer}.{fraction}").parse().ok()?
+    };
+    operands.c = exponent;
+    Some(operands)
+}
+
+/// Expands a sample range such as `0.0~1.5` into its values `0.0`, `0.1`, …, `1.5`.
+///
+/// Ranges with more than 1000 values, or with bounds that are not plain decimals with the
+/// same number of fraction digits, are represented by their bounds only.
+#[cfg(feature = "experimental")]
+fn sample_values(
+    range: &rules::reference::ast::SampleRange,
+) -> alloc::vec::Vec<alloc::string::String> {
+    let lower = &range.lower_val.0;
+    let Some(upper) = &range.upper_val else {
+        return alloc::vec![lower.clone()];
+    };
+    let bounds = alloc::vec![lower.clone(), upper.0.clone()];
+
+    let fraction_digits = |value: &str| value.split_once('.').map_or(0, |(_, f)| f.len());
+    let scaled = |value: &str| -> Option<u64> {
+        if !value.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+            return None;
+        }
+        value.replace('.', "").parse().ok()
+    };
+    let v = fraction_digits(lower);
+    let (Some(start), Some(end)) = (scaled(lower), scaled(&upper.0)) else {
+        return bounds;
+    };
+    if v != fraction_digits(&upper.0) || end < start || end - start >= 1000 {
+        return bounds;
+    }
+
+    (start..=end)
+        .map(|n| {
+            if v == 0 {
+                return alloc::format!("{n}");
+            }
+            // Left-pad so that there is at least one integer digit
+            let digits = alloc::format!("{n:0>width$}", width = v + 1);
+            let (integer, fraction) = digits.split_at(digits.len() - v);
+            alloc::format!("{integer}.{fraction}")
+        })
+        .collect()
 }
 
 /// A [`PluralRules`] that also has the ability to retrieve an appropriate [`Plural Category`] for a
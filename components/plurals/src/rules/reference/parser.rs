@@ -306,8 +306,15 @@ impl<'p> Parser<'p> {
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
+        // The exponent is written as `c` in CLDR, with `e` as a deprecated synonym
+        let exponent = match self.lexer.peek() {
+            Some(Token::E) => Some('e'),
+            Some(Token::Operand(ast::Operand::C)) => Some('c'),
+            _ => None,
+        };
+        if let Some(exponent) = exponent {
+            self.lexer.next();
+            s.push(exponent);
             match self.lexer.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
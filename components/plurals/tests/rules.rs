@@ -55,3 +55,84 @@ fn test_parsing_operands() {
         }
     }
 }
+
+#[test]
+fn test_try_from_rules() {
+    use icu_plurals::{PluralCategory, PluralRules, PluralsError};
+
+    // French cardinal rules from CLDR
+    let fr = PluralRules::try_from_rules([
+        (PluralCategory::One, "i = 0,1 @integer 0, 1 @decimal 0.0~1.5"),
+        (
+            PluralCategory::Many,
+            "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …",
+        ),
+        (
+            PluralCategory::Other,
+            " @integer 2~17, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, 1.0001c3, 1.1c3, 2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+        ),
+    ])
+    .expect("CLDR rules should be valid");
+    assert_eq!(fr.category_for(1_usize), PluralCategory::One);
+    assert_eq!(fr.category_for(1_000_000_usize), PluralCategory::Many);
+    assert_eq!(fr.category_for(2_usize), PluralCategory::Other);
+    assert_eq!(
+        fr.categories().collect::<Vec<_>>(),
+        [
+            PluralCategory::One,
+            PluralCategory::Many,
+            PluralCategory::Other
+        ]
+    );
+
+    // Rules without samples are not validated
+    let pr = PluralRules::try_from_rules([(PluralCategory::Two, "n = 2")]).unwrap();
+    assert_eq!(pr.category_for(2_usize), PluralCategory::Two);
+
+    for (rules, error) in [
+        (
+            &[
+                (PluralCategory::One, "n = 1"),
+                (PluralCategory::One, "n = 2"),
+            ][..],
+            PluralsError::InvalidRule(PluralCategory::One),
+        ),
+        (
+            &[(PluralCategory::Few, "@integer 3")],
+            PluralsError::InvalidRule(PluralCategory::Few),
+        ),
+        (
+            &[(PluralCategory::Other, "n = 1")],
+            PluralsError::InvalidRule(PluralCategory::Other),
+        ),
+        (
+            &[(PluralCategory::One, "n = 1 @integer 1, 2")],
+            PluralsError::SampleMismatch(PluralCategory::One, PluralCategory::Other),
+        ),
+        (
+            &[
+                (PluralCategory::One, "n = 1..3 @integer 1~3"),
+                (PluralCategory::Two, "n = 2 @integer 2"),
+            ],
+            PluralsError::SampleMismatch(PluralCategory::One, PluralCategory::Two),
+        ),
+        (
+            &[
+                (PluralCategory::One, "n = 1"),
+                (PluralCategory::Other, "@decimal 0.0~1.5"),
+            ],
+            PluralsError::SampleMismatch(PluralCategory::Other, PluralCategory::One),
+        ),
+    ] {
+        assert_eq!(
+            PluralRules::try_from_rules(rules.iter().copied()).unwrap_err(),
+            error,
+            "{rules:?}"
+        );
+    }
+
+    assert!(matches!(
+        PluralRules::try_from_rules([(PluralCategory::One, "n = ")]),
+        Err(PluralsError::Parser(_))
+    ));
+}
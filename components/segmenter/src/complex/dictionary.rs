@@ -27,6 +27,8 @@ struct DictionaryBreakIterator<
     X: Iterator<Item = usize> + ?Sized,
 > {
     trie: Char16Trie<'l>,
+    /// The user dictionary layered over `trie`, whose values are weights.
+    user_trie: Option<Char16Trie<'l>>,
     iter: Y::IterAttr,
     len: usize,
     grapheme_iter: X,
@@ -47,56 +49,77 @@ impl<'l, 's, Y: DictionaryType<'l, 's> + ?Sized, X: Iterator<Item = usize> + ?Si
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut trie_iter = self.trie.iter();
-        let mut intermediate_length = 0;
+        let mut trie_iter = Some(self.trie.iter());
+        let mut user_trie_iter = self.user_trie.as_ref().map(Char16Trie::iter);
         let mut not_match = false;
-        let mut previous_match = None;
+        // The end, weight, and following iterator of the best match so far
+        let mut previous_match: Option<(usize, i32, Y::IterAttr)> = None;
         let mut last_grapheme_offset = 0;
 
         while let Some(next) = self.iter.next() {
             let ch = Y::to_char(next.1);
-            match trie_iter.next(ch) {
-                TrieResult::FinalValue(_) => {
-                    return Some(next.0 + Y::char_len(next.1));
-                }
-                TrieResult::Intermediate(_) => {
-                    // Dictionary has to match with grapheme cluster segment.
-                    // If not, we ignore it.
-                    while last_grapheme_offset < next.0 + Y::char_len(next.1) {
-                        if let Some(offset) = self.grapheme_iter.next() {
-                            last_grapheme_offset = offset;
+            let end = next.0 + Y::char_len(next.1);
+            for (trie_iter, is_user) in [(&mut trie_iter, false), (&mut user_trie_iter, true)] {
+                let Some(iter) = trie_iter else {
+                    continue;
+                };
+                let value = match iter.next(ch) {
+                    TrieResult::FinalValue(value) => {
+                        *trie_iter = None;
+                        value
+                    }
+                    TrieResult::Intermediate(value) => {
+                        // Dictionary has to match with grapheme cluster segment.
+                        // If not, we ignore it.
+                        while last_grapheme_offset < end {
+                            if let Some(offset) = self.grapheme_iter.next() {
+                                last_grapheme_offset = offset;
+                                continue;
+                            }
+                            last_grapheme_offset = self.len;
+                            break;
+                        }
+                        if last_grapheme_offset != end {
                             continue;
                         }
-                        last_grapheme_offset = self.len;
-                        break;
+                        value
                     }
-                    if last_grapheme_offset != next.0 + Y::char_len(next.1) {
+                    TrieResult::NoMatch => {
+                        *trie_iter = None;
                         continue;
                     }
-
-                    intermediate_length = next.0 + Y::char_len(next.1);
-                    previous_match = Some(self.iter.clone());
-                }
-                TrieResult::NoMatch => {
-                    if intermediate_length > 0 {
-                        if let Some(previous_match) = previous_match {
-                            // Rewind previous match point
-                            self.iter = previous_match;
-                        }
-                        return Some(intermediate_length);
+                    TrieResult::NoValue => {
+                        // Prefix string is matched
+                        not_match = true;
+                        continue;
                     }
-                    // Not found
-                    return Some(next.0 + Y::char_len(next.1));
+                };
+                // Only user dictionary words are weighted. Among equal weights, the longest
+                // match wins.
+                let weight = if is_user { value } else { 0 };
+                if previous_match
+                    .as_ref()
+                    .map_or(true, |&(_, previous_weight, _)| weight >= previous_weight)
+                {
+                    previous_match = Some((end, weight, self.iter.clone()));
                 }
-                TrieResult::NoValue => {
-                    // Prefix string is matched
-                    not_match = true;
+            }
+
+            if trie_iter.is_none() && user_trie_iter.is_none() {
+                if let Some((previous_end, _, previous_iter)) = previous_match {
+                    // Rewind previous match point
+                    self.iter = previous_iter;
+                    return Some(previous_end);
                 }
+                // Not found
+                return Some(end);
             }
         }
 
-        if intermediate_length > 0 {
-            Some(intermediate_length)
+        if let Some((previous_end, _, previous_iter)) = previous_match {
+            // Rewind previous match point, as a prefix of a longer word may have been scanned
+            self.iter = previous_iter;
+            Some(previous_end)
         } else if not_match {
             // no match by scanning text
             Some(self.len)
@@ -138,16 +161,23 @@ impl<'l, 's> DictionaryType<'l, 's> for char {
 
 pub(super) struct DictionarySegmenter<'l> {
     dict: &'l UCharDictionaryBreakDataV1<'l>,
+    user_dict: Option<&'l UCharDictionaryBreakDataV1<'l>>,
     grapheme: &'l RuleBreakDataV1<'l>,
 }
 
 impl<'l> DictionarySegmenter<'l> {
     pub(super) fn new(
         dict: &'l UCharDictionaryBreakDataV1<'l>,
+        user_dict: Option<&'l UCharDictionaryBreakDataV1<'l>>,
         grapheme: &'l RuleBreakDataV1<'l>,
     ) -> Self {
         // TODO: no way to verify trie data
-        Self { dict, grapheme }
+        Self {
+            dict,
+            // An empty trie has no root node
+            user_dict: user_dict.filter(|user_dict| !user_dict.trie_data.is_empty()),
+            grapheme,
+        }
     }
 
     /// Create a dictionary based break iterator for an `str` (a UTF-8 string).
@@ -155,6 +185,9 @@ impl<'l> DictionarySegmenter<'l> {
         let grapheme_iter = GraphemeClusterSegmenter::new_and_segment_str(input, self.grapheme);
         DictionaryBreakIterator::<char, GraphemeClusterBreakIteratorUtf8> {
             trie: Char16Trie::new(self.dict.trie_data.clone()),
+            user_trie: self
+                .user_dict
+                .map(|user_dict| Char16Trie::new(user_dict.trie_data.clone())),
             iter: input.char_indices(),
             len: input.len(),
             grapheme_iter,
@@ -166,6 +199,9 @@ impl<'l> DictionarySegmenter<'l> {
         let grapheme_iter = GraphemeClusterSegmenter::new_and_segment_utf16(input, self.grapheme);
         DictionaryBreakIterator::<u32, GraphemeClusterBreakIteratorUtf16> {
             trie: Char16Trie::new(self.dict.trie_data.clone()),
+            user_trie: self
+                .user_dict
+                .map(|user_dict| Char16Trie::new(user_dict.trie_data.clone())),
             iter: Utf16Indices::new(input),
             len: input.len(),
             grapheme_iter,
@@ -206,6 +242,7 @@ mod tests {
         let word_segmenter = WordSegmenter::new_dictionary();
         let dict_segmenter = DictionarySegmenter::new(
             dict_payload.get(),
+            None,
             crate::provider::Baked::SINGLETON_SEGMENTER_GRAPHEME_V1,
         );
 
@@ -242,6 +279,41 @@ mod tests {
         assert_eq!(result, vec![0, 8]);
     }
 
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn user_dictionary_test() {
+        let dict =
+            UCharDictionaryBreakDataV1::from_words([("ab", None), ("abc", None), ("cd", None)]);
+        let grapheme = crate::provider::Baked::SINGLETON_SEGMENTER_GRAPHEME_V1;
+
+        for (user_words, s, expected) in [
+            // Built-in dictionary only
+            (vec![], "abcd", vec![3, 4]),
+            // Longest match across both dictionaries
+            (vec![("abcde", None)], "abcdef", vec![5, 6]),
+            (vec![("abcde", None)], "abcdx", vec![3, 4, 5]),
+            // Higher weights win over longer words
+            (vec![("a", Some(1))], "abc", vec![1, 2, 3]),
+            // Lower weights only match where no other word does
+            (
+                vec![("abx", Some(-1)), ("xy", Some(-1))],
+                "abxy",
+                vec![2, 4],
+            ),
+        ] {
+            let user_dict = UCharDictionaryBreakDataV1::from_words(user_words);
+            let dict_segmenter = DictionarySegmenter::new(&dict, Some(&user_dict), grapheme);
+
+            let result: Vec<usize> = dict_segmenter.segment_str(s).collect();
+            assert_eq!(result, expected, "{s}");
+
+            // All characters are one code unit in UTF-16 and UTF-8
+            let s_utf16: Vec<u16> = s.encode_utf16().collect();
+            let result: Vec<usize> = dict_segmenter.segment_utf16(&s_utf16).collect();
+            assert_eq!(result, expected, "{s}");
+        }
+    }
+
     #[test]
     fn khmer_dictionary_test() {
         let segmenter = LineSegmenter::new_dictionary();
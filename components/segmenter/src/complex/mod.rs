@@ -37,6 +37,7 @@ pub(crate) struct ComplexPayloads {
     lo: Option<DictOrLstm>,
     th: Option<DictOrLstm>,
     ja: Option<DataPayload<UCharDictionaryBreakDataV1Marker>>,
+    user: Option<DataPayload<UCharDictionaryBreakDataV1Marker>>,
 }
 
 impl ComplexPayloads {
@@ -93,6 +94,7 @@ impl ComplexPayloads {
                 .map(DataPayload::cast)
                 .map(Err),
             ja: None,
+            user: None,
         }
     }
 
@@ -118,6 +120,7 @@ impl ComplexPayloads {
                 .map(DataPayload::cast)
                 .map(Err),
             ja: None,
+            user: None,
         })
     }
 
@@ -163,6 +166,7 @@ impl ComplexPayloads {
             )
             .unwrap()
             .map(DataPayload::cast),
+            user: None,
         }
     }
 
@@ -189,6 +193,7 @@ impl ComplexPayloads {
                 .map(Ok),
             ja: try_load::<DictionaryForWordOnlyAutoV1Marker, D>(provider, locale!("ja"))?
                 .map(DataPayload::cast),
+            user: None,
         })
     }
 
@@ -223,6 +228,7 @@ impl ComplexPayloads {
             )
             .unwrap()
             .map(DataPayload::cast),
+            user: None,
         }
     }
 
@@ -250,6 +256,7 @@ impl ComplexPayloads {
                 .map(Err),
             ja: try_load::<DictionaryForWordOnlyAutoV1Marker, D>(provider, locale!("ja"))?
                 .map(DataPayload::cast),
+            user: None,
         })
     }
 
@@ -290,6 +297,7 @@ impl ComplexPayloads {
             .map(DataPayload::cast)
             .map(Ok),
             ja: None,
+            user: None,
         }
     }
 
@@ -314,8 +322,18 @@ impl ComplexPayloads {
                 .map(DataPayload::cast)
                 .map(Ok),
             ja: None,
+            user: None,
         })
     }
+
+    /// Layers a user dictionary over the dictionaries of all languages.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn with_user_dictionary(self, user: UCharDictionaryBreakDataV1<'static>) -> Self {
+        Self {
+            user: Some(DataPayload::from_owned(user)),
+            ..self
+        }
+    }
 }
 
 fn try_load<M: KeyedDataMarker, P: DataProvider<M> + ?Sized>(
@@ -350,9 +368,13 @@ pub(crate) fn complex_language_segment_utf16(
         match payloads.select(lang) {
             Some(Ok(dict)) => {
                 result.extend(
-                    DictionarySegmenter::new(dict.get(), payloads.grapheme.get())
-                        .segment_utf16(slice)
-                        .map(|n| offset + n),
+                    DictionarySegmenter::new(
+                        dict.get(),
+                        payloads.user.as_ref().map(DataPayload::get),
+                        payloads.grapheme.get(),
+                    )
+                    .segment_utf16(slice)
+                    .map(|n| offset + n),
                 );
             }
             #[cfg(feature = "lstm")]
@@ -382,9 +404,13 @@ pub(crate) fn complex_language_segment_str(payloads: &ComplexPayloads, input: &s
         match payloads.select(lang) {
             Some(Ok(dict)) => {
                 result.extend(
-                    DictionarySegmenter::new(dict.get(), payloads.grapheme.get())
-                        .segment_str(slice)
-                        .map(|n| offset + n),
+                    DictionarySegmenter::new(
+                        dict.get(),
+                        payloads.user.as_ref().map(DataPayload::get),
+                        payloads.grapheme.get(),
+                    )
+                    .segment_str(slice)
+                    .map(|n| offset + n),
                 );
             }
             #[cfg(feature = "lstm")]
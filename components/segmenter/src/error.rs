@@ -18,6 +18,12 @@ pub enum SegmenterError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("{0}")]
     Data(DataError),
+    /// The segmentation rules could not be parsed at this byte offset.
+    #[displaydoc("Invalid segmentation rules at byte offset {0}")]
+    InvalidRules(usize),
+    /// The segmentation rules need more states than the rule-based segmenter supports.
+    #[displaydoc("Segmentation rules are too complex")]
+    TooComplexRules,
 }
 
 impl From<DataError> for SegmenterError {
@@ -3,9 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::vec::Vec;
+use core::ops::Range;
 use icu_provider::prelude::*;
 
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::incremental::resegment;
+use crate::indices::{
+    ChunkCharIndices, InputIndices, Latin1Indices, ResumableBreakIterator, Utf16Indices,
+};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
@@ -30,6 +34,51 @@ pub struct GraphemeClusterBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Size
 
 derive_usize_iterator_with_type!(GraphemeClusterBreakIterator);
 
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> GraphemeClusterBreakIterator<'l, 's, Y> {
+    /// Returns the first grapheme cluster boundary after `offset`, or `None` if `offset` is at or
+    /// after the end of the string.
+    ///
+    /// This does not change the position of the iterator. Segmentation resumes at the last
+    /// position before `offset` at which the rules always break, such as between two letters,
+    /// instead of at the start of the string. Its cost is therefore linear in the distance to
+    /// that position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::GraphemeClusterSegmenter;
+    ///
+    /// let segmenter = GraphemeClusterSegmenter::new();
+    /// // "नमस्ते" is three grapheme clusters of two code units each.
+    /// let text: Vec<u16> = "नमस्ते".encode_utf16().collect();
+    /// let iter = segmenter.segment_utf16(&text);
+    ///
+    /// assert_eq!(iter.following(1), Some(2));
+    /// assert_eq!(iter.preceding(4), Some(2));
+    /// assert!(iter.is_boundary(4));
+    /// assert!(!iter.is_boundary(3));
+    /// ```
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        self.0.following(offset)
+    }
+
+    /// Returns the last grapheme cluster boundary before `offset`, or `None` if `offset` is 0.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        self.0.preceding(offset)
+    }
+
+    /// Returns whether there is a grapheme cluster boundary at `offset`.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        self.0.is_boundary(offset)
+    }
+}
+
 /// Grapheme cluster break iterator for an `str` (a UTF-8 string).
 ///
 /// For examples of use, see [`GraphemeClusterSegmenter`].
@@ -54,6 +103,12 @@ pub type GraphemeClusterBreakIteratorLatin1<'l, 's> =
 pub type GraphemeClusterBreakIteratorUtf16<'l, 's> =
     GraphemeClusterBreakIterator<'l, 's, RuleBreakTypeUtf16>;
 
+/// Grapheme cluster break iterator for a UTF-8 string split into chunks, such as a rope.
+///
+/// For examples of use, see [`GraphemeClusterSegmenter::segment_chunks()`].
+pub type GraphemeClusterBreakIteratorChunks<'l, 's, I> =
+    GraphemeClusterBreakIterator<'l, 's, RuleBreakTypeChunks<I>>;
+
 /// Segments a string into grapheme clusters.
 ///
 /// Supports loading grapheme cluster break data, and creating grapheme cluster break iterators for
@@ -129,6 +184,9 @@ pub type GraphemeClusterBreakIteratorUtf16<'l, 's> =
 #[derive(Debug)]
 pub struct GraphemeClusterSegmenter {
     payload: DataPayload<GraphemeClusterBreakDataV1Marker>,
+    /// Whether the boundaries after a hard line break do not depend on the text before it,
+    /// which holds for the Unicode rules, but not necessarily for custom rules.
+    resume_at_hard_breaks: bool,
 }
 
 #[cfg(feature = "compiled_data")]
@@ -150,6 +208,7 @@ impl GraphemeClusterSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_GRAPHEME_V1,
             ),
+            resume_at_hard_breaks: true,
         }
     }
 
@@ -169,7 +228,31 @@ impl GraphemeClusterSegmenter {
         D: DataProvider<GraphemeClusterBreakDataV1Marker> + ?Sized,
     {
         let payload = provider.load(Default::default())?.take_payload()?;
-        Ok(Self { payload })
+        Ok(Self {
+            payload,
+            resume_at_hard_breaks: true,
+        })
+    }
+
+    /// Constructs a [`GraphemeClusterSegmenter`] from rules in the ICU break rule syntax.
+    ///
+    /// See [`RuleBreakDataV1::try_from_rules()`] for the supported syntax.
+    ///
+    /// Custom rules may match across hard line breaks, so [`Self::resegment_chunks()`] segments
+    /// from the start of the string.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[cfg(all(feature = "compiled_data", feature = "experimental"))]
+    pub fn try_new_with_rules(rules: &str) -> Result<Self, SegmenterError> {
+        Ok(Self {
+            payload: DataPayload::from_owned(RuleBreakDataV1::try_from_rules(rules)?),
+            resume_at_hard_breaks: false,
+        })
     }
 
     /// Creates a grapheme cluster break iterator for an `str` (a UTF-8 string).
@@ -195,6 +278,8 @@ impl GraphemeClusterSegmenter {
             data: payload,
             complex: None,
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
 
@@ -215,6 +300,8 @@ impl GraphemeClusterSegmenter {
             data: self.payload.get(),
             complex: None,
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
     /// Creates a grapheme cluster break iterator for a Latin-1 (8-bit) string.
@@ -232,6 +319,8 @@ impl GraphemeClusterSegmenter {
             data: self.payload.get(),
             complex: None,
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
 
@@ -258,8 +347,100 @@ impl GraphemeClusterSegmenter {
             data: payload,
             complex: None,
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
+
+    /// Creates a grapheme cluster break iterator for a UTF-8 string split into chunks, such
+    /// as the leaves of a rope.
+    ///
+    /// The boundaries are offsets from the start of the first chunk, and chunk edges need not
+    /// be grapheme cluster boundaries.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::GraphemeClusterSegmenter;
+    ///
+    /// let segmenter = GraphemeClusterSegmenter::new();
+    /// // The flag of the United States is split across the chunks.
+    /// let chunks = ["Hi \u{1F1FA}", "\u{1F1F8}!"];
+    ///
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_chunks(chunks.into_iter()).collect();
+    /// assert_eq!(&breakpoints, &[0, 1, 2, 3, 11, 12]);
+    /// ```
+    pub fn segment_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> GraphemeClusterBreakIteratorChunks<'l, 's, I>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        let input = ChunkCharIndices::new(chunks);
+        GraphemeClusterBreakIterator(RuleBreakIterator {
+            iter: input.clone(),
+            len: ChunkCharIndices::input_len(input.clone()),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            complex: None,
+            boundary_property: 0,
+            complex_word_type: None,
+            input,
+        })
+    }
+
+    /// Updates the grapheme cluster boundaries of a UTF-8 string split into chunks after the
+    /// bytes in `replaced` were replaced by `new_len` bytes.
+    ///
+    /// `boundaries` are the boundaries before the edit, such as those returned by
+    /// [`Self::segment_chunks()`], and `chunks_at` returns the chunks of the edited string
+    /// starting at the given byte offset. Only the text between the last hard line break
+    /// before the edit and the first hard line break after it, such as line feeds, is
+    /// segmented again. This range of the edited string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::GraphemeClusterSegmenter;
+    ///
+    /// let segmenter = GraphemeClusterSegmenter::new();
+    /// let mut text = String::from("ab\ncd\nef");
+    /// let mut boundaries: Vec<usize> =
+    ///     segmenter.segment_chunks([text.as_str()].into_iter()).collect();
+    ///
+    /// // Add an acute accent to "c", which joins it into a single grapheme cluster.
+    /// text.insert(4, '\u{301}');
+    /// let resegmented = segmenter.resegment_chunks(&mut boundaries, 4..4, 2, |i| {
+    ///     [&text[i..]].into_iter()
+    /// });
+    ///
+    /// assert_eq!(resegmented, 3..8);
+    /// assert_eq!(&boundaries, &[0, 1, 2, 3, 6, 7, 8, 9, 10]);
+    /// ```
+    pub fn resegment_chunks<'s, I>(
+        &self,
+        boundaries: &mut Vec<usize>,
+        replaced: Range<usize>,
+        new_len: usize,
+        chunks_at: impl FnMut(usize) -> I,
+    ) -> Range<usize>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        resegment(
+            boundaries,
+            replaced,
+            new_len,
+            self.resume_at_hard_breaks,
+            chunks_at,
+            |chunks| self.segment_chunks(chunks),
+        )
+    }
 }
 
 #[test]
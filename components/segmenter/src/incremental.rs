@@ -0,0 +1,104 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Re-segmentation of chunked text after an edit.
+
+use crate::indices::{ChunkCharIndices, InputIndices};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Updates `boundaries`, the boundaries of a text before the code units in `replaced` were
+/// replaced by `new_len` code units, to the boundaries of the edited text.
+///
+/// `chunks_at` returns the chunks of the edited text starting at the given offset, and
+/// `segment` segments such chunks. Only the text between the last hard break before the edit
+/// and the first hard break after it is segmented again; this range is returned. If
+/// `resume_at_hard_breaks` is false, because the rules may look across hard breaks, the whole
+/// text is segmented again.
+pub(crate) fn resegment<'s, I, B>(
+    boundaries: &mut Vec<usize>,
+    replaced: Range<usize>,
+    new_len: usize,
+    resume_at_hard_breaks: bool,
+    mut chunks_at: impl FnMut(usize) -> I,
+    mut segment: impl FnMut(I) -> B,
+) -> Range<usize>
+where
+    I: Iterator<Item = &'s str> + Clone,
+    B: Iterator<Item = usize>,
+{
+    let old_end = replaced.end;
+    let new_end = replaced.start + new_len;
+
+    // Hard breaks are mandatory boundaries, so the safe point at the start is one of the old
+    // boundaries. The text before it is unchanged, and the rules do not look across it.
+    let prefix_len = boundaries.partition_point(|&b| b <= replaced.start);
+    let start = boundaries
+        .get(..prefix_len)
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .copied()
+        .filter(|_| resume_at_hard_breaks)
+        .find(|&b| {
+            let window_start = b.saturating_sub(3);
+            ChunkCharIndices::is_hard_break_before(
+                ChunkCharIndices::new(chunks_at(window_start)),
+                b - window_start,
+            )
+        })
+        .unwrap_or(0);
+
+    // The first hard break ending after the edit is followed by unchanged text, so the old
+    // boundaries from there on are still valid once shifted. If it is missing from the old
+    // boundaries, they were stale, and the rest of the text is segmented again.
+    let end = resume_at_hard_breaks
+        .then(|| first_hard_break(ChunkCharIndices::new(chunks_at(new_end))))
+        .flatten()
+        .map(|i| new_end + i);
+    let tail: Option<Vec<usize>> = end.and_then(|end| {
+        let index = boundaries.binary_search(&(end - new_end + old_end)).ok()?;
+        Some(
+            boundaries
+                .get(index..)
+                .unwrap_or_default()
+                .iter()
+                .map(|&b| b - old_end + new_end)
+                .collect(),
+        )
+    });
+
+    boundaries.truncate(boundaries.partition_point(|&b| b < start));
+    let resegmented = segment(chunks_at(start)).map(|b| start + b);
+    match (end, tail) {
+        (Some(end), Some(tail)) => {
+            boundaries.extend(resegmented.take_while(|&b| b < end));
+            boundaries.extend(tail);
+            start..end
+        }
+        _ => {
+            boundaries.extend(resegmented);
+            start..boundaries.last().copied().unwrap_or(start)
+        }
+    }
+}
+
+/// Returns the offset after the first hard break in the chunks, if any.
+fn first_hard_break<'s, I: Iterator<Item = &'s str>>(
+    chars: ChunkCharIndices<'s, I>,
+) -> Option<usize> {
+    let mut previous_cr = false;
+    let mut end = 0;
+    for (i, ch) in chars {
+        if previous_cr && ch != '\n' {
+            return Some(i);
+        }
+        end = i + ch.len_utf8();
+        if matches!(ch, '\n' | '\u{85}' | '\u{2028}' | '\u{2029}') {
+            return Some(end);
+        }
+        previous_cr = ch == '\r';
+    }
+    previous_cr.then_some(end)
+}
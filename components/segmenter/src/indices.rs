@@ -2,6 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::CharIndices;
+use utf8_iter::Utf8CharIndices;
+
 /// Similar to [`core::str::CharIndices`] for Latin-1 strings, represented as `[u8]`.
 ///
 /// Contrary to [`core::str::CharIndices`], the second element of the
@@ -82,6 +87,379 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
+/// Similar to [`core::str::CharIndices`] for a string split into chunks, such as the
+/// leaves of a rope.
+///
+/// The first element of the [`Iterator::Item`] is the offset from the start of the
+/// first chunk, rather than from the start of the current chunk.
+#[derive(Clone)]
+pub struct ChunkCharIndices<'s, I> {
+    front_offset: usize,
+    chunk: &'s str,
+    chunks: I,
+}
+
+impl<'s, I: Iterator<Item = &'s str>> ChunkCharIndices<'s, I> {
+    pub fn new(chunks: I) -> Self {
+        Self {
+            front_offset: 0,
+            chunk: "",
+            chunks,
+        }
+    }
+}
+
+impl<'s, I> fmt::Debug for ChunkCharIndices<'s, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkCharIndices")
+            .field("front_offset", &self.front_offset)
+            .field("chunk", &self.chunk)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'s, I: Iterator<Item = &'s str>> Iterator for ChunkCharIndices<'s, I> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        loop {
+            let mut chars = self.chunk.chars();
+            if let Some(ch) = chars.next() {
+                let index = self.front_offset;
+                self.front_offset += ch.len_utf8();
+                self.chunk = chars.as_str();
+                return Some((index, ch));
+            }
+            self.chunk = self.chunks.next()?;
+        }
+    }
+}
+
+/// A character iterator that can be created at any character boundary of its input.
+///
+/// This allows segmentation to resume at a safe point, such as after a line feed, instead of
+/// at the start of the input.
+pub trait InputIndices<'s>: Iterator + Sized {
+    /// The input being iterated, such as `&str`.
+    type Input: Clone + fmt::Debug;
+
+    /// Returns the length of the input in code units.
+    fn input_len(input: Self::Input) -> usize;
+
+    /// Returns the input starting at `offset`, which must be a character boundary.
+    fn input_from(input: Self::Input, offset: usize) -> Self::Input;
+
+    /// Creates an iterator over the input.
+    fn from_input(input: Self::Input) -> Self;
+
+    /// Returns the code point starting at `offset`, or `None` at the end of the input.
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32>;
+
+    /// Returns the offset and the code point of the character ending at `offset`, or `None`
+    /// if `offset` is 0 or not a character boundary.
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)>;
+
+    /// Returns whether the character ending at `offset` is a hard break.
+    ///
+    /// These are LF, CR not followed by LF, NEL, LS, and PS, after which all of the
+    /// Unicode line, grapheme cluster, word, and sentence break rules require a break.
+    fn is_hard_break_before(input: Self::Input, offset: usize) -> bool {
+        Self::char_before(input.clone(), offset).map_or(false, |(_, before)| {
+            is_hard_break(before, Self::char_at(input, offset).unwrap_or_default())
+        })
+    }
+
+    /// Returns the last character boundary in `1..=offset` that is followed by a character and
+    /// for which `is_safe_point` holds for the code points before and after it, or 0 if there
+    /// is none.
+    fn last_safe_point(
+        input: Self::Input,
+        mut offset: usize,
+        mut is_safe_point: impl FnMut(u32, u32) -> bool,
+    ) -> usize {
+        let mut after = Self::char_at(input.clone(), offset);
+        while let Some((start, before)) = Self::char_before(input.clone(), offset) {
+            if after.map_or(false, |after| is_safe_point(before, after)) {
+                return offset;
+            }
+            after = Some(before);
+            offset = start;
+        }
+        0
+    }
+}
+
+/// Returns whether there is a hard break between the code points `before` and `after`, see
+/// [`InputIndices::is_hard_break_before`].
+pub(crate) fn is_hard_break(before: u32, after: u32) -> bool {
+    matches!(before, 0x0A | 0x85 | 0x2028 | 0x2029) || (before == 0x0D && after != 0x0A)
+}
+
+/// A break iterator that can be restarted at a safe point of its input, which gives random
+/// access to its boundaries.
+///
+/// A safe point is a boundary after which the boundaries do not depend on the text before it.
+/// Safe points are found by scanning backwards from an offset, so random access is linear in
+/// the distance to the previous safe point.
+pub(crate) trait ResumableBreakIterator<'s>: Iterator<Item = usize> + Sized {
+    /// The character iterator over the input.
+    type Indices: InputIndices<'s>;
+
+    /// Returns the whole input of this iterator.
+    fn input(&self) -> <Self::Indices as InputIndices<'s>>::Input;
+
+    /// Creates an iterator with the same data over the start of `input`.
+    fn with_input(&self, input: <Self::Indices as InputIndices<'s>>::Input) -> Self;
+
+    /// Returns whether the boundary between the code points `before` and `after` is a safe point
+    /// wherever they occur.
+    ///
+    /// By default, these are the hard breaks, which are mandatory in the Unicode rules.
+    fn is_safe_point(&self, before: u32, after: u32) -> bool {
+        is_hard_break(before, after)
+    }
+
+    /// Returns the offset of the last safe point at or before `offset`, or 0, and an iterator
+    /// over the boundaries from there, relative to that offset.
+    fn resume_at_safe_point(&self, offset: usize) -> (usize, Self) {
+        let offset = offset.min(Self::Indices::input_len(self.input()));
+        let start = Self::Indices::last_safe_point(self.input(), offset, |before, after| {
+            self.is_safe_point(before, after)
+        });
+        let iter = self.with_input(Self::Indices::input_from(self.input(), start));
+        (start, iter)
+    }
+
+    /// Returns the first boundary after `offset`, or `None` if `offset` is at or after the
+    /// end of the input.
+    fn following(&self, offset: usize) -> Option<usize> {
+        let (start, mut iter) = self.resume_at_safe_point(offset);
+        iter.find(|&i| start + i > offset).map(|i| start + i)
+    }
+
+    /// Returns the last boundary before `offset`, or `None` if `offset` is 0.
+    fn preceding(&self, offset: usize) -> Option<usize> {
+        let (start, iter) = self.resume_at_safe_point(offset.checked_sub(1)?);
+        // A safe point is a boundary even if the rules do not break at the start of the input.
+        core::iter::once(start)
+            .filter(|&start| start != 0)
+            .chain(iter.map(|i| start + i))
+            .take_while(|&i| i < offset)
+            .last()
+    }
+
+    /// Returns whether there is a boundary at `offset`.
+    fn is_boundary(&self, offset: usize) -> bool {
+        let (start, mut iter) = self.resume_at_safe_point(offset);
+        (start == offset && start != 0)
+            || iter.find(|&i| start + i >= offset) == Some(offset - start)
+    }
+}
+
+impl<'s> InputIndices<'s> for CharIndices<'s> {
+    type Input = &'s str;
+
+    fn input_len(input: Self::Input) -> usize {
+        input.len()
+    }
+
+    fn input_from(input: Self::Input, offset: usize) -> Self::Input {
+        input.get(offset..).unwrap_or_default()
+    }
+
+    fn from_input(input: Self::Input) -> Self {
+        input.char_indices()
+    }
+
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32> {
+        input.get(offset..)?.chars().next().map(u32::from)
+    }
+
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)> {
+        let ch = input.get(..offset)?.chars().next_back()?;
+        Some((offset - ch.len_utf8(), ch.into()))
+    }
+}
+
+impl<'s> InputIndices<'s> for Utf8CharIndices<'s> {
+    type Input = &'s [u8];
+
+    fn input_len(input: Self::Input) -> usize {
+        input.len()
+    }
+
+    fn input_from(input: Self::Input, offset: usize) -> Self::Input {
+        input.get(offset..).unwrap_or_default()
+    }
+
+    fn from_input(input: Self::Input) -> Self {
+        Self::new(input)
+    }
+
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32> {
+        Self::new(input.get(offset..)?)
+            .next()
+            .map(|(_, ch)| ch.into())
+    }
+
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)> {
+        // Ill-formed sequences are decoded the same way backwards as forwards, but a sequence
+        // cut off at `offset` is not a character boundary if it continues after it.
+        let (i, ch) = Self::new(input.get(..offset)?).next_back()?;
+        let mut following = Self::new(input.get(i..)?);
+        following.next();
+        (input.len() - following.as_slice().len() == offset).then_some((i, ch.into()))
+    }
+}
+
+impl<'s> InputIndices<'s> for Latin1Indices<'s> {
+    type Input = &'s [u8];
+
+    fn input_len(input: Self::Input) -> usize {
+        input.len()
+    }
+
+    fn input_from(input: Self::Input, offset: usize) -> Self::Input {
+        input.get(offset..).unwrap_or_default()
+    }
+
+    fn from_input(input: Self::Input) -> Self {
+        Self::new(input)
+    }
+
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32> {
+        input.get(offset).copied().map(u32::from)
+    }
+
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)> {
+        let i = offset.checked_sub(1)?;
+        Some((i, (*input.get(i)?).into()))
+    }
+}
+
+impl<'s> InputIndices<'s> for Utf16Indices<'s> {
+    type Input = &'s [u16];
+
+    fn input_len(input: Self::Input) -> usize {
+        input.len()
+    }
+
+    fn input_from(input: Self::Input, offset: usize) -> Self::Input {
+        input.get(offset..).unwrap_or_default()
+    }
+
+    fn from_input(input: Self::Input) -> Self {
+        Self::new(input)
+    }
+
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32> {
+        Self::new(input.get(offset..)?).next().map(|(_, ch)| ch)
+    }
+
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)> {
+        let i = offset.checked_sub(1)?;
+        let ch = *input.get(i)? as u32;
+        if (ch & 0xfc00) == 0xd800
+            && input
+                .get(offset)
+                .map_or(false, |&next| (next & 0xfc00) == 0xdc00)
+        {
+            // `offset` is within a surrogate pair.
+            return None;
+        }
+        // Only a low surrogate preceded by a high surrogate forms a pair, as when iterating
+        // forwards.
+        if (ch & 0xfc00) == 0xdc00 {
+            if let Some(high) = i.checked_sub(1).and_then(|i| input.get(i)) {
+                let high = *high as u32;
+                if (high & 0xfc00) == 0xd800 {
+                    return Some((i - 1, ((high & 0x3ff) << 10) + (ch & 0x3ff) + 0x10000));
+                }
+            }
+        }
+        Some((i, ch))
+    }
+}
+
+impl<'s, I: Iterator<Item = &'s str> + Clone> InputIndices<'s> for ChunkCharIndices<'s, I> {
+    type Input = Self;
+
+    fn input_len(input: Self::Input) -> usize {
+        input.front_offset + input.chunk.len() + input.chunks.map(str::len).sum::<usize>()
+    }
+
+    fn input_from(mut input: Self::Input, offset: usize) -> Self::Input {
+        while input.front_offset + input.chunk.len() < offset {
+            input.front_offset += input.chunk.len();
+            let Some(chunk) = input.chunks.next() else {
+                input.chunk = "";
+                break;
+            };
+            input.chunk = chunk;
+        }
+        input.chunk = input
+            .chunk
+            .get(offset.saturating_sub(input.front_offset)..)
+            .unwrap_or_default();
+        input.front_offset = 0;
+        input
+    }
+
+    fn from_input(input: Self::Input) -> Self {
+        input
+    }
+
+    fn char_at(input: Self::Input, offset: usize) -> Option<u32> {
+        Self::input_from(input, offset)
+            .next()
+            .map(|(_, ch)| ch.into())
+    }
+
+    fn char_before(input: Self::Input, offset: usize) -> Option<(usize, u32)> {
+        let mut start = input.front_offset;
+        for chunk in core::iter::once(input.chunk).chain(input.chunks) {
+            if start + chunk.len() >= offset && start < offset {
+                let ch = chunk.get(..offset - start)?.chars().next_back()?;
+                return Some((offset - ch.len_utf8(), ch.into()));
+            }
+            start += chunk.len();
+        }
+        None
+    }
+
+    fn last_safe_point(
+        input: Self::Input,
+        offset: usize,
+        mut is_safe_point: impl FnMut(u32, u32) -> bool,
+    ) -> usize {
+        let mut after = Self::char_at(input.clone(), offset);
+        // Chunks can only be read forwards, so collect the chunks before `offset`, and scan
+        // them backwards.
+        let mut chunks = Vec::new();
+        let mut start = input.front_offset;
+        for chunk in core::iter::once(input.chunk).chain(input.chunks) {
+            if start >= offset {
+                break;
+            }
+            chunks.push((start, chunk));
+            start += chunk.len();
+        }
+        for (start, chunk) in chunks.into_iter().rev() {
+            let Some(prefix) = chunk.get(..(offset - start).min(chunk.len())) else {
+                return 0;
+            };
+            for (i, ch) in prefix.char_indices().rev() {
+                if after.map_or(false, |after| is_safe_point(ch.into(), after)) {
+                    return start + i + ch.len_utf8();
+                }
+                after = Some(ch.into());
+            }
+        }
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::indices::*;
@@ -126,4 +504,77 @@ mod tests {
         let n = indices.next();
         assert_eq!(n, None);
     }
+
+    #[test]
+    fn last_safe_point() {
+        let text = "ab\r\ncd\ref";
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        for offset in 0..=text.len() {
+            let expected = match offset {
+                0..=3 => 0,
+                4..=6 => 4,
+                _ => 7,
+            };
+            let chunks = ChunkCharIndices::new(["ab\r", "", "\ncd", "\r", "ef"].into_iter());
+            assert_eq!(
+                ChunkCharIndices::last_safe_point(chunks, offset, is_hard_break),
+                expected
+            );
+            assert_eq!(
+                <CharIndices as InputIndices>::last_safe_point(text, offset, is_hard_break),
+                expected
+            );
+            assert_eq!(
+                Utf16Indices::last_safe_point(&utf16, offset, is_hard_break),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn char_before_within_character() {
+        let utf16 = [0x0061, 0xd83d, 0xde03, 0x0062];
+        assert_eq!(Utf16Indices::char_before(&utf16, 3), Some((1, 0x1f603)));
+        assert_eq!(Utf16Indices::char_before(&utf16, 2), None);
+
+        // An ill-formed sequence that is cut off by `offset`.
+        let utf8 = [0x61, 0xe2, 0x80, 0xa8, 0xe2, 0x80, 0x62];
+        assert_eq!(Utf8CharIndices::char_before(&utf8, 4), Some((1, 0x2028)));
+        assert_eq!(Utf8CharIndices::char_before(&utf8, 3), None);
+        assert_eq!(Utf8CharIndices::char_before(&utf8, 6), Some((4, 0xfffd)));
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn resumes_near_offset() {
+        use crate::provider::{Baked, RuleBreakDataV1};
+        use crate::rule_segmenter::{RuleBreakIterator, RuleBreakTypeUtf8};
+
+        fn segment<'l, 's>(
+            data: &'l RuleBreakDataV1<'l>,
+            input: &'s str,
+        ) -> RuleBreakIterator<'l, 's, RuleBreakTypeUtf8> {
+            RuleBreakIterator {
+                iter: input.char_indices(),
+                len: input.len(),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data,
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            }
+        }
+
+        let text = "Lorem ipsum dolor sit amet. ".repeat(100);
+        let offset = text.len() / 2 + 2;
+
+        let iter = segment(Baked::SINGLETON_SEGMENTER_GRAPHEME_V1, &text);
+        assert_eq!(iter.resume_at_safe_point(offset).0, offset);
+
+        let iter = segment(Baked::SINGLETON_SEGMENTER_WORD_V1, &text);
+        let start = iter.resume_at_safe_point(offset).0;
+        assert!(offset - start < 6, "{start}");
+    }
 }
@@ -119,9 +119,14 @@ extern crate alloc;
 
 mod complex;
 mod error;
+mod incremental;
 mod indices;
 mod iterator_helpers;
 mod rule_segmenter;
+#[cfg(all(feature = "compiled_data", feature = "experimental"))]
+mod rules;
+#[cfg(feature = "experimental")]
+mod user_dictionary;
 
 mod grapheme;
 mod line;
@@ -144,21 +149,27 @@ pub use crate::word::WordSegmenter;
 pub use crate::line::LineBreakOptions;
 pub use crate::line::LineBreakStrictness;
 pub use crate::line::LineBreakWordOption;
+pub use crate::sentence::SentenceBreakOptions;
+pub use crate::sentence::SentenceBreakSuppressions;
 pub use crate::word::WordType;
 
 // Typedefs
+pub use crate::grapheme::GraphemeClusterBreakIteratorChunks;
 pub use crate::grapheme::GraphemeClusterBreakIteratorLatin1;
 pub use crate::grapheme::GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::grapheme::GraphemeClusterBreakIteratorUtf16;
 pub use crate::grapheme::GraphemeClusterBreakIteratorUtf8;
+pub use crate::line::LineBreakIteratorChunks;
 pub use crate::line::LineBreakIteratorLatin1;
 pub use crate::line::LineBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::line::LineBreakIteratorUtf16;
 pub use crate::line::LineBreakIteratorUtf8;
+pub use crate::sentence::SentenceBreakIteratorChunks;
 pub use crate::sentence::SentenceBreakIteratorLatin1;
 pub use crate::sentence::SentenceBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::sentence::SentenceBreakIteratorUtf16;
 pub use crate::sentence::SentenceBreakIteratorUtf8;
+pub use crate::word::WordBreakIteratorChunks;
 pub use crate::word::WordBreakIteratorLatin1;
 pub use crate::word::WordBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::word::WordBreakIteratorUtf16;
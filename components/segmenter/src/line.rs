@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::*;
+use crate::incremental::resegment;
 use crate::indices::*;
 use crate::provider::*;
 use crate::SegmenterError;
@@ -10,6 +11,8 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::char;
+use core::marker::PhantomData;
+use core::ops::Range;
 use core::str::CharIndices;
 use icu_provider::prelude::*;
 use utf8_iter::Utf8CharIndices;
@@ -218,6 +221,11 @@ pub type LineBreakIteratorLatin1<'l, 's> = LineBreakIterator<'l, 's, LineBreakTy
 /// For examples of use, see [`LineSegmenter`].
 pub type LineBreakIteratorUtf16<'l, 's> = LineBreakIterator<'l, 's, LineBreakTypeUtf16>;
 
+/// Line break iterator for a UTF-8 string split into chunks, such as a rope.
+///
+/// For examples of use, see [`LineSegmenter::segment_chunks()`].
+pub type LineBreakIteratorChunks<'l, 's, I> = LineBreakIterator<'l, 's, LineBreakTypeChunks<I>>;
+
 /// Supports loading line break data, and creating line break iterators for different string
 /// encodings.
 ///
@@ -642,6 +650,58 @@ impl LineSegmenter {
         })
     }
 
+    /// Constructs a [`LineSegmenter`] with an invariant locale, custom [`LineBreakOptions`], and
+    /// compiled dictionary data for complex scripts (Khmer, Lao, Myanmar, and Thai), with a user
+    /// dictionary layered over the built-in dictionaries, for words such as product names and
+    /// slang.
+    ///
+    /// See [`UCharDictionaryBreakDataV1::from_words()`] for building a user dictionary, and how
+    /// its words are weighed against the built-in ones.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    #[cfg(feature = "compiled_data")]
+    pub fn new_dictionary_with_user_dictionary(
+        options: LineBreakOptions,
+        user_dictionary: UCharDictionaryBreakDataV1<'static>,
+    ) -> Self {
+        Self {
+            options,
+            payload: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_SEGMENTER_LINE_V1,
+            ),
+            complex: ComplexPayloads::new_southeast_asian().with_user_dictionary(user_dictionary),
+        }
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new_dictionary_with_user_dictionary)]
+    #[cfg(feature = "experimental")]
+    pub fn try_new_dictionary_with_user_dictionary_unstable<D>(
+        provider: &D,
+        options: LineBreakOptions,
+        user_dictionary: UCharDictionaryBreakDataV1<'static>,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker>
+            + DataProvider<DictionaryForWordLineExtendedV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            options,
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_southeast_asian(provider)?
+                .with_user_dictionary(user_dictionary),
+        })
+    }
+
     /// Creates a line break iterator for an `str` (a UTF-8 string).
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
@@ -654,6 +714,7 @@ impl LineSegmenter {
             data: self.payload.get(),
             options: &self.options,
             complex: &self.complex,
+            input,
         }
     }
     /// Creates a line break iterator for a potentially ill-formed UTF8 string
@@ -673,6 +734,7 @@ impl LineSegmenter {
             data: self.payload.get(),
             options: &self.options,
             complex: &self.complex,
+            input,
         }
     }
     /// Creates a line break iterator for a Latin-1 (8-bit) string.
@@ -687,6 +749,7 @@ impl LineSegmenter {
             data: self.payload.get(),
             options: &self.options,
             complex: &self.complex,
+            input,
         }
     }
 
@@ -702,8 +765,90 @@ impl LineSegmenter {
             data: self.payload.get(),
             options: &self.options,
             complex: &self.complex,
+            input,
+        }
+    }
+
+    /// Creates a line break iterator for a UTF-8 string split into chunks, such as the leaves
+    /// of a rope.
+    ///
+    /// The break opportunities are offsets from the start of the first chunk, and chunk edges
+    /// need not be break opportunities. Runs of text that need a dictionary or LSTM model may
+    /// span several chunks.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::LineSegmenter;
+    ///
+    /// let segmenter = LineSegmenter::new_auto();
+    /// let chunks = ["Hello Wo", "rld.\nXin ch", "ào!"];
+    ///
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_chunks(chunks.into_iter()).collect();
+    /// assert_eq!(&breakpoints, &[0, 6, 13, 17, 23]);
+    /// ```
+    pub fn segment_chunks<'l, 's, I>(&'l self, chunks: I) -> LineBreakIteratorChunks<'l, 's, I>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        let input = ChunkCharIndices::new(chunks);
+        LineBreakIterator {
+            iter: input.clone(),
+            len: ChunkCharIndices::input_len(input.clone()),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            options: &self.options,
+            complex: &self.complex,
+            input,
         }
     }
+
+    /// Updates the line break opportunities of a UTF-8 string split into chunks after the
+    /// bytes in `replaced` were replaced by `new_len` bytes.
+    ///
+    /// `boundaries` are the break opportunities before the edit, such as those returned by
+    /// [`Self::segment_chunks()`], and `chunks_at` returns the chunks of the edited string
+    /// starting at the given byte offset. Only the text between the last hard line break
+    /// before the edit and the first hard line break after it is segmented again. This range
+    /// of the edited string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::LineSegmenter;
+    ///
+    /// let segmenter = LineSegmenter::new_auto();
+    /// let mut text = String::from("The quick\nbrown fox\njumps");
+    /// let mut boundaries: Vec<usize> =
+    ///     segmenter.segment_chunks([text.as_str()].into_iter()).collect();
+    /// assert_eq!(&boundaries, &[0, 4, 10, 16, 20, 25]);
+    ///
+    /// text.insert_str(19, "-like");
+    /// let resegmented = segmenter.resegment_chunks(&mut boundaries, 19..19, 5, |i| {
+    ///     [&text[i..]].into_iter()
+    /// });
+    ///
+    /// assert_eq!(resegmented, 10..25);
+    /// assert_eq!(&boundaries, &[0, 4, 10, 16, 20, 25, 30]);
+    /// ```
+    pub fn resegment_chunks<'s, I>(
+        &self,
+        boundaries: &mut Vec<usize>,
+        replaced: Range<usize>,
+        new_len: usize,
+        chunks_at: impl FnMut(usize) -> I,
+    ) -> Range<usize>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        resegment(boundaries, replaced, new_len, true, chunks_at, |chunks| {
+            self.segment_chunks(chunks)
+        })
+    }
 }
 
 impl RuleBreakDataV1<'_> {
@@ -817,7 +962,7 @@ fn is_break_utf32_by_loose(
 /// This is implemented by ICU4X for several common string types.
 pub trait LineBreakType<'l, 's> {
     /// The iterator over characters.
-    type IterAttr: Iterator<Item = (usize, Self::CharType)> + Clone;
+    type IterAttr: Iterator<Item = (usize, Self::CharType)> + InputIndices<'s> + Clone;
 
     /// The character type.
     type CharType: Copy + Into<u32>;
@@ -858,6 +1003,7 @@ pub struct LineBreakIterator<'l, 's, Y: LineBreakType<'l, 's> + ?Sized> {
     data: &'l RuleBreakDataV1<'l>,
     options: &'l LineBreakOptions,
     complex: &'l ComplexPayloads,
+    input: <Y::IterAttr as InputIndices<'s>>::Input,
 }
 
 impl<'l, 's, Y: LineBreakType<'l, 's>> Iterator for LineBreakIterator<'l, 's, Y> {
@@ -1006,6 +1152,73 @@ impl<'l, 's, Y: LineBreakType<'l, 's>> Iterator for LineBreakIterator<'l, 's, Y>
     }
 }
 
+impl<'l, 's, Y: LineBreakType<'l, 's>> LineBreakIterator<'l, 's, Y> {
+    /// Returns the first line break opportunity after `offset`, or `None` if `offset` is at
+    /// or after the end of the string.
+    ///
+    /// This does not change the position of the iterator. Segmentation resumes at the last
+    /// hard line break before `offset`, such as a line feed, instead of at the start of the
+    /// string. Its cost is therefore linear in the length of the paragraph containing `offset`,
+    /// which is the whole string if it has no line breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::LineSegmenter;
+    ///
+    /// let segmenter = LineSegmenter::new_auto();
+    /// let text = "Hello World.\nXin chào thế giới!";
+    /// let iter = segmenter.segment_str(text);
+    ///
+    /// assert_eq!(iter.following(0), Some(6));
+    /// assert_eq!(iter.following(6), Some(13));
+    /// assert_eq!(iter.preceding(16), Some(13));
+    /// assert!(iter.is_boundary(17));
+    /// assert!(!iter.is_boundary(18));
+    /// assert_eq!(iter.following(text.len()), None);
+    /// ```
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        ResumableBreakIterator::following(self, offset)
+    }
+
+    /// Returns the last line break opportunity before `offset`, or `None` if `offset` is 0.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        ResumableBreakIterator::preceding(self, offset)
+    }
+
+    /// Returns whether there is a line break opportunity at `offset`.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        ResumableBreakIterator::is_boundary(self, offset)
+    }
+}
+
+impl<'l, 's, Y: LineBreakType<'l, 's>> ResumableBreakIterator<'s> for LineBreakIterator<'l, 's, Y> {
+    type Indices = Y::IterAttr;
+
+    fn input(&self) -> <Y::IterAttr as InputIndices<'s>>::Input {
+        self.input.clone()
+    }
+
+    fn with_input(&self, input: <Y::IterAttr as InputIndices<'s>>::Input) -> Self {
+        Self {
+            iter: Y::IterAttr::from_input(input.clone()),
+            len: Y::IterAttr::input_len(input.clone()),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.data,
+            options: self.options,
+            complex: self.complex,
+            input,
+        }
+    }
+}
+
 enum StringBoundaryPosType {
     Start,
     Middle,
@@ -1185,6 +1398,38 @@ where
     }
 }
 
+#[derive(Debug)]
+pub struct LineBreakTypeChunks<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> LineBreakType<'l, 's> for LineBreakTypeChunks<I> {
+    type IterAttr = ChunkCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> u8 {
+        iterator.data.get_linebreak_property_utf32_with_rule(
+            c as u32,
+            iterator.options.strictness,
+            iterator.options.word_option,
+        )
+    }
+
+    #[inline]
+    fn use_complex_breaking(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> bool {
+        iterator.data.use_complex_breaking_utf32(c as u32)
+    }
+
+    fn get_current_position_character_len(iterator: &LineBreakIterator<'l, 's, Self>) -> usize {
+        iterator.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        iter: &mut LineBreakIterator<'l, 's, Self>,
+        left_codepoint: char,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
+    }
+}
+
 #[derive(Debug)]
 pub struct LineBreakTypeLatin1;
 
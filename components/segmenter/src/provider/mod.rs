@@ -21,6 +21,7 @@ pub use lstm::*;
 use crate::WordType;
 use icu_collections::codepointtrie::CodePointTrie;
 use icu_provider::prelude::*;
+use zerotrie::ZeroTriePerfectHash;
 use zerovec::ZeroVec;
 
 #[cfg(feature = "compiled_data")]
@@ -39,6 +40,7 @@ const _: () = {
     pub mod icu {
         pub use crate as segmenter;
         pub use icu_collections as collections;
+        pub use icu_locid_transform as locid_transform;
     }
     icu_segmenter_data::make_provider!(Baked);
     icu_segmenter_data::impl_segmenter_dictionary_w_auto_v1!(Baked);
@@ -47,6 +49,7 @@ const _: () = {
     icu_segmenter_data::impl_segmenter_line_v1!(Baked);
     #[cfg(feature = "lstm")]
     icu_segmenter_data::impl_segmenter_lstm_wl_auto_v1!(Baked);
+    icu_segmenter_data::impl_segmenter_sentence_suppressions_v1!(Baked);
     icu_segmenter_data::impl_segmenter_sentence_v1!(Baked);
     icu_segmenter_data::impl_segmenter_word_v1!(Baked);
 };
@@ -60,6 +63,7 @@ pub const KEYS: &[DataKey] = &[
     LineBreakDataV1Marker::KEY,
     LstmForWordLineAutoV1Marker::KEY,
     SentenceBreakDataV1Marker::KEY,
+    SentenceBreakSuppressionsV1Marker::KEY,
     WordBreakDataV1Marker::KEY,
 ];
 
@@ -111,9 +115,40 @@ pub struct RuleBreakDataV1<'data> {
 
     /// The index of "SA" state (or 127 if the complex language isn't handled) for
     /// [`Self::break_state_table`].
+    ///
+    /// In word break data, the simple states after it up to [`Self::last_codepoint_property`],
+    /// such as Hiragana and Han, are also handled by the complex language segmenter, and
+    /// [`Self::word_type_table`] gives the word types of their characters.
     pub complex_property: u8,
 }
 
+/// Abbreviations after which a sentence break is suppressed, such as "Mr." in English.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    SentenceBreakSuppressionsV1Marker = "segmenter/sentence/suppressions@1"
+)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct SentenceBreakSuppressionsV1<'data> {
+    /// The abbreviations, including their final full stop, with their characters in
+    /// reverse order, such as ".rM" for "Mr.". The values are unused.
+    ///
+    /// The keys are reversed so that they can be matched by walking backwards from a
+    /// rule-based sentence boundary.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub suppressions: ZeroTriePerfectHash<ZeroVec<'data, u8>>,
+}
+
 /// char16trie data for dictionary break
 ///
 /// <div class="stab unstable">
@@ -258,6 +293,8 @@ impl<'de> serde::Deserialize<'de> for WordType {
                 Ok(0) => Ok(WordType::None),
                 Ok(1) => Ok(WordType::Number),
                 Ok(2) => Ok(WordType::Letter),
+                Ok(3) => Ok(WordType::Kana),
+                Ok(4) => Ok(WordType::Ideo),
                 Ok(_) => Err(D::Error::custom("invalid value")),
                 Err(e) => Err(e),
             }
@@ -3,9 +3,12 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::ComplexPayloads;
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{
+    ChunkCharIndices, InputIndices, Latin1Indices, ResumableBreakIterator, Utf16Indices,
+};
 use crate::provider::*;
 use crate::WordType;
+use core::marker::PhantomData;
 use core::str::CharIndices;
 use utf8_iter::Utf8CharIndices;
 
@@ -13,7 +16,10 @@ use utf8_iter::Utf8CharIndices;
 /// encoding methods and granularity such as grapheme cluster, word, etc.
 pub trait RuleBreakType<'l, 's> {
     /// The iterator over characters.
-    type IterAttr: Iterator<Item = (usize, Self::CharType)> + Clone + core::fmt::Debug;
+    type IterAttr: Iterator<Item = (usize, Self::CharType)>
+        + InputIndices<'s>
+        + Clone
+        + core::fmt::Debug;
 
     /// The character type.
     type CharType: Copy + Into<u32> + core::fmt::Debug;
@@ -45,6 +51,10 @@ pub struct RuleBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
     pub(crate) data: &'l RuleBreakDataV1<'l>,
     pub(crate) complex: Option<&'l ComplexPayloads>,
     pub(crate) boundary_property: u8,
+    /// The word type of the segment preceding the current boundary if it was
+    /// segmented by a dictionary or LSTM model
+    pub(crate) complex_word_type: Option<WordType>,
+    pub(crate) input: <Y::IterAttr as InputIndices<'s>>::Input,
 }
 
 impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for RuleBreakIterator<'l, 's, Y> {
@@ -54,19 +64,26 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for RuleBreakIterator<'
         // If we have break point cache by previous run, return this result
         if let Some(&first_result) = self.result_cache.first() {
             let mut i = 0;
+            let mut word_type = WordType::Letter;
             loop {
                 if i == first_result {
                     self.result_cache = self.result_cache.iter().skip(1).map(|r| r - i).collect();
+                    self.complex_word_type = Some(word_type);
                     return self.get_current_position();
                 }
                 i += Y::get_current_position_character_len(self);
+                if let Some(c) = self.get_current_codepoint() {
+                    word_type = self.complex_word_type_with(word_type, c);
+                }
                 self.advance_iter();
                 if self.is_eof() {
                     self.result_cache.clear();
+                    self.complex_word_type = Some(word_type);
                     return Some(self.len);
                 }
             }
         }
+        self.complex_word_type = None;
 
         if self.is_eof() {
             self.advance_iter();
@@ -101,8 +118,8 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for RuleBreakIterator<'
 
             // Some segmenter rules doesn't have language-specific rules, we have to use LSTM (or dictionary) segmenter.
             // If property is marked as SA, use it
-            if right_prop == self.data.complex_property {
-                if left_prop != self.data.complex_property {
+            if self.is_complex_property(right_prop) {
+                if !self.is_complex_property(left_prop) {
                     // break before SA
                     self.boundary_property = left_prop;
                     return self.get_current_position();
@@ -154,13 +171,19 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for RuleBreakIterator<'
                                 self.current_pos_data = previous_pos_data;
                                 return self.get_current_position();
                             }
-                            BreakState::Break => return self.get_current_position(),
+                            BreakState::Break => {
+                                self.boundary_property = index;
+                                return self.get_current_position();
+                            }
                             BreakState::Intermediate(i) => {
-                                index = i;
                                 if previous_break_state_is_cp_prop {
                                     // Move marker
+                                    previous_left_prop = i;
+                                } else {
+                                    // The marker is placed after the state that is left
                                     previous_left_prop = index;
                                 }
+                                index = i;
                                 previous_iter = self.iter.clone();
                                 previous_pos_data = self.current_pos_data;
                             }
@@ -181,6 +204,63 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for RuleBreakIterator<'
     }
 }
 
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> ResumableBreakIterator<'s>
+    for RuleBreakIterator<'l, 's, Y>
+{
+    type Indices = Y::IterAttr;
+
+    fn input(&self) -> <Y::IterAttr as InputIndices<'s>>::Input {
+        self.input.clone()
+    }
+
+    fn with_input(&self, input: <Y::IterAttr as InputIndices<'s>>::Input) -> Self {
+        Self {
+            iter: Y::IterAttr::from_input(input.clone()),
+            len: Y::IterAttr::input_len(input.clone()),
+            current_pos_data: None,
+            result_cache: alloc::vec::Vec::new(),
+            data: self.data,
+            complex: self.complex,
+            boundary_property: 0,
+            complex_word_type: None,
+            input,
+        }
+    }
+
+    /// Returns whether every state in which `before` can be consumed breaks before `after`.
+    ///
+    /// After a boundary, the state machine restarts from the character following it, so the
+    /// boundaries after a position that always breaks do not depend on the text before it.
+    /// Characters segmented by a dictionary or LSTM model are never safe points, as their
+    /// boundaries depend on the whole run.
+    fn is_safe_point(&self, before: u32, after: u32) -> bool {
+        let before = self.data.property_table.get32(before);
+        let after = self.data.property_table.get32(after);
+        if self.is_complex_property(before) || self.is_complex_property(after) {
+            return false;
+        }
+        let breaks_before_after = |state| {
+            matches!(
+                self.get_break_state_from_table(state, after),
+                BreakState::Break | BreakState::NoMatch
+            )
+        };
+        let state_count =
+            self.data.break_state_table.len() / self.data.property_count.max(1) as usize;
+        // The state after consuming `before` is its property if the machine restarts or keeps
+        // going there, or a state reached from some other state by consuming it.
+        breaks_before_after(before)
+            && (0..state_count).all(|state| {
+                match self.get_break_state_from_table(state as u8, before) {
+                    BreakState::Index(next) | BreakState::Intermediate(next) => {
+                        breaks_before_after(next)
+                    }
+                    BreakState::Keep | BreakState::Break | BreakState::NoMatch => true,
+                }
+            })
+    }
+}
+
 impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> RuleBreakIterator<'l, 's, Y> {
     pub(crate) fn advance_iter(&mut self) {
         self.current_pos_data = self.iter.next();
@@ -208,6 +288,37 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> RuleBreakIterator<'l, 's, Y> {
         self.data.property_table.get32(codepoint.into())
     }
 
+    /// Returns whether characters with this property are segmented by a dictionary or LSTM model
+    pub(crate) fn is_complex_property(&self, property: u8) -> bool {
+        (self.data.complex_property..=self.data.last_codepoint_property).contains(&property)
+    }
+
+    /// Returns the [`WordType`] of a word segmented by a dictionary or LSTM model after
+    /// appending `codepoint` to a word of type `word_type`.
+    ///
+    /// Words containing characters with an ideographic word type are [`WordType::Ideo`], other
+    /// words containing characters with a kana word type are [`WordType::Kana`], and all other
+    /// words, such as Thai words, are [`WordType::Letter`].
+    pub(crate) fn complex_word_type_with(
+        &self,
+        word_type: WordType,
+        codepoint: Y::CharType,
+    ) -> WordType {
+        let codepoint_type = match self.get_break_property(codepoint) {
+            0 => WordType::None,
+            property => self
+                .data
+                .word_type_table
+                .get((property - 1) as usize)
+                .unwrap_or(WordType::None),
+        };
+        match (word_type, codepoint_type) {
+            (WordType::Ideo, _) | (_, WordType::Ideo) => WordType::Ideo,
+            (WordType::Kana, _) | (_, WordType::Kana) => WordType::Kana,
+            _ => WordType::Letter,
+        }
+    }
+
     fn get_break_state_from_table(&self, left: u8, right: u8) -> BreakState {
         let idx = left as usize * self.data.property_count as usize + right as usize;
         // We use unwrap_or to fall back to the base case and prevent panics on bad data.
@@ -220,9 +331,9 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> RuleBreakIterator<'l, 's, Y> {
     /// Return the status value of break boundary.
     /// If segmenter isn't word, always return WordType::None
     pub fn word_type(&self) -> WordType {
-        if self.result_cache.first().is_some() {
-            // Dictionary type (CJ and East Asian) is letter.
-            return WordType::Letter;
+        if let Some(word_type) = self.complex_word_type {
+            // Dictionary or LSTM segmented (CJ and Southeast Asian)
+            return word_type;
         }
         if self.boundary_property == 0 {
             // break position is SOT / Any
@@ -320,3 +431,22 @@ impl<'l, 's> RuleBreakType<'l, 's> for RuleBreakTypeUtf16 {
         unreachable!()
     }
 }
+
+#[derive(Debug)]
+pub struct RuleBreakTypeChunks<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> RuleBreakType<'l, 's> for RuleBreakTypeChunks<I> {
+    type IterAttr = ChunkCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        _: &mut RuleBreakIterator<'l, 's, Self>,
+        _: Self::CharType,
+    ) -> Option<usize> {
+        unreachable!()
+    }
+}
@@ -0,0 +1,892 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compiler from ICU break rule source to [`RuleBreakDataV1`].
+//!
+//! The rules are compiled into a DFA over classes of code points, the same way as ICU4C does:
+//! every set in the rules is split into disjoint classes, every rule is turned into a regular
+//! expression over those classes, and the union of the rules is made deterministic with the
+//! positions of its sets (Glushkov's construction). Chaining adds the first positions of all
+//! rules after a position that ends a rule.
+//!
+//! The DFA is then mapped onto the break state table of the rule-based segmenter. Each class is
+//! a simple state, standing for the DFA state after reading a single code point of that class,
+//! and all other DFA states become combined states. A transition into the state of a simple
+//! class is [`BreakState::Keep`], and a transition into a combined state is
+//! [`BreakState::Intermediate`] if the state it leaves is accepting, so that the segmenter
+//! falls back to the longest match.
+
+use crate::provider::{BreakState, RuleBreakDataV1};
+use crate::{SegmenterError, WordType};
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_collections::codepointinvlist::{CodePointInversionList, CodePointInversionListBuilder};
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType};
+use icu_unicodeset_parse::VariableMap;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// The maximum number of states, including SOT and EOT. [`BreakState::Intermediate`] uses the
+/// seventh bit of its index, so indices are limited to six bits.
+const MAX_STATES: usize = 64;
+
+const CODE_POINT_LIMIT: u32 = 0x110000;
+
+impl RuleBreakDataV1<'static> {
+    /// Compiles rule-based break data from rules in the
+    /// [ICU break rule syntax](https://unicode-org.github.io/icu/userguide/boundaryanalysis/break-rules.html).
+    ///
+    /// The rules may define variables with `$Name = ...;`, and use sets in the
+    /// [UnicodeSet syntax](https://unicode.org/reports/tr35/#Unicode_Sets), literal characters,
+    /// quoted strings, grouping, alternation, and the `*`, `+`, and `?` operators. A rule may end
+    /// with a status tag such as `{200}`, which is mapped to a [`WordType`] as in ICU4C: `100..200`
+    /// is [`WordType::Number`], `200..300` is [`WordType::Letter`], `300..400` is
+    /// [`WordType::Kana`], `400..500` is [`WordType::Ideo`], and everything else is
+    /// [`WordType::None`].
+    ///
+    /// The `!!chain`, `!!forward`, `!!quoted_literals_only`, and `!!lookAheadHardBreak` options
+    /// are supported, and `!!reverse`, `!!safe_forward`, and `!!safe_reverse` rules are ignored,
+    /// as ICU4C does. Rules starting with `^` are not chained into. Look-ahead (`/`) and
+    /// `$dictionary` are not supported, and rules using them are rejected with
+    /// [`SegmenterError::InvalidRules`].
+    ///
+    /// Text that no rule matches is broken after every code point.
+    ///
+    /// The state table of the segmenter has room for 64 states: one for each class of code
+    /// points that the sets of the rules tell apart, one for each further state of the
+    /// deterministic automaton of the rules, and one each for the start and the end of the
+    /// text. This is enough for tailorings of the size of the ICU grapheme cluster rules, but
+    /// not for the full ICU word or line rules; rules that need more states are rejected with
+    /// [`SegmenterError::TooComplexRules`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    pub fn try_from_rules(rules: &str) -> Result<Self, SegmenterError> {
+        let rules = Parser::new(rules).parse()?;
+        compile(&rules)
+    }
+}
+
+/// A regular expression over the sets of a rule source.
+#[derive(Debug, Clone)]
+enum Node {
+    /// Any code point of the set with this index.
+    Set(usize),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Star(Box<Node>),
+    Plus(Box<Node>),
+    Optional(Box<Node>),
+}
+
+/// The parsed forward rules of a rule source.
+#[derive(Debug)]
+struct Rules {
+    chain: bool,
+    /// The rules with their status values, and whether they can be chained into.
+    rules: Vec<(Node, u32, bool)>,
+    sets: Vec<CodePointInversionList<'static>>,
+}
+
+#[derive(Debug)]
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+    sets: Vec<CodePointInversionList<'static>>,
+    variables: BTreeMap<&'a str, Node>,
+    /// The variables that are sets, for use inside of other sets.
+    set_variables: VariableMap<'static>,
+    quoted_literals_only: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            sets: Vec::new(),
+            variables: BTreeMap::new(),
+            set_variables: VariableMap::new(),
+            quoted_literals_only: false,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        self.source.get(self.offset..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self) -> SegmenterError {
+        SegmenterError::InvalidRules(self.offset)
+    }
+
+    /// Skips white space and comments, which run from `#` to the end of the line.
+    fn skip_white_space(&mut self) {
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    let rest = self.rest();
+                    self.offset += rest.find(|c| c == '\n' || c == '\r').unwrap_or(rest.len());
+                }
+                Some(c) if is_pattern_white_space(c) => {
+                    self.bump();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SegmenterError> {
+        self.skip_white_space();
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<&'a str, SegmenterError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+        self.offset += len;
+        Ok(rest.get(..len).unwrap_or_default())
+    }
+
+    fn parse(mut self) -> Result<Rules, SegmenterError> {
+        let mut chain = false;
+        let mut forward = true;
+        let mut rules = Vec::new();
+        loop {
+            self.skip_white_space();
+            let start = self.offset;
+            if self.peek().is_none() {
+                break;
+            }
+            if self.rest().starts_with("!!") {
+                self.offset += 2;
+                match self.parse_name()? {
+                    "chain" => chain = true,
+                    "forward" => forward = true,
+                    "reverse" | "safe_forward" | "safe_reverse" => forward = false,
+                    "quoted_literals_only" => self.quoted_literals_only = true,
+                    "lookAheadHardBreak" => {}
+                    _ => return Err(SegmenterError::InvalidRules(start)),
+                }
+                self.expect(';')?;
+            } else if !forward {
+                self.skip_statement()?;
+            } else if let Some(name) = self.parse_assignment()? {
+                let node = self.parse_alternation()?;
+                self.expect(';')?;
+                if let Node::Set(index) = node {
+                    let set = self
+                        .sets
+                        .get(index)
+                        .cloned()
+                        .and_then(|set| {
+                            CodePointInversionListAndStringList::try_from(set, VarZeroVec::new())
+                                .ok()
+                        })
+                        .ok_or(SegmenterError::InvalidRules(start))?;
+                    self.set_variables
+                        .insert_set(String::from(name), set)
+                        .map_err(|_| SegmenterError::InvalidRules(start))?;
+                }
+                if self.variables.insert(name, node).is_some() {
+                    return Err(SegmenterError::InvalidRules(start));
+                }
+            } else {
+                // `^` prevents chaining into the rule
+                let chain_into = self.peek() != Some('^');
+                if !chain_into {
+                    self.bump();
+                }
+                let node = self.parse_alternation()?;
+                self.skip_white_space();
+                let status = if self.peek() == Some('{') {
+                    self.parse_status()?
+                } else {
+                    0
+                };
+                self.expect(';')?;
+                rules.push((node, status, chain_into));
+            }
+        }
+        Ok(Rules {
+            chain,
+            rules,
+            sets: self.sets,
+        })
+    }
+
+    /// Parses `$Name =` if the statement is a variable definition.
+    fn parse_assignment(&mut self) -> Result<Option<&'a str>, SegmenterError> {
+        let start = self.offset;
+        if self.peek() != Some('$') {
+            return Ok(None);
+        }
+        self.bump();
+        let name = self.parse_name()?;
+        self.skip_white_space();
+        if self.peek() == Some('=') {
+            self.bump();
+            Ok(Some(name))
+        } else {
+            self.offset = start;
+            Ok(None)
+        }
+    }
+
+    /// Skips a statement of the ignored reverse and safe rules.
+    fn skip_statement(&mut self) -> Result<(), SegmenterError> {
+        let mut depth = 0usize;
+        let mut quoted = false;
+        loop {
+            match self.bump().ok_or_else(|| self.error())? {
+                '\'' => quoted = !quoted,
+                '\\' if !quoted => {
+                    self.bump();
+                }
+                '[' if !quoted => depth += 1,
+                ']' if !quoted => depth = depth.saturating_sub(1),
+                ';' if !quoted && depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_status(&mut self) -> Result<u32, SegmenterError> {
+        self.bump();
+        self.skip_white_space();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let status = rest
+            .get(..len)
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| self.error())?;
+        self.offset += len;
+        self.expect('}')?;
+        Ok(status)
+    }
+
+    fn parse_alternation(&mut self) -> Result<Node, SegmenterError> {
+        let mut alternatives = vec![self.parse_sequence()?];
+        loop {
+            self.skip_white_space();
+            if self.peek() != Some('|') {
+                break;
+            }
+            self.bump();
+            alternatives.push(self.parse_sequence()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+
+    fn parse_sequence(&mut self) -> Result<Node, SegmenterError> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_white_space();
+            match self.peek() {
+                None | Some(';' | '|' | ')' | '{') => break,
+                _ => {}
+            }
+            let mut node = self.parse_atom()?;
+            loop {
+                self.skip_white_space();
+                node = match self.peek() {
+                    Some('*') => Node::Star(Box::new(node)),
+                    Some('+') => Node::Plus(Box::new(node)),
+                    Some('?') => Node::Optional(Box::new(node)),
+                    _ => break,
+                };
+                self.bump();
+            }
+            items.push(node);
+        }
+        match items.len() {
+            0 => Err(self.error()),
+            1 => Ok(items.remove(0)),
+            _ => Ok(Node::Concat(items)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, SegmenterError> {
+        let start = self.offset;
+        match self.peek() {
+            Some('(') => {
+                self.bump();
+                let node = self.parse_alternation()?;
+                self.expect(')')?;
+                Ok(node)
+            }
+            Some('$') => {
+                self.bump();
+                let name = self.parse_name()?;
+                self.variables
+                    .get(name)
+                    .cloned()
+                    .ok_or(SegmenterError::InvalidRules(start))
+            }
+            Some('[') => self.parse_set(),
+            Some('\\') => {
+                if matches!(self.rest().get(1..2), Some("p" | "P" | "N")) {
+                    return self.parse_set();
+                }
+                self.bump();
+                let c = match self.bump() {
+                    Some('u') => self.parse_hex(4)?,
+                    Some('U') => self.parse_hex(8)?,
+                    Some(c) => c,
+                    None => return Err(self.error()),
+                };
+                Ok(self.literal(c))
+            }
+            Some('\'') => {
+                self.bump();
+                // `''` is an apostrophe
+                if self.peek() == Some('\'') {
+                    self.bump();
+                    return Ok(self.literal('\''));
+                }
+                let mut items = Vec::new();
+                loop {
+                    match self.bump() {
+                        None => return Err(SegmenterError::InvalidRules(start)),
+                        Some('\'') if self.peek() == Some('\'') => {
+                            self.bump();
+                            items.push(self.literal('\''));
+                        }
+                        Some('\'') => break,
+                        Some(c) => items.push(self.literal(c)),
+                    }
+                }
+                Ok(if items.len() == 1 {
+                    items.remove(0)
+                } else {
+                    Node::Concat(items)
+                })
+            }
+            Some(c) if !self.quoted_literals_only && !is_syntax(c) => {
+                self.bump();
+                Ok(self.literal(c))
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_hex(&mut self, len: usize) -> Result<char, SegmenterError> {
+        let c = self
+            .rest()
+            .get(..len)
+            .and_then(|s| u32::from_str_radix(s, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error())?;
+        self.offset += len;
+        Ok(c)
+    }
+
+    fn parse_set(&mut self) -> Result<Node, SegmenterError> {
+        let (set, len) = icu_unicodeset_parse::parse_with_variables(
+            self.rest(),
+            &self.set_variables,
+        )
+        .map_err(
+            // Errors without an offset occur at the end of the source
+            |e| SegmenterError::InvalidRules(self.offset + e.offset().unwrap_or(self.rest().len())),
+        )?;
+        if set.has_strings() {
+            return Err(self.error());
+        }
+        self.offset += len;
+        Ok(self.add_set(set.code_points().clone()))
+    }
+
+    fn literal(&mut self, c: char) -> Node {
+        let mut builder = CodePointInversionListBuilder::new();
+        builder.add_char(c);
+        self.add_set(builder.build())
+    }
+
+    fn add_set(&mut self, set: CodePointInversionList<'static>) -> Node {
+        self.sets.push(set);
+        Node::Set(self.sets.len() - 1)
+    }
+}
+
+fn is_pattern_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t'..='\r' | ' ' | '\u{85}' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+fn is_syntax(c: char) -> bool {
+    is_pattern_white_space(c)
+        || matches!(
+            c,
+            '$' | '['
+                | ']'
+                | '('
+                | ')'
+                | '|'
+                | '*'
+                | '+'
+                | '?'
+                | '{'
+                | '}'
+                | ';'
+                | '/'
+                | '^'
+                | '.'
+                | '\\'
+                | '\''
+                | '!'
+                | '='
+                | '#'
+        )
+}
+
+/// The positions of a regular expression, see Glushkov's construction.
+#[derive(Debug, Default)]
+struct Positions {
+    /// The set of each position.
+    sets: Vec<usize>,
+    /// The positions that may follow each position.
+    follow: Vec<BTreeSet<usize>>,
+}
+
+impl Positions {
+    /// Adds the positions of a node, returning whether it matches the empty string, and its
+    /// first and last positions.
+    fn add(&mut self, node: &Node) -> (bool, BTreeSet<usize>, BTreeSet<usize>) {
+        match node {
+            Node::Set(set) => {
+                let position = self.sets.len();
+                self.sets.push(*set);
+                self.follow.push(BTreeSet::new());
+                (
+                    false,
+                    BTreeSet::from([position]),
+                    BTreeSet::from([position]),
+                )
+            }
+            Node::Concat(nodes) => {
+                let mut nullable = true;
+                let mut first = BTreeSet::new();
+                let mut last = BTreeSet::new();
+                for node in nodes {
+                    let (n, f, l) = self.add(node);
+                    self.add_follow(&last, &f);
+                    if nullable {
+                        first.extend(f);
+                    }
+                    if n {
+                        last.extend(l);
+                    } else {
+                        last = l;
+                    }
+                    nullable &= n;
+                }
+                (nullable, first, last)
+            }
+            Node::Alternation(nodes) => {
+                let mut nullable = false;
+                let mut first = BTreeSet::new();
+                let mut last = BTreeSet::new();
+                for node in nodes {
+                    let (n, f, l) = self.add(node);
+                    nullable |= n;
+                    first.extend(f);
+                    last.extend(l);
+                }
+                (nullable, first, last)
+            }
+            Node::Star(node) => {
+                let (_, first, last) = self.add(node);
+                self.add_follow(&last, &first);
+                (true, first, last)
+            }
+            Node::Plus(node) => {
+                let (nullable, first, last) = self.add(node);
+                self.add_follow(&last, &first);
+                (nullable, first, last)
+            }
+            Node::Optional(node) => {
+                let (_, first, last) = self.add(node);
+                (true, first, last)
+            }
+        }
+    }
+
+    fn add_follow(&mut self, from: &BTreeSet<usize>, to: &BTreeSet<usize>) {
+        for &position in from {
+            if let Some(follow) = self.follow.get_mut(position) {
+                follow.extend(to);
+            }
+        }
+    }
+}
+
+fn compile(rules: &Rules) -> Result<RuleBreakDataV1<'static>, SegmenterError> {
+    let mut positions = Positions::default();
+    let mut first = BTreeSet::new();
+    // The first positions of the rules that can be chained into
+    let mut chain_first = BTreeSet::new();
+    // The positions that end a rule, with the largest status of those rules
+    let mut ends = BTreeMap::<usize, u32>::new();
+    for (node, status, chain_into) in &rules.rules {
+        let (_, f, l) = positions.add(node);
+        if *chain_into {
+            chain_first.extend(f.iter().copied());
+        }
+        first.extend(f);
+        for position in l {
+            let end = ends.entry(position).or_insert(*status);
+            *end = (*end).max(*status);
+        }
+    }
+
+    // Split the code points into classes by the sets they are in. The code points that are in
+    // no set are class 0.
+    let mut boundaries = BTreeSet::from([0, CODE_POINT_LIMIT]);
+    for set in &rules.sets {
+        boundaries.extend(set.get_inversion_list_vec());
+    }
+    let mut class_sets = vec![vec![false; rules.sets.len()]];
+    let mut classes = BTreeMap::from([(vec![false; rules.sets.len()], 0)]);
+    // The start of each range of code points, with its class
+    let mut ranges = Vec::new();
+    for &start in boundaries.iter().filter(|&&start| start < CODE_POINT_LIMIT) {
+        let signature = rules
+            .sets
+            .iter()
+            .map(|set| set.contains32(start))
+            .collect::<Vec<_>>();
+        let class = *classes.entry(signature.clone()).or_insert_with(|| {
+            class_sets.push(signature);
+            class_sets.len() - 1
+        });
+        let class = u8::try_from(class).map_err(|_| SegmenterError::TooComplexRules)?;
+        ranges.push((start, class));
+    }
+
+    let matches = |position: usize, class: usize| {
+        positions
+            .sets
+            .get(position)
+            .and_then(|&set| class_sets.get(class)?.get(set).copied())
+            .unwrap_or(false)
+    };
+    let status = |state: &BTreeSet<usize>| state.iter().filter_map(|p| ends.get(p)).max().copied();
+    let states_for_classes = |first: &BTreeSet<usize>| {
+        (0..class_sets.len())
+            .map(|class| {
+                first
+                    .iter()
+                    .copied()
+                    .filter(|&p| matches(p, class))
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    // The state after reading a single code point of each class
+    let simple_states = states_for_classes(&first);
+    // The positions that chaining adds after reading a code point of each class
+    let chain_states = states_for_classes(&chain_first);
+    let step = |state: &BTreeSet<usize>, class: usize| {
+        let mut next = state
+            .iter()
+            .filter_map(|&p| positions.follow.get(p))
+            .flatten()
+            .copied()
+            .filter(|&p| matches(p, class))
+            .collect::<BTreeSet<_>>();
+        if rules.chain && next.iter().any(|p| ends.contains_key(p)) {
+            next.extend(chain_states.get(class).into_iter().flatten());
+        }
+        next
+    };
+
+    let class_count = class_sets.len();
+    let mut states = simple_states.clone();
+    let mut combined_states = BTreeMap::new();
+    let mut rows = Vec::new();
+    while let Some(state) = states.get(rows.len()).cloned() {
+        let simple = rows.len() < class_count;
+        let accepting = status(&state).is_some();
+        let mut row = Vec::with_capacity(class_count);
+        for (class, simple_state) in simple_states.iter().enumerate() {
+            let next = step(&state, class);
+            row.push(if next.is_empty() {
+                if simple || accepting {
+                    BreakState::Break
+                } else {
+                    BreakState::NoMatch
+                }
+            } else if status(&next).is_some() && &next == simple_state {
+                BreakState::Keep
+            } else {
+                let index = *combined_states.entry(next.clone()).or_insert_with(|| {
+                    states.push(next);
+                    states.len() - 1
+                });
+                let index = u8::try_from(index)
+                    .ok()
+                    .filter(|&i| usize::from(i) + 2 < MAX_STATES)
+                    .ok_or(SegmenterError::TooComplexRules)?;
+                if !simple && accepting {
+                    BreakState::Intermediate(index)
+                } else {
+                    BreakState::Index(index)
+                }
+            });
+        }
+        rows.push((row, simple || accepting));
+    }
+
+    let state_count = states.len();
+    let property_count = state_count + 2;
+    let mut break_state_table = Vec::with_capacity(property_count * property_count);
+    for (row, can_break_at_end) in rows {
+        break_state_table.extend(row);
+        // Combined states are never on the right
+        break_state_table.extend((class_count..state_count).map(|_| BreakState::Break));
+        // SOT
+        break_state_table.push(BreakState::Break);
+        // EOT
+        break_state_table.push(if can_break_at_end {
+            BreakState::Break
+        } else {
+            BreakState::NoMatch
+        });
+    }
+    // SOT and EOT
+    break_state_table.extend((0..2 * property_count).map(|_| BreakState::Break));
+
+    // The word type of property 0 is implicit
+    let word_type_table = states
+        .iter()
+        .skip(1)
+        .map(|state| match status(state) {
+            Some(100..=199) => WordType::Number,
+            Some(200..=299) => WordType::Letter,
+            Some(300..=399) => WordType::Kana,
+            Some(400..=499) => WordType::Ideo,
+            _ => WordType::None,
+        })
+        .chain([WordType::None, WordType::None])
+        .collect::<Vec<_>>();
+
+    let too_complex = |_| SegmenterError::TooComplexRules;
+    Ok(RuleBreakDataV1 {
+        property_table: build_trie(&ranges)?,
+        break_state_table: ZeroVec::alloc_from_slice(&break_state_table),
+        word_type_table: ZeroVec::alloc_from_slice(&word_type_table),
+        property_count: u8::try_from(property_count).map_err(too_complex)?,
+        last_codepoint_property: u8::try_from(class_count - 1).map_err(too_complex)?,
+        sot_property: u8::try_from(state_count).map_err(too_complex)?,
+        eot_property: u8::try_from(state_count + 1).map_err(too_complex)?,
+        complex_property: 127,
+    })
+}
+
+/// Builds a fast [`CodePointTrie`] from the start of each range of code points and its value.
+/// The ranges are sorted, and the first one starts at 0.
+fn build_trie(ranges: &[(u32, u8)]) -> Result<CodePointTrie<'static, u8>, SegmenterError> {
+    // Blocks of 64 code points for the BMP, and of 16 code points above it.
+    const FAST_BLOCK: usize = 1 << 6;
+    const SMALL_BLOCK: usize = 1 << 4;
+    const INDEX_2_LENGTH: usize = 1 << 5;
+    const INDEX_3_LENGTH: usize = 1 << 5;
+
+    fn intern<T: Ord + Copy>(
+        block: &[T],
+        blocks: &mut BTreeMap<Vec<T>, u16>,
+        array: &mut Vec<T>,
+        limit: usize,
+    ) -> Result<u16, SegmenterError> {
+        if let Some(&offset) = blocks.get(block) {
+            return Ok(offset);
+        }
+        let offset = u16::try_from(array.len())
+            .ok()
+            .filter(|&o| usize::from(o) < limit)
+            .ok_or(SegmenterError::TooComplexRules)?;
+        array.extend_from_slice(block);
+        blocks.insert(block.to_vec(), offset);
+        Ok(offset)
+    }
+
+    // Fills a block with the values of the next code points
+    let mut ranges = ranges.iter().peekable();
+    let mut value = 0;
+    let mut next_values = |block: &mut [u8], start: u32| {
+        for (code_point, entry) in (start..).zip(block.iter_mut()) {
+            while let Some(&(_, v)) = ranges.next_if(|&&(s, _)| s <= code_point) {
+                value = v;
+            }
+            *entry = value;
+        }
+    };
+
+    let mut data = Vec::new();
+    let mut data_blocks = BTreeMap::new();
+    let mut index = Vec::new();
+    let mut fast_block = [0; FAST_BLOCK];
+    for start in (0..0x10000).step_by(FAST_BLOCK) {
+        next_values(&mut fast_block, start);
+        index.push(intern(&fast_block, &mut data_blocks, &mut data, 0x10000)?);
+    }
+
+    // Each code point above the BMP has an index-1 entry for its 0x4000 code points, which
+    // points to an index-2 block for its 0x200 code points, which points to an index-3 block,
+    // which points to its data block.
+    let mut index_3_offsets = Vec::new();
+    let mut index_3_blocks = BTreeMap::new();
+    let mut index_3 = Vec::new();
+    let mut small_block = [0; SMALL_BLOCK];
+    let mut index_3_block = [0; INDEX_3_LENGTH];
+    for start in (0x10000..CODE_POINT_LIMIT).step_by(SMALL_BLOCK * INDEX_3_LENGTH) {
+        for (entry, start) in index_3_block.iter_mut().zip((start..).step_by(SMALL_BLOCK)) {
+            next_values(&mut small_block, start);
+            *entry = intern(&small_block, &mut data_blocks, &mut data, 0x10000)?;
+        }
+        index_3_offsets.push(intern(
+            &index_3_block,
+            &mut index_3_blocks,
+            &mut index_3,
+            0x8000,
+        )?);
+    }
+    let index_1_start = index.len();
+    index.resize(index_1_start + index_3_offsets.len() / INDEX_2_LENGTH, 0);
+    let mut index_2_offsets = Vec::new();
+    let mut index_2_blocks = BTreeMap::new();
+    let mut index_2 = Vec::new();
+    for block in index_3_offsets.chunks_exact(INDEX_2_LENGTH) {
+        index_2_offsets.push(intern(block, &mut index_2_blocks, &mut index_2, 0x8000)?);
+    }
+    // Index-2 blocks follow index-1, and index-3 blocks follow index-2
+    let index_2_start = index.len();
+    let index_3_start = index_2_start + index_2.len();
+    let rebase = |offset: u16, start: usize| {
+        u16::try_from(usize::from(offset) + start)
+            .ok()
+            .filter(|&o| o < 0x8000)
+            .ok_or(SegmenterError::TooComplexRules)
+    };
+    for (entry, &offset) in index
+        .iter_mut()
+        .skip(index_1_start)
+        .zip(index_2_offsets.iter())
+    {
+        *entry = rebase(offset, index_2_start)?;
+    }
+    for offset in index_2 {
+        index.push(rebase(offset, index_3_start)?);
+    }
+    index.extend(index_3);
+
+    // The high value and the error value
+    data.extend([0, 0]);
+
+    let header = CodePointTrieHeader {
+        high_start: CODE_POINT_LIMIT,
+        shifted12_high_start: (CODE_POINT_LIMIT >> 12) as u16,
+        index3_null_offset: 0xffff,
+        data_null_offset: 0xfffff,
+        null_value: 0,
+        trie_type: TrieType::Fast,
+    };
+    CodePointTrie::try_new(
+        header,
+        ZeroVec::alloc_from_slice(&index),
+        ZeroVec::alloc_from_slice(&data),
+    )
+    .map_err(|_| SegmenterError::TooComplexRules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_trie() {
+        let value = |cp| match cp {
+            0x41..=0x5A => 1,
+            0x3040..=0x309F => 2,
+            0x1F600..=0x1F64F => 3,
+            0x20000..=0x2A6DF => 4,
+            _ => 0,
+        };
+        let trie = build_trie(&[
+            (0, 0),
+            (0x41, 1),
+            (0x5B, 0),
+            (0x3040, 2),
+            (0x30A0, 0),
+            (0x1F600, 3),
+            (0x1F650, 0),
+            (0x20000, 4),
+            (0x2A6E0, 0),
+        ])
+        .unwrap();
+        for cp in 0..CODE_POINT_LIMIT {
+            assert_eq!(trie.get32(cp), value(cp), "U+{cp:04X}");
+        }
+        assert_eq!(
+            trie.iter_ranges()
+                .filter(|range| range.value == 4)
+                .map(|range| range.range)
+                .collect::<Vec<_>>(),
+            vec![0x20000..=0x2A6DF]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        for (rules, offset) in [
+            ("$Letter = [a-z]; $Lettr+;", 17),
+            ("[a-z]+", 6),
+            ("!!unknown;", 0),
+            ("[a-z] / [0-9];", 6),
+            ("[a-z]+ {abc};", 8),
+            ("$A = [a]; $A = [b];", 10),
+        ] {
+            assert_eq!(
+                RuleBreakDataV1::try_from_rules(rules),
+                Err(SegmenterError::InvalidRules(offset)),
+                "{rules}"
+            );
+        }
+    }
+}
@@ -3,10 +3,14 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::vec::Vec;
+use core::ops::Range;
+use icu_locid::extensions::unicode::{key, value};
 use icu_provider::prelude::*;
 
-use crate::indices::{Latin1Indices, Utf16Indices};
-use crate::iterator_helpers::derive_usize_iterator_with_type;
+use crate::incremental::resegment;
+use crate::indices::{
+    ChunkCharIndices, InputIndices, Latin1Indices, ResumableBreakIterator, Utf16Indices,
+};
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
 use utf8_iter::Utf8CharIndices;
@@ -24,11 +28,124 @@ use utf8_iter::Utf8CharIndices;
 ///
 /// For examples of use, see [`SentenceSegmenter`].
 #[derive(Debug)]
-pub struct SentenceBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized>(
-    RuleBreakIterator<'l, 's, Y>,
-);
+pub struct SentenceBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
+    inner: RuleBreakIterator<'l, 's, Y>,
+    suppressions: Option<&'l SentenceBreakSuppressionsV1<'l>>,
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for SentenceBreakIterator<'l, 's, Y> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let boundary = self.inner.next()?;
+            if !self.is_suppressed(boundary) {
+                return Some(boundary);
+            }
+        }
+    }
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> SentenceBreakIterator<'l, 's, Y> {
+    fn new(
+        inner: RuleBreakIterator<'l, 's, Y>,
+        suppressions: Option<&'l SentenceBreakSuppressionsV1<'l>>,
+    ) -> Self {
+        Self {
+            inner,
+            suppressions,
+        }
+    }
 
-derive_usize_iterator_with_type!(SentenceBreakIterator);
+    /// Returns whether the rule-based boundary at `boundary` follows an abbreviation in the
+    /// suppression list.
+    ///
+    /// The abbreviation is the last whitespace-delimited word before `boundary`. As the
+    /// suppressions are stored reversed, it is matched by walking backwards from `boundary`,
+    /// without allocating. Hard line breaks, such as line feeds, and the ends of the input
+    /// are never suppressed.
+    fn is_suppressed(&self, boundary: usize) -> bool {
+        let Some(suppressions) = self.suppressions else {
+            return false;
+        };
+        if boundary == 0 || boundary >= self.inner.len {
+            return false;
+        }
+        let mut cursor = suppressions.suppressions.cursor();
+        let mut offset = boundary;
+        let mut in_word = false;
+        while let Some((start, c)) = Y::IterAttr::char_before(self.inner.input.clone(), offset) {
+            let c = char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER);
+            if c.is_whitespace() {
+                if in_word {
+                    break;
+                }
+                if matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}') {
+                    return false;
+                }
+            } else {
+                in_word = true;
+                for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                    cursor.step(b);
+                }
+                if cursor.is_empty() {
+                    return false;
+                }
+            }
+            offset = start;
+        }
+        in_word && cursor.take_value().is_some()
+    }
+
+    /// Returns the first sentence boundary after `offset`, or `None` if `offset` is at or
+    /// after the end of the string.
+    ///
+    /// This does not change the position of the iterator. Segmentation resumes at the last
+    /// position before `offset` at which the rules always break, such as after a line feed,
+    /// instead of at the start of the string. Its cost is therefore linear in the distance to
+    /// that position, which is the whole string if it has no line breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::SentenceSegmenter;
+    ///
+    /// let segmenter = SentenceSegmenter::new();
+    /// let text = b"Hello World. Xin ch\xe0o th\xea gi\xf4i!";
+    /// let iter = segmenter.segment_latin1(text);
+    ///
+    /// assert_eq!(iter.following(5), Some(13));
+    /// assert_eq!(iter.preceding(13), Some(0));
+    /// assert!(iter.is_boundary(13));
+    /// ```
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        let mut boundary = self.inner.following(offset)?;
+        while self.is_suppressed(boundary) {
+            boundary = self.inner.following(boundary)?;
+        }
+        Some(boundary)
+    }
+
+    /// Returns the last sentence boundary before `offset`, or `None` if `offset` is 0.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        let mut boundary = self.inner.preceding(offset)?;
+        while self.is_suppressed(boundary) {
+            boundary = self.inner.preceding(boundary)?;
+        }
+        Some(boundary)
+    }
+
+    /// Returns whether there is a sentence boundary at `offset`.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        self.inner.is_boundary(offset) && !self.is_suppressed(offset)
+    }
+}
 
 /// Sentence break iterator for an `str` (a UTF-8 string).
 ///
@@ -51,6 +168,37 @@ pub type SentenceBreakIteratorLatin1<'l, 's> = SentenceBreakIterator<'l, 's, Rul
 /// For examples of use, see [`SentenceSegmenter`].
 pub type SentenceBreakIteratorUtf16<'l, 's> = SentenceBreakIterator<'l, 's, RuleBreakTypeUtf16>;
 
+/// Sentence break iterator for a UTF-8 string split into chunks, such as a rope.
+///
+/// For examples of use, see [`SentenceSegmenter::segment_chunks()`].
+pub type SentenceBreakIteratorChunks<'l, 's, I> =
+    SentenceBreakIterator<'l, 's, RuleBreakTypeChunks<I>>;
+
+/// Whether to suppress sentence breaks after abbreviations, such as "Mr." in English.
+///
+/// This corresponds to the `-u-ss` Unicode extension keyword.
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SentenceBreakSuppressions {
+    /// Do not suppress any sentence breaks (`-u-ss-none`).
+    None,
+    /// Suppress sentence breaks after the abbreviations listed by CLDR for the locale
+    /// (`-u-ss-standard`).
+    Standard,
+}
+
+/// Options to tailor sentence-breaking behavior.
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct SentenceBreakOptions {
+    /// Whether to suppress sentence breaks after abbreviations. See
+    /// [`SentenceBreakSuppressions`].
+    ///
+    /// If `None`, the `-u-ss` Unicode extension keyword of the locale is used, and breaks are
+    /// not suppressed if it is absent.
+    pub suppressions: Option<SentenceBreakSuppressions>,
+}
+
 /// Supports loading sentence break data, and creating sentence break iterators for different string
 /// encodings.
 ///
@@ -97,9 +245,34 @@ pub type SentenceBreakIteratorUtf16<'l, 's> = SentenceBreakIterator<'l, 's, Rule
 ///     &["Ceci tuera cela. ", "Le livre tuera l’édifice."]
 /// );
 /// ```
+///
+/// Suppress sentence breaks after abbreviations, such as "Mr.":
+///
+/// ```rust
+/// use icu::locid::locale;
+/// use icu::segmenter::SentenceSegmenter;
+///
+/// let text = "Mr. Smith went to Washington. He said hi.";
+///
+/// let segmenter = SentenceSegmenter::new();
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 4, 30, 41]);
+///
+/// let segmenter = SentenceSegmenter::try_new_with_options(
+///     &locale!("en-u-ss-standard").into(),
+///     Default::default(),
+/// )
+/// .expect("locale should be present");
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 30, 41]);
+/// ```
 #[derive(Debug)]
 pub struct SentenceSegmenter {
     payload: DataPayload<SentenceBreakDataV1Marker>,
+    suppressions: Option<DataPayload<SentenceBreakSuppressionsV1Marker>>,
+    /// Whether the boundaries after a hard line break do not depend on the text before it,
+    /// which holds for the Unicode rules, but not necessarily for custom rules.
+    resume_at_hard_breaks: bool,
 }
 
 #[cfg(feature = "compiled_data")]
@@ -121,6 +294,8 @@ impl SentenceSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_SENTENCE_V1,
             ),
+            suppressions: None,
+            resume_at_hard_breaks: true,
         }
     }
 
@@ -141,22 +316,122 @@ impl SentenceSegmenter {
         D: DataProvider<SentenceBreakDataV1Marker> + ?Sized,
     {
         let payload = provider.load(Default::default())?.take_payload()?;
-        Ok(Self { payload })
+        Ok(Self {
+            payload,
+            suppressions: None,
+            resume_at_hard_breaks: true,
+        })
+    }
+
+    /// Constructs a [`SentenceSegmenter`] for the given locale and options, using compiled
+    /// data.
+    ///
+    /// See [`SentenceBreakOptions`] for how sentence breaks after abbreviations, such as "Mr.",
+    /// are suppressed.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_with_options(
+        locale: &DataLocale,
+        options: SentenceBreakOptions,
+    ) -> Result<Self, SegmenterError> {
+        Self::try_new_with_options_unstable(&crate::provider::Baked, locale, options)
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: SentenceBreakOptions,
+        error: SegmenterError,
+        #[cfg(skip)]
+        functions: [
+            try_new_with_options,
+            try_new_with_options_with_any_provider,
+            try_new_with_options_with_buffer_provider,
+            try_new_with_options_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_options)]
+    pub fn try_new_with_options_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: SentenceBreakOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<SentenceBreakDataV1Marker>
+            + DataProvider<SentenceBreakSuppressionsV1Marker>
+            + ?Sized,
+    {
+        let payload = provider.load(Default::default())?.take_payload()?;
+        let suppressions =
+            options
+                .suppressions
+                .unwrap_or_else(|| match locale.get_unicode_ext(&key!("ss")) {
+                    Some(v) if v == value!("standard") => SentenceBreakSuppressions::Standard,
+                    _ => SentenceBreakSuppressions::None,
+                });
+        let suppressions = match suppressions {
+            SentenceBreakSuppressions::Standard => Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
+            SentenceBreakSuppressions::None => None,
+        };
+        Ok(Self {
+            payload,
+            suppressions,
+            resume_at_hard_breaks: true,
+        })
+    }
+
+    /// Constructs a [`SentenceSegmenter`] from rules in the ICU break rule syntax.
+    ///
+    /// See [`RuleBreakDataV1::try_from_rules()`] for the supported syntax. Sentence breaks are
+    /// not suppressed after abbreviations.
+    ///
+    /// Custom rules may match across hard line breaks, so [`Self::resegment_chunks()`] segments
+    /// from the start of the string.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[cfg(all(feature = "compiled_data", feature = "experimental"))]
+    pub fn try_new_with_rules(rules: &str) -> Result<Self, SegmenterError> {
+        Ok(Self {
+            payload: DataPayload::from_owned(RuleBreakDataV1::try_from_rules(rules)?),
+            suppressions: None,
+            resume_at_hard_breaks: false,
+        })
     }
 
     /// Creates a sentence break iterator for an `str` (a UTF-8 string).
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
     pub fn segment_str<'l, 's>(&'l self, input: &'s str) -> SentenceBreakIteratorUtf8<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: input.char_indices(),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            RuleBreakIterator {
+                iter: input.char_indices(),
+                len: input.len(),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data: self.payload.get(),
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            },
+            self.suppressions.as_ref().map(DataPayload::get),
+        )
     }
     /// Creates a sentence break iterator for a potentially ill-formed UTF8 string
     ///
@@ -167,15 +442,20 @@ impl SentenceSegmenter {
         &'l self,
         input: &'s [u8],
     ) -> SentenceBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Utf8CharIndices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            RuleBreakIterator {
+                iter: Utf8CharIndices::new(input),
+                len: input.len(),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data: self.payload.get(),
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            },
+            self.suppressions.as_ref().map(DataPayload::get),
+        )
     }
     /// Creates a sentence break iterator for a Latin-1 (8-bit) string.
     ///
@@ -184,30 +464,129 @@ impl SentenceSegmenter {
         &'l self,
         input: &'s [u8],
     ) -> SentenceBreakIteratorLatin1<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Latin1Indices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            RuleBreakIterator {
+                iter: Latin1Indices::new(input),
+                len: input.len(),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data: self.payload.get(),
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            },
+            self.suppressions.as_ref().map(DataPayload::get),
+        )
     }
 
     /// Creates a sentence break iterator for a UTF-16 string.
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
     pub fn segment_utf16<'l, 's>(&'l self, input: &'s [u16]) -> SentenceBreakIteratorUtf16<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Utf16Indices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            RuleBreakIterator {
+                iter: Utf16Indices::new(input),
+                len: input.len(),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data: self.payload.get(),
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            },
+            self.suppressions.as_ref().map(DataPayload::get),
+        )
+    }
+
+    /// Creates a sentence break iterator for a UTF-8 string split into chunks, such as the
+    /// leaves of a rope.
+    ///
+    /// The boundaries are offsets from the start of the first chunk, and chunk edges need not
+    /// be sentence boundaries.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::SentenceSegmenter;
+    ///
+    /// let segmenter = SentenceSegmenter::new();
+    /// let chunks = ["Hello Wor", "ld. How a", "re you?"];
+    ///
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_chunks(chunks.into_iter()).collect();
+    /// assert_eq!(&breakpoints, &[0, 13, 25]);
+    /// ```
+    pub fn segment_chunks<'l, 's, I>(&'l self, chunks: I) -> SentenceBreakIteratorChunks<'l, 's, I>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        let input = ChunkCharIndices::new(chunks);
+        SentenceBreakIterator::new(
+            RuleBreakIterator {
+                iter: input.clone(),
+                len: ChunkCharIndices::input_len(input.clone()),
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data: self.payload.get(),
+                complex: None,
+                boundary_property: 0,
+                complex_word_type: None,
+                input,
+            },
+            self.suppressions.as_ref().map(DataPayload::get),
+        )
+    }
+
+    /// Updates the sentence boundaries of a UTF-8 string split into chunks after the bytes in
+    /// `replaced` were replaced by `new_len` bytes.
+    ///
+    /// `boundaries` are the boundaries before the edit, such as those returned by
+    /// [`Self::segment_chunks()`], and `chunks_at` returns the chunks of the edited string
+    /// starting at the given byte offset. Only the text between the last hard line break
+    /// before the edit and the first hard line break after it, such as line feeds, is
+    /// segmented again. This range of the edited string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::SentenceSegmenter;
+    ///
+    /// let segmenter = SentenceSegmenter::new();
+    /// let mut text = String::from("One. Two.\nThree.");
+    /// let mut boundaries: Vec<usize> =
+    ///     segmenter.segment_chunks([text.as_str()].into_iter()).collect();
+    /// assert_eq!(&boundaries, &[0, 5, 10, 16]);
+    ///
+    /// text.replace_range(5..8, "Two? Four");
+    /// let resegmented = segmenter.resegment_chunks(&mut boundaries, 5..8, 9, |i| {
+    ///     [&text[i..]].into_iter()
+    /// });
+    ///
+    /// assert_eq!(resegmented, 0..16);
+    /// assert_eq!(&boundaries, &[0, 5, 10, 16, 22]);
+    /// ```
+    pub fn resegment_chunks<'s, I>(
+        &self,
+        boundaries: &mut Vec<usize>,
+        replaced: Range<usize>,
+        new_len: usize,
+        chunks_at: impl FnMut(usize) -> I,
+    ) -> Range<usize>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        resegment(
+            boundaries,
+            replaced,
+            new_len,
+            self.resume_at_hard_breaks,
+            chunks_at,
+            |chunks| self.segment_chunks(chunks),
+        )
     }
 }
 
@@ -218,3 +597,31 @@ fn empty_string() {
     let breaks: Vec<usize> = segmenter.segment_str("").collect();
     assert_eq!(breaks, [0]);
 }
+
+#[cfg(all(test, feature = "compiled_data"))]
+#[test]
+fn suppressions_non_ascii() {
+    use icu_locid::locale;
+
+    let segmenter = SentenceSegmenter::try_new_with_options(
+        &locale!("ru-u-ss-standard").into(),
+        Default::default(),
+    )
+    .unwrap();
+    // "г." and "ул." are suppressed, including directly after a line feed.
+    let text = "Адрес: г. Москва, ул. Тверская. Конец.\nг. Москва.";
+    let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+    assert_eq!(
+        breaks,
+        [
+            0,
+            text.find("Конец").unwrap(),
+            text.find('\n').unwrap() + 1,
+            text.len()
+        ]
+    );
+    let iter = segmenter.segment_str(text);
+    assert!(!iter.is_boundary(text.find("Москва").unwrap()));
+    assert_eq!(iter.following(1), text.find("Конец"));
+    assert_eq!(iter.preceding(text.find("Конец").unwrap()), Some(0));
+}
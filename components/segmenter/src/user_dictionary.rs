@@ -0,0 +1,362 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Builder for [`UCharDictionaryBreakDataV1`] from a list of words.
+//!
+//! The words are serialized into the `UCharsTrie` format of ICU4C, which is read by
+//! [`Char16Trie`](icu_collections::char16trie::Char16Trie), the same way as the fast build
+//! option of ICU4C's `UCharsTrieBuilder`: the trie is written back to front, so that every
+//! node is written after the nodes it jumps to, and jumps are deltas to later positions.
+
+use crate::provider::UCharDictionaryBreakDataV1;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use zerovec::ZeroVec;
+
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MAX_ONE_UNIT_VALUE: i32 = 0x3fff;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const MAX_TWO_UNIT_VALUE: i32 = 0x3ffeffff;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+
+const MAX_ONE_UNIT_NODE_VALUE: i32 = 0xff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 = 0x4040;
+const MAX_TWO_UNIT_NODE_VALUE: i32 = 0xfdffff;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+
+const MAX_ONE_UNIT_DELTA: usize = 0xfbff;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = 0xfc00;
+const MAX_TWO_UNIT_DELTA: usize = 0x3feffff;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+
+impl UCharDictionaryBreakDataV1<'static> {
+    /// Builds dictionary break data from a list of words with optional weights, for layering
+    /// over the built-in dictionaries with constructors such as
+    /// [`WordSegmenter::new_dictionary_with_user_dictionary()`](crate::WordSegmenter::new_dictionary_with_user_dictionary).
+    ///
+    /// Where words of the built-in and of this dictionary start at the same position, the word
+    /// with the highest weight is used, and the longest one among words of equal weight. Words
+    /// of the built-in dictionaries and words without a weight have a weight of 0, so a positive
+    /// weight prefers a word over longer words, and a negative weight only uses it where no
+    /// other word matches.
+    ///
+    /// Empty words are ignored, and the last weight is used for repeated words.
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    pub fn from_words<'a, I>(words: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, Option<i32>)>,
+    {
+        let entries: Vec<(Vec<u16>, i32)> = words
+            .into_iter()
+            .filter(|(word, _)| !word.is_empty())
+            .map(|(word, weight)| (word.encode_utf16().collect(), weight.unwrap_or(0)))
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .collect();
+        let mut builder = Builder {
+            entries: &entries,
+            units: Vec::new(),
+        };
+        if !entries.is_empty() {
+            builder.write_node(0, entries.len(), 0);
+        }
+        // The units were written back to front
+        builder.units.reverse();
+        Self {
+            trie_data: ZeroVec::alloc_from_slice(&builder.units),
+        }
+    }
+}
+
+/// A writer of a `UCharsTrie` for sorted, distinct, and non-empty strings.
+///
+/// Offsets of written nodes are counted from the end of the trie, and stay valid as the trie
+/// grows towards its front.
+struct Builder<'a> {
+    entries: &'a [(Vec<u16>, i32)],
+    /// The units written so far, in reverse order.
+    units: Vec<u16>,
+}
+
+impl Builder<'_> {
+    fn len(&self, i: usize) -> usize {
+        self.entries.get(i).map_or(0, |(s, _)| s.len())
+    }
+
+    fn unit(&self, i: usize, unit_index: usize) -> Option<u16> {
+        self.entries.get(i)?.0.get(unit_index).copied()
+    }
+
+    fn value(&self, i: usize) -> i32 {
+        self.entries.get(i).map_or(0, |&(_, value)| value)
+    }
+
+    /// Writes `units` in front of the trie, and returns the offset of the first one.
+    fn write(&mut self, units: &[u16]) -> usize {
+        self.units.extend(units.iter().rev());
+        self.units.len()
+    }
+
+    /// Writes the units `unit_index..unit_index + length` of the string `i`.
+    fn write_element_units(&mut self, i: usize, unit_index: usize, length: usize) -> usize {
+        let entries = self.entries;
+        let units = entries
+            .get(i)
+            .and_then(|(s, _)| s.get(unit_index..unit_index + length))
+            .unwrap_or_default();
+        self.write(units)
+    }
+
+    /// Writes the node for the strings `start..limit`, which share their first `unit_index`
+    /// units.
+    fn write_node(&mut self, mut start: usize, limit: usize, unit_index: usize) -> usize {
+        let mut value = None;
+        if self.len(start) == unit_index {
+            // An intermediate or final value
+            value = Some(self.value(start));
+            start += 1;
+            if start == limit {
+                return self.write_value_and_final(self.value(start - 1), true);
+            }
+        }
+        // All strings in start..limit are now longer than unit_index
+        let node = if self.unit(start, unit_index) == self.unit(limit - 1, unit_index) {
+            // Linear match
+            let mut last_unit_index = unit_index;
+            while self.unit(start, last_unit_index).is_some()
+                && self.unit(start, last_unit_index) == self.unit(limit - 1, last_unit_index)
+            {
+                last_unit_index += 1;
+            }
+            self.write_node(start, limit, last_unit_index);
+            let mut length = last_unit_index - unit_index;
+            while length > MAX_LINEAR_MATCH_LENGTH {
+                last_unit_index -= MAX_LINEAR_MATCH_LENGTH;
+                length -= MAX_LINEAR_MATCH_LENGTH;
+                self.write_element_units(start, last_unit_index, MAX_LINEAR_MATCH_LENGTH);
+                self.write(&[MIN_LINEAR_MATCH + MAX_LINEAR_MATCH_LENGTH as u16 - 1]);
+            }
+            self.write_element_units(start, unit_index, length);
+            MIN_LINEAR_MATCH + length as u16 - 1
+        } else {
+            // Branch
+            let mut length = 0;
+            let mut i = start;
+            while i < limit {
+                i = self.skip_unit(i, limit, unit_index);
+                length += 1;
+            }
+            self.write_branch_sub_node(start, limit, unit_index, length);
+            length -= 1;
+            if length < MIN_LINEAR_MATCH as usize {
+                length as u16
+            } else {
+                self.write(&[length as u16]);
+                0
+            }
+        };
+        self.write_value_and_type(value, node)
+    }
+
+    /// Returns the index of the first string in `i..limit` whose unit at `unit_index` differs
+    /// from that of the string `i`, or `limit`.
+    fn skip_unit(&self, mut i: usize, limit: usize, unit_index: usize) -> usize {
+        let unit = self.unit(i, unit_index);
+        while i < limit && self.unit(i, unit_index) == unit {
+            i += 1;
+        }
+        i
+    }
+
+    /// Writes the `length` branches of the strings `start..limit` on their unit at
+    /// `unit_index`, splitting them in halves while there are too many for a linear search.
+    fn write_branch_sub_node(
+        &mut self,
+        mut start: usize,
+        limit: usize,
+        unit_index: usize,
+        mut length: usize,
+    ) -> usize {
+        let mut splits = Vec::new();
+        while length > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+            // Branch on the middle unit, encoding the less-than branch first
+            let mut i = start;
+            for _ in 0..length / 2 {
+                i = self.skip_unit(i, limit, unit_index);
+            }
+            let less_than = self.write_branch_sub_node(start, i, unit_index, length / 2);
+            splits.push((self.unit(i, unit_index).unwrap_or_default(), less_than));
+            // Continue with the greater-or-equal branch
+            start = i;
+            length -= length / 2;
+        }
+
+        // The starts of the strings of each unit except the last one, and whether the unit
+        // ends a single string
+        let mut branches = Vec::with_capacity(length - 1);
+        for _ in 0..length - 1 {
+            let i = self.skip_unit(start, limit, unit_index);
+            branches.push((start, start + 1 == i && self.len(start) == unit_index + 1));
+            start = i;
+        }
+
+        // Write the sub-nodes in reverse order, so that the first ones have the shortest jumps
+        let mut jump_targets = Vec::with_capacity(branches.len());
+        let mut branch_limit = start;
+        for &(branch_start, is_final) in branches.iter().rev() {
+            jump_targets.push(if is_final {
+                0
+            } else {
+                self.write_node(branch_start, branch_limit, unit_index + 1)
+            });
+            branch_limit = branch_start;
+        }
+        // The sub-node of the last unit follows it directly
+        self.write_node(start, limit, unit_index + 1);
+        let mut offset = self.write(&[self.unit(start, unit_index).unwrap_or_default()]);
+        for (&(branch_start, is_final), jump_target) in branches.iter().rev().zip(jump_targets) {
+            if is_final {
+                self.write_value_and_final(self.value(branch_start), true);
+            } else {
+                self.write_value_and_final((offset - jump_target) as i32, false);
+            }
+            offset = self.write(&[self.unit(branch_start, unit_index).unwrap_or_default()]);
+        }
+
+        while let Some((middle_unit, less_than)) = splits.pop() {
+            self.write_delta_to(less_than);
+            offset = self.write(&[middle_unit]);
+        }
+        offset
+    }
+
+    fn write_value_and_final(&mut self, value: i32, is_final: bool) -> usize {
+        let final_bit = if is_final { VALUE_IS_FINAL } else { 0 };
+        if (0..=MAX_ONE_UNIT_VALUE).contains(&value) {
+            self.write(&[value as u16 | final_bit])
+        } else if (0..=MAX_TWO_UNIT_VALUE).contains(&value) {
+            self.write(&[
+                (MIN_TWO_UNIT_VALUE_LEAD + (value >> 16) as u16) | final_bit,
+                value as u16,
+            ])
+        } else {
+            self.write(&[
+                THREE_UNIT_VALUE_LEAD | final_bit,
+                (value as u32 >> 16) as u16,
+                value as u16,
+            ])
+        }
+    }
+
+    fn write_value_and_type(&mut self, value: Option<i32>, node: u16) -> usize {
+        match value {
+            None => self.write(&[node]),
+            Some(value @ 0..=MAX_ONE_UNIT_NODE_VALUE) => {
+                self.write(&[((value + 1) << 6) as u16 | node])
+            }
+            Some(value @ 0..=MAX_TWO_UNIT_NODE_VALUE) => self.write(&[
+                (MIN_TWO_UNIT_NODE_VALUE_LEAD + ((value >> 10) as u16 & 0x7fc0)) | node,
+                value as u16,
+            ]),
+            Some(value) => self.write(&[
+                THREE_UNIT_NODE_VALUE_LEAD | node,
+                (value as u32 >> 16) as u16,
+                value as u16,
+            ]),
+        }
+    }
+
+    fn write_delta_to(&mut self, jump_target: usize) -> usize {
+        let delta = self.units.len() - jump_target;
+        if delta <= MAX_ONE_UNIT_DELTA {
+            self.write(&[delta as u16])
+        } else if delta <= MAX_TWO_UNIT_DELTA {
+            self.write(&[MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16, delta as u16])
+        } else {
+            self.write(&[THREE_UNIT_DELTA_LEAD, (delta >> 16) as u16, delta as u16])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+    use icu_collections::char16trie::{Char16Trie, TrieResult};
+
+    fn lookup(trie: &Char16Trie, word: &str) -> TrieResult {
+        let mut iter = trie.iter();
+        let mut result = TrieResult::NoValue;
+        for c in word.chars() {
+            result = iter.next(c);
+        }
+        result
+    }
+
+    #[test]
+    fn test_from_words() {
+        // Long linear matches, wide branches, supplementary code points, and values of all sizes
+        let mut words: Vec<(String, Option<i32>)> = (0..300)
+            .map(|i| (format!("{i}"), Some(i * 1009)))
+            .chain([
+                ("a".into(), None),
+                ("abcdefghijklmnopqrstuvwxyz".into(), Some(-1)),
+                (
+                    "abcdefghijklmnopqrstuvwxyz0123456789".into(),
+                    Some(0x4000_0000),
+                ),
+                ("abcdefghijklmnopq".into(), Some(0x0100_0000)),
+                ("𝔘𝔫𝔦𝔠𝔬𝔡𝔢".into(), Some(7)),
+                ("𝔘𝔫".into(), Some(i32::MIN)),
+                ("กขค".into(), Some(0x3fff)),
+                ("กข".into(), Some(0xfe0000)),
+            ])
+            .collect();
+        words.extend((0x4e00..0x4f00).map(|c| {
+            (
+                String::from(char::from_u32(c).unwrap()),
+                Some(c as i32 * 4000),
+            )
+        }));
+        let data = UCharDictionaryBreakDataV1::from_words(
+            words.iter().map(|(word, weight)| (word.as_str(), *weight)),
+        );
+        let trie = Char16Trie::new(data.trie_data);
+
+        for (word, weight) in &words {
+            let weight = weight.unwrap_or(0);
+            let result = lookup(&trie, word);
+            let is_prefix = words
+                .iter()
+                .any(|(other, _)| other != word && other.starts_with(word.as_str()));
+            if is_prefix {
+                assert_eq!(result, TrieResult::Intermediate(weight), "{word}");
+            } else {
+                assert_eq!(result, TrieResult::FinalValue(weight), "{word}");
+            }
+        }
+
+        assert_eq!(lookup(&trie, "abc"), TrieResult::NoValue);
+        assert_eq!(lookup(&trie, "abd"), TrieResult::NoMatch);
+        assert_eq!(lookup(&trie, "300"), TrieResult::NoMatch);
+        assert_eq!(lookup(&trie, "𝔘𝔫𝔦"), TrieResult::NoValue);
+    }
+
+    #[test]
+    fn test_from_no_words() {
+        let data = UCharDictionaryBreakDataV1::from_words([("", Some(1))]);
+        assert!(data.trie_data.is_empty());
+    }
+}
@@ -3,7 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::*;
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::incremental::resegment;
+use crate::indices::{
+    ChunkCharIndices, InputIndices, Latin1Indices, ResumableBreakIterator, Utf16Indices,
+};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::provider::*;
 use crate::rule_segmenter::*;
@@ -11,6 +14,8 @@ use crate::SegmenterError;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Range;
 use core::str::CharIndices;
 use icu_provider::prelude::*;
 use utf8_iter::Utf8CharIndices;
@@ -34,6 +39,50 @@ pub struct WordBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized>(
 
 derive_usize_iterator_with_type!(WordBreakIterator);
 
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> WordBreakIterator<'l, 's, Y> {
+    /// Returns the first word boundary after `offset`, or `None` if `offset` is at or
+    /// after the end of the string.
+    ///
+    /// This does not change the position of the iterator. Segmentation resumes at the last
+    /// position before `offset` at which the rules always break, such as between a letter and
+    /// a space, instead of at the start of the string. Its cost is therefore linear in the
+    /// distance to that position, which is usually the length of a word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::WordSegmenter;
+    ///
+    /// let segmenter = WordSegmenter::new_auto();
+    /// let text = "Hello World.\nXin chào thế giới!";
+    /// let iter = segmenter.segment_str(text);
+    ///
+    /// assert_eq!(iter.following(2), Some(5));
+    /// assert_eq!(iter.preceding(16), Some(13));
+    /// assert!(iter.is_boundary(17));
+    /// assert!(!iter.is_boundary(18));
+    /// ```
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        self.0.following(offset)
+    }
+
+    /// Returns the last word boundary before `offset`, or `None` if `offset` is 0.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        self.0.preceding(offset)
+    }
+
+    /// Returns whether there is a word boundary at `offset`.
+    ///
+    /// This does not change the position of the iterator. See [`Self::following()`] for
+    /// an example.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        self.0.is_boundary(offset)
+    }
+}
+
 /// The word type tag that is returned by [`WordBreakIterator::word_type()`].
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -44,8 +93,12 @@ pub enum WordType {
     None = 0,
     /// Number category tag.
     Number = 1,
-    /// Letter category tag, including CJK.
+    /// Letter category tag, including Southeast Asian scripts such as Thai.
     Letter = 2,
+    /// Kana category tag, for Hiragana and Katakana words.
+    Kana = 3,
+    /// Ideographic category tag, for words containing ideographs such as Chinese characters.
+    Ideo = 4,
 }
 
 impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> WordBreakIterator<'l, 's, Y> {
@@ -55,7 +108,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> WordBreakIterator<'l, 's, Y> {
         self.0.word_type()
     }
     /// Returns `true` when the segment preceding the current boundary is word-like,
-    /// such as letter, number, or CJK.
+    /// such as letter, number, kana, or ideographic.
     #[inline]
     pub fn is_word_like(&self) -> bool {
         self.0.is_word_like()
@@ -83,6 +136,11 @@ pub type WordBreakIteratorLatin1<'l, 's> = WordBreakIterator<'l, 's, RuleBreakTy
 /// For examples of use, see [`WordSegmenter`].
 pub type WordBreakIteratorUtf16<'l, 's> = WordBreakIterator<'l, 's, WordBreakTypeUtf16>;
 
+/// Word break iterator for a UTF-8 string split into chunks, such as a rope.
+///
+/// For examples of use, see [`WordSegmenter::segment_chunks()`].
+pub type WordBreakIteratorChunks<'l, 's, I> = WordBreakIterator<'l, 's, WordBreakTypeChunks<I>>;
+
 /// Supports loading word break data, and creating word break iterators for different string
 /// encodings.
 ///
@@ -152,7 +210,10 @@ pub type WordBreakIteratorUtf16<'l, 's> = WordBreakIterator<'l, 's, WordBreakTyp
 #[derive(Debug)]
 pub struct WordSegmenter {
     payload: DataPayload<WordBreakDataV1Marker>,
-    complex: ComplexPayloads,
+    complex: Option<ComplexPayloads>,
+    /// Whether the boundaries after a hard line break do not depend on the text before it,
+    /// which holds for the Unicode rules, but not necessarily for custom rules.
+    resume_at_hard_breaks: bool,
 }
 
 impl WordSegmenter {
@@ -191,7 +252,8 @@ impl WordSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
-            complex: ComplexPayloads::new_auto(),
+            complex: Some(ComplexPayloads::new_auto()),
+            resume_at_hard_breaks: true,
         }
     }
 
@@ -222,7 +284,8 @@ impl WordSegmenter {
     {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
-            complex: ComplexPayloads::try_new_auto(provider)?,
+            complex: Some(ComplexPayloads::try_new_auto(provider)?),
+            resume_at_hard_breaks: true,
         })
     }
 
@@ -266,7 +329,8 @@ impl WordSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
-            complex: ComplexPayloads::new_lstm(),
+            complex: Some(ComplexPayloads::new_lstm()),
+            resume_at_hard_breaks: true,
         }
     }
 
@@ -296,7 +360,8 @@ impl WordSegmenter {
     {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
-            complex: ComplexPayloads::try_new_lstm(provider)?,
+            complex: Some(ComplexPayloads::try_new_lstm(provider)?),
+            resume_at_hard_breaks: true,
         })
     }
 
@@ -334,7 +399,8 @@ impl WordSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
-            complex: ComplexPayloads::new_dict(),
+            complex: Some(ComplexPayloads::new_dict()),
+            resume_at_hard_breaks: true,
         }
     }
 
@@ -363,7 +429,145 @@ impl WordSegmenter {
     {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
-            complex: ComplexPayloads::try_new_dict(provider)?,
+            complex: Some(ComplexPayloads::try_new_dict(provider)?),
+            resume_at_hard_breaks: true,
+        })
+    }
+
+    /// Constructs a [`WordSegmenter`] with an invariant locale and compiled dictionary data for
+    /// complex scripts (Chinese, Japanese, Khmer, Lao, Myanmar, and Thai), with a user dictionary
+    /// layered over the built-in dictionaries, for words such as product names and slang.
+    ///
+    /// See [`UCharDictionaryBreakDataV1::from_words()`] for building a user dictionary, and how
+    /// its words are weighed against the built-in ones.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::segmenter::provider::UCharDictionaryBreakDataV1;
+    /// use icu::segmenter::WordSegmenter;
+    ///
+    /// let th_str = "ทุกสองสัปดาห์";
+    ///
+    /// let segmenter = WordSegmenter::new_dictionary();
+    /// let th_bps = segmenter.segment_str(th_str).collect::<Vec<_>>();
+    /// assert_eq!(th_bps, [0, 9, 18, 39]);
+    ///
+    /// // "สองสัปดาห์" (two weeks) is one word
+    /// let user_dictionary =
+    ///     UCharDictionaryBreakDataV1::from_words([("สองสัปดาห์", None)]);
+    /// let segmenter =
+    ///     WordSegmenter::new_dictionary_with_user_dictionary(user_dictionary);
+    /// let th_bps = segmenter.segment_str(th_str).collect::<Vec<_>>();
+    /// assert_eq!(th_bps, [0, 9, 39]);
+    /// ```
+    #[cfg(feature = "experimental")]
+    #[cfg(feature = "compiled_data")]
+    pub fn new_dictionary_with_user_dictionary(
+        user_dictionary: UCharDictionaryBreakDataV1<'static>,
+    ) -> Self {
+        Self {
+            payload: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
+            ),
+            complex: Some(ComplexPayloads::new_dict().with_user_dictionary(user_dictionary)),
+            resume_at_hard_breaks: true,
+        }
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new_dictionary_with_user_dictionary)]
+    #[cfg(feature = "experimental")]
+    pub fn try_new_dictionary_with_user_dictionary_unstable<D>(
+        provider: &D,
+        user_dictionary: UCharDictionaryBreakDataV1<'static>,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<DictionaryForWordOnlyAutoV1Marker>
+            + DataProvider<DictionaryForWordLineExtendedV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: Some(
+                ComplexPayloads::try_new_dict(provider)?.with_user_dictionary(user_dictionary),
+            ),
+            resume_at_hard_breaks: true,
+        })
+    }
+
+    /// Constructs a [`WordSegmenter`] from rules in the ICU break rule syntax, for tailoring
+    /// word boundaries to a domain, such as keeping hashtags together.
+    ///
+    /// See [`RuleBreakDataV1::try_from_rules()`] for the supported syntax. The status tags of
+    /// the rules give the [`WordType`] of the words. Complex scripts are not segmented with a
+    /// dictionary or LSTM model.
+    ///
+    /// Custom rules may match across hard line breaks, so [`Self::resegment_chunks()`] segments
+    /// from the start of the string.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::segmenter::{WordSegmenter, WordType};
+    ///
+    /// let segmenter = WordSegmenter::try_new_with_rules(
+    ///     r"
+    ///     !!chain;
+    ///     $Letter = [\p{Alphabetic}];
+    ///     $Digit = [\p{Nd}];
+    ///     $Letter+ {200};
+    ///     $Digit+ {100};
+    ///     $Letter '+'+ {200};
+    ///     '#' $Letter+ {200};
+    ///     ",
+    /// )
+    /// .expect("rules should be valid");
+    ///
+    /// let text = "I like C++ #rust 42";
+    /// let mut iter = segmenter.segment_str(text);
+    /// let words: Vec<(&str, WordType)> =
+    ///     std::iter::from_fn(|| Some((iter.next()?, iter.word_type())))
+    ///         .collect::<Vec<_>>()
+    ///         .windows(2)
+    ///         .map(|w| (&text[w[0].0..w[1].0], w[1].1))
+    ///         .filter(|(_, word_type)| *word_type != WordType::None)
+    ///         .collect();
+    ///
+    /// assert_eq!(
+    ///     words,
+    ///     [
+    ///         ("I", WordType::Letter),
+    ///         ("like", WordType::Letter),
+    ///         ("C++", WordType::Letter),
+    ///         ("#rust", WordType::Letter),
+    ///         ("42", WordType::Number),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(all(feature = "compiled_data", feature = "experimental"))]
+    pub fn try_new_with_rules(rules: &str) -> Result<Self, SegmenterError> {
+        Ok(Self {
+            payload: DataPayload::from_owned(RuleBreakDataV1::try_from_rules(rules)?),
+            complex: None,
+            resume_at_hard_breaks: false,
         })
     }
 
@@ -377,8 +581,10 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
-            complex: Some(&self.complex),
+            complex: self.complex.as_ref(),
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
 
@@ -397,8 +603,10 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
-            complex: Some(&self.complex),
+            complex: self.complex.as_ref(),
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
 
@@ -412,8 +620,10 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
-            complex: Some(&self.complex),
+            complex: self.complex.as_ref(),
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
 
@@ -427,10 +637,99 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
-            complex: Some(&self.complex),
+            complex: self.complex.as_ref(),
+            boundary_property: 0,
+            complex_word_type: None,
+            input,
+        })
+    }
+
+    /// Creates a word break iterator for a UTF-8 string split into chunks, such as the leaves
+    /// of a rope.
+    ///
+    /// The boundaries are offsets from the start of the first chunk, and chunk edges need not
+    /// be word boundaries. Runs of text that need a dictionary or LSTM model may span several
+    /// chunks.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::WordSegmenter;
+    ///
+    /// let segmenter = WordSegmenter::new_auto();
+    /// let chunks = ["Hello Wo", "rld. Xin ch", "ào!"];
+    ///
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_chunks(chunks.into_iter()).collect();
+    /// assert_eq!(&breakpoints, &[0, 5, 6, 11, 12, 13, 16, 17, 22, 23]);
+    /// ```
+    pub fn segment_chunks<'l, 's, I>(&'l self, chunks: I) -> WordBreakIteratorChunks<'l, 's, I>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        let input = ChunkCharIndices::new(chunks);
+        WordBreakIterator(RuleBreakIterator {
+            iter: input.clone(),
+            len: ChunkCharIndices::input_len(input.clone()),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            complex: self.complex.as_ref(),
             boundary_property: 0,
+            complex_word_type: None,
+            input,
         })
     }
+
+    /// Updates the word boundaries of a UTF-8 string split into chunks after the bytes in
+    /// `replaced` were replaced by `new_len` bytes.
+    ///
+    /// `boundaries` are the boundaries before the edit, such as those returned by
+    /// [`Self::segment_chunks()`], and `chunks_at` returns the chunks of the edited string
+    /// starting at the given byte offset. Only the text between the last hard line break
+    /// before the edit and the first hard line break after it, such as line feeds, is
+    /// segmented again. This range of the edited string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::WordSegmenter;
+    ///
+    /// let segmenter = WordSegmenter::new_auto();
+    /// let mut text = String::from("one two\nthree four\nfive");
+    /// let mut boundaries: Vec<usize> =
+    ///     segmenter.segment_chunks([text.as_str()].into_iter()).collect();
+    ///
+    /// // Join "three" and "four".
+    /// text.replace_range(13..14, "_");
+    /// let resegmented = segmenter.resegment_chunks(&mut boundaries, 13..14, 1, |i| {
+    ///     [&text[i..]].into_iter()
+    /// });
+    ///
+    /// assert_eq!(resegmented, 8..19);
+    /// assert_eq!(&boundaries, &[0, 3, 4, 7, 8, 18, 19, 23]);
+    /// ```
+    pub fn resegment_chunks<'s, I>(
+        &self,
+        boundaries: &mut Vec<usize>,
+        replaced: Range<usize>,
+        new_len: usize,
+        chunks_at: impl FnMut(usize) -> I,
+    ) -> Range<usize>
+    where
+        I: Iterator<Item = &'s str> + Clone,
+    {
+        resegment(
+            boundaries,
+            replaced,
+            new_len,
+            self.resume_at_hard_breaks,
+            chunks_at,
+            |chunks| self.segment_chunks(chunks),
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -471,6 +770,25 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypePotentiallyIllFormedUtf8 {
     }
 }
 
+#[derive(Debug)]
+pub struct WordBreakTypeChunks<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> RuleBreakType<'l, 's> for WordBreakTypeChunks<I> {
+    type IterAttr = ChunkCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        iter: &mut RuleBreakIterator<'l, 's, Self>,
+        left_codepoint: Self::CharType,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
+    }
+}
+
 /// handle_complex_language impl for UTF8 iterators
 fn handle_complex_language_utf8<'l, 's, T>(
     iter: &mut RuleBreakIterator<'l, 's, T>,
@@ -489,7 +807,7 @@ where
         s.push(iter.get_current_codepoint()?);
         iter.advance_iter();
         if let Some(current_break_property) = iter.get_current_break_property() {
-            if current_break_property != iter.data.complex_property {
+            if !iter.is_complex_property(current_break_property) {
                 break;
             }
         } else {
@@ -501,15 +819,18 @@ where
     // Restore iterator to move to head of complex string
     iter.iter = start_iter;
     iter.current_pos_data = start_point;
-    #[allow(clippy::unwrap_used)] // iter.complex present for word segmenter
+    #[allow(clippy::unwrap_used)]
+    // iter.complex present for word segmenter data with a complex property
     let breaks = complex_language_segment_str(iter.complex.unwrap(), &s);
     iter.result_cache = breaks;
     let first_pos = *iter.result_cache.first()?;
     let mut i = left_codepoint.len_utf8();
+    let mut word_type = iter.complex_word_type_with(WordType::Letter, left_codepoint);
     loop {
         if i == first_pos {
             // Re-calculate breaking offset
             iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
+            iter.complex_word_type = Some(word_type);
             return iter.get_current_position();
         }
         debug_assert!(
@@ -518,9 +839,13 @@ where
             iter.get_current_position()
         );
         i += T::get_current_position_character_len(iter);
+        if let Some(c) = iter.get_current_codepoint() {
+            word_type = iter.complex_word_type_with(word_type, c);
+        }
         iter.advance_iter();
         if iter.is_eof() {
             iter.result_cache.clear();
+            iter.complex_word_type = Some(word_type);
             return Some(iter.len);
         }
     }
@@ -554,7 +879,7 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf16 {
             s.push(iter.get_current_codepoint()? as u16);
             iter.advance_iter();
             if let Some(current_break_property) = iter.get_current_break_property() {
-                if current_break_property != iter.data.complex_property {
+                if !iter.is_complex_property(current_break_property) {
                     break;
                 }
             } else {
@@ -566,16 +891,19 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf16 {
         // Restore iterator to move to head of complex string
         iter.iter = start_iter;
         iter.current_pos_data = start_point;
-        #[allow(clippy::unwrap_used)] // iter.complex present for word segmenter
+        #[allow(clippy::unwrap_used)]
+        // iter.complex present for word segmenter data with a complex property
         let breaks = complex_language_segment_utf16(iter.complex.unwrap(), &s);
         iter.result_cache = breaks;
         // result_cache vector is utf-16 index that is in BMP.
         let first_pos = *iter.result_cache.first()?;
         let mut i = 1;
+        let mut word_type = iter.complex_word_type_with(WordType::Letter, left_codepoint);
         loop {
             if i == first_pos {
                 // Re-calculate breaking offset
                 iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
+                iter.complex_word_type = Some(word_type);
                 return iter.get_current_position();
             }
             debug_assert!(
@@ -584,9 +912,13 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf16 {
                 iter.get_current_position()
             );
             i += 1;
+            if let Some(c) = iter.get_current_codepoint() {
+                word_type = iter.complex_word_type_with(word_type, c);
+            }
             iter.advance_iter();
             if iter.is_eof() {
                 iter.result_cache.clear();
+                iter.complex_word_type = Some(word_type);
                 return Some(iter.len);
             }
         }
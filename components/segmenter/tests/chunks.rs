@@ -0,0 +1,137 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu::locid::locale;
+use icu_segmenter::GraphemeClusterSegmenter;
+use icu_segmenter::LineSegmenter;
+use icu_segmenter::SentenceSegmenter;
+use icu_segmenter::WordSegmenter;
+
+const TEXTS: &[&str] = &[
+    "",
+    "a",
+    "Hello World. Xin chào thế giới!",
+    "First line.\nSecond line.\r\nThird line.\rFourth line.",
+    "Mr. Smith went\u{85}to Washington.\u{2028}He said: \"Hi!\"\u{2029}Bye.",
+    "Dr. Mrs. Ms. Smith. Mr.\nJones, Ph.D. Visited the U.S. Today.  ",
+    "नमस्ते 👨‍👩‍👧 e\u{301}\u{302} 🇺🇸🇫🇷\nagain",
+    "ภาษาไทยภาษาไทย\nພາສາລາວ ภาษาไทย. 今日は良い天気ですね。",
+];
+
+/// Splits `text` into chunks of `n` characters.
+fn chunks_of(text: &str, n: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let len = rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+        let (chunk, tail) = rest.split_at(len);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+/// Edits as the replaced range and the replacement.
+const EDITS: &[(std::ops::Range<usize>, &str)] = &[
+    (0..0, "Start. "),
+    (0..1, ""),
+    (3..3, "\n"),
+    (3..4, "\r\n"),
+    (5..5, "x y. Z"),
+    (1..8, ""),
+];
+
+macro_rules! check_chunks {
+    ($segmenter:expr) => {
+        let segmenter = $segmenter;
+        for text in TEXTS {
+            let expected: Vec<usize> = segmenter.segment_str(text).collect();
+            for n in [1, 2, 3, 7, 100] {
+                let chunks = chunks_of(text, n);
+                let actual: Vec<usize> = segmenter.segment_chunks(chunks.iter().copied()).collect();
+                assert_eq!(actual, expected, "{text:?} in chunks of {n}");
+
+                let iter = segmenter.segment_chunks(chunks.iter().copied());
+                for offset in 0..=text.len() {
+                    assert_eq!(
+                        iter.following(offset),
+                        expected.iter().copied().find(|&i| i > offset),
+                        "following({offset}) in {text:?}"
+                    );
+                    assert_eq!(
+                        iter.is_boundary(offset),
+                        expected.contains(&offset),
+                        "is_boundary({offset}) in {text:?}"
+                    );
+                }
+            }
+
+            for (replaced, replacement) in EDITS {
+                if replaced.end > text.len()
+                    || !text.is_char_boundary(replaced.start)
+                    || !text.is_char_boundary(replaced.end)
+                {
+                    continue;
+                }
+                let mut edited = text.to_string();
+                edited.replace_range(replaced.clone(), replacement);
+                let mut boundaries: Vec<usize> = segmenter.segment_str(text).collect();
+                let resegmented = segmenter.resegment_chunks(
+                    &mut boundaries,
+                    replaced.clone(),
+                    replacement.len(),
+                    |i| chunks_of(&edited[i..], 3).into_iter(),
+                );
+                let expected: Vec<usize> = segmenter.segment_str(&edited).collect();
+                assert_eq!(
+                    boundaries, expected,
+                    "{text:?} with {replaced:?} replaced by {replacement:?}"
+                );
+                assert!(resegmented.start <= replaced.start);
+                assert!(resegmented.end >= replaced.start + replacement.len());
+            }
+        }
+    };
+}
+
+#[test]
+fn grapheme_chunks() {
+    check_chunks!(GraphemeClusterSegmenter::new());
+}
+
+#[test]
+fn word_chunks() {
+    check_chunks!(WordSegmenter::new_dictionary());
+}
+
+#[test]
+fn sentence_chunks() {
+    check_chunks!(SentenceSegmenter::new());
+}
+
+#[test]
+fn sentence_suppressions_chunks() {
+    check_chunks!(SentenceSegmenter::try_new_with_options(
+        &locale!("en-u-ss-standard").into(),
+        Default::default()
+    )
+    .unwrap());
+}
+
+#[test]
+fn line_chunks() {
+    check_chunks!(LineSegmenter::new_dictionary());
+}
+
+#[test]
+fn resegment_only_edited_paragraph() {
+    let segmenter = WordSegmenter::new_dictionary();
+    let text = "one two\nthree four\nfive";
+    let mut boundaries: Vec<usize> = segmenter.segment_str(text).collect();
+    let edited = "one two\nthree fourteen\nfive";
+    let resegmented =
+        segmenter.resegment_chunks(&mut boundaries, 18..18, 4, |i| [&edited[i..]].into_iter());
+    assert_eq!(resegmented, 8..23);
+    assert_eq!(boundaries, [0, 3, 4, 7, 8, 13, 14, 22, 23, 27]);
+}
@@ -0,0 +1,277 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::{
+    GraphemeClusterSegmenter, SegmenterError, SentenceSegmenter, WordSegmenter, WordType,
+};
+
+const WORD_RULES: &str = r"
+!!chain;
+!!quoted_literals_only;
+
+$Letter = [\p{Alphabetic}];
+$Digit = [\p{Nd}];
+$Local = [$Letter $Digit . _ %+\-];
+$Domain = [$Letter $Digit \-];
+
+# Words and numbers
+$Letter+ {200};
+$Digit+ ('.' $Digit+)? {100};
+
+# C++, F#, and hashtags
+$Letter ('+' '+' | '#') {200};
+'#' $Letter ($Letter | $Digit)* {200};
+
+# Email addresses
+$Local+ '@' $Domain+ ('.' $Domain+)+ {200};
+
+!!reverse;
+# Ignored, as in ICU4C
+$Letter $Letter;
+";
+
+fn words(segmenter: &WordSegmenter, text: &str) -> Vec<(String, WordType)> {
+    let mut iter = segmenter.segment_str(text);
+    let boundaries: Vec<(usize, WordType)> =
+        std::iter::from_fn(|| Some((iter.next()?, iter.word_type()))).collect();
+    boundaries
+        .windows(2)
+        .map(|w| (text[w[0].0..w[1].0].to_string(), w[1].1))
+        .collect()
+}
+
+#[test]
+fn word_rules() {
+    let segmenter = WordSegmenter::try_new_with_rules(WORD_RULES).unwrap();
+
+    let text = "Write C++ and F# to me@example.com, #icu4x #1 3.14";
+    assert_eq!(
+        words(&segmenter, text),
+        [
+            ("Write", WordType::Letter),
+            (" ", WordType::None),
+            ("C++", WordType::Letter),
+            (" ", WordType::None),
+            ("and", WordType::Letter),
+            (" ", WordType::None),
+            ("F#", WordType::Letter),
+            (" ", WordType::None),
+            ("to", WordType::Letter),
+            (" ", WordType::None),
+            ("me@example.com", WordType::Letter),
+            (",", WordType::None),
+            (" ", WordType::None),
+            ("#icu4x", WordType::Letter),
+            (" ", WordType::None),
+            ("#", WordType::None),
+            ("1", WordType::Number),
+            (" ", WordType::None),
+            ("3.14", WordType::Number),
+        ]
+        .map(|(word, word_type)| (word.to_string(), word_type))
+    );
+}
+
+#[test]
+fn word_rules_longest_match() {
+    let segmenter = WordSegmenter::try_new_with_rules(WORD_RULES).unwrap();
+
+    // Falls back to the longest match when a longer rule fails
+    for (text, expected) in [
+        ("C+", vec!["C", "+"]),
+        ("john.doe", vec!["john", ".", "doe"]),
+        ("john.doe@example", vec!["john", ".", "doe", "@", "example"]),
+        ("me@example.com.", vec!["me@example.com", "."]),
+        ("3.", vec!["3", "."]),
+    ] {
+        assert_eq!(
+            words(&segmenter, text)
+                .into_iter()
+                .map(|(word, _)| word)
+                .collect::<Vec<_>>(),
+            expected,
+            "{text}"
+        );
+    }
+
+    // All encodings agree
+    let text = "Write C++ to me@example.com";
+    let utf8: Vec<usize> = segmenter.segment_str(text).collect();
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    assert_eq!(segmenter.segment_utf16(&utf16).collect::<Vec<_>>(), utf8);
+    assert_eq!(
+        segmenter
+            .segment_latin1(text.as_bytes())
+            .collect::<Vec<_>>(),
+        utf8
+    );
+}
+
+#[test]
+fn word_rules_kana_and_ideo() {
+    let segmenter = WordSegmenter::try_new_with_rules(
+        r"
+        [\p{Alphabetic}-[\p{Hiragana}\p{Katakana}\p{Han}]]+ {200};
+        [\p{Hiragana}\p{Katakana}\u30FC]+ {300};
+        \p{Han}+ {400};
+        ",
+    )
+    .unwrap();
+
+    let text = "ICUのライブラリ漢字";
+    assert_eq!(
+        words(&segmenter, text),
+        [
+            ("ICU", WordType::Letter),
+            ("のライブラリ", WordType::Kana),
+            ("漢字", WordType::Ideo),
+        ]
+        .map(|(word, word_type)| (word.to_string(), word_type))
+    );
+}
+
+#[test]
+fn grapheme_and_sentence_rules() {
+    let segmenter = GraphemeClusterSegmenter::try_new_with_rules(
+        r"
+        $CR = \u000D;
+        $LF = \u000A;
+        $Extend = [\p{Grapheme_Extend}];
+        $CR $LF;
+        [^$CR $LF] $Extend+;
+        ",
+    )
+    .unwrap();
+    let text = "e\u{301}\r\nx";
+    assert_eq!(
+        segmenter.segment_str(text).collect::<Vec<_>>(),
+        [0, 3, 5, 6]
+    );
+
+    let segmenter = SentenceSegmenter::try_new_with_rules(
+        r"
+        [^.?!]+ ([.?!]+ ' '*)?;
+        ",
+    )
+    .unwrap();
+    let text = "Hi there. How are you? Fine";
+    assert_eq!(
+        segmenter.segment_str(text).collect::<Vec<_>>(),
+        [0, 10, 23, 27]
+    );
+}
+
+/// The forward rules of ICU4C's `char.txt`, without the look-ahead rule for regional indicators.
+const GRAPHEME_RULES: &str = r"
+!!chain;
+!!lookAheadHardBreak;
+
+$CR          = [\p{Grapheme_Cluster_Break = CR}];
+$LF          = [\p{Grapheme_Cluster_Break = LF}];
+$Control     = [[\p{Grapheme_Cluster_Break = Control}]];
+$Extend      = [[\p{Grapheme_Cluster_Break = Extend}]];
+$ZWJ         = [\p{Grapheme_Cluster_Break = ZWJ}];
+$Regional_Indicator = [\p{Grapheme_Cluster_Break = Regional_Indicator}];
+$Prepend     = [\p{Grapheme_Cluster_Break = Prepend}];
+$SpacingMark = [\p{Grapheme_Cluster_Break = SpacingMark}];
+$L       = [\p{Grapheme_Cluster_Break = L}];
+$V       = [\p{Grapheme_Cluster_Break = V}];
+$T       = [\p{Grapheme_Cluster_Break = T}];
+$LV      = [\p{Grapheme_Cluster_Break = LV}];
+$LVT     = [\p{Grapheme_Cluster_Break = LVT}];
+$Extended_Pict = [\p{Extended_Pictographic}];
+
+# GB 3
+$CR $LF;
+
+# GB 6 - GB 8
+$L ($L | $V | $LV | $LVT);
+($LV | $V) ($V | $T);
+($LVT | $T) $T;
+
+# GB 9 - GB 9a
+[^$Control $CR $LF] ($Extend | $ZWJ);
+[^$Control $CR $LF] $SpacingMark;
+
+# GB 9b
+$Prepend [^$Control $CR $LF];
+
+# GB 11
+$Extended_Pict $Extend* $ZWJ $Extended_Pict;
+
+# GB 12 - GB 13
+^$Prepend* $Regional_Indicator $Regional_Indicator;
+";
+
+#[test]
+fn grapheme_rules_match_built_in_segmenter() {
+    let segmenter = GraphemeClusterSegmenter::try_new_with_rules(GRAPHEME_RULES).unwrap();
+    let built_in = GraphemeClusterSegmenter::new();
+
+    for text in [
+        "e\u{301}\r\n\r\rx\n",
+        "🇺🇸🇫🇷🇩🇪",
+        "a🇺🇸🇫b",
+        "👨\u{200d}👩\u{200d}👧 ❤\u{fe0f}\u{200d}🔥",
+        "각\u{1100}\u{1161}\u{11a8}한국어",
+        "नमस्ते क्षत्रिय",
+        "\u{600}\u{661}x\u{0}\u{308}",
+    ] {
+        assert_eq!(
+            segmenter.segment_str(text).collect::<Vec<_>>(),
+            built_in.segment_str(text).collect::<Vec<_>>(),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn too_complex_rules() {
+    // Every prefix of the rule is a state of its own
+    assert_eq!(
+        WordSegmenter::try_new_with_rules(
+            "'abcdefghijklmnopqrstuvwxyz' 'abcdefghijklmnopqrstuvwxyz' 'abcdefghijklmnopqrstuvwxyz';"
+        )
+        .unwrap_err(),
+        SegmenterError::TooComplexRules
+    );
+}
+
+#[test]
+fn invalid_rules() {
+    assert_eq!(
+        WordSegmenter::try_new_with_rules("$Letter = [\\p{Alphabetic}];\n$Leter+;").unwrap_err(),
+        SegmenterError::InvalidRules(28)
+    );
+    assert_eq!(
+        WordSegmenter::try_new_with_rules("!!quoted_literals_only; [a-z] x;").unwrap_err(),
+        SegmenterError::InvalidRules(30)
+    );
+    assert_eq!(
+        WordSegmenter::try_new_with_rules("[a-z+;").unwrap_err(),
+        SegmenterError::InvalidRules(6)
+    );
+}
+
+#[test]
+fn rules_across_hard_breaks() {
+    // Unlike the Unicode rules, these rules do not break after a line feed.
+    let segmenter = WordSegmenter::try_new_with_rules(r"[a-z\u000A]+ {200};").unwrap();
+    let text = "ab\ncd";
+    let iter = segmenter.segment_str(text);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 5]);
+
+    let iter = segmenter.segment_str(text);
+    assert!(!iter.is_boundary(3));
+    assert_eq!(iter.following(3), Some(5));
+    assert_eq!(iter.preceding(5), Some(0));
+
+    // Inserting a line feed between "c" and "d" segments the whole string again.
+    let mut boundaries: Vec<usize> = segmenter.segment_chunks([text].into_iter()).collect();
+    let text = "ab\nc\nd";
+    let resegmented =
+        segmenter.resegment_chunks(&mut boundaries, 4..4, 1, |i| [&text[i..]].into_iter());
+    assert_eq!(resegmented, 0..6);
+    assert_eq!(boundaries, [0, 6]);
+}
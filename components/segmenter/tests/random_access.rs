@@ -0,0 +1,138 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu::locid::locale;
+use icu_segmenter::GraphemeClusterSegmenter;
+use icu_segmenter::LineSegmenter;
+use icu_segmenter::SentenceSegmenter;
+use icu_segmenter::WordSegmenter;
+
+const TEXTS: &[&str] = &[
+    "",
+    "a",
+    "Hello World. Xin chào thế giới!",
+    "First line.\nSecond line.\r\nThird line.\rFourth line.",
+    "Mr. Smith went\u{85}to Washington.\u{2028}He said: \"Hi!\"\u{2029}Bye.",
+    "Dr. Mrs. Ms. Smith. Mr.\nJones, Ph.D. Visited the U.S. Today.  ",
+    "\n\n\r\n\r\r\u{85}",
+    "नमस्ते 👨‍👩‍👧 e\u{301}\u{302} 🇺🇸🇫🇷\nagain",
+    "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod?\nTempor!",
+    "Can't stop at 3.14 or 1,000.5, U.S.A. -- e.g. \"quoted.\" (Really?) Yes.",
+    "🇺🇸🇫🇷🇩🇪x 🇺🇸🇫 a\u{200D}👨 👍🏽\u{200D}\u{200D} \u{5E9}\u{5C1}\"\u{5D1} \u{5D0}'s",
+    "tab\tand  spaces \u{A0}\u{AD}soft-hyphen\u{300}\u{300} end",
+];
+
+/// Checks the random access methods at every offset against the boundaries
+/// returned by iterating from the start.
+fn check(
+    text: &str,
+    len: usize,
+    all: &[usize],
+    following: impl Fn(usize) -> Option<usize>,
+    preceding: impl Fn(usize) -> Option<usize>,
+    is_boundary: impl Fn(usize) -> bool,
+) {
+    for offset in 0..=len + 1 {
+        assert_eq!(
+            following(offset),
+            all.iter().copied().find(|&i| i > offset),
+            "following({offset}) in {text:?}"
+        );
+        assert_eq!(
+            preceding(offset),
+            all.iter().copied().filter(|&i| i < offset).last(),
+            "preceding({offset}) in {text:?}"
+        );
+        assert_eq!(
+            is_boundary(offset),
+            all.contains(&offset),
+            "is_boundary({offset}) in {text:?}"
+        );
+    }
+}
+
+macro_rules! check_all_encodings {
+    ($segmenter:expr) => {
+        let segmenter = $segmenter;
+        for text in TEXTS {
+            let iter = segmenter.segment_str(text);
+            let all: Vec<usize> = segmenter.segment_str(text).collect();
+            check(
+                text,
+                text.len(),
+                &all,
+                |i| iter.following(i),
+                |i| iter.preceding(i),
+                |i| iter.is_boundary(i),
+            );
+
+            let utf16: Vec<u16> = text.encode_utf16().collect();
+            let iter = segmenter.segment_utf16(&utf16);
+            let all: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+            check(
+                text,
+                utf16.len(),
+                &all,
+                |i| iter.following(i),
+                |i| iter.preceding(i),
+                |i| iter.is_boundary(i),
+            );
+
+            if text.chars().all(|c| (c as u32) < 0x100) {
+                let latin1: Vec<u8> = text.chars().map(|c| c as u8).collect();
+                let iter = segmenter.segment_latin1(&latin1);
+                let all: Vec<usize> = segmenter.segment_latin1(&latin1).collect();
+                check(
+                    text,
+                    latin1.len(),
+                    &all,
+                    |i| iter.following(i),
+                    |i| iter.preceding(i),
+                    |i| iter.is_boundary(i),
+                );
+            }
+        }
+    };
+}
+
+#[test]
+fn grapheme_random_access() {
+    check_all_encodings!(GraphemeClusterSegmenter::new());
+}
+
+#[test]
+fn word_random_access() {
+    check_all_encodings!(WordSegmenter::new_dictionary());
+}
+
+#[test]
+fn sentence_random_access() {
+    check_all_encodings!(SentenceSegmenter::new());
+}
+
+#[test]
+fn sentence_suppressions_random_access() {
+    check_all_encodings!(SentenceSegmenter::try_new_with_options(
+        &locale!("en-u-ss-standard").into(),
+        Default::default()
+    )
+    .unwrap());
+}
+
+#[test]
+fn line_random_access() {
+    check_all_encodings!(LineSegmenter::new_dictionary());
+}
+
+#[test]
+fn random_access_does_not_advance() {
+    let segmenter = WordSegmenter::new_dictionary();
+    let text = "One two\nthree";
+    let mut iter = segmenter.segment_str(text);
+    assert_eq!(iter.following(4), Some(7));
+    assert_eq!(iter.preceding(13), Some(8));
+    assert!(iter.is_boundary(8));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(3));
+}
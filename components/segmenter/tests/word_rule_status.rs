@@ -105,3 +105,51 @@ fn rule_status_no_word() {
     assert!(!iter.is_word_like(), "always false");
 }
 */
+
+#[test]
+fn rule_status_japanese() {
+    let segmenter = WordSegmenter::new_auto();
+    // Hiragana, Katakana, and Kanji
+    let mut iter = segmenter.segment_str("うなぎうなじテレビ日本");
+
+    assert_eq!(iter.next(), Some(0), "SOT");
+    assert_eq!(iter.word_type(), WordType::None, "none");
+
+    assert_eq!(iter.next(), Some(9), "after うなぎ");
+    assert_eq!(iter.word_type(), WordType::Kana, "kana");
+    assert!(iter.is_word_like(), "Kana is true");
+
+    assert_eq!(iter.next(), Some(18), "after うなじ");
+    assert_eq!(iter.word_type(), WordType::Kana, "kana");
+
+    assert_eq!(iter.next(), Some(27), "after テレビ");
+    assert_eq!(iter.word_type(), WordType::Kana, "kana");
+
+    assert_eq!(iter.next(), Some(33), "after 日本");
+    assert_eq!(iter.word_type(), WordType::Ideo, "ideo");
+    assert!(iter.is_word_like(), "Ideo is true");
+
+    assert_eq!(iter.next(), None, "EOT");
+}
+
+#[test]
+fn rule_status_japanese_utf16() {
+    let segmenter = WordSegmenter::new_auto();
+    let text: Vec<u16> = "うなぎうなじテレビ日本".encode_utf16().collect();
+    let mut iter = segmenter.segment_utf16(&text);
+
+    let mut word_types = vec![];
+    while let Some(index) = iter.next() {
+        word_types.push((index, iter.word_type()));
+    }
+    assert_eq!(
+        word_types,
+        [
+            (0, WordType::None),
+            (3, WordType::Kana),
+            (6, WordType::Kana),
+            (9, WordType::Kana),
+            (11, WordType::Ideo),
+        ]
+    );
+}
@@ -31,7 +31,10 @@
 //! 2. BCP-47 time zone IDs, like `"uschi"`
 //!
 //! ICU4X uses BCP-47 time zone IDs for all of its APIs. To get a BCP-47 time zone from an
-//! IANA time zone, use [`IanaToBcp47Mapper`].
+//! IANA time zone, use [`IanaToBcp47Mapper`]. Windows time zone IDs, like `"Central Standard Time"`,
+//! can be mapped with [`WindowsTimeZoneMapper`].
+//! To enumerate the time zones of a region, or find its primary time zone, use
+//! [`TimeZoneRegionMapper`].
 //!
 //! ## Metazone
 //!
@@ -75,7 +78,7 @@
 //! The following calculations are currently supported or will be supported:
 //!
 //! 1. Time Zone + Local DateTime → Meta Zone ([`MetazoneCalculator`])
-//! 2. Time Zone + Absolute Time → Offset + Zone Variant (not yet supported)
+//! 2. Time Zone + Absolute Time → Offset + Zone Variant ([`ZoneOffsetCalculator`])
 //!
 //! # Examples
 //!
@@ -106,6 +109,40 @@
 //!
 //! assert_eq!("amce", time_zone.metazone_id.unwrap().0.as_str());
 //! ```
+//!
+//! Create a time zone for which only the time zone ID is known, and calculate the offset and
+//! zone variant at a certain UTC datetime, as well as the metazone at the corresponding local
+//! datetime:
+//!
+//! ```
+//! use icu::calendar::DateTime;
+//! use icu::timezone::CustomTimeZone;
+//! use icu::timezone::MetazoneCalculator;
+//! use icu::timezone::ZoneOffsetCalculator;
+//! use icu::timezone::ZoneVariant;
+//! use tinystr::tinystr;
+//!
+//! let mut time_zone = CustomTimeZone::new_empty();
+//! time_zone.time_zone_id = Some(tinystr!(8, "uschi").into());
+//!
+//! // Compute the offset and zone variant at July 1, 2022, 12:00 UTC:
+//! let zoc = ZoneOffsetCalculator::new();
+//! let utc_datetime = DateTime::try_new_iso_datetime(2022, 7, 1, 12, 0, 0).unwrap();
+//! time_zone.maybe_calculate_offset_and_variant(&zoc, &utc_datetime);
+//!
+//! let gmt_offset = time_zone.gmt_offset.unwrap();
+//! assert_eq!(gmt_offset.offset_seconds(), -5 * 3600);
+//! assert_eq!(time_zone.zone_variant, Some(ZoneVariant::daylight()));
+//!
+//! // Compute the metazone at the local datetime:
+//! let local_datetime = DateTime::from_minutes_since_local_unix_epoch(
+//!     utc_datetime.minutes_since_local_unix_epoch() + gmt_offset.offset_seconds() / 60,
+//! );
+//! let mzc = MetazoneCalculator::new();
+//! time_zone.maybe_calculate_metazone(&mzc, &local_datetime);
+//!
+//! assert_eq!("amce", time_zone.metazone_id.unwrap().0.as_str());
+//! ```
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -129,15 +166,21 @@ mod error;
 mod iana_ids;
 mod metazone;
 pub mod provider;
+mod regions;
 mod time_zone;
 mod types;
+mod windows_ids;
+mod zone_offset;
 
 pub use error::TimeZoneError;
 pub use iana_ids::{IanaBcp47RoundTripMapper, IanaToBcp47Mapper};
 pub use metazone::MetazoneCalculator;
 pub use provider::{MetazoneId, TimeZoneBcp47Id};
+pub use regions::{TimeZoneRegionInfo, TimeZoneRegionMapper};
 pub use time_zone::CustomTimeZone;
 pub use types::{GmtOffset, ZoneVariant};
+pub use windows_ids::WindowsTimeZoneMapper;
+pub use zone_offset::ZoneOffsetCalculator;
 
 #[doc(no_inline)]
 pub use TimeZoneError as Error;
@@ -43,14 +43,20 @@ const _: () = {
     icu_timezone_data::impl_time_zone_bcp47_to_iana_v1!(Baked);
     icu_timezone_data::impl_time_zone_iana_to_bcp47_v1!(Baked);
     icu_timezone_data::impl_time_zone_metazone_period_v1!(Baked);
+    icu_timezone_data::impl_time_zone_offset_period_v1!(Baked);
+    icu_timezone_data::impl_time_zone_regions_v1!(Baked);
+    icu_timezone_data::impl_time_zone_windows_zones_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of keys required by this component.
 pub const KEYS: &[DataKey] = &[
     MetazonePeriodV1Marker::KEY,
+    ZoneOffsetPeriodV1Marker::KEY,
     names::Bcp47ToIanaMapV1Marker::KEY,
     names::IanaToBcp47MapV1Marker::KEY,
+    names::TimeZoneRegionsV1Marker::KEY,
+    names::WindowsZonesMapV1Marker::KEY,
 ];
 
 /// TimeZone ID in BCP47 format
@@ -185,3 +191,120 @@ pub struct MetazonePeriodV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub ZeroMap2d<'data, TimeZoneBcp47Id, i32, Option<MetazoneId>>,
 );
+
+/// An ICU4X mapping to the UTC offsets of time zones over time, derived from the IANA time zone
+/// database.
+///
+/// Each time zone has a list of explicit offset transitions, as well as a list of recurring
+/// daylight saving time rules, which apply from a certain point in time until superseded by a
+/// later transition or rule. The first transition of each time zone is at the earliest
+/// representable time (`i32::MIN` minutes), and holds the offset in effect before the recorded
+/// history of the time zone, which is usually the local mean time.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    ZoneOffsetPeriodV1Marker,
+    "time_zone/offset_period@1",
+    singleton
+))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneOffsetPeriodV1<'data> {
+    /// The offset transitions of each time zone. The second level key is the number of minutes
+    /// since the Unix epoch (UTC) at which the offset takes effect.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transitions: ZeroMap2d<'data, TimeZoneBcp47Id, i32, ZoneOffset>,
+    /// The recurring daylight saving time rules of each time zone. The second level key is the
+    /// number of minutes since the Unix epoch (UTC) from which the rule applies. A rule takes
+    /// precedence over transitions with the same or an earlier key.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap2d<'data, TimeZoneBcp47Id, i32, ZoneOffsetRule>,
+}
+
+/// A UTC offset in effect in a time zone, as used in [`ZoneOffsetPeriodV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneOffsetULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneOffset {
+    /// The offset from UTC in seconds
+    pub offset_seconds: i32,
+    /// Whether the offset is a daylight saving (summer) time offset
+    pub daylight: bool,
+}
+
+/// A recurring daylight saving time rule, as used in [`ZoneOffsetPeriodV1`].
+///
+/// This corresponds to a POSIX `TZ` string with a daylight saving time rule in the `Mm.w.d/time`
+/// format. Daylight saving time always has the larger offset.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneOffsetRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneOffsetRule {
+    /// The standard offset from UTC in seconds
+    pub standard_offset_seconds: i32,
+    /// The daylight saving time offset from UTC in seconds
+    pub daylight_offset_seconds: i32,
+    /// When daylight saving time starts, in local standard time
+    pub start: ZoneTransitionRule,
+    /// When daylight saving time ends, in local daylight saving time
+    pub end: ZoneTransitionRule,
+}
+
+/// The yearly recurring date and time of a transition, as used in [`ZoneOffsetRule`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneTransitionRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneTransitionRule {
+    /// The month of the transition, from 1 to 12
+    pub month: u8,
+    /// The week of the month of the transition, from 1 to 5, where 5 means the last week
+    pub week: u8,
+    /// The day of the week of the transition, from 0 (Sunday) to 6 (Saturday)
+    pub weekday: u8,
+    /// The local time of the transition in seconds after midnight. This can be negative or
+    /// exceed a day.
+    pub time_seconds: i32,
+}
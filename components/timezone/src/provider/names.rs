@@ -14,9 +14,10 @@
 
 use crate::TimeZoneBcp47Id;
 use core::str;
+use icu_locid::subtags::Region;
 use icu_provider::prelude::*;
 use zerotrie::ZeroTrie;
-use zerovec::{VarZeroVec, ZeroVec};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
 
 /// A mapping from IANA time zone identifiers to BCP-47 time zone identifiers.
 ///
@@ -89,3 +90,78 @@ pub struct Bcp47ToIanaMapV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub canonical_iana_ids: VarZeroVec<'data, str>,
 }
+
+/// A mapping between Windows time zone identifiers and BCP-47 time zone identifiers,
+/// based on the CLDR `windowsZones` supplemental data.
+///
+/// A Windows time zone identifier maps to a BCP-47 time zone identifier per territory,
+/// with the `001` territory holding the default mapping. Each BCP-47 time zone identifier
+/// maps back to a single Windows time zone identifier.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq)]
+#[icu_provider::data_struct(marker(
+    WindowsZonesMapV1Marker,
+    "time_zone/windows_zones@1",
+    singleton
+))]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider::names),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct WindowsZonesMapV1<'data> {
+    /// A map from lowercased `"{windows_id}/{territory}"` strings to indexes of BCP-47
+    /// time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub map: ZeroTrie<ZeroVec<'data, u8>>,
+    /// A sorted list of BCP-47 time zone identifiers that have a Windows time zone identifier.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_ids: ZeroVec<'data, TimeZoneBcp47Id>,
+    /// A sorted list of Windows time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub windows_ids: VarZeroVec<'data, str>,
+    /// For each entry in [`Self::bcp47_ids`], the index of its Windows time zone identifier
+    /// in [`Self::windows_ids`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_to_windows: ZeroVec<'data, u16>,
+}
+
+/// A mapping from canonical BCP-47 time zone identifiers to the regions they belong to,
+/// along with the primary time zone of each region.
+///
+/// Time zones that do not belong to a region, such as `utc` or `unk`, are not included.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq)]
+#[icu_provider::data_struct(marker(TimeZoneRegionsV1Marker, "time_zone/regions@1", singleton))]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider::names),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct TimeZoneRegionsV1<'data> {
+    /// A map from canonical BCP-47 time zone identifiers to their regions.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroMap<'data, TimeZoneBcp47Id, Region>,
+    /// A sorted list of the BCP-47 time zone identifiers that are the primary time zone
+    /// of their region.
+    ///
+    /// A time zone is primary if it is the only time zone in its region, or if it is
+    /// listed in the CLDR `primaryZones` data. Regions with multiple time zones and no
+    /// `primaryZones` entry, such as `US`, have no primary time zone.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub primary_zones: ZeroVec<'data, TimeZoneBcp47Id>,
+}
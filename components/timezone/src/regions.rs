@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::names::*;
+use crate::TimeZoneBcp47Id;
+use icu_locid::subtags::Region;
+use icu_provider::prelude::*;
+
+/// Information about a canonical time zone, returned by [`TimeZoneRegionMapperBorrowed::iter()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TimeZoneRegionInfo {
+    /// The canonical BCP-47 identifier of the time zone.
+    pub time_zone_id: TimeZoneBcp47Id,
+    /// The region the time zone belongs to.
+    pub region: Region,
+    /// Whether this is the primary time zone of its region.
+    pub is_primary: bool,
+}
+
+/// A mapper from canonical BCP-47 time zone identifiers to their regions, which can be
+/// used to enumerate time zones, for example to build a time zone picker.
+///
+/// The mapper also knows the primary time zone of a region: the time zone that represents
+/// the region as a whole, such as `"deber"` (Europe/Berlin) for Germany. A region has a
+/// primary time zone if it has only a single time zone, or if one is designated in the CLDR
+/// `primaryZones` data. Large regions with several time zones, such as the United States,
+/// have no primary time zone.
+///
+/// Time zones that are not associated with a region, such as `"utc"` or `"unk"`, are not
+/// enumerated.
+///
+/// To list the time zones of a region with localized names, sorted for display, use
+/// `TimeZoneFormatter::format_zones_for_region()` in `icu_datetime`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::subtags::region;
+/// use icu::timezone::TimeZoneRegionMapper;
+/// use tinystr::tinystr;
+///
+/// let mapper = TimeZoneRegionMapper::new();
+/// let mapper = mapper.as_borrowed();
+///
+/// // All the time zones in Germany
+/// assert_eq!(
+///     mapper
+///         .zones_for_region(region!("DE"))
+///         .map(|id| id.0)
+///         .collect::<Vec<_>>(),
+///     [tinystr!(8, "deber"), tinystr!(8, "debsngn")]
+/// );
+///
+/// // Germany's primary time zone is Europe/Berlin
+/// assert_eq!(
+///     mapper.primary_zone(region!("DE")),
+///     Some(tinystr!(8, "deber").into())
+/// );
+///
+/// // Time zones in regions with only one time zone are primary
+/// assert_eq!(
+///     mapper.primary_zone(region!("FR")),
+///     Some(tinystr!(8, "frpar").into())
+/// );
+///
+/// // The United States has no primary time zone
+/// assert_eq!(mapper.primary_zone(region!("US")), None);
+///
+/// // The region of a time zone
+/// assert_eq!(
+///     mapper.region(tinystr!(8, "uslax").into()),
+///     Some(region!("US"))
+/// );
+/// assert_eq!(mapper.region(tinystr!(8, "utc").into()), None);
+/// ```
+#[derive(Debug)]
+pub struct TimeZoneRegionMapper {
+    data: DataPayload<TimeZoneRegionsV1Marker>,
+}
+
+impl TimeZoneRegionMapper {
+    /// Creates a new [`TimeZoneRegionMapper`] using compiled data.
+    ///
+    /// See [`TimeZoneRegionMapper`] for an example.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        TimeZoneRegionMapper {
+            data: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_REGIONS_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<TimeZoneRegionsV1Marker> + ?Sized,
+    {
+        let data = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns a borrowed version of the mapper that can be queried.
+    ///
+    /// This avoids a small potential cost of reading the data pointer.
+    pub fn as_borrowed(&self) -> TimeZoneRegionMapperBorrowed {
+        TimeZoneRegionMapperBorrowed {
+            data: self.data.get(),
+        }
+    }
+}
+
+/// A borrowed wrapper around time zone region data, returned by
+/// [`TimeZoneRegionMapper::as_borrowed()`]. More efficient to query.
+#[derive(Debug)]
+pub struct TimeZoneRegionMapperBorrowed<'a> {
+    data: &'a TimeZoneRegionsV1<'a>,
+}
+
+impl<'a> TimeZoneRegionMapperBorrowed<'a> {
+    /// Returns an iterator over all canonical time zones that belong to a region, in
+    /// alphabetical order of their BCP-47 identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::subtags::region;
+    /// use icu::timezone::TimeZoneRegionMapper;
+    /// use tinystr::tinystr;
+    ///
+    /// let mapper = TimeZoneRegionMapper::new();
+    ///
+    /// let zones_in_germany = mapper
+    ///     .as_borrowed()
+    ///     .iter()
+    ///     .filter(|info| info.region == region!("DE"))
+    ///     .map(|info| (info.time_zone_id.0, info.is_primary))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     zones_in_germany,
+    ///     [(tinystr!(8, "deber"), true), (tinystr!(8, "debsngn"), false)]
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = TimeZoneRegionInfo> + 'a {
+        let primary_zones = &self.data.primary_zones;
+        self.data
+            .regions
+            .iter_copied()
+            .map(move |(time_zone_id, region)| TimeZoneRegionInfo {
+                time_zone_id,
+                region,
+                is_primary: primary_zones.binary_search(&time_zone_id).is_ok(),
+            })
+    }
+
+    /// Returns an iterator over the canonical time zones in the given region, in
+    /// alphabetical order of their BCP-47 identifiers.
+    ///
+    /// See examples in [`TimeZoneRegionMapper`].
+    pub fn zones_for_region(&self, region: Region) -> impl Iterator<Item = TimeZoneBcp47Id> + 'a {
+        self.iter()
+            .filter(move |info| info.region == region)
+            .map(|info| info.time_zone_id)
+    }
+
+    /// Returns the primary time zone of the given region, if it has one.
+    ///
+    /// See examples in [`TimeZoneRegionMapper`].
+    pub fn primary_zone(&self, region: Region) -> Option<TimeZoneBcp47Id> {
+        self.data
+            .primary_zones
+            .iter()
+            .find(|&time_zone_id| self.region(time_zone_id) == Some(region))
+    }
+
+    /// Returns the region of a canonical BCP-47 time zone identifier.
+    ///
+    /// Returns `None` if the time zone is unknown, not canonical, or not associated
+    /// with a region.
+    ///
+    /// See examples in [`TimeZoneRegionMapper`].
+    pub fn region(&self, time_zone_id: TimeZoneBcp47Id) -> Option<Region> {
+        self.data.regions.get_copied(&time_zone_id)
+    }
+}
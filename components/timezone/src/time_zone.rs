@@ -5,6 +5,7 @@
 use crate::provider::{MetazoneId, TimeZoneBcp47Id};
 
 use crate::metazone::MetazoneCalculator;
+use crate::zone_offset::ZoneOffsetCalculator;
 use crate::{GmtOffset, TimeZoneError, ZoneVariant};
 use core::str::FromStr;
 use icu_calendar::{DateTime, Iso};
@@ -151,6 +152,52 @@ impl CustomTimeZone {
         }
         self
     }
+
+    /// Overwrite the GMT offset and zone variant by calculating them from the time zone id at
+    /// the given UTC datetime.
+    ///
+    /// The fields are left unchanged if the time zone id is not set, or if the offset cannot be
+    /// calculated because the time zone is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::timezone::provider::TimeZoneBcp47Id;
+    /// use icu::timezone::{CustomTimeZone, ZoneOffsetCalculator, ZoneVariant};
+    /// use icu_calendar::DateTime;
+    /// use tinystr::tinystr;
+    ///
+    /// let zoc = ZoneOffsetCalculator::new();
+    /// let mut tz = CustomTimeZone::new_empty();
+    /// tz.time_zone_id = Some(TimeZoneBcp47Id(tinystr!(8, "gblon")));
+    ///
+    /// tz.maybe_calculate_offset_and_variant(
+    ///     &zoc,
+    ///     &DateTime::try_new_iso_datetime(2023, 7, 1, 12, 0, 0).unwrap(),
+    /// );
+    /// assert_eq!(tz.gmt_offset.map(|offset| offset.offset_seconds()), Some(3600));
+    /// assert_eq!(tz.zone_variant, Some(ZoneVariant::daylight()));
+    ///
+    /// tz.maybe_calculate_offset_and_variant(
+    ///     &zoc,
+    ///     &DateTime::try_new_iso_datetime(2023, 12, 1, 12, 0, 0).unwrap(),
+    /// );
+    /// assert_eq!(tz.gmt_offset.map(|offset| offset.offset_seconds()), Some(0));
+    /// assert_eq!(tz.zone_variant, Some(ZoneVariant::standard()));
+    /// ```
+    pub fn maybe_calculate_offset_and_variant(
+        &mut self,
+        zone_offset_calculator: &ZoneOffsetCalculator,
+        utc_datetime: &DateTime<Iso>,
+    ) -> &mut Self {
+        if let Some((gmt_offset, zone_variant)) = self.time_zone_id.and_then(|time_zone_id| {
+            zone_offset_calculator.compute_offset_and_variant(time_zone_id, utc_datetime)
+        }) {
+            self.gmt_offset = Some(gmt_offset);
+            self.zone_variant = Some(zone_variant);
+        }
+        self
+    }
 }
 
 impl FromStr for CustomTimeZone {
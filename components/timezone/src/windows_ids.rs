@@ -0,0 +1,160 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::names::*;
+use crate::TimeZoneBcp47Id;
+use icu_locid::subtags::Region;
+use icu_provider::prelude::*;
+
+/// A mapper between Windows time zone identifiers and BCP-47 time zone identifiers.
+///
+/// The mapping is taken from the CLDR `windowsZones` supplemental data. A Windows time zone
+/// identifier can correspond to different BCP-47 time zones depending on the region; if no
+/// region is given, or if there is no mapping specific to the region, the default mapping
+/// (territory `001`) is used.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::subtags::region;
+/// use icu::timezone::WindowsTimeZoneMapper;
+///
+/// let mapper = WindowsTimeZoneMapper::new();
+/// let mapper_borrowed = mapper.as_borrowed();
+///
+/// // The Windows zone "Pacific Standard Time" is the BCP-47 zone "uslax"
+/// assert_eq!(
+///     mapper_borrowed.windows_to_bcp47("Pacific Standard Time", None),
+///     Some("uslax".parse().unwrap())
+/// );
+///
+/// // In Canada, it is the BCP-47 zone "cavan"
+/// assert_eq!(
+///     mapper_borrowed
+///         .windows_to_bcp47("Pacific Standard Time", Some(region!("CA"))),
+///     Some("cavan".parse().unwrap())
+/// );
+///
+/// // Regions without a specific mapping use the default
+/// assert_eq!(
+///     mapper_borrowed
+///         .windows_to_bcp47("Pacific Standard Time", Some(region!("FR"))),
+///     Some("uslax".parse().unwrap())
+/// );
+///
+/// // And back to the Windows zone
+/// assert_eq!(
+///     mapper_borrowed.bcp47_to_windows("cavan".parse().unwrap()),
+///     Some("Pacific Standard Time")
+/// );
+/// ```
+#[derive(Debug)]
+pub struct WindowsTimeZoneMapper {
+    data: DataPayload<WindowsZonesMapV1Marker>,
+}
+
+impl WindowsTimeZoneMapper {
+    /// Creates a new [`WindowsTimeZoneMapper`] using compiled data.
+    ///
+    /// See [`WindowsTimeZoneMapper`] for an example.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        WindowsTimeZoneMapper {
+            data: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_WINDOWS_ZONES_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<WindowsZonesMapV1Marker> + ?Sized,
+    {
+        let data = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns a borrowed version of the mapper that can be queried.
+    ///
+    /// This avoids a small potential cost of reading the data pointer.
+    pub fn as_borrowed(&self) -> WindowsTimeZoneMapperBorrowed {
+        WindowsTimeZoneMapperBorrowed {
+            data: self.data.get(),
+        }
+    }
+}
+
+/// A borrowed wrapper around Windows time zone data, returned by
+/// [`WindowsTimeZoneMapper::as_borrowed()`]. More efficient to query.
+#[derive(Debug)]
+pub struct WindowsTimeZoneMapperBorrowed<'a> {
+    data: &'a WindowsZonesMapV1<'a>,
+}
+
+impl<'a> WindowsTimeZoneMapperBorrowed<'a> {
+    /// Looks up a BCP-47 time zone identifier based on an ASCII-case-insensitive match for
+    /// the given Windows time zone identifier and an optional region.
+    ///
+    /// If the region is `None` or has no specific mapping, the default mapping for the
+    /// Windows time zone identifier is returned.
+    ///
+    /// See examples in [`WindowsTimeZoneMapper`].
+    pub fn windows_to_bcp47(
+        &self,
+        windows_id: &str,
+        region: Option<Region>,
+    ) -> Option<TimeZoneBcp47Id> {
+        region
+            .and_then(|region| self.get(windows_id, region.as_str()))
+            .or_else(|| self.get(windows_id, "001"))
+    }
+
+    /// Looks up the Windows time zone identifier of a BCP-47 time zone identifier.
+    ///
+    /// See examples in [`WindowsTimeZoneMapper`].
+    pub fn bcp47_to_windows(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        let index = self.data.bcp47_ids.binary_search(&bcp47_id).ok()?;
+        let windows_index = self.data.bcp47_to_windows.get(index)?;
+        self.data.windows_ids.get(usize::from(windows_index))
+    }
+
+    fn get(&self, windows_id: &str, territory: &str) -> Option<TimeZoneBcp47Id> {
+        // The longest Windows ID in CLDR appears to be "Central Brazilian Standard Time"
+        // which is 31 characters long, so 48 should be plenty. Longer IDs can't match.
+        let mut key = [0u8; 48];
+        let mut len = 0;
+        for (slot, byte) in key.iter_mut().zip(
+            windows_id
+                .bytes()
+                .chain(core::iter::once(b'/'))
+                .chain(territory.bytes()),
+        ) {
+            *slot = byte.to_ascii_lowercase();
+            len += 1;
+        }
+        if len != windows_id.len() + 1 + territory.len() {
+            return None;
+        }
+        let idx = self.data.map.get(key.get(..len)?)?;
+        self.data.bcp47_ids.get(idx)
+    }
+}
@@ -0,0 +1,269 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::{
+    TimeZoneBcp47Id, ZoneOffsetPeriodV1Marker, ZoneOffsetRule, ZoneTransitionRule,
+};
+use crate::{GmtOffset, ZoneVariant};
+use icu_calendar::{Date, DateTime, Iso};
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+
+/// [`ZoneOffsetCalculator`] uses data from the [data provider] to calculate the GMT offset and
+/// zone variant (standard or daylight time) of a time zone at a point in time.
+///
+/// The data is derived from the IANA time zone database, including its history before the Unix
+/// epoch (1970-01-01T00:00:00Z). Before a time zone adopted standard time, its offset is the local
+/// mean time. Note that the time zone database is less reliable for the time before 1970.
+///
+/// [data provider]: icu_provider
+#[derive(Debug)]
+pub struct ZoneOffsetCalculator {
+    pub(super) offset_period: DataPayload<ZoneOffsetPeriodV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for ZoneOffsetCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZoneOffsetCalculator {
+    /// Constructs a `ZoneOffsetCalculator` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        ZoneOffsetCalculator {
+            offset_period: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_OFFSET_PERIOD_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<ZoneOffsetPeriodV1Marker> + ?Sized),
+    ) -> Result<Self, TimeZoneError> {
+        let offset_period = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { offset_period })
+    }
+
+    /// Calculate the GMT offset and zone variant from timezone id and UTC datetime.
+    ///
+    /// Returns `None` if the time zone is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::timezone::provider::TimeZoneBcp47Id;
+    /// use icu::timezone::{ZoneOffsetCalculator, ZoneVariant};
+    /// use icu_calendar::DateTime;
+    /// use tinystr::tinystr;
+    ///
+    /// let zoc = ZoneOffsetCalculator::new();
+    ///
+    /// let offset_and_variant = |id, datetime| {
+    ///     zoc.compute_offset_and_variant(TimeZoneBcp47Id(id), &datetime)
+    ///         .map(|(offset, variant)| (offset.offset_seconds(), variant))
+    /// };
+    ///
+    /// // America/Los_Angeles in winter
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(2024, 1, 1, 12, 0, 0).unwrap()
+    ///     ),
+    ///     Some((-8 * 3600, ZoneVariant::standard()))
+    /// );
+    ///
+    /// // America/Los_Angeles in summer
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(2024, 7, 1, 12, 0, 0).unwrap()
+    ///     ),
+    ///     Some((-7 * 3600, ZoneVariant::daylight()))
+    /// );
+    ///
+    /// // Daylight saving time in 2024 started at 2024-03-10T10:00:00Z
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(2024, 3, 10, 9, 59, 59).unwrap()
+    ///     ),
+    ///     Some((-8 * 3600, ZoneVariant::standard()))
+    /// );
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(2024, 3, 10, 10, 0, 0).unwrap()
+    ///     ),
+    ///     Some((-7 * 3600, ZoneVariant::daylight()))
+    /// );
+    ///
+    /// // Daylight saving time in the southern hemisphere spans the new year
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "ausyd"),
+    ///         DateTime::try_new_iso_datetime(2024, 1, 1, 0, 0, 0).unwrap()
+    ///     ),
+    ///     Some((11 * 3600, ZoneVariant::daylight()))
+    /// );
+    ///
+    /// // Before the Unix epoch
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(1969, 12, 31, 0, 0, 0).unwrap()
+    ///     ),
+    ///     Some((-8 * 3600, ZoneVariant::standard()))
+    /// );
+    ///
+    /// // Local mean time before standard time was adopted in 1883
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "uslax"),
+    ///         DateTime::try_new_iso_datetime(1880, 1, 1, 0, 0, 0).unwrap()
+    ///     ),
+    ///     Some((-(7 * 3600 + 52 * 60 + 58), ZoneVariant::standard()))
+    /// );
+    ///
+    /// // Unknown time zone
+    /// assert_eq!(
+    ///     offset_and_variant(
+    ///         tinystr!(8, "unk"),
+    ///         DateTime::try_new_iso_datetime(2024, 1, 1, 0, 0, 0).unwrap()
+    ///     ),
+    ///     None
+    /// );
+    /// ```
+    pub fn compute_offset_and_variant(
+        &self,
+        time_zone_id: TimeZoneBcp47Id,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Option<(GmtOffset, ZoneVariant)> {
+        let offset_period = self.offset_period.get();
+        let minutes_since_unix_epoch = utc_datetime.minutes_since_local_unix_epoch();
+
+        let mut transition = None;
+        if let Some(cursor) = offset_period.transitions.get0(&time_zone_id) {
+            for (minutes, offset) in cursor.iter1_copied() {
+                let minutes = i32::from_unaligned(*minutes);
+                if minutes_since_unix_epoch >= minutes {
+                    transition = Some((minutes, offset));
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let mut rule = None;
+        if let Some(cursor) = offset_period.rules.get0(&time_zone_id) {
+            for (minutes, r) in cursor.iter1_copied() {
+                let minutes = i32::from_unaligned(*minutes);
+                if minutes_since_unix_epoch >= minutes {
+                    rule = Some((minutes, r));
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let (offset_seconds, daylight) = match (transition, rule) {
+            (transition, Some((rule_minutes, rule)))
+                if transition.map_or(true, |(minutes, _)| minutes <= rule_minutes) =>
+            {
+                let seconds_since_unix_epoch = i64::from(minutes_since_unix_epoch) * 60
+                    + i64::from(utc_datetime.time.second.number());
+                if is_daylight(
+                    &rule,
+                    utc_datetime.date.year().number,
+                    seconds_since_unix_epoch,
+                ) {
+                    (rule.daylight_offset_seconds, true)
+                } else {
+                    (rule.standard_offset_seconds, false)
+                }
+            }
+            (Some((_, offset)), _) => (offset.offset_seconds, offset.daylight),
+            (None, _) => return None,
+        };
+
+        Some((
+            GmtOffset::try_from_offset_seconds(offset_seconds).ok()?,
+            if daylight {
+                ZoneVariant::daylight()
+            } else {
+                ZoneVariant::standard()
+            },
+        ))
+    }
+}
+
+/// Whether daylight saving time is in effect under `rule` at the given number of seconds since
+/// the Unix epoch, which falls into the UTC year `year`.
+fn is_daylight(rule: &ZoneOffsetRule, year: i32, seconds_since_unix_epoch: i64) -> bool {
+    // The transitions of the previous year guarantee that there is a transition before the
+    // given time. The transitions of the next year are needed in case a transition time is
+    // far enough before midnight to fall into the current UTC year.
+    let mut latest: Option<(i64, bool)> = None;
+    for year in [year.saturating_sub(1), year, year.saturating_add(1)] {
+        for (transition, daylight) in [
+            (
+                transition_seconds(&rule.start, year, rule.standard_offset_seconds),
+                true,
+            ),
+            (
+                transition_seconds(&rule.end, year, rule.daylight_offset_seconds),
+                false,
+            ),
+        ] {
+            let Some(transition) = transition else {
+                continue;
+            };
+            if transition <= seconds_since_unix_epoch
+                && latest.map_or(true, |(latest, _)| transition > latest)
+            {
+                latest = Some((transition, daylight));
+            }
+        }
+    }
+    latest.map_or(false, |(_, daylight)| daylight)
+}
+
+/// The number of seconds since the Unix epoch of the transition described by `rule` in `year`,
+/// where the local time of the transition has the given offset.
+fn transition_seconds(rule: &ZoneTransitionRule, year: i32, offset_seconds: i32) -> Option<i64> {
+    let first_of_month = Date::try_new_iso_date(year, rule.month, 1).ok()?;
+    // `IsoWeekday` counts from Monday = 1 to Sunday = 7, rules count from Sunday = 0
+    let first_weekday = first_of_month.day_of_week() as u8 % 7;
+    let mut day = 1
+        + (7 + rule.weekday % 7 - first_weekday) % 7
+        + 7 * rule.week.clamp(1, 5).saturating_sub(1);
+    while day > first_of_month.days_in_month() {
+        day -= 7;
+    }
+    let midnight = DateTime::try_new_iso_datetime(year, rule.month, day, 0, 0, 0).ok()?;
+    Some(
+        i64::from(midnight.minutes_since_local_unix_epoch()) * 60 + i64::from(rule.time_seconds)
+            - i64::from(offset_seconds),
+    )
+}
@@ -0,0 +1,190 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Grammatical features of languages, such as the grammatical cases and genders of nouns.
+
+use icu_provider::prelude::*;
+
+use crate::provider::GrammaticalFeaturesV1Marker;
+
+/// A grammatical case, as used by the CLDR grammatical features and inflected unit data.
+#[zerovec::make_ule(GrammaticalCaseULE)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::grammar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum GrammaticalCase {
+    /// The case of the subject, which is also used for unit patterns without a case.
+    #[default]
+    Nominative = 0,
+    /// The case expressing the absence of something ("without").
+    Abessive = 1,
+    /// The case expressing movement away from something.
+    Ablative = 2,
+    /// The case of the direct object.
+    Accusative = 3,
+    /// The case expressing location at or on something.
+    Adessive = 4,
+    /// The case expressing movement onto or towards something.
+    Allative = 5,
+    /// The case expressing the cause or purpose of something.
+    Causal = 6,
+    /// The case expressing accompaniment ("together with").
+    Comitative = 7,
+    /// The case of the indirect object.
+    Dative = 8,
+    /// The case expressing movement off the surface of something.
+    Delative = 9,
+    /// The case expressing movement out of something.
+    Elative = 10,
+    /// The case of the agent of a transitive verb.
+    Ergative = 11,
+    /// The case expressing a temporary state ("as").
+    Essive = 12,
+    /// The case expressing possession.
+    Genitive = 13,
+    /// The case expressing movement into something.
+    Illative = 14,
+    /// The case expressing location inside something.
+    Inessive = 15,
+    /// The case expressing the means by which something is done.
+    Instrumental = 16,
+    /// The case expressing location.
+    Locative = 17,
+    /// The case expressing location in copular clauses.
+    LocativeCopulative = 18,
+    /// The case used for all nouns not in the nominative case.
+    Oblique = 19,
+    /// The case expressing partialness or an indefinite quantity.
+    Partitive = 20,
+    /// The case of the object of a preposition.
+    Prepositional = 21,
+    /// The case expressing a person who accompanies the subject.
+    Sociative = 22,
+    /// The case expressing movement onto the surface of something.
+    Sublative = 23,
+    /// The case expressing location on top of something.
+    Superessive = 24,
+    /// The case expressing the end point of a movement or time span ("until").
+    Terminative = 25,
+    /// The case expressing a change of state ("becoming").
+    Translative = 26,
+    /// The case used to address someone.
+    Vocative = 27,
+}
+
+/// A grammatical gender, as used by the CLDR grammatical features and inflected unit data.
+#[zerovec::make_ule(GrammaticalGenderULE)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::grammar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum GrammaticalGender {
+    /// The gender of animate nouns.
+    Animate = 0,
+    /// The gender of inanimate nouns.
+    Inanimate = 1,
+    /// The gender of nouns referring to persons.
+    Personal = 2,
+    /// The common gender, which merges the masculine and feminine genders.
+    Common = 3,
+    /// The feminine gender.
+    Feminine = 4,
+    /// The masculine gender.
+    Masculine = 5,
+    /// The neuter gender.
+    Neuter = 6,
+}
+
+/// The grammatical features of a language: the cases and genders for which its nouns
+/// are inflected.
+///
+/// Languages without data, such as English, have no cases or genders.
+///
+/// # Examples
+///
+/// ```
+/// use icu_dimension::grammar::{
+///     GrammaticalCase, GrammaticalFeatures, GrammaticalGender,
+/// };
+/// use icu_dimension::provider::*;
+/// use icu_locid::locale;
+/// use icu_provider::AsDowncastingAnyProvider;
+/// use icu_provider_adapters::any_payload::AnyPayloadProvider;
+///
+/// // Hand-written grammatical features of German
+/// let provider =
+///     AnyPayloadProvider::from_owned::<GrammaticalFeaturesV1Marker>(
+///         GrammaticalFeaturesV1 {
+///             cases: [
+///                 GrammaticalCase::Nominative,
+///                 GrammaticalCase::Accusative,
+///                 GrammaticalCase::Dative,
+///                 GrammaticalCase::Genitive,
+///             ]
+///             .into_iter()
+///             .collect(),
+///             genders: [
+///                 GrammaticalGender::Feminine,
+///                 GrammaticalGender::Masculine,
+///                 GrammaticalGender::Neuter,
+///             ]
+///             .into_iter()
+///             .collect(),
+///         },
+///     );
+///
+/// let features = GrammaticalFeatures::try_new_unstable(
+///     &provider.as_downcasting(),
+///     &locale!("de").into(),
+/// )
+/// .unwrap();
+///
+/// assert!(features.cases().any(|case| case == GrammaticalCase::Dative));
+/// assert_eq!(features.genders().count(), 3);
+/// ```
+#[derive(Debug)]
+pub struct GrammaticalFeatures {
+    data: DataPayload<GrammaticalFeaturesV1Marker>,
+}
+
+impl GrammaticalFeatures {
+    /// Creates a new [`GrammaticalFeatures`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<GrammaticalFeaturesV1Marker> + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, DataError> {
+        let data = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns the grammatical cases of the language.
+    pub fn cases(&self) -> impl Iterator<Item = GrammaticalCase> + '_ {
+        self.data.get().cases.iter()
+    }
+
+    /// Returns the grammatical genders of the language.
+    pub fn genders(&self) -> impl Iterator<Item = GrammaticalGender> + '_ {
+        self.data.get().genders.iter()
+    }
+}
@@ -6,5 +6,12 @@
 
 extern crate alloc;
 
+mod error;
+pub mod grammar;
 pub mod provider;
 pub mod ule;
+pub mod units;
+
+pub use error::DimensionError;
+#[doc(no_inline)]
+pub use DimensionError as Error;
@@ -9,14 +9,20 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use crate::grammar::{GrammaticalCase, GrammaticalGender};
 use alloc::borrow::Cow;
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
-use zerovec::{VarZeroVec, ZeroMap};
+use zerovec::ule::UnvalidatedStr;
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d, ZeroVec};
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of keys required by this component.
-pub const KEYS: &[DataKey] = &[CurrencyEssentialsV1Marker::KEY];
+pub const KEYS: &[DataKey] = &[
+    CurrencyEssentialsV1Marker::KEY,
+    GrammaticalFeaturesV1Marker::KEY,
+    UnitsDisplayNamesV1Marker::KEY,
+];
 
 /// This type contains all of the essential data for currency formatting.
 ///
@@ -114,3 +120,86 @@ pub struct CurrencyPatterns {
     /// If the value is `None`, this means that the narrow pattern does not have a place holder.
     pub narrow_place_holder_index: Option<PlaceholderValue>,
 }
+
+/// The grammatical features of a language, from the CLDR grammatical features data
+/// for nominal targets.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(GrammaticalFeaturesV1Marker = "grammar/features@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct GrammaticalFeaturesV1<'data> {
+    /// The grammatical cases of the language, sorted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub cases: ZeroVec<'data, GrammaticalCase>,
+
+    /// The grammatical genders of the language, sorted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub genders: ZeroVec<'data, GrammaticalGender>,
+}
+
+/// The long display names of units, with their grammatical genders and their patterns
+/// inflected by grammatical case.
+///
+/// Units are identified by their CLDR identifiers, such as `duration-hour`. The patterns
+/// contain a `{0}` placeholder for the number. Patterns for a case other than
+/// [`GrammaticalCase::Nominative`] are only included if they differ from the nominative
+/// pattern for the same plural category.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(UnitsDisplayNamesV1Marker = "units/displaynames@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsDisplayNamesV1<'data> {
+    /// Maps from a unit to its display name, such as `Stunden`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub display_names: ZeroMap<'data, UnvalidatedStr, str>,
+
+    /// Maps from a unit to its grammatical gender, if the language has genders.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub genders: ZeroMap<'data, UnvalidatedStr, GrammaticalGender>,
+
+    /// The patterns for the plural category `zero`, by unit and case.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+
+    /// The patterns for the plural category `one`, by unit and case.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+
+    /// The patterns for the plural category `two`, by unit and case.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+
+    /// The patterns for the plural category `few`, by unit and case.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+
+    /// The patterns for the plural category `many`, by unit and case.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+
+    /// The patterns for the plural category `other`, by unit and case. Every unit has
+    /// a nominative pattern for this category.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap2d<'data, UnvalidatedStr, GrammaticalCase, str>,
+}
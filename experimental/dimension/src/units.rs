@@ -0,0 +1,285 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of numbers with units, such as "2 Stunden", inflected by grammatical case.
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_plurals::{provider::CardinalV1Marker, PluralCategory, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+use zerovec::ule::UnvalidatedStr;
+use zerovec::ZeroMap2d;
+
+use crate::grammar::{GrammaticalCase, GrammaticalGender};
+use crate::provider::UnitsDisplayNamesV1Marker;
+use crate::DimensionError;
+
+/// A bag of options for defining how to format units using [`UnitFormatter`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct UnitFormatterOptions {
+    /// The grammatical case in which to format units, such as the dative in
+    /// "mit 2 Tagen".
+    ///
+    /// Units without patterns for this case, including all units in languages
+    /// without grammatical cases, are formatted in the nominative.
+    pub case: GrammaticalCase,
+}
+
+impl From<GrammaticalCase> for UnitFormatterOptions {
+    fn from(case: GrammaticalCase) -> Self {
+        Self { case }
+    }
+}
+
+/// A formatter for numbers with units, using the long unit names of a locale.
+///
+/// Units are identified by their CLDR identifiers, such as `duration-hour` or
+/// `length-kilometer`.
+#[derive(Debug)]
+pub struct UnitFormatter {
+    display_names: DataPayload<UnitsDisplayNamesV1Marker>,
+    plural_rules: PluralRules,
+    fixed_decimal_format: FixedDecimalFormatter,
+    options: UnitFormatterOptions,
+}
+
+impl UnitFormatter {
+    /// Creates a new [`UnitFormatter`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: UnitFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<UnitsDisplayNamesV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let display_names = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
+        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            FixedDecimalFormatterOptions::default(),
+        )?;
+        Ok(Self {
+            display_names,
+            plural_rules,
+            fixed_decimal_format,
+            options,
+        })
+    }
+
+    /// Formats a number with a unit, in the grammatical case of the options.
+    ///
+    /// Returns `None` if the locale has no data for the unit.
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal, unit: &str) -> Option<FormattedUnit<'l>> {
+        let data = self.display_names.get();
+        let unit = UnvalidatedStr::from_str(unit);
+        let patterns = match self.plural_rules.category_for(value) {
+            PluralCategory::Zero => &data.zero,
+            PluralCategory::One => &data.one,
+            PluralCategory::Two => &data.two,
+            PluralCategory::Few => &data.few,
+            PluralCategory::Many => &data.many,
+            PluralCategory::Other => &data.other,
+        };
+        let pattern = self
+            .pattern(patterns, unit)
+            .or_else(|| self.pattern(&data.other, unit))?;
+        Some(FormattedUnit {
+            fixed_decimal_format: &self.fixed_decimal_format,
+            value,
+            pattern,
+        })
+    }
+
+    /// Returns the pattern for the unit in the requested case, falling back to the
+    /// nominative.
+    fn pattern<'l>(
+        &self,
+        patterns: &'l ZeroMap2d<'_, UnvalidatedStr, GrammaticalCase, str>,
+        unit: &UnvalidatedStr,
+    ) -> Option<&'l str> {
+        patterns
+            .get_2d(unit, &self.options.case)
+            .or_else(|| patterns.get_2d(unit, &GrammaticalCase::Nominative))
+    }
+
+    /// Returns the display name of the unit, such as "Stunden" for `duration-hour` in German.
+    pub fn display_name(&self, unit: &str) -> Option<&str> {
+        self.display_names
+            .get()
+            .display_names
+            .get(UnvalidatedStr::from_str(unit))
+    }
+
+    /// Returns the grammatical gender of the unit, such as [`GrammaticalGender::Feminine`]
+    /// for `duration-hour` in German, for agreement with other words.
+    ///
+    /// Returns `None` if the language has no grammatical genders or no data for the unit.
+    pub fn gender(&self, unit: &str) -> Option<GrammaticalGender> {
+        self.display_names
+            .get()
+            .genders
+            .get_copied(UnvalidatedStr::from_str(unit))
+    }
+}
+
+/// An intermediate structure returned by [`UnitFormatter`].
+/// This structure can be consumed via [`Writeable`] trait to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedUnit<'l> {
+    fixed_decimal_format: &'l FixedDecimalFormatter,
+    value: &'l FixedDecimal,
+    pattern: &'l str,
+}
+
+impl<'l> Writeable for FormattedUnit<'l> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        match self.pattern.split_once("{0}") {
+            Some((prefix, suffix)) => {
+                sink.write_str(prefix)?;
+                self.fixed_decimal_format
+                    .format(self.value)
+                    .write_to_parts(sink)?;
+                sink.write_str(suffix)
+            }
+            None => sink.write_str(self.pattern),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedUnit<'_>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::UnitsDisplayNamesV1;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+    use GrammaticalCase::*;
+
+    /// Provides a hand-written subset of German unit data, and compiled plural and decimal data.
+    struct TestProvider;
+
+    impl DataProvider<UnitsDisplayNamesV1Marker> for TestProvider {
+        fn load(
+            &self,
+            _: DataRequest,
+        ) -> Result<DataResponse<UnitsDisplayNamesV1Marker>, DataError> {
+            let day = UnvalidatedStr::from_str("duration-day");
+            let hour = UnvalidatedStr::from_str("duration-hour");
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(DataPayload::from_owned(UnitsDisplayNamesV1 {
+                    display_names: [(day, "Tage"), (hour, "Stunden")].into_iter().collect(),
+                    genders: [
+                        (day, GrammaticalGender::Masculine),
+                        (hour, GrammaticalGender::Feminine),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    one: [
+                        (day, Nominative, "{0} Tag"),
+                        (day, Genitive, "{0} Tages"),
+                        (hour, Nominative, "{0} Stunde"),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    other: [
+                        (day, Nominative, "{0} Tage"),
+                        (day, Dative, "{0} Tagen"),
+                        (hour, Nominative, "{0} Stunden"),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    ..Default::default()
+                })),
+            })
+        }
+    }
+
+    impl DataProvider<CardinalV1Marker> for TestProvider {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<CardinalV1Marker>, DataError> {
+            icu_plurals::provider::Baked.load(req)
+        }
+    }
+
+    impl DataProvider<DecimalSymbolsV1Marker> for TestProvider {
+        fn load(
+            &self,
+            req: DataRequest,
+        ) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
+            icu_decimal::provider::Baked.load(req)
+        }
+    }
+
+    fn format(case: GrammaticalCase, value: i32, unit: &str) -> String {
+        UnitFormatter::try_new_unstable(&TestProvider, &locale!("de").into(), case.into())
+            .unwrap()
+            .format(&value.into(), unit)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_cases() {
+        for (case, value, unit, expected) in [
+            (Nominative, 1, "duration-day", "1 Tag"),
+            (Nominative, 2, "duration-day", "2 Tage"),
+            (Genitive, 1, "duration-day", "1 Tages"),
+            (Genitive, 2, "duration-day", "2 Tage"),
+            (Dative, 1, "duration-day", "1 Tag"),
+            (Dative, 2000, "duration-day", "2.000 Tagen"),
+            // Units without case patterns fall back to the nominative
+            (Dative, 1, "duration-hour", "1 Stunde"),
+            (Dative, 2, "duration-hour", "2 Stunden"),
+            // Cases that German does not have fall back to the nominative
+            (Instrumental, 2, "duration-day", "2 Tage"),
+        ] {
+            assert_eq!(
+                format(case, value, unit),
+                expected,
+                "{case:?} {value} {unit}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        let formatter = UnitFormatter::try_new_unstable(
+            &TestProvider,
+            &locale!("de").into(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(formatter.display_name("duration-hour"), Some("Stunden"));
+        assert_eq!(
+            formatter.gender("duration-hour"),
+            Some(GrammaticalGender::Feminine)
+        );
+        assert_eq!(formatter.gender("length-meter"), None);
+        assert!(formatter
+            .format(&FixedDecimal::from(1), "length-meter")
+            .is_none());
+        let value = FixedDecimal::from(-1);
+        assert_writeable_eq!(formatter.format(&value, "duration-day").unwrap(), "-1 Tag");
+    }
+}
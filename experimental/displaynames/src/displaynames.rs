@@ -7,12 +7,14 @@
 use crate::options::*;
 use crate::provider::*;
 use alloc::borrow::Cow;
-use alloc::string::String;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use icu_locid::{
-    subtags::Language, subtags::Region, subtags::Script, subtags::Variant, LanguageIdentifier,
-    Locale,
+    extensions::Extensions, subtags::Language, subtags::Region, subtags::Script, subtags::Variant,
+    LanguageIdentifier, Locale,
 };
 use icu_provider::prelude::*;
+use tinystr::{tinystr, TinyAsciiStr};
 use zerovec::ule::UnvalidatedStr;
 
 /// Lookup of the locale-specific display names by region code.
@@ -328,21 +330,73 @@ impl LanguageDisplayNames {
 /// assert_eq!(display_name.of(&locale!("xx-YY")), "xx (YY)");
 /// assert_eq!(display_name.of(&locale!("xx")), "xx");
 /// ```
+///
+/// Extensions are displayed as qualifiers:
+///
+/// ```
+/// use icu_displaynames::{DisplayNamesOptions, LocaleDisplayNamesFormatter};
+/// use icu_locid::{locale, Locale};
+///
+/// let locale = locale!("en-001");
+/// let options: DisplayNamesOptions = Default::default();
+/// let display_name =
+///     LocaleDisplayNamesFormatter::try_new(&locale.into(), options)
+///         .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     display_name.of(&"de-DE-u-ca-buddhist-co-phonebk".parse::<Locale>().unwrap()),
+///     "German (Germany, Buddhist Calendar, Phonebook Sort Order)"
+/// );
+/// assert_eq!(
+///     display_name.of(&"ar-u-ca-islamic-civil-nu-xyz".parse::<Locale>().unwrap()),
+///     "Arabic (Hijri Calendar (tabular, civil epoch), Numbers: xyz)"
+/// );
+/// assert_eq!(
+///     display_name.of(&"ja-t-it-m0-ungegn-x-foo".parse::<Locale>().unwrap()),
+///     "Japanese (Transformed: Italian, UN GEGN Transliteration, Private-Use: foo)"
+/// );
+/// ```
+///
+/// With [`LanguageDisplay::Standard`], dialect names are not used:
+///
+/// ```
+/// use icu_displaynames::{
+///     DisplayNamesOptions, LanguageDisplay, LocaleDisplayNamesFormatter,
+/// };
+/// use icu_locid::locale;
+///
+/// let locale = locale!("en-001");
+/// let mut options: DisplayNamesOptions = Default::default();
+/// options.language_display = LanguageDisplay::Standard;
+/// let display_name =
+///     LocaleDisplayNamesFormatter::try_new(&locale.into(), options)
+///         .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     display_name.of(&locale!("en-GB")),
+///     "English (United Kingdom)"
+/// );
+/// ```
 pub struct LocaleDisplayNamesFormatter {
     options: DisplayNamesOptions,
-    // patterns: DataPayload<LocaleDisplayNamesPatternsV1Marker>,
+    patterns: DataPayload<LocaleDisplayNamesPatternsV1Marker>,
     locale_data: DataPayload<LocaleDisplayNamesV1Marker>,
 
     language_data: DataPayload<LanguageDisplayNamesV1Marker>,
     script_data: DataPayload<ScriptDisplayNamesV1Marker>,
     region_data: DataPayload<RegionDisplayNamesV1Marker>,
     variant_data: DataPayload<VariantDisplayNamesV1Marker>,
-    // key_data: DataPayload<KeyDisplayNamesV1Marker>,
+    key_data: DataPayload<KeyDisplayNamesV1Marker>,
     // measurement_data: DataPayload<MeasurementSystemsDisplayNamesV1Marker>,
     // subdivisions_data: DataPayload<SubdivisionsDisplayNamesV1Marker>,
     // transforms_data: DataPayload<TransformsDisplayNamesV1Marker>,
 }
 
+/// The key used to display the language of a transform extension.
+const TRANSFORM_KEY: TinyAsciiStr<2> = tinystr!(2, "t");
+/// The key used to display the subtags of a private-use extension.
+const PRIVATE_USE_KEY: TinyAsciiStr<2> = tinystr!(2, "x");
+
 impl LocaleDisplayNamesFormatter {
     icu_provider::gen_any_buffer_data_constructors!(
         locale: include,
@@ -370,10 +424,12 @@ impl LocaleDisplayNamesFormatter {
     ) -> Result<Self, DataError>
     where
         D: DataProvider<LocaleDisplayNamesV1Marker>
+            + DataProvider<LocaleDisplayNamesPatternsV1Marker>
             + DataProvider<LanguageDisplayNamesV1Marker>
             + DataProvider<ScriptDisplayNamesV1Marker>
             + DataProvider<RegionDisplayNamesV1Marker>
-            + DataProvider<VariantDisplayNamesV1Marker>,
+            + DataProvider<VariantDisplayNamesV1Marker>
+            + DataProvider<KeyDisplayNamesV1Marker>,
     {
         let req = DataRequest {
             locale,
@@ -382,11 +438,13 @@ impl LocaleDisplayNamesFormatter {
 
         Ok(Self {
             options,
+            patterns: provider.load(req)?.take_payload()?,
             language_data: provider.load(req)?.take_payload()?,
             locale_data: provider.load(req)?.take_payload()?,
             script_data: provider.load(req)?.take_payload()?,
             region_data: provider.load(req)?.take_payload()?,
             variant_data: provider.load(req)?.take_payload()?,
+            key_data: provider.load(req)?.take_payload()?,
         })
     }
 
@@ -395,49 +453,51 @@ impl LocaleDisplayNamesFormatter {
     /// <https://www.unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm>
     // TODO: Make this return a writeable instead of using alloc
     pub fn of<'a, 'b: 'a, 'c: 'a>(&'b self, locale: &'c Locale) -> Cow<'a, str> {
+        let (ldn, mut lqs) = self.language_identifier_parts(&locale.id);
+        self.push_extension_qualifiers(&locale.extensions, &mut lqs);
+
+        if lqs.is_empty() {
+            // The LDN fully represents the locale
+            return Cow::Borrowed(ldn);
+        }
+
+        Cow::Owned(self.format_parts(ldn, lqs))
+    }
+
+    /// Returns the locale display name (LDN) and the list of qualifying strings (LQS) of a
+    /// language identifier.
+    fn language_identifier_parts<'a, 'b: 'a, 'c: 'a>(
+        &'b self,
+        langid: &'c LanguageIdentifier,
+    ) -> (&'a str, Vec<Cow<'a, str>>) {
         // Step - 1: Construct a locale display name string (LDN) for the longest matching subtag.
         let mut ldn = None;
 
         // These qualifying strings (QS) will later be set to None if they are part of the LDN.
         // Using references as we might borrow from these later
-        let mut script_qs = locale.id.script.as_ref();
-        let mut region_qs = locale.id.region.as_ref();
-        let variants_qs = Cow::Borrowed(&*locale.id.variants);
-
-        // TODO: handle the other possible longest subtag cases
-
-        if let Some(script) = locale.id.script {
-            let data = self.locale_data.get();
-            let id = LanguageIdentifier::from((locale.id.language, Some(script), None));
-            let cmp = |uvstr: &UnvalidatedStr| id.strict_cmp(uvstr).reverse();
-            if let Some(x) = match self.options.style {
-                Some(Style::Short) => data.short_names.get_by(cmp),
-                Some(Style::Long) => data.long_names.get_by(cmp),
-                Some(Style::Menu) => data.menu_names.get_by(cmp),
-                _ => None,
-            }
-            .or_else(|| data.names.get_by(cmp))
-            {
-                ldn = Some(x);
-                script_qs = None;
-            }
-        }
-
-        if ldn.is_none() {
-            if let Some(region) = locale.id.region {
-                let data = self.locale_data.get();
-                let id = LanguageIdentifier::from((locale.id.language, None, Some(region)));
-                let cmp = |uvstr: &UnvalidatedStr| id.strict_cmp(uvstr).reverse();
-                if let Some(x) = match self.options.style {
-                    Some(Style::Short) => data.short_names.get_by(cmp),
-                    Some(Style::Long) => data.long_names.get_by(cmp),
-                    Some(Style::Menu) => data.menu_names.get_by(cmp),
-                    _ => None,
-                }
-                .or_else(|| data.names.get_by(cmp))
-                {
+        let mut script_qs = langid.script.as_ref();
+        let mut region_qs = langid.region.as_ref();
+
+        if self.options.language_display == LanguageDisplay::Dialect {
+            let candidates = [
+                langid
+                    .script
+                    .zip(langid.region)
+                    .map(|(s, r)| (Some(s), Some(r))),
+                langid.script.map(|s| (Some(s), None)),
+                langid.region.map(|r| (None, Some(r))),
+            ];
+            for (script, region) in candidates.into_iter().flatten() {
+                let id = LanguageIdentifier::from((langid.language, script, region));
+                if let Some(x) = self.dialect_name(&id) {
                     ldn = Some(x);
-                    region_qs = None;
+                    if script.is_some() {
+                        script_qs = None;
+                    }
+                    if region.is_some() {
+                        region_qs = None;
+                    }
+                    break;
                 }
             }
         }
@@ -445,7 +505,7 @@ impl LocaleDisplayNamesFormatter {
         let ldn = ldn
             .or_else(|| {
                 let data = self.language_data.get();
-                let key = locale.id.language.into_tinystr().to_unvalidated();
+                let key = langid.language.into_tinystr().to_unvalidated();
                 match self.options.style {
                     Some(Style::Short) => data.short_names.get(&key),
                     Some(Style::Long) => data.long_names.get(&key),
@@ -454,12 +514,7 @@ impl LocaleDisplayNamesFormatter {
                 }
                 .or_else(|| data.names.get(&key))
             })
-            .unwrap_or(locale.id.language.as_str());
-
-        if script_qs.is_none() && region_qs.is_none() && variants_qs.is_empty() {
-            // The LDN fully represents the locale
-            return Cow::Borrowed(ldn);
-        }
+            .unwrap_or(langid.language.as_str());
 
         // Step - 2: Construct the list of qualifying substrings (LQS).
 
@@ -485,7 +540,7 @@ impl LocaleDisplayNamesFormatter {
             .unwrap_or(region.as_str())
         });
 
-        let variants_qs = variants_qs.iter().map(|variant_key| {
+        let variants_qs = langid.variants.iter().map(|variant_key| {
             self.variant_data
                 .get()
                 .names
@@ -493,32 +548,143 @@ impl LocaleDisplayNamesFormatter {
                 .unwrap_or(variant_key.as_str())
         });
 
-        let lqs = script_qs.into_iter().chain(region_qs).chain(variants_qs);
+        let lqs = script_qs
+            .into_iter()
+            .chain(region_qs)
+            .chain(variants_qs)
+            .map(Cow::Borrowed)
+            .collect();
 
-        // Step - 3: Write LDN and LQS to output
-        // TODO: Move to an `impl Writeable`
+        (ldn, lqs)
+    }
 
-        // TODO: load from data
-        let (before, middle, after) = (" (", ", ", ")");
+    /// Returns the dialect name of a language identifier, such as "British English".
+    fn dialect_name(&self, langid: &LanguageIdentifier) -> Option<&str> {
+        let data = self.locale_data.get();
+        let cmp = |uvstr: &UnvalidatedStr| langid.strict_cmp(uvstr).reverse();
+        match self.options.style {
+            Some(Style::Short) => data.short_names.get_by(cmp),
+            Some(Style::Long) => data.long_names.get_by(cmp),
+            Some(Style::Menu) => data.menu_names.get_by(cmp),
+            _ => None,
+        }
+        .or_else(|| data.names.get_by(cmp))
+    }
 
-        let mut output = String::with_capacity(
-            ldn.len() + before.len() + lqs.clone().map(|x| x.len() + middle.len()).sum::<usize>()
-                - middle.len()
-                + after.len(),
-        );
-        output.push_str(ldn);
-        output.push_str(before);
+    /// Appends the qualifying strings of the transform, Unicode, and private-use extensions
+    /// to the LQS.
+    fn push_extension_qualifiers<'a, 'b: 'a, 'c: 'a>(
+        &'b self,
+        extensions: &'c Extensions,
+        lqs: &mut Vec<Cow<'a, str>>,
+    ) {
+        if let Some(tlang) = extensions.transform.lang.as_ref() {
+            let (ldn, tlqs) = self.language_identifier_parts(tlang);
+            let tlang = if tlqs.is_empty() {
+                Cow::Borrowed(ldn)
+            } else {
+                Cow::Owned(self.format_parts(ldn, tlqs))
+            };
+            lqs.push(self.key_value_name(TRANSFORM_KEY, &tlang));
+        }
+        for (key, value) in extensions.transform.fields.iter() {
+            lqs.push(self.keyword_name(key.into_tinystr(), &value.to_string()));
+        }
 
-        let mut first = true;
-        for lqs in lqs {
-            if !first {
-                output.push_str(middle);
+        for attribute in extensions.unicode.attributes.iter() {
+            lqs.push(Cow::Borrowed(attribute.as_str()));
+        }
+        for (key, value) in extensions.unicode.keywords.iter() {
+            let value = if value.as_tinystr_slice().is_empty() {
+                Cow::Borrowed("true")
             } else {
-                first = false;
-            }
-            output.push_str(lqs);
+                Cow::Owned(value.to_string())
+            };
+            lqs.push(self.keyword_name(key.into_tinystr(), &value));
+        }
+
+        if !extensions.private.is_empty() {
+            let subtags = extensions
+                .private
+                .iter()
+                .map(|subtag| subtag.as_str())
+                .collect::<Vec<_>>()
+                .join("-");
+            lqs.push(self.key_value_name(PRIVATE_USE_KEY, &subtags));
+        }
+    }
+
+    /// Returns the display name of an extension keyword, such as "Buddhist Calendar" for
+    /// `ca-buddhist`, or "Calendar: xyz" if the value has no display name.
+    fn keyword_name(&self, key: TinyAsciiStr<2>, value: &str) -> Cow<str> {
+        self.key_data
+            .get()
+            .type_names
+            .get_2d(&key.to_unvalidated(), UnvalidatedStr::from_str(value))
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| self.key_value_name(key, value))
+    }
+
+    /// Combines the display name of an extension key with a value, such as "Private-Use: foo".
+    fn key_value_name(&self, key: TinyAsciiStr<2>, value: &str) -> Cow<'static, str> {
+        let key_name = self
+            .key_data
+            .get()
+            .names
+            .get(&key.to_unvalidated())
+            .unwrap_or(key.as_str());
+        Cow::Owned(interpolate(
+            &self.patterns.get().locale_key_type_pattern,
+            key_name,
+            value,
+        ))
+    }
+
+    /// Step - 3: Combines the LDN and the LQS using the locale patterns.
+    fn format_parts(&self, ldn: &str, lqs: Vec<Cow<str>>) -> String {
+        let patterns = self.patterns.get();
+        let qualifiers = lqs
+            .into_iter()
+            .fold(None, |acc: Option<String>, qs| {
+                Some(match acc {
+                    None => qs.into_owned(),
+                    Some(acc) => interpolate(&patterns.locale_separator, &acc, &qs),
+                })
+            })
+            .unwrap_or_default();
+        interpolate(&patterns.locale_pattern, ldn, &qualifiers)
+    }
+}
+
+/// Replaces `{0}` and `{1}` in a pattern with the given strings.
+fn interpolate(pattern: &str, first: &str, second: &str) -> String {
+    let mut len = 0;
+    for_each_part(pattern, first, second, |part| len += part.len());
+    let mut output = String::with_capacity(len);
+    for_each_part(pattern, first, second, |part| output.push_str(part));
+    output
+}
+
+fn for_each_part<'a>(
+    pattern: &'a str,
+    first: &'a str,
+    second: &'a str,
+    mut f: impl FnMut(&'a str),
+) {
+    let mut rest = pattern;
+    while let Some(index) = rest.find('{') {
+        let (literal, placeholder) = rest.split_at(index);
+        f(literal);
+        if let Some(after) = placeholder.strip_prefix("{0}") {
+            f(first);
+            rest = after;
+        } else if let Some(after) = placeholder.strip_prefix("{1}") {
+            f(second);
+            rest = after;
+        } else {
+            f("{");
+            rest = placeholder.get(1..).unwrap_or_default();
         }
-        output.push_str(after);
-        Cow::Owned(output)
     }
+    f(rest);
 }
@@ -9,10 +9,11 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::borrow::Cow;
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
 use zerovec::ule::UnvalidatedStr;
-use zerovec::ZeroMap;
+use zerovec::{ZeroMap, ZeroMap2d};
 
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
@@ -32,8 +33,10 @@ const _: () = {
         pub use icu_locid_transform as locid_transform;
     }
     icu_displaynames_data::make_provider!(Baked);
+    icu_displaynames_data::impl_displaynames_keys_v1!(Baked);
     icu_displaynames_data::impl_displaynames_languages_v1!(Baked);
     icu_displaynames_data::impl_displaynames_locales_v1!(Baked);
+    icu_displaynames_data::impl_displaynames_patterns_v1!(Baked);
     icu_displaynames_data::impl_displaynames_regions_v1!(Baked);
     icu_displaynames_data::impl_displaynames_scripts_v1!(Baked);
     icu_displaynames_data::impl_displaynames_variants_v1!(Baked);
@@ -42,8 +45,10 @@ const _: () = {
 #[cfg(feature = "datagen")]
 /// The latest minimum set of keys required by this component.
 pub const KEYS: &[DataKey] = &[
+    KeyDisplayNamesV1Marker::KEY,
     LanguageDisplayNamesV1Marker::KEY,
     LocaleDisplayNamesV1Marker::KEY,
+    LocaleDisplayNamesPatternsV1Marker::KEY,
     RegionDisplayNamesV1Marker::KEY,
     ScriptDisplayNamesV1Marker::KEY,
     VariantDisplayNamesV1Marker::KEY,
@@ -58,6 +63,7 @@ type UnvalidatedLanguage = UnvalidatedTinyAsciiStr<3>;
 type UnvalidatedScript = UnvalidatedTinyAsciiStr<4>;
 type UnvalidatedLocale = UnvalidatedStr;
 type UnvalidatedVariant = UnvalidatedTinyAsciiStr<8>;
+type UnvalidatedKey = UnvalidatedTinyAsciiStr<2>;
 
 #[icu_provider::data_struct(RegionDisplayNamesV1Marker = "displaynames/regions@1")]
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -162,3 +168,50 @@ pub struct VariantDisplayNamesV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub names: ZeroMap<'data, UnvalidatedVariant, str>,
 }
+
+#[icu_provider::data_struct(KeyDisplayNamesV1Marker = "displaynames/keys@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// KeyDisplayNames provides the user-translated names for the keys and values of
+/// locale extensions.
+///
+/// Keys are BCP-47 extension keys such as `ca` or `d0`, as well as the `t` and `x`
+/// singletons. Values are the BCP-47 values of the key, such as `buddhist`.
+pub struct KeyDisplayNamesV1<'data> {
+    /// Mapping for key to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedKey, str>,
+    /// Mapping for key and value to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub type_names: ZeroMap2d<'data, UnvalidatedKey, UnvalidatedStr, str>,
+}
+
+#[icu_provider::data_struct(LocaleDisplayNamesPatternsV1Marker = "displaynames/patterns@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// LocaleDisplayNamesPatterns provides the patterns used to compose a locale display name.
+///
+/// Each pattern has the placeholders `{0}` and `{1}`.
+pub struct LocaleDisplayNamesPatternsV1<'data> {
+    /// Pattern combining a locale display name with its qualifiers, such as `{0} ({1})`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_pattern: Cow<'data, str>,
+    /// Pattern joining two qualifiers, such as `{0}, {1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_separator: Cow<'data, str>,
+    /// Pattern combining the display name of a key with a value, such as `{0}: {1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_key_type_pattern: Cow<'data, str>,
+}
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_displaynames::{DisplayNamesOptions, LocaleDisplayNamesFormatter};
+use icu_displaynames::{DisplayNamesOptions, LanguageDisplay, LocaleDisplayNamesFormatter, Style};
 use icu_locid::locale;
 use icu_locid::Locale;
 use std::borrow::Cow;
@@ -97,3 +97,85 @@ fn test_concatenate() {
         }
     }
 }
+
+#[test]
+fn test_extensions() {
+    let cases = [
+        (
+            "de-DE-u-ca-buddhist-co-phonebk",
+            "German (Germany, Buddhist Calendar, Phonebook Sort Order)",
+        ),
+        ("en-u-kn", "English (Sort Digits Numerically)"),
+        ("en-u-hc-h99", "English (Hour Cycle (12 vs 24): h99)"),
+        ("en-u-attr-zz-abc", "English (attr, zz: abc)"),
+        ("de-CH-u-nu-thai", "Swiss High German (Thai Digits)"),
+        ("ja-t-it", "Japanese (Transformed: Italian)"),
+        (
+            "ja-t-de-ch-d0-fwidth",
+            "Japanese (Transformed: Swiss High German, Fullwidth)",
+        ),
+        ("en-x-foo-bar", "English (Private-Use: foo-bar)"),
+        (
+            "es-419-t-en-u-ms-metric-x-priv",
+            "Latin American Spanish (Transformed: English, Metric System, Private-Use: priv)",
+        ),
+    ];
+    let display_name =
+        LocaleDisplayNamesFormatter::try_new(&locale!("en-001").into(), Default::default())
+            .expect("Data should load successfully");
+    for (input, expected) in cases {
+        let locale: Locale = input.parse().unwrap();
+        let result = display_name.of(&locale);
+        assert_eq!(result, expected, "{input}");
+        let result = result.into_owned();
+        assert_eq!(result.capacity(), result.len());
+    }
+}
+
+#[test]
+fn test_language_display_and_style() {
+    let cases = [
+        (
+            LanguageDisplay::Standard,
+            None,
+            "en-GB",
+            "English (United Kingdom)",
+        ),
+        (
+            LanguageDisplay::Standard,
+            None,
+            "de-CH-u-ca-gregory",
+            "German (Switzerland, Gregorian Calendar)",
+        ),
+        (
+            LanguageDisplay::Dialect,
+            Some(Style::Short),
+            "en-GB",
+            "UK English",
+        ),
+        (
+            LanguageDisplay::Standard,
+            Some(Style::Short),
+            "en-GB",
+            "English (UK)",
+        ),
+        (
+            LanguageDisplay::Dialect,
+            Some(Style::Menu),
+            "zh-t-zh-hk",
+            "Chinese, Mandarin (Transformed: Chinese, Mandarin (Hong Kong SAR China))",
+        ),
+    ];
+    for (language_display, style, input, expected) in cases {
+        let mut options: DisplayNamesOptions = Default::default();
+        options.language_display = language_display;
+        options.style = style;
+        let display_name = LocaleDisplayNamesFormatter::try_new(&locale!("en-001").into(), options)
+            .expect("Data should load successfully");
+        assert_eq!(
+            display_name.of(&input.parse().unwrap()),
+            expected,
+            "{input}"
+        );
+    }
+}
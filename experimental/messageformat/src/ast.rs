@@ -0,0 +1,113 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::MessageError;
+
+/// A parsed MessageFormat 2.0 message.
+///
+/// Messages are parsed once and can then be formatted any number of times
+/// with [`MessageFormatter::format`](crate::MessageFormatter::format).
+///
+/// # Examples
+///
+/// ```
+/// use icu::messageformat::{Message, MessageError};
+///
+/// assert!(Message::try_from_str("Hello, {$name}!").is_ok());
+/// assert!(Message::try_from_str(
+///     ".match {$count :number} one {{One item}} * {{{$count} items}}"
+/// )
+/// .is_ok());
+///
+/// assert_eq!(
+///     Message::try_from_str("Hello, {$name"),
+///     Err(MessageError::Syntax(13))
+/// );
+/// assert_eq!(
+///     Message::try_from_str(".match {$count :number} one {{One item}}"),
+///     Err(MessageError::MissingFallbackVariant)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub(crate) declarations: Vec<Declaration>,
+    pub(crate) body: Body,
+}
+
+impl Message {
+    /// Parses and validates a message in MessageFormat 2.0 syntax.
+    pub fn try_from_str(source: &str) -> Result<Self, MessageError> {
+        crate::parser::parse(source)
+    }
+}
+
+impl FromStr for Message {
+    type Err = MessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+/// An `.input` or `.local` declaration. An `.input` declaration's expression has
+/// the declared variable as its operand.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Declaration {
+    pub(crate) name: String,
+    pub(crate) expression: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Body {
+    Pattern(Pattern),
+    Matcher {
+        selectors: Vec<Expression>,
+        variants: Vec<Variant>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Variant {
+    pub(crate) keys: Vec<Key>,
+    pub(crate) pattern: Pattern,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Key {
+    Literal(String),
+    CatchAll,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct Pattern(pub(crate) Vec<PatternPart>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PatternPart {
+    Text(String),
+    Expression(Expression),
+    /// Markup such as `{#b}`, `{/b}` or `{#img/}`, which produces no output.
+    Markup,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Expression {
+    pub(crate) operand: Option<Operand>,
+    pub(crate) function: Option<Function>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Operand {
+    Literal(String),
+    Variable(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Function {
+    pub(crate) name: String,
+    pub(crate) options: Vec<(String, Operand)>,
+}
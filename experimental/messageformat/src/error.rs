@@ -0,0 +1,90 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use displaydoc::Display;
+use icu_datetime::DateTimeError;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MessageError {
+    /// The message is not valid MessageFormat 2.0 syntax.
+    #[displaydoc("Syntax error at byte {0}")]
+    Syntax(usize),
+    /// The message contains a reserved or private-use annotation or statement.
+    #[displaydoc("Unsupported expression at byte {0}")]
+    UnsupportedExpression(usize),
+    /// A variable is declared more than once.
+    #[displaydoc("Duplicate declaration of ${0}")]
+    DuplicateDeclaration(String),
+    /// A variant does not have exactly one key per selector.
+    #[displaydoc("Variant key count does not match the selector count")]
+    VariantKeyMismatch,
+    /// No variant consists solely of catch-all (`*`) keys.
+    #[displaydoc("Missing fallback variant")]
+    MissingFallbackVariant,
+    /// A selector has no function annotation.
+    #[displaydoc("Selector is missing an annotation")]
+    MissingSelectorAnnotation,
+    /// A variable was not declared and not passed as an argument.
+    #[displaydoc("Unresolved variable ${0}")]
+    UnresolvedVariable(String),
+    /// A function is not registered with the formatter.
+    #[displaydoc("Unknown function :{0}")]
+    UnknownFunction(String),
+    /// A function was called with an operand it cannot handle.
+    #[displaydoc("Invalid operand")]
+    BadOperand,
+    /// A function was called with an invalid option value.
+    #[displaydoc("Invalid value for option {0}")]
+    BadOption(String),
+    /// A function that does not support selection was used as a selector.
+    #[displaydoc("Function does not support selection")]
+    NotSelectable,
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// An error originating from [`DateTimeFormatter`](icu_datetime::DateTimeFormatter).
+    #[displaydoc("Error formatting date: {0}")]
+    DateTime(DateTimeError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MessageError {}
+
+impl From<PluralsError> for MessageError {
+    fn from(e: PluralsError) -> Self {
+        MessageError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for MessageError {
+    fn from(e: DataError) -> Self {
+        MessageError::Data(e)
+    }
+}
+
+impl From<DecimalError> for MessageError {
+    fn from(e: DecimalError) -> Self {
+        MessageError::Decimal(e)
+    }
+}
+
+impl From<DateTimeError> for MessageError {
+    fn from(e: DateTimeError) -> Self {
+        MessageError::DateTime(e)
+    }
+}
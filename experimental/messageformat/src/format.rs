@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use fixed_decimal::FixedDecimal;
+use writeable::Writeable;
+
+use crate::{MessageError, MessageFormatter};
+
+pub mod parts {
+    //! Parts of a [`FormattedMessage`](crate::FormattedMessage).
+
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::FormattedMessage) to mark
+    /// text from the message pattern.
+    pub const LITERAL: Part = Part {
+        category: "messageformat",
+        value: "literal",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::FormattedMessage) to mark
+    /// a formatted expression, including any bidi isolation characters.
+    pub const PLACEHOLDER: Part = Part {
+        category: "messageformat",
+        value: "placeholder",
+    };
+}
+
+/// An intermediate structure returned by [`MessageFormatter`](crate::MessageFormatter).
+/// This structure can be consumed via [`Writeable`](Writeable) trait to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedMessage<'a> {
+    pub(crate) formatter: &'a MessageFormatter,
+    pub(crate) parts: Vec<ResolvedPart<'a>>,
+    pub(crate) errors: Vec<MessageError>,
+}
+
+impl<'a> FormattedMessage<'a> {
+    /// Returns the errors that occurred while formatting the message, in the order
+    /// in which they occurred.
+    ///
+    /// See [`MessageFormatter::format`] for an example.
+    pub fn errors(&self) -> &[MessageError] {
+        &self.errors
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum ResolvedPart<'a> {
+    Text(&'a str),
+    Placeholder {
+        value: FormattedValue,
+        /// The isolate character to wrap the value in, if any.
+        isolate: Option<char>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) enum FormattedValue {
+    String(String),
+    Number(FixedDecimal),
+}
+
+impl<'a> Writeable for FormattedMessage<'a> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        for part in &self.parts {
+            match part {
+                ResolvedPart::Text(text) => {
+                    sink.with_part(parts::LITERAL, |s| s.write_str(text))?
+                }
+                ResolvedPart::Placeholder { value, isolate } => {
+                    sink.with_part(parts::PLACEHOLDER, |s| {
+                        if let Some(isolate) = isolate {
+                            s.write_char(*isolate)?;
+                        }
+                        match value {
+                            FormattedValue::String(v) => s.write_str(v)?,
+                            FormattedValue::Number(v) => self
+                                .formatter
+                                .fixed_decimal_format
+                                .format(v)
+                                .write_to_parts(s)?,
+                        }
+                        if isolate.is_some() {
+                            // U+2069 POP DIRECTIONAL ISOLATE
+                            s.write_char('\u{2069}')?;
+                        }
+                        Ok(())
+                    })?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedMessage<'_>);
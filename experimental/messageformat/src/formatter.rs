@@ -0,0 +1,621 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use icu_calendar::provider::{
+    ChineseCacheV1Marker, DangiCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
+    VietnameseCacheV1Marker, WeekDataV1Marker,
+};
+use icu_datetime::provider::calendar::*;
+use icu_datetime::{DateTimeError, DateTimeFormatter, DateTimeFormatterOptions};
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+
+use crate::ast::*;
+use crate::format::{FormattedMessage, FormattedValue, ResolvedPart};
+use crate::function::{DateTimeFunction, DateTimeKind, NumberFunction, StringFunction};
+use crate::options::{BidiIsolation, MessageFormatterOptions};
+use crate::{mf1, FunctionOptions, MessageArguments, MessageError, MessageFunction, Value};
+
+/// A formatter for [Unicode MessageFormat 2.0](https://www.unicode.org/reports/tr35/tr35-72/tr35-messageFormat.html)
+/// messages.
+///
+/// The formatter provides the default functions `:string`, `:number`, `:integer`,
+/// `:datetime`, `:date` and `:time`. Further functions can be added with
+/// [`MessageFormatter::register_function`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::locid::locale;
+/// use icu::messageformat::{Message, MessageArguments, MessageFormatter};
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     MessageFormatter::try_new(&locale!("en").into(), Default::default())
+///         .expect("locale should be present");
+///
+/// let message = Message::try_from_str(
+///     ".input {$count :integer}
+///      .match {$count}
+///      0 {{You have no new messages since {$date :date style=long}.}}
+///      one {{You have one new message since {$date :date style=long}.}}
+///      * {{You have {$count} new messages since {$date :date style=long}.}}",
+/// )
+/// .unwrap();
+///
+/// let date = DateTime::try_new_iso_datetime(2024, 3, 1, 9, 30, 0).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format(
+///         &message,
+///         &MessageArguments::new()
+///             .with("count", 1)
+///             .with("date", date.clone())
+///     ),
+///     "You have one new message since March 1, 2024."
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(
+///         &message,
+///         &MessageArguments::new().with("count", 1200).with("date", date)
+///     ),
+///     "You have 1,200 new messages since March 1, 2024."
+/// );
+/// ```
+pub struct MessageFormatter {
+    pub(crate) fixed_decimal_format: FixedDecimalFormatter,
+    options: MessageFormatterOptions,
+    functions: BTreeMap<String, Box<dyn MessageFunction>>,
+}
+
+impl fmt::Debug for MessageFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageFormatter")
+            .field("fixed_decimal_format", &self.fixed_decimal_format)
+            .field("options", &self.options)
+            .field("functions", &self.functions.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// A resolved expression: its value and, if it has an annotation, the function
+/// call that can be used for selection.
+#[derive(Clone)]
+struct Resolved<'a> {
+    value: Value,
+    selector: Option<Selector<'a>>,
+}
+
+#[derive(Clone)]
+struct Selector<'a> {
+    function: &'a dyn MessageFunction,
+    operand: Option<Value>,
+    options: FunctionOptions,
+}
+
+/// The declared variables, or the fallback strings of the declarations that could
+/// not be resolved.
+type Environment<'a> = BTreeMap<&'a str, Result<Resolved<'a>, String>>;
+
+impl MessageFormatter {
+    /// Creates a new [`MessageFormatter`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: MessageFormatterOptions,
+    ) -> Result<Self, MessageError> {
+        let mut formatter = Self::new_with_number_functions(
+            FixedDecimalFormatter::try_new(locale, FixedDecimalFormatterOptions::default())?,
+            || PluralRules::try_new_cardinal(locale),
+            || PluralRules::try_new_ordinal(locale),
+            options,
+        )?;
+        formatter
+            .register_datetime_functions(|options| DateTimeFormatter::try_new(locale, options))?;
+        Ok(formatter)
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: MessageFormatterOptions,
+        error: MessageError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: MessageFormatterOptions,
+    ) -> Result<Self, MessageError>
+    where
+        D: DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<ChineseCacheV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<DangiCacheV1Marker>
+            + DataProvider<VietnameseCacheV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        let mut formatter = Self::new_with_number_functions(
+            FixedDecimalFormatter::try_new_unstable(
+                provider,
+                locale,
+                FixedDecimalFormatterOptions::default(),
+            )?,
+            || PluralRules::try_new_cardinal_unstable(provider, locale),
+            || PluralRules::try_new_ordinal_unstable(provider, locale),
+            options,
+        )?;
+        formatter.register_datetime_functions(|options| {
+            DateTimeFormatter::try_new_unstable(provider, locale, options)
+        })?;
+        Ok(formatter)
+    }
+
+    fn new_with_number_functions<E>(
+        fixed_decimal_format: FixedDecimalFormatter,
+        cardinal: impl Fn() -> Result<PluralRules, E>,
+        ordinal: impl Fn() -> Result<PluralRules, E>,
+        options: MessageFormatterOptions,
+    ) -> Result<Self, MessageError>
+    where
+        MessageError: From<E>,
+    {
+        let mut formatter = Self {
+            fixed_decimal_format,
+            options,
+            functions: BTreeMap::new(),
+        };
+        formatter.register_function("string", StringFunction);
+        for (name, integer) in [("number", false), ("integer", true)] {
+            formatter.register_function(
+                name,
+                NumberFunction {
+                    cardinal: cardinal()?,
+                    ordinal: ordinal()?,
+                    integer,
+                },
+            );
+        }
+        Ok(formatter)
+    }
+
+    fn register_datetime_functions(
+        &mut self,
+        new_formatter: impl Fn(DateTimeFormatterOptions) -> Result<DateTimeFormatter, DateTimeError>,
+    ) -> Result<(), MessageError> {
+        for (name, kind) in [
+            ("datetime", DateTimeKind::DateTime),
+            ("date", DateTimeKind::Date),
+            ("time", DateTimeKind::Time),
+        ] {
+            self.register_function(name, DateTimeFunction::try_new(kind, &new_formatter)?);
+        }
+        Ok(())
+    }
+
+    /// Registers a function that messages can call as `:name`, replacing any
+    /// function previously registered under the same name.
+    ///
+    /// See [`MessageFunction`] for an example.
+    pub fn register_function(&mut self, name: &str, function: impl MessageFunction + 'static) {
+        self.functions.insert(name.into(), Box::new(function));
+    }
+
+    /// Formats a message with the given arguments.
+    ///
+    /// As specified by MessageFormat 2.0, errors do not abort formatting. An expression
+    /// that cannot be resolved, for example because a variable is missing or a function
+    /// fails, is formatted as its fallback representation, such as `{$name}`, and a
+    /// selector that cannot be resolved only matches catch-all (`*`) keys. The errors are
+    /// returned by [`FormattedMessage::errors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::messageformat::{
+    ///     Message, MessageArguments, MessageError, MessageFormatter,
+    /// };
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter =
+    ///     MessageFormatter::try_new(&locale!("en").into(), Default::default())
+    ///         .expect("locale should be present");
+    /// let message =
+    ///     Message::try_from_str("Hello, {$name}! You have {|many| :number} messages.")
+    ///         .unwrap();
+    ///
+    /// let formatted = formatter.format(&message, &MessageArguments::new());
+    /// assert_writeable_eq!(formatted, "Hello, {$name}! You have {|many|} messages.");
+    /// assert_eq!(
+    ///     formatted.errors(),
+    ///     [
+    ///         MessageError::UnresolvedVariable("name".into()),
+    ///         MessageError::BadOperand
+    ///     ]
+    /// );
+    /// ```
+    pub fn format<'a>(
+        &'a self,
+        message: &'a Message,
+        arguments: &MessageArguments,
+    ) -> FormattedMessage<'a> {
+        let mut errors = Vec::new();
+        let mut env = Environment::new();
+        for declaration in &message.declarations {
+            // The operand of an `.input` declaration is not yet in `env`, so it is
+            // resolved from the arguments.
+            let resolved = self.resolve(&declaration.expression, &env, arguments, &mut errors);
+            env.insert(&declaration.name, resolved);
+        }
+
+        let pattern = match &message.body {
+            Body::Pattern(pattern) => Some(pattern),
+            Body::Matcher {
+                selectors,
+                variants,
+            } => self.select(selectors, variants, &env, arguments, &mut errors),
+        };
+        let Some(pattern) = pattern else {
+            // The parser ensures that there is a variant with only catch-all keys
+            errors.push(MessageError::MissingFallbackVariant);
+            return FormattedMessage {
+                formatter: self,
+                parts: Vec::new(),
+                errors,
+            };
+        };
+
+        let mut parts = Vec::with_capacity(pattern.0.len());
+        for part in &pattern.0 {
+            parts.push(match part {
+                PatternPart::Text(text) => ResolvedPart::Text(text),
+                PatternPart::Markup => continue,
+                PatternPart::Expression(expression) => {
+                    let value = self
+                        .resolve(expression, &env, arguments, &mut errors)
+                        .and_then(|resolved| {
+                            self.format_value(resolved.value).map_err(|error| {
+                                errors.push(error);
+                                fallback(expression)
+                            })
+                        })
+                        .unwrap_or_else(|fallback| {
+                            FormattedValue::String(format!("{{{fallback}}}"))
+                        });
+                    ResolvedPart::Placeholder {
+                        value,
+                        isolate: self.isolate(expression, &env, arguments, &mut errors),
+                    }
+                }
+            });
+        }
+        FormattedMessage {
+            formatter: self,
+            parts,
+            errors,
+        }
+    }
+
+    /// Formats a message in ICU MessageFormat syntax with the given arguments.
+    ///
+    /// See the [`mf1`](crate::mf1) module for the supported syntax.
+    pub fn format_mf1<'a>(
+        &'a self,
+        message: &'a mf1::Message,
+        arguments: &MessageArguments,
+    ) -> Result<FormattedMessage<'a>, MessageError> {
+        let mut parts = Vec::new();
+        self.resolve_mf1(&message.pattern, None, arguments, &mut parts)?;
+        Ok(FormattedMessage {
+            formatter: self,
+            parts,
+            errors: Vec::new(),
+        })
+    }
+
+    pub(crate) fn function(&self, name: &str) -> Result<&dyn MessageFunction, MessageError> {
+        self.functions
+            .get(name)
+            .map(|function| function.as_ref())
+            .ok_or_else(|| MessageError::UnknownFunction(name.into()))
+    }
+
+    /// Resolves a variable. If it cannot be resolved, the error is added to `errors` and
+    /// the fallback string of the variable is returned instead.
+    fn variable<'a>(
+        &self,
+        name: &str,
+        env: &Environment<'a>,
+        arguments: &MessageArguments,
+        errors: &mut Vec<MessageError>,
+    ) -> Result<Resolved<'a>, String> {
+        // A declaration that failed to resolve has the fallback string of its expression
+        if let Some(resolved) = env.get(name) {
+            return resolved.clone();
+        }
+        match arguments.get(name) {
+            Some(value) => Ok(Resolved {
+                value: value.clone(),
+                selector: None,
+            }),
+            None => {
+                errors.push(MessageError::UnresolvedVariable(name.into()));
+                Err(format!("${name}"))
+            }
+        }
+    }
+
+    fn operand_value(
+        &self,
+        operand: &Operand,
+        env: &Environment,
+        arguments: &MessageArguments,
+        errors: &mut Vec<MessageError>,
+    ) -> Option<Value> {
+        match operand {
+            Operand::Literal(literal) => Some(Value::String(literal.clone())),
+            Operand::Variable(name) => self
+                .variable(name, env, arguments, errors)
+                .ok()
+                .map(|resolved| resolved.value),
+        }
+    }
+
+    /// Resolves an expression. If it cannot be resolved, the error is added to `errors`
+    /// and the fallback string of the expression is returned instead.
+    fn resolve<'a>(
+        &'a self,
+        expression: &Expression,
+        env: &Environment<'a>,
+        arguments: &MessageArguments,
+        errors: &mut Vec<MessageError>,
+    ) -> Result<Resolved<'a>, String> {
+        let operand = match &expression.operand {
+            None => None,
+            Some(Operand::Literal(literal)) => Some(Resolved {
+                value: Value::String(literal.clone()),
+                selector: None,
+            }),
+            Some(Operand::Variable(name)) => Some(self.variable(name, env, arguments, errors)?),
+        };
+        let Some(function) = &expression.function else {
+            // The parser ensures that an expression has an operand or a function
+            return operand.ok_or_else(|| fallback(expression));
+        };
+
+        let mut options = FunctionOptions::default();
+        for (name, value) in &function.options {
+            // Options in the `u:` namespace are handled by the formatter itself, and
+            // options that cannot be resolved are ignored
+            if !name.starts_with("u:") {
+                if let Some(value) = self.operand_value(value, env, arguments, errors) {
+                    options.values.insert(name.clone(), value);
+                }
+            }
+        }
+        let function = match self.function(&function.name) {
+            Ok(function) => function,
+            Err(error) => {
+                errors.push(error);
+                return Err(fallback(expression));
+            }
+        };
+        let operand = operand.map(|operand| operand.value);
+        match function.format(operand.as_ref(), &options) {
+            Ok(value) => Ok(Resolved {
+                value,
+                selector: Some(Selector {
+                    function,
+                    operand,
+                    options,
+                }),
+            }),
+            Err(error) => {
+                errors.push(error);
+                Err(fallback(expression))
+            }
+        }
+    }
+
+    /// Selects the best variant, following the MessageFormat 2.0 pattern
+    /// selection algorithm.
+    ///
+    /// A selector that cannot be resolved or that fails to select only matches
+    /// catch-all keys.
+    fn select<'m>(
+        &self,
+        selectors: &[Expression],
+        variants: &'m [Variant],
+        env: &Environment,
+        arguments: &MessageArguments,
+        errors: &mut Vec<MessageError>,
+    ) -> Option<&'m Pattern> {
+        // For each selector, the matching keys in order of preference
+        let mut preferences: Vec<Vec<&str>> = Vec::with_capacity(selectors.len());
+        for (i, selector) in selectors.iter().enumerate() {
+            let mut keys: Vec<&str> = variants
+                .iter()
+                .filter_map(|variant| match variant.keys.get(i) {
+                    Some(Key::Literal(key)) => Some(key.as_str()),
+                    _ => None,
+                })
+                .collect();
+            keys.sort_unstable();
+            keys.dedup();
+            let matches = match self.resolve(selector, env, arguments, errors) {
+                Ok(Resolved {
+                    selector: Some(selector),
+                    ..
+                }) => selector
+                    .function
+                    .select(selector.operand.as_ref(), &selector.options, &keys)
+                    .unwrap_or_else(|error| {
+                        errors.push(error);
+                        Vec::new()
+                    }),
+                Ok(Resolved { selector: None, .. }) => {
+                    errors.push(MessageError::MissingSelectorAnnotation);
+                    Vec::new()
+                }
+                // The error has already been recorded
+                Err(_) => Vec::new(),
+            };
+            preferences.push(
+                matches
+                    .iter()
+                    .filter_map(|&j| keys.get(j).copied())
+                    .collect(),
+            );
+        }
+
+        let rank = |variant: &Variant, i: usize| match (variant.keys.get(i), preferences.get(i)) {
+            (Some(Key::Literal(key)), Some(preferred)) => preferred.iter().position(|p| p == key),
+            // Catch-all keys sort after all matching keys
+            _ => Some(usize::MAX),
+        };
+        let mut candidates: Vec<&Variant> = variants
+            .iter()
+            .filter(|variant| (0..selectors.len()).all(|i| rank(variant, i).is_some()))
+            .collect();
+        // Sorting by each selector from the last to the first one orders the
+        // candidates by the first selector, then by the second one, and so on.
+        for i in (0..selectors.len()).rev() {
+            candidates.sort_by_key(|variant| rank(variant, i));
+        }
+        candidates.first().map(|variant| &variant.pattern)
+    }
+
+    pub(crate) fn format_value(&self, value: Value) -> Result<FormattedValue, MessageError> {
+        Ok(match value {
+            Value::String(s) => FormattedValue::String(s),
+            Value::Number(n) => FormattedValue::Number(n),
+            // Unannotated dates are formatted with the default `:datetime` options
+            Value::DateTime(_) => match self
+                .function("datetime")?
+                .format(Some(&value), &FunctionOptions::default())?
+            {
+                Value::DateTime(_) => return Err(MessageError::BadOperand),
+                value => self.format_value(value)?,
+            },
+        })
+    }
+
+    /// Returns the isolate character for a placeholder without a `u:dir` option.
+    pub(crate) fn default_isolate(&self) -> Option<char> {
+        match self.options.bidi_isolation {
+            BidiIsolation::None => None,
+            // U+2068 FIRST STRONG ISOLATE
+            BidiIsolation::Isolate => Some('\u{2068}'),
+        }
+    }
+
+    /// Returns the isolate character for a placeholder, taking the `u:dir` option into account.
+    fn isolate(
+        &self,
+        expression: &Expression,
+        env: &Environment,
+        arguments: &MessageArguments,
+        errors: &mut Vec<MessageError>,
+    ) -> Option<char> {
+        if self.options.bidi_isolation == BidiIsolation::None {
+            return None;
+        }
+        let dir = expression
+            .function
+            .as_ref()
+            .and_then(|function| function.options.iter().find(|(name, _)| name == "u:dir"))
+            .and_then(|(_, value)| self.operand_value(value, env, arguments, errors));
+        Some(match dir.as_ref().map(|dir| dir.as_str()) {
+            // U+2066 LEFT-TO-RIGHT ISOLATE
+            Some(Some("ltr")) => '\u{2066}',
+            // U+2067 RIGHT-TO-LEFT ISOLATE
+            Some(Some("rtl")) => '\u{2067}',
+            // U+2068 FIRST STRONG ISOLATE
+            None | Some(Some("auto")) => '\u{2068}',
+            Some(_) => {
+                errors.push(MessageError::BadOption("u:dir".into()));
+                '\u{2068}'
+            }
+        })
+    }
+}
+
+/// Returns the fallback string of an expression that cannot be resolved, which is
+/// formatted in braces in place of the expression.
+fn fallback(expression: &Expression) -> String {
+    match (&expression.operand, &expression.function) {
+        (Some(Operand::Literal(literal)), _) => {
+            let mut fallback = String::from("|");
+            for c in literal.chars() {
+                if matches!(c, '\\' | '|') {
+                    fallback.push('\\');
+                }
+                fallback.push(c);
+            }
+            fallback.push('|');
+            fallback
+        }
+        (Some(Operand::Variable(name)), _) => format!("${name}"),
+        (None, Some(function)) => format!(":{}", function.name),
+        // U+FFFD REPLACEMENT CHARACTER
+        (None, None) => "\u{FFFD}".into(),
+    }
+}
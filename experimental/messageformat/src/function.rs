@@ -0,0 +1,332 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The functions that can be called from a message, such as `:number` in `{$x :number}`.
+
+use alloc::vec::Vec;
+
+use fixed_decimal::FixedDecimal;
+use icu_datetime::options::length::{Bag, Date, Time};
+use icu_datetime::{DateTimeError, DateTimeFormatter, DateTimeFormatterOptions};
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::MaybeSendSync;
+use writeable::Writeable;
+
+use crate::{FunctionOptions, MessageError, Value};
+
+/// A function that can be registered with a [`MessageFormatter`](crate::MessageFormatter)
+/// using [`MessageFormatter::register_function`](crate::MessageFormatter::register_function).
+///
+/// This trait is implemented for closures taking the operand and options of the
+/// function call.
+///
+/// With the `sync` Cargo feature, functions have to be [`Send`] and [`Sync`], which
+/// makes [`MessageFormatter`](crate::MessageFormatter) [`Send`] and [`Sync`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::locale;
+/// use icu::messageformat::{
+///     FunctionOptions, Message, MessageArguments, MessageError,
+///     MessageFormatter, Value,
+/// };
+/// use writeable::assert_writeable_eq;
+///
+/// let mut formatter =
+///     MessageFormatter::try_new(&locale!("en").into(), Default::default())
+///         .expect("locale should be present");
+/// formatter.register_function(
+///     "uppercase",
+///     |operand: Option<&Value>, _: &FunctionOptions| match operand {
+///         Some(Value::String(s)) => Ok(Value::String(s.to_uppercase())),
+///         _ => Err(MessageError::BadOperand),
+///     },
+/// );
+///
+/// let message = Message::try_from_str("Hello, {$name :uppercase}!").unwrap();
+/// assert_writeable_eq!(
+///     formatter.format(&message, &MessageArguments::new().with("name", "Ada")),
+///     "Hello, ADA!"
+/// );
+/// ```
+pub trait MessageFunction: MaybeSendSync {
+    /// Formats the operand of the function call, if any, with the given options.
+    fn format(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+    ) -> Result<Value, MessageError>;
+
+    /// Returns the indices of the `keys` that match the operand, most preferred first.
+    ///
+    /// The default implementation formats the operand and matches keys that are equal
+    /// to the resulting string.
+    fn select(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+        keys: &[&str],
+    ) -> Result<Vec<usize>, MessageError> {
+        match self.format(operand, options)? {
+            Value::String(s) => Ok(keys.iter().position(|key| *key == s).into_iter().collect()),
+            _ => Err(MessageError::NotSelectable),
+        }
+    }
+}
+
+impl<F> MessageFunction for F
+where
+    F: Fn(Option<&Value>, &FunctionOptions) -> Result<Value, MessageError> + MaybeSendSync,
+{
+    fn format(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+    ) -> Result<Value, MessageError> {
+        self(operand, options)
+    }
+}
+
+/// The `:string` function.
+#[derive(Debug)]
+pub(crate) struct StringFunction;
+
+impl MessageFunction for StringFunction {
+    fn format(&self, operand: Option<&Value>, _: &FunctionOptions) -> Result<Value, MessageError> {
+        match operand {
+            Some(Value::String(s)) => Ok(Value::String(s.clone())),
+            Some(Value::Number(n)) => Ok(Value::String(n.write_to_string().into_owned())),
+            _ => Err(MessageError::BadOperand),
+        }
+    }
+}
+
+/// The `:number` and `:integer` functions.
+///
+/// Supports the `minimumIntegerDigits`, `minimumFractionDigits`, `maximumFractionDigits`
+/// and `select` (`plural`, `ordinal` or `exact`) options.
+#[derive(Debug)]
+pub(crate) struct NumberFunction {
+    pub(crate) cardinal: PluralRules,
+    pub(crate) ordinal: PluralRules,
+    pub(crate) integer: bool,
+}
+
+impl NumberFunction {
+    fn number(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+    ) -> Result<FixedDecimal, MessageError> {
+        let mut number = match operand {
+            Some(Value::Number(n)) => n.clone(),
+            Some(Value::String(s)) => s.parse().map_err(|_| MessageError::BadOperand)?,
+            _ => return Err(MessageError::BadOperand),
+        };
+        if self.integer {
+            number.trunc(0);
+        } else {
+            if let Some(digits) = digits_option(options, "maximumFractionDigits")? {
+                number.half_even(-digits);
+            }
+            if let Some(digits) = digits_option(options, "minimumFractionDigits")? {
+                number.pad_end(-digits);
+            }
+        }
+        if let Some(digits) = digits_option(options, "minimumIntegerDigits")? {
+            number.pad_start(digits);
+        }
+        Ok(number)
+    }
+}
+
+fn digits_option(options: &FunctionOptions, name: &str) -> Result<Option<i16>, MessageError> {
+    let digits = match options.get(name) {
+        None => return Ok(None),
+        Some(Value::String(s)) => s.parse::<u8>().ok(),
+        Some(Value::Number(n)) => n.write_to_string().parse::<u8>().ok(),
+        Some(_) => None,
+    };
+    match digits {
+        Some(digits) if digits <= 100 => Ok(Some(digits.into())),
+        _ => Err(MessageError::BadOption(name.into())),
+    }
+}
+
+fn category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+impl MessageFunction for NumberFunction {
+    fn format(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+    ) -> Result<Value, MessageError> {
+        self.number(operand, options).map(Value::Number)
+    }
+
+    /// Matches numeric keys that are equal to the operand first, then the key
+    /// named after its plural category.
+    fn select(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+        keys: &[&str],
+    ) -> Result<Vec<usize>, MessageError> {
+        let number = self.number(operand, options)?;
+        let rules = match options.get_str("select") {
+            None | Some("plural") => Some(&self.cardinal),
+            Some("ordinal") => Some(&self.ordinal),
+            Some("exact") => None,
+            Some(_) => return Err(MessageError::BadOption("select".into())),
+        };
+        let trimmed = number.clone().trimmed_end();
+        let mut matches: Vec<usize> = keys
+            .iter()
+            .enumerate()
+            .filter(|(_, key)| {
+                key.parse::<FixedDecimal>()
+                    .map_or(false, |key| key.trimmed_end() == trimmed)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if let Some(rules) = rules {
+            let category = category_name(rules.category_for(&number));
+            matches.extend(keys.iter().position(|key| *key == category));
+        }
+        Ok(matches)
+    }
+}
+
+/// The `:datetime`, `:date` and `:time` functions.
+///
+/// `:datetime` supports the `dateStyle` and `timeStyle` options, `:date` and `:time`
+/// support the `style` option. Valid date styles are `full`, `long`, `medium` and `short`;
+/// the `full` and `long` time styles include a time zone, which [`Value::DateTime`] does
+/// not have, so only `medium` and `short` are valid time styles.
+///
+/// A formatter is created up front for every combination of styles that the function
+/// accepts.
+#[derive(Debug)]
+pub(crate) struct DateTimeFunction {
+    kind: DateTimeKind,
+    formatters: Vec<(Bag, DateTimeFormatter)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DateTimeKind {
+    DateTime,
+    Date,
+    Time,
+}
+
+impl DateTimeFunction {
+    pub(crate) fn try_new(
+        kind: DateTimeKind,
+        new_formatter: impl Fn(DateTimeFormatterOptions) -> Result<DateTimeFormatter, DateTimeError>,
+    ) -> Result<Self, DateTimeError> {
+        const DATE_STYLES: [Date; 4] = [Date::Full, Date::Long, Date::Medium, Date::Short];
+        const TIME_STYLES: [Time; 2] = [Time::Medium, Time::Short];
+        let date = DATE_STYLES
+            .into_iter()
+            .map(Bag::from_date_style)
+            .filter(|_| kind != DateTimeKind::Time);
+        let time = TIME_STYLES
+            .into_iter()
+            .map(Bag::from_time_style)
+            .filter(|_| kind != DateTimeKind::Date);
+        let date_time = DATE_STYLES
+            .into_iter()
+            .flat_map(|date| {
+                TIME_STYLES
+                    .into_iter()
+                    .map(move |time| Bag::from_date_time_style(date, time))
+            })
+            .filter(|_| kind == DateTimeKind::DateTime);
+        Ok(Self {
+            kind,
+            formatters: date
+                .chain(time)
+                .chain(date_time)
+                .map(|bag| Ok((bag, new_formatter(bag.into())?)))
+                .collect::<Result<_, DateTimeError>>()?,
+        })
+    }
+
+    fn date_style(options: &FunctionOptions, name: &str) -> Result<Option<Date>, MessageError> {
+        Ok(Some(match options.get(name) {
+            None => return Ok(None),
+            Some(Value::String(s)) if s == "full" => Date::Full,
+            Some(Value::String(s)) if s == "long" => Date::Long,
+            Some(Value::String(s)) if s == "medium" => Date::Medium,
+            Some(Value::String(s)) if s == "short" => Date::Short,
+            Some(_) => return Err(MessageError::BadOption(name.into())),
+        }))
+    }
+
+    fn time_style(options: &FunctionOptions, name: &str) -> Result<Option<Time>, MessageError> {
+        Ok(Some(match options.get(name) {
+            None => return Ok(None),
+            Some(Value::String(s)) if s == "medium" => Time::Medium,
+            Some(Value::String(s)) if s == "short" => Time::Short,
+            Some(_) => return Err(MessageError::BadOption(name.into())),
+        }))
+    }
+}
+
+impl MessageFunction for DateTimeFunction {
+    fn format(
+        &self,
+        operand: Option<&Value>,
+        options: &FunctionOptions,
+    ) -> Result<Value, MessageError> {
+        let Some(Value::DateTime(datetime)) = operand else {
+            return Err(MessageError::BadOperand);
+        };
+        let bag = match self.kind {
+            DateTimeKind::DateTime => match (
+                Self::date_style(options, "dateStyle")?,
+                Self::time_style(options, "timeStyle")?,
+            ) {
+                (Some(date), Some(time)) => Bag::from_date_time_style(date, time),
+                (Some(date), None) => Bag::from_date_style(date),
+                (None, Some(time)) => Bag::from_time_style(time),
+                (None, None) => Bag::from_date_time_style(Date::Medium, Time::Short),
+            },
+            DateTimeKind::Date => {
+                Bag::from_date_style(Self::date_style(options, "style")?.unwrap_or(Date::Medium))
+            }
+            DateTimeKind::Time => {
+                Bag::from_time_style(Self::time_style(options, "style")?.unwrap_or(Time::Short))
+            }
+        };
+        // There is a formatter for every bag that can be built from the styles above
+        let (_, formatter) = self
+            .formatters
+            .iter()
+            .find(|(b, _)| *b == bag)
+            .ok_or(MessageError::BadOperand)?;
+        Ok(Value::String(
+            formatter.format_to_string(&datetime.to_any())?,
+        ))
+    }
+
+    fn select(
+        &self,
+        _: Option<&Value>,
+        _: &FunctionOptions,
+        _: &[&str],
+    ) -> Result<Vec<usize>, MessageError> {
+        Err(MessageError::NotSelectable)
+    }
+}
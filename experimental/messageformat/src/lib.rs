@@ -0,0 +1,103 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Parsing and formatting of [Unicode MessageFormat 2.0](https://www.unicode.org/reports/tr35/tr35-72/tr35-messageFormat.html)
+//! messages.
+//!
+//! This module is published as its own crate ([`icu_messageformat`](https://docs.rs/icu_messageformat/latest/icu_messageformat/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! A [`Message`] is parsed once and can then be formatted by a [`MessageFormatter`] with
+//! different [`MessageArguments`]. Numbers are formatted with
+//! [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter) and selected on with
+//! [`PluralRules`](icu_plurals::PluralRules); dates are formatted with
+//! [`DateTimeFormatter`](icu_datetime::DateTimeFormatter). Applications can provide their
+//! own functions by implementing [`MessageFunction`].
+//!
+//! Messages in the ICU MessageFormat syntax of ICU4C and ICU4J can be formatted with the
+//! same formatter using the [`mf1`] module.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::locale;
+//! use icu::messageformat::options::BidiIsolation;
+//! use icu::messageformat::{Message, MessageArguments, MessageFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter =
+//!     MessageFormatter::try_new(&locale!("en").into(), Default::default())
+//!         .expect("locale should be present");
+//!
+//! let message = Message::try_from_str(
+//!     ".match {$count :number select=ordinal}
+//!      one {{{$name} finished {$count}st.}}
+//!      two {{{$name} finished {$count}nd.}}
+//!      few {{{$name} finished {$count}rd.}}
+//!      * {{{$name} finished {$count}th.}}",
+//! )
+//! .unwrap();
+//!
+//! let arguments = MessageArguments::new().with("name", "Ada").with("count", 22);
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(&message, &arguments),
+//!     "Ada finished 22nd."
+//! );
+//!
+//! // Placeholders can be isolated from the surrounding text
+//! let formatter = MessageFormatter::try_new(
+//!     &locale!("en").into(),
+//!     BidiIsolation::Isolate.into(),
+//! )
+//! .expect("locale should be present");
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(&message, &arguments),
+//!     "\u{2068}Ada\u{2069} finished \u{2068}22\u{2069}nd."
+//! );
+//! ```
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod ast;
+mod error;
+mod format;
+mod formatter;
+mod function;
+pub mod mf1;
+pub mod options;
+mod parser;
+mod value;
+
+pub use ast::Message;
+pub use error::MessageError;
+pub use format::{parts, FormattedMessage};
+pub use formatter::MessageFormatter;
+pub use function::MessageFunction;
+pub use value::{FunctionOptions, MessageArguments, Value};
+#[doc(no_inline)]
+pub use MessageError as Error;
@@ -0,0 +1,689 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compatibility with the ICU MessageFormat syntax used by ICU4C and ICU4J, such as
+//! `{count, plural, one {# item} other {# items}}`.
+//!
+//! Messages are parsed into a [`Message`] and formatted with
+//! [`MessageFormatter::format_mf1`], using the same functions as MessageFormat 2.0
+//! messages:
+//!
+//! | Argument                         | Function                                        |
+//! |----------------------------------|-------------------------------------------------|
+//! | `{n, number}`                    | `:number`                                       |
+//! | `{n, number, integer}`           | `:integer`                                      |
+//! | `{n, number, ::.00}`             | `:number`, with the precision of the skeleton   |
+//! | `{d, date, short}`               | `:date`, with the given style                   |
+//! | `{d, time, short}`               | `:time`, with the given style                   |
+//! | `{n, plural, ...}`               | `:number`, selecting on the plural category     |
+//! | `{n, selectordinal, ...}`        | `:number select=ordinal`                        |
+//!
+//! Apostrophes quote syntax characters as in ICU's default `DOUBLE_OPTIONAL` mode.
+//! The `choice`, `spellout`, `ordinal` and `duration` argument types, as well as number
+//! styles other than `integer` and precision skeletons, and the `long` and `full` time
+//! styles, which include a time zone, are not supported and are rejected with
+//! [`MessageError::UnsupportedExpression`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::locale;
+//! use icu::messageformat::mf1;
+//! use icu::messageformat::{MessageArguments, MessageFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter =
+//!     MessageFormatter::try_new(&locale!("en").into(), Default::default())
+//!         .expect("locale should be present");
+//!
+//! let message = mf1::Message::try_from_str(
+//!     "{host} invited {guests, plural, offset:1
+//!         =0 {nobody}
+//!         =1 {{guest}}
+//!         one {{guest} and one other person}
+//!         other {{guest} and # other people}} to '{'the'}' party.",
+//! )
+//! .unwrap();
+//!
+//! let arguments = MessageArguments::new().with("host", "Ada").with("guest", "Bob");
+//!
+//! assert_writeable_eq!(
+//!     formatter
+//!         .format_mf1(&message, &arguments.clone().with("guests", 2))
+//!         .unwrap(),
+//!     "Ada invited Bob and one other person to {the} party."
+//! );
+//! assert_writeable_eq!(
+//!     formatter
+//!         .format_mf1(&message, &arguments.with("guests", 1001))
+//!         .unwrap(),
+//!     "Ada invited Bob and 1,000 other people to {the} party."
+//! );
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use fixed_decimal::FixedDecimal;
+use writeable::Writeable;
+
+use crate::format::{FormattedValue, ResolvedPart};
+use crate::{FunctionOptions, MessageArguments, MessageError, MessageFormatter, Value};
+
+/// A parsed ICU MessageFormat message.
+///
+/// Arguments can be named, such as `{name}`, or numbered, such as `{0}`; numbered
+/// arguments are looked up in [`MessageArguments`] by their number, such as `"0"`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::messageformat::{mf1, MessageError};
+///
+/// assert!(mf1::Message::try_from_str("{0} has {1, number} items").is_ok());
+///
+/// assert_eq!(
+///     mf1::Message::try_from_str("{n, plural, one {# item}}"),
+///     Err(MessageError::MissingFallbackVariant)
+/// );
+/// assert_eq!(
+///     mf1::Message::try_from_str("{n, spellout}"),
+///     Err(MessageError::UnsupportedExpression(4))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub(crate) pattern: Pattern,
+}
+
+impl Message {
+    /// Parses a message in ICU MessageFormat syntax.
+    pub fn try_from_str(source: &str) -> Result<Self, MessageError> {
+        let mut parser = Parser { source, pos: 0 };
+        let pattern = parser.pattern(0, Context::Top)?;
+        Ok(Self { pattern })
+    }
+}
+
+impl FromStr for Message {
+    type Err = MessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Pattern(Vec<Part>);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    /// `#` in a plural sub-message
+    Number,
+    Argument {
+        name: String,
+        kind: ArgumentKind,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ArgumentKind {
+    /// An argument without a type, such as `{name}`
+    Simple,
+    /// A `number`, `date` or `time` argument, formatted with a function
+    Function {
+        name: &'static str,
+        options: Vec<(&'static str, String)>,
+    },
+    Plural {
+        ordinal: bool,
+        offset: u32,
+        variants: Vec<(PluralKey, Pattern)>,
+    },
+    Select(Vec<(String, Pattern)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PluralKey {
+    /// An explicit value, such as `=0`
+    Exact(FixedDecimal),
+    Keyword(String),
+}
+
+/// The kind of argument a message is nested in, which determines how `#` and
+/// apostrophes are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    Top,
+    Plural,
+    Select,
+}
+
+fn is_identifier_char(c: char) -> bool {
+    !c.is_whitespace() && (!c.is_ascii_punctuation() || c == '_')
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> MessageError {
+        MessageError::Syntax(self.pos)
+    }
+
+    fn rest(&self) -> &'a str {
+        self.source.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), MessageError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, MessageError> {
+        let start = self.pos;
+        while self.peek().map_or(false, is_identifier_char) {
+            self.bump();
+        }
+        match self.source.get(start..self.pos) {
+            Some(identifier) if !identifier.is_empty() => Ok(identifier),
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Parses message text and arguments, stopping at the end of the input at the
+    /// top level or before the closing `}` of a sub-message.
+    fn pattern(&mut self, depth: usize, context: Context) -> Result<Pattern, MessageError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if depth > 0 => return Err(self.error()),
+                None => break,
+                Some('}') if depth > 0 => break,
+                Some('{') => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument(depth)?);
+                }
+                Some('#') if context == Context::Plural => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Number);
+                }
+                Some('\'') => {
+                    self.bump();
+                    match self.peek() {
+                        // A doubled apostrophe is a literal apostrophe
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        // An apostrophe before a syntax character starts quoted text
+                        Some('{' | '}') => self.quoted(&mut text),
+                        Some('#') if context == Context::Plural => self.quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Pattern(parts))
+    }
+
+    /// Appends quoted text to `text`, up to the next single apostrophe or the end of
+    /// the input.
+    fn quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.bump() {
+            if c == '\'' && !self.eat('\'') {
+                break;
+            }
+            text.push(c);
+        }
+    }
+
+    /// Parses an argument, after the opening `{`.
+    fn argument(&mut self, depth: usize) -> Result<Part, MessageError> {
+        self.skip_whitespace();
+        let name = self.identifier()?.into();
+        self.skip_whitespace();
+        if self.eat('}') {
+            return Ok(Part::Argument {
+                name,
+                kind: ArgumentKind::Simple,
+            });
+        }
+        self.expect(',')?;
+        self.skip_whitespace();
+        let type_start = self.pos;
+        let argument_type = self.identifier()?;
+        self.skip_whitespace();
+        let kind = match argument_type {
+            "number" | "date" | "time" => {
+                let style_start = self.pos;
+                let style = if self.eat(',') {
+                    self.simple_style()?
+                } else {
+                    ""
+                };
+                self.expect('}')?;
+                Self::function(argument_type, style)
+                    .ok_or(MessageError::UnsupportedExpression(style_start))?
+            }
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.skip_whitespace();
+                let mut offset = 0;
+                if self.rest().starts_with("offset:") {
+                    self.pos += "offset:".len();
+                    self.skip_whitespace();
+                    let start = self.pos;
+                    while self.peek().map_or(false, |c| c.is_ascii_digit()) {
+                        self.bump();
+                    }
+                    offset = self
+                        .source
+                        .get(start..self.pos)
+                        .and_then(|digits| digits.parse().ok())
+                        .ok_or_else(|| self.error())?;
+                }
+                let variants = self.variants(depth, Context::Plural)?;
+                ArgumentKind::Plural {
+                    ordinal: argument_type == "selectordinal",
+                    offset,
+                    variants: variants
+                        .into_iter()
+                        .map(|(key, pattern)| {
+                            let key = match key.strip_prefix('=') {
+                                Some(value) => PluralKey::Exact(
+                                    value
+                                        .parse()
+                                        .map_err(|_| MessageError::Syntax(type_start))?,
+                                ),
+                                None => PluralKey::Keyword(key.into()),
+                            };
+                            Ok((key, pattern))
+                        })
+                        .collect::<Result<_, MessageError>>()?,
+                }
+            }
+            "select" => {
+                self.expect(',')?;
+                ArgumentKind::Select(
+                    self.variants(depth, Context::Select)?
+                        .into_iter()
+                        .map(|(key, pattern)| (key.into(), pattern))
+                        .collect(),
+                )
+            }
+            _ => return Err(MessageError::UnsupportedExpression(type_start)),
+        };
+        Ok(Part::Argument { name, kind })
+    }
+
+    /// Parses the variants of a plural or select argument, including the closing `}`.
+    fn variants(
+        &mut self,
+        depth: usize,
+        context: Context,
+    ) -> Result<Vec<(&'a str, Pattern)>, MessageError> {
+        let mut variants = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                break;
+            }
+            let start = self.pos;
+            // Explicit plural values, such as `=0`
+            if context == Context::Plural && self.eat('=') {
+                while self
+                    .peek()
+                    .map_or(false, |c| c.is_ascii_digit() || c == '.' || c == '-')
+                {
+                    self.bump();
+                }
+                if self.pos == start + 1 {
+                    return Err(self.error());
+                }
+            } else {
+                self.identifier()?;
+            }
+            let key = self.source.get(start..self.pos).unwrap_or_default();
+            self.skip_whitespace();
+            self.expect('{')?;
+            let pattern = self.pattern(depth + 1, context)?;
+            self.expect('}')?;
+            variants.push((key, pattern));
+        }
+        if !variants.iter().any(|(key, _)| *key == "other") {
+            return Err(MessageError::MissingFallbackVariant);
+        }
+        Ok(variants)
+    }
+
+    /// Returns the trimmed style of a `number`, `date` or `time` argument, after the `,`.
+    fn simple_style(&mut self) -> Result<&'a str, MessageError> {
+        let start = self.pos;
+        let mut depth = 0;
+        loop {
+            match self.peek() {
+                None => return Err(self.error()),
+                Some('}') if depth == 0 => break,
+                Some('}') => depth -= 1,
+                Some('{') => depth += 1,
+                Some('\'') => {
+                    self.bump();
+                    while !matches!(self.peek(), Some('\'') | None) {
+                        self.bump();
+                    }
+                }
+                _ => {}
+            }
+            self.bump();
+        }
+        Ok(self.source.get(start..self.pos).unwrap_or_default().trim())
+    }
+
+    /// Maps a `number`, `date` or `time` argument to a function call.
+    fn function(argument_type: &str, style: &str) -> Option<ArgumentKind> {
+        let mut name = match argument_type {
+            "number" => "number",
+            "date" => "date",
+            _ => "time",
+        };
+        let mut options = Vec::new();
+        match (name, style) {
+            ("number", "") => {}
+            // ICU defaults to the medium style for dates and times
+            (_, "") => options.push(("style", "medium".into())),
+            ("number", "integer") => name = "integer",
+            ("number", skeleton) => {
+                for stem in skeleton.strip_prefix("::")?.split_whitespace() {
+                    if stem == "precision-integer" {
+                        options.push(("maximumFractionDigits", "0".into()));
+                    } else if let Some(digits) = stem.strip_prefix("integer-width/*") {
+                        if digits.is_empty() || digits.chars().any(|c| c != '0') {
+                            return None;
+                        }
+                        options.push(("minimumIntegerDigits", digits.len().to_string()));
+                    } else if let Some(digits) = stem.strip_prefix('.') {
+                        // Fraction precision, such as `.00` or `.0##`
+                        let minimum = digits.chars().take_while(|&c| c == '0').count();
+                        let optional = digits.get(minimum..)?;
+                        if optional.chars().any(|c| c != '#') {
+                            return None;
+                        }
+                        options.push(("minimumFractionDigits", minimum.to_string()));
+                        options.push((
+                            "maximumFractionDigits",
+                            (minimum + optional.len()).to_string(),
+                        ));
+                    } else {
+                        return None;
+                    }
+                }
+            }
+            ("time", "short" | "medium") | ("date", "short" | "medium" | "long" | "full") => {
+                options.push(("style", style.into()))
+            }
+            _ => return None,
+        }
+        Some(ArgumentKind::Function { name, options })
+    }
+}
+
+/// Subtracts a plural offset from a number, keeping its fraction digits.
+fn subtract(number: &FixedDecimal, offset: u32) -> Result<FixedDecimal, MessageError> {
+    if offset == 0 {
+        return Ok(number.clone());
+    }
+    let scale = -*number.magnitude_range().start();
+    let scaled: i128 = number
+        .clone()
+        .multiplied_pow10(scale)
+        .write_to_string()
+        .parse()
+        .map_err(|_| MessageError::BadOperand)?;
+    let offset = 10i128
+        .checked_pow(scale.unsigned_abs().into())
+        .and_then(|factor| factor.checked_mul(offset.into()))
+        .ok_or(MessageError::BadOperand)?;
+    Ok(FixedDecimal::from(scaled - offset).multiplied_pow10(-scale))
+}
+
+impl MessageFormatter {
+    /// Resolves a pattern, where `number` is the value that `#` stands for.
+    pub(crate) fn resolve_mf1<'a>(
+        &self,
+        pattern: &'a Pattern,
+        number: Option<&FixedDecimal>,
+        arguments: &MessageArguments,
+        parts: &mut Vec<ResolvedPart<'a>>,
+    ) -> Result<(), MessageError> {
+        for part in &pattern.0 {
+            let (name, kind) = match part {
+                Part::Text(text) => {
+                    parts.push(ResolvedPart::Text(text));
+                    continue;
+                }
+                Part::Number => {
+                    // The parser only produces `#` in plural sub-messages
+                    let number = number.ok_or(MessageError::BadOperand)?;
+                    parts.push(ResolvedPart::Placeholder {
+                        value: FormattedValue::Number(number.clone()),
+                        isolate: self.default_isolate(),
+                    });
+                    continue;
+                }
+                Part::Argument { name, kind } => (name, kind),
+            };
+            let value = arguments
+                .get(name)
+                .ok_or_else(|| MessageError::UnresolvedVariable(name.clone()))?;
+            match kind {
+                ArgumentKind::Simple => parts.push(ResolvedPart::Placeholder {
+                    value: self.format_value(value.clone())?,
+                    isolate: self.default_isolate(),
+                }),
+                ArgumentKind::Function { name, options } => {
+                    let options = FunctionOptions {
+                        values: options
+                            .iter()
+                            .map(|(name, value)| ((*name).into(), Value::String(value.clone())))
+                            .collect(),
+                    };
+                    let value = self.function(name)?.format(Some(value), &options)?;
+                    parts.push(ResolvedPart::Placeholder {
+                        value: self.format_value(value)?,
+                        isolate: self.default_isolate(),
+                    });
+                }
+                ArgumentKind::Plural {
+                    ordinal,
+                    offset,
+                    variants,
+                } => {
+                    let number = self.function("number")?;
+                    let Value::Number(value) = number.format(Some(value), &Default::default())?
+                    else {
+                        return Err(MessageError::BadOperand);
+                    };
+                    let offset_value = subtract(&value, *offset)?;
+                    let exact = value.clone().trimmed_end();
+                    let keywords: Vec<&str> = variants
+                        .iter()
+                        .filter_map(|(key, _)| match key {
+                            PluralKey::Keyword(keyword) => Some(keyword.as_str()),
+                            PluralKey::Exact(_) => None,
+                        })
+                        .collect();
+                    let mut options = FunctionOptions::default();
+                    options.values.insert(
+                        "select".into(),
+                        Value::from(if *ordinal { "ordinal" } else { "plural" }),
+                    );
+                    // Explicit values match the value before the offset is subtracted,
+                    // and take precedence over plural categories.
+                    let keyword = number
+                        .select(
+                            Some(&Value::Number(offset_value.clone())),
+                            &options,
+                            &keywords,
+                        )?
+                        .first()
+                        .and_then(|&i| keywords.get(i).copied())
+                        .unwrap_or("other");
+                    let pattern = variants
+                        .iter()
+                        .find(|(key, _)| {
+                            matches!(key, PluralKey::Exact(key) if key.clone().trimmed_end() == exact)
+                        })
+                        .or_else(|| {
+                            variants.iter().find(
+                                |(key, _)| matches!(key, PluralKey::Keyword(key) if key == keyword),
+                            )
+                        })
+                        .map(|(_, pattern)| pattern)
+                        .ok_or(MessageError::MissingFallbackVariant)?;
+                    self.resolve_mf1(pattern, Some(&offset_value), arguments, parts)?;
+                }
+                ArgumentKind::Select(variants) => {
+                    let key = value.as_str().ok_or(MessageError::BadOperand)?;
+                    let pattern = variants
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .or_else(|| variants.iter().find(|(k, _)| k == "other"))
+                        .map(|(_, pattern)| pattern)
+                        .ok_or(MessageError::MissingFallbackVariant)?;
+                    // `#` only refers to the innermost plural argument in its own
+                    // sub-messages, not in a nested select
+                    self.resolve_mf1(pattern, None, arguments, parts)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoting() {
+        for (source, expected) in [
+            ("It''s", "It's"),
+            ("It's", "It's"),
+            ("'{literal}'", "{literal}"),
+            ("'{'quoted''s'}'", "{quoted's}"),
+            ("a '#' b", "a '#' b"),
+            ("'{unterminated", "{unterminated"),
+            ("trailing '", "trailing '"),
+            ("top } level", "top } level"),
+        ] {
+            assert_eq!(
+                Message::try_from_str(source).map(|m| m.pattern),
+                Ok(Pattern(vec![Part::Text(expected.into())])),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_plural() {
+        let message = Message::try_from_str(
+            "{n, plural, offset:2 =0 {none} one {'#' is #} other {{g, select, x {#} other {'#}}}}",
+        )
+        .unwrap();
+        let Part::Argument {
+            kind:
+                ArgumentKind::Plural {
+                    ordinal,
+                    offset,
+                    variants,
+                },
+            ..
+        } = &message.pattern.0[0]
+        else {
+            panic!("expected a plural argument");
+        };
+        assert!(!ordinal);
+        assert_eq!(*offset, 2);
+        assert_eq!(variants[0].0, PluralKey::Exact(FixedDecimal::from(0)));
+        assert_eq!(
+            variants[1].1,
+            Pattern(vec![Part::Text("# is ".into()), Part::Number])
+        );
+        // `#` is literal in a nested select, and an apostrophe before it does not quote
+        let Part::Argument {
+            kind: ArgumentKind::Select(select),
+            ..
+        } = &variants[2].1 .0[0]
+        else {
+            panic!("expected a select argument");
+        };
+        assert_eq!(select[0].1, Pattern(vec![Part::Text("#".into())]));
+        assert_eq!(select[1].1, Pattern(vec![Part::Text("'#".into())]));
+    }
+
+    #[test]
+    fn test_subtract() {
+        for (number, offset, expected) in [
+            ("5", 1, "4"),
+            ("1.50", 1, "0.50"),
+            ("0.5", 1, "-0.5"),
+            ("-3", 2, "-5"),
+            ("1200", 0, "1200"),
+        ] {
+            assert_eq!(
+                subtract(&number.parse().unwrap(), offset)
+                    .unwrap()
+                    .write_to_string(),
+                expected
+            );
+        }
+    }
+}
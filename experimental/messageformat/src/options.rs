@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for configuring [`MessageFormatter`](crate::MessageFormatter).
+
+/// A bag of options for defining how to format messages using
+/// [`MessageFormatter`](crate::MessageFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MessageFormatterOptions {
+    /// Whether to isolate placeholders from the surrounding text.
+    pub bidi_isolation: BidiIsolation,
+}
+
+impl From<BidiIsolation> for MessageFormatterOptions {
+    fn from(bidi_isolation: BidiIsolation) -> Self {
+        Self { bidi_isolation }
+    }
+}
+
+/// Configures whether formatted placeholders are wrapped in Unicode bidi isolates,
+/// so that right-to-left values do not reorder the surrounding text or vice versa.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum BidiIsolation {
+    /// Do not isolate placeholders.
+    #[default]
+    None,
+
+    /// Wrap each placeholder in U+2068 FIRST STRONG ISOLATE and U+2069 POP DIRECTIONAL ISOLATE.
+    ///
+    /// A placeholder with the `u:dir=ltr` or `u:dir=rtl` option is wrapped in
+    /// U+2066 LEFT-TO-RIGHT ISOLATE or U+2067 RIGHT-TO-LEFT ISOLATE instead.
+    Isolate,
+}
@@ -0,0 +1,535 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for the MessageFormat 2.0 syntax, as specified in
+//! [LDML 45](https://www.unicode.org/reports/tr35/tr35-72/tr35-messageFormat.html).
+//!
+//! Reserved and private-use annotations and statements are rejected with
+//! [`MessageError::UnsupportedExpression`]; attributes and markup are parsed and ignored.
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ast::*;
+use crate::MessageError;
+
+pub(crate) fn parse(source: &str) -> Result<Message, MessageError> {
+    let mut parser = Parser { source, pos: 0 };
+    parser.skip_whitespace();
+    let message = if parser.peek() == Some('.') || parser.starts_with("{{") {
+        parser.complex_message()?
+    } else {
+        parser.pos = 0;
+        Message {
+            declarations: Vec::new(),
+            body: Body::Pattern(parser.pattern(false)?),
+        }
+    };
+    if parser.pos != source.len() {
+        return Err(parser.error());
+    }
+    validate(&message)?;
+    Ok(message)
+}
+
+/// Checks the data model errors that the grammar alone does not catch.
+fn validate(message: &Message) -> Result<(), MessageError> {
+    let mut declared = BTreeSet::new();
+    let mut annotated = BTreeSet::new();
+    for declaration in &message.declarations {
+        if !declared.insert(declaration.name.as_str()) {
+            return Err(MessageError::DuplicateDeclaration(declaration.name.clone()));
+        }
+        if is_annotated(&declaration.expression, &annotated) {
+            annotated.insert(declaration.name.as_str());
+        }
+    }
+    if let Body::Matcher {
+        selectors,
+        variants,
+    } = &message.body
+    {
+        if !selectors
+            .iter()
+            .all(|selector| is_annotated(selector, &annotated))
+        {
+            return Err(MessageError::MissingSelectorAnnotation);
+        }
+        if variants
+            .iter()
+            .any(|variant| variant.keys.len() != selectors.len())
+        {
+            return Err(MessageError::VariantKeyMismatch);
+        }
+        if !variants
+            .iter()
+            .any(|variant| variant.keys.iter().all(|key| *key == Key::CatchAll))
+        {
+            return Err(MessageError::MissingFallbackVariant);
+        }
+    }
+    Ok(())
+}
+
+fn is_annotated(expression: &Expression, annotated: &BTreeSet<&str>) -> bool {
+    expression.function.is_some()
+        || matches!(&expression.operand, Some(Operand::Variable(name)) if annotated.contains(name.as_str()))
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n' | '\u{3000}')
+}
+
+fn is_name_start(c: char) -> bool {
+    matches!(c,
+        'a'..='z'
+        | 'A'..='Z'
+        | '_'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFC}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start(c)
+        || matches!(c,
+            '0'..='9'
+            | '-'
+            | '.'
+            | '\u{B7}'
+            | '\u{300}'..='\u{36F}'
+            | '\u{203F}'..='\u{2040}'
+        )
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> MessageError {
+        MessageError::Syntax(self.pos)
+    }
+
+    fn rest(&self) -> &'a str {
+        self.source.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest().starts_with(s)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), MessageError> {
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Skips optional whitespace, returning whether any was present.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().map_or(false, is_whitespace) {
+            self.bump();
+        }
+        self.pos != start
+    }
+
+    fn complex_message(&mut self) -> Result<Message, MessageError> {
+        let mut declarations = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat(".input") {
+                self.skip_whitespace();
+                self.expect("{")?;
+                let expression = self.expression()?;
+                let Some(Operand::Variable(name)) = &expression.operand else {
+                    return Err(self.error());
+                };
+                declarations.push(Declaration {
+                    name: name.clone(),
+                    expression,
+                });
+            } else if self.eat(".local") {
+                if !self.skip_whitespace() {
+                    return Err(self.error());
+                }
+                self.expect("$")?;
+                let name = self.name()?;
+                self.skip_whitespace();
+                self.expect("=")?;
+                self.skip_whitespace();
+                self.expect("{")?;
+                let expression = self.expression()?;
+                declarations.push(Declaration { name, expression });
+            } else if self.eat(".match") {
+                let body = self.matcher()?;
+                return Ok(Message { declarations, body });
+            } else if self.starts_with("{{") {
+                let body = Body::Pattern(self.quoted_pattern()?);
+                self.skip_whitespace();
+                return Ok(Message { declarations, body });
+            } else if self.peek() == Some('.') {
+                return Err(MessageError::UnsupportedExpression(self.pos));
+            } else {
+                return Err(self.error());
+            }
+        }
+    }
+
+    fn matcher(&mut self) -> Result<Body, MessageError> {
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if !self.eat("{") {
+                break;
+            }
+            selectors.push(self.expression()?);
+        }
+        if selectors.is_empty() {
+            return Err(self.error());
+        }
+        let mut variants = Vec::new();
+        while self.peek().is_some() {
+            let mut keys = Vec::new();
+            while !self.starts_with("{{") {
+                keys.push(if self.eat("*") {
+                    Key::CatchAll
+                } else {
+                    Key::Literal(self.literal()?)
+                });
+                // Keys are separated by whitespace
+                if !self.skip_whitespace() && !self.starts_with("{{") {
+                    return Err(self.error());
+                }
+            }
+            if keys.is_empty() {
+                return Err(self.error());
+            }
+            variants.push(Variant {
+                keys,
+                pattern: self.quoted_pattern()?,
+            });
+            self.skip_whitespace();
+        }
+        if variants.is_empty() {
+            return Err(self.error());
+        }
+        Ok(Body::Matcher {
+            selectors,
+            variants,
+        })
+    }
+
+    fn quoted_pattern(&mut self) -> Result<Pattern, MessageError> {
+        self.expect("{{")?;
+        let pattern = self.pattern(true)?;
+        self.expect("}}")?;
+        Ok(pattern)
+    }
+
+    /// Parses pattern text and placeholders, stopping at the end of the input
+    /// or, if `quoted`, before the closing `}}`.
+    fn pattern(&mut self, quoted: bool) -> Result<Pattern, MessageError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if quoted => return Err(self.error()),
+                None => break,
+                Some('}') if quoted && self.starts_with("}}") => break,
+                Some('}') => return Err(self.error()),
+                Some('{') => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(PatternPart::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(self.placeholder()?);
+                }
+                Some('\\') => {
+                    self.bump();
+                    text.push(self.escaped()?);
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(PatternPart::Text(text));
+        }
+        Ok(Pattern(parts))
+    }
+
+    fn escaped(&mut self) -> Result<char, MessageError> {
+        match self.peek() {
+            Some(c @ ('\\' | '{' | '|' | '}')) => {
+                self.bump();
+                Ok(c)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Parses an expression or markup, after the opening `{`.
+    fn placeholder(&mut self) -> Result<PatternPart, MessageError> {
+        self.skip_whitespace();
+        let open = if self.eat("#") {
+            true
+        } else if self.eat("/") {
+            false
+        } else {
+            return Ok(PatternPart::Expression(self.expression()?));
+        };
+        self.identifier()?;
+        self.options()?;
+        self.attributes()?;
+        self.skip_whitespace();
+        if open {
+            // Standalone markup
+            self.eat("/");
+        }
+        self.expect("}")?;
+        Ok(PatternPart::Markup)
+    }
+
+    /// Parses an expression, after the opening `{`.
+    fn expression(&mut self) -> Result<Expression, MessageError> {
+        self.skip_whitespace();
+        let operand = match self.peek() {
+            Some('$' | '|') => Some(self.operand()?),
+            Some(c) if is_name_start(c) || c.is_ascii_digit() || c == '-' => Some(self.operand()?),
+            _ => None,
+        };
+        let separated = operand.is_none() || self.skip_whitespace();
+        let function = match self.peek() {
+            Some(':') if separated => {
+                self.bump();
+                let name = self.identifier()?;
+                let options = self.options()?;
+                Some(Function { name, options })
+            }
+            Some('^' | '&' | '!' | '%' | '*' | '+' | '<' | '>' | '?' | '~') if separated => {
+                return Err(MessageError::UnsupportedExpression(self.pos))
+            }
+            _ => None,
+        };
+        if operand.is_none() && function.is_none() {
+            return Err(self.error());
+        }
+        self.attributes()?;
+        self.skip_whitespace();
+        self.expect("}")?;
+        Ok(Expression { operand, function })
+    }
+
+    fn options(&mut self) -> Result<Vec<(String, Operand)>, MessageError> {
+        let mut options = Vec::new();
+        loop {
+            let start = self.pos;
+            if !self.skip_whitespace() || !self.peek().map_or(false, is_name_start) {
+                self.pos = start;
+                return Ok(options);
+            }
+            let name = self.identifier()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            options.push((name, self.operand()?));
+        }
+    }
+
+    fn attributes(&mut self) -> Result<(), MessageError> {
+        loop {
+            let start = self.pos;
+            if !self.skip_whitespace() || !self.eat("@") {
+                self.pos = start;
+                return Ok(());
+            }
+            self.identifier()?;
+            let before_value = self.pos;
+            self.skip_whitespace();
+            if self.eat("=") {
+                self.skip_whitespace();
+                self.operand()?;
+            } else {
+                self.pos = before_value;
+            }
+        }
+    }
+
+    fn operand(&mut self) -> Result<Operand, MessageError> {
+        if self.eat("$") {
+            Ok(Operand::Variable(self.name()?))
+        } else {
+            Ok(Operand::Literal(self.literal()?))
+        }
+    }
+
+    fn literal(&mut self) -> Result<String, MessageError> {
+        if self.eat("|") {
+            let mut value = String::new();
+            loop {
+                match self.bump() {
+                    Some('|') => return Ok(value),
+                    Some('\\') => value.push(self.escaped()?),
+                    Some(c) => value.push(c),
+                    None => return Err(self.error()),
+                }
+            }
+        }
+        // An unquoted literal is either a name or a number literal; both consist
+        // of name characters, plus the sign of an exponent.
+        let start = self.pos;
+        match self.peek() {
+            Some(c) if is_name_start(c) || c.is_ascii_digit() || c == '-' => {}
+            _ => return Err(self.error()),
+        }
+        while self.peek().map_or(false, |c| is_name_char(c) || c == '+') {
+            self.bump();
+        }
+        Ok(self.source.get(start..self.pos).unwrap_or_default().into())
+    }
+
+    fn name(&mut self) -> Result<String, MessageError> {
+        let start = self.pos;
+        if !self.peek().map_or(false, is_name_start) {
+            return Err(self.error());
+        }
+        while self.peek().map_or(false, is_name_char) {
+            self.bump();
+        }
+        Ok(self.source.get(start..self.pos).unwrap_or_default().into())
+    }
+
+    /// Parses a name with an optional namespace, such as `u:dir`.
+    fn identifier(&mut self) -> Result<String, MessageError> {
+        let mut identifier = self.name()?;
+        if self.eat(":") {
+            identifier.push(':');
+            identifier.push_str(&self.name()?);
+        }
+        Ok(identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple() {
+        let message = parse("Hello \\{world\\}, {$name :string u:dir=rtl @attr}{#b}!{/b}").unwrap();
+        assert_eq!(message.declarations, Vec::new());
+        assert_eq!(
+            message.body,
+            Body::Pattern(Pattern(vec![
+                PatternPart::Text("Hello {world}, ".into()),
+                PatternPart::Expression(Expression {
+                    operand: Some(Operand::Variable("name".into())),
+                    function: Some(Function {
+                        name: "string".into(),
+                        options: vec![("u:dir".into(), Operand::Literal("rtl".into()))],
+                    }),
+                }),
+                PatternPart::Markup,
+                PatternPart::Text("!".into()),
+                PatternPart::Markup,
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_complex() {
+        let message = parse(
+            ".input {$count :number}
+            .local $unit = {|km²| :string}
+            .match {$count} {$unit :string}
+            0 |km²| {{No area}}
+            one * {{{$count} {$unit}}}
+            * * {{{$count} {$unit}s}}",
+        )
+        .unwrap();
+        assert_eq!(message.declarations.len(), 2);
+        assert_eq!(message.declarations[0].name, "count");
+        assert_eq!(message.declarations[1].name, "unit");
+        let Body::Matcher {
+            selectors,
+            variants,
+        } = &message.body
+        else {
+            panic!("expected a matcher");
+        };
+        assert_eq!(selectors.len(), 2);
+        assert_eq!(
+            variants[0].keys,
+            vec![Key::Literal("0".into()), Key::Literal("km²".into())]
+        );
+        assert_eq!(variants[2].keys, vec![Key::CatchAll, Key::CatchAll]);
+    }
+
+    #[test]
+    fn test_errors() {
+        for (source, error) in [
+            ("{$x", MessageError::Syntax(3)),
+            ("a } b", MessageError::Syntax(2)),
+            ("\\n", MessageError::Syntax(1)),
+            ("{$x:number}", MessageError::Syntax(3)),
+            ("{{unterminated}", MessageError::Syntax(14)),
+            ("{!reserved}", MessageError::UnsupportedExpression(1)),
+            (".foo {{x}}", MessageError::UnsupportedExpression(0)),
+            (
+                ".local $x = {1} .local $x = {2} {{}}",
+                MessageError::DuplicateDeclaration("x".into()),
+            ),
+            (
+                ".match {$x} * {{}}",
+                MessageError::MissingSelectorAnnotation,
+            ),
+            (
+                ".match {$x :number} * * {{}}",
+                MessageError::VariantKeyMismatch,
+            ),
+            (
+                ".match {$x :number} one {{}}",
+                MessageError::MissingFallbackVariant,
+            ),
+        ] {
+            assert_eq!(parse(source), Err(error), "{source}");
+        }
+    }
+}
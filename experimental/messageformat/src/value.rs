@@ -0,0 +1,153 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{DateTime, Iso};
+
+/// A value that can be passed to a message as an argument, or that is produced
+/// by a [`MessageFunction`](crate::MessageFunction).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    /// A string, which is formatted verbatim.
+    String(String),
+    /// A number, which is formatted with a [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    Number(FixedDecimal),
+    /// A date and time, which is formatted with the `:datetime` function.
+    DateTime(DateTime<Iso>),
+}
+
+impl Value {
+    /// Returns the string if this is a [`Value::String`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<FixedDecimal> for Value {
+    fn from(value: FixedDecimal) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<DateTime<Iso>> for Value {
+    fn from(value: DateTime<Iso>) -> Self {
+        Value::DateTime(value)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The named arguments passed to [`MessageFormatter::format`](crate::MessageFormatter::format).
+///
+/// # Examples
+///
+/// ```
+/// use icu::messageformat::{MessageArguments, Value};
+///
+/// let arguments = MessageArguments::new()
+///     .with("name", "Ada")
+///     .with("count", 3);
+///
+/// assert_eq!(arguments.get("name"), Some(&Value::from("Ada")));
+/// assert_eq!(arguments.get("missing"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageArguments {
+    values: BTreeMap<String, Value>,
+}
+
+impl MessageArguments {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an argument, replacing any previous argument with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Adds an argument and returns `self`, for chaining.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Returns the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for MessageArguments {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// The resolved options of a function call, such as `minimumFractionDigits=2`
+/// in `{$x :number minimumFractionDigits=2}`.
+///
+/// Literal option values are passed as [`Value::String`]; options whose value
+/// is a variable receive the resolved value of that variable. Options in the `u:`
+/// namespace are interpreted by the formatter and are not passed to functions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FunctionOptions {
+    pub(crate) values: BTreeMap<String, Value>,
+}
+
+impl FunctionOptions {
+    /// Returns the value of the given option.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Returns the value of the given option if it is a string.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(Value::as_str)
+    }
+
+    /// Returns an iterator over all options, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> + '_ {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
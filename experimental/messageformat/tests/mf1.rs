@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu::locid::locale;
+use icu_messageformat::mf1::Message;
+use icu_messageformat::options::BidiIsolation;
+use icu_messageformat::{MessageArguments, MessageError, MessageFormatter};
+
+fn format(formatter: &MessageFormatter, message: &str, arguments: &MessageArguments) -> String {
+    let message = Message::try_from_str(message).expect("message should be valid");
+    formatter
+        .format_mf1(&message, arguments)
+        .expect("message should format")
+        .to_string()
+}
+
+#[test]
+fn test_plural() {
+    let formatter = MessageFormatter::try_new(&locale!("ru").into(), Default::default())
+        .expect("locale should be present");
+    let message = "{count, plural, =0 {нет файлов} one {# файл} few {# файла} other {# файлов}}";
+
+    for (count, expected) in [
+        (0, "нет файлов"),
+        (1, "1 файл"),
+        (3, "3 файла"),
+        (11, "11 файлов"),
+        (21, "21 файл"),
+        (1000, "1\u{a0}000 файлов"),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new().with("count", count)
+            ),
+            expected
+        );
+    }
+
+    // Fractional values select on their visible digits
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = "{n, plural, one {# mile} other {# miles}}";
+    for (n, expected) in [("1", "1 mile"), ("1.0", "1.0 miles"), ("2.5", "2.5 miles")] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new().with("n", n.parse::<FixedDecimal>().unwrap())
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_offset_and_nested_select() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = "{gender, select,
+        female {{guests, plural, offset:1
+            =0 {{host} does not give a party.}
+            =1 {{host} invites {guest} to her party.}
+            one {{host} invites {guest} and one other person to her party.}
+            other {{host} invites {guest} and # other people to her party.}}}
+        other {{guests, plural, offset:1
+            =0 {{host} does not give a party.}
+            =1 {{host} invites {guest} to their party.}
+            one {{host} invites {guest} and one other person to their party.}
+            other {{host} invites {guest} and # other people to their party.}}}}";
+
+    for (gender, guests, expected) in [
+        ("female", 0, "Ada does not give a party."),
+        ("female", 1, "Ada invites Bob to her party."),
+        (
+            "female",
+            2,
+            "Ada invites Bob and one other person to her party.",
+        ),
+        (
+            "female",
+            5,
+            "Ada invites Bob and 4 other people to her party.",
+        ),
+        (
+            "male",
+            3,
+            "Ada invites Bob and 2 other people to their party.",
+        ),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new()
+                    .with("gender", gender)
+                    .with("guests", guests)
+                    .with("host", "Ada")
+                    .with("guest", "Bob")
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_selectordinal() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = "Take the {n, selectordinal, one {#st} two {#nd} few {#rd} =11 {eleventh} other {#th}} exit";
+    for (n, expected) in [
+        (1, "Take the 1st exit"),
+        (2, "Take the 2nd exit"),
+        (3, "Take the 3rd exit"),
+        (4, "Take the 4th exit"),
+        (11, "Take the eleventh exit"),
+        (23, "Take the 23rd exit"),
+        (1001, "Take the 1,001st exit"),
+    ] {
+        assert_eq!(
+            format(&formatter, message, &MessageArguments::new().with("n", n)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_arguments() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let datetime = icu::calendar::DateTime::try_new_iso_datetime(2024, 3, 1, 17, 5, 0).unwrap();
+    let arguments = MessageArguments::new()
+        .with("0", "Ada")
+        .with("n", "1234.5".parse::<FixedDecimal>().unwrap())
+        .with("d", datetime);
+
+    for (message, expected) in [
+        ("Hello, {0}!", "Hello, Ada!"),
+        ("{ 0 }''s files", "Ada's files"),
+        ("{n}", "1,234.5"),
+        ("{n, number}", "1,234.5"),
+        ("{n, number, integer}", "1,234"),
+        ("{n, number, ::.00}", "1,234.50"),
+        ("{n, number, ::.0##}", "1,234.5"),
+        ("{n, number, ::precision-integer}", "1,234"),
+        ("{n, number, :: integer-width/*000000 .000}", "001,234.500"),
+        ("{d}", "Mar 1, 2024, 5:05\u{202f}PM"),
+        ("{d, date}", "Mar 1, 2024"),
+        ("{d, date, full}", "Friday, March 1, 2024"),
+        ("{d, time}", "5:05:00\u{202f}PM"),
+        ("{d, time, short}", "5:05\u{202f}PM"),
+        ("'{0}' is {0}", "{0} is Ada"),
+    ] {
+        assert_eq!(
+            format(&formatter, message, &arguments),
+            expected,
+            "{message}"
+        );
+    }
+
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), BidiIsolation::Isolate.into())
+        .expect("locale should be present");
+    assert_eq!(
+        format(
+            &formatter,
+            "{0} has {n, plural, other {# items}}",
+            &arguments
+        ),
+        "\u{2068}Ada\u{2069} has \u{2068}1,234.5\u{2069} items"
+    );
+}
+
+#[test]
+fn test_errors() {
+    for (message, error) in [
+        ("{0", MessageError::Syntax(2)),
+        ("{n, plural, one {#}", MessageError::Syntax(19)),
+        ("{n, plural, =x {#} other {#}}", MessageError::Syntax(13)),
+        ("{n, select, a {a}}", MessageError::MissingFallbackVariant),
+        (
+            "{n, choice, 0#none|1#one}",
+            MessageError::UnsupportedExpression(4),
+        ),
+        (
+            "{n, number, percent}",
+            MessageError::UnsupportedExpression(10),
+        ),
+        (
+            "{n, number, ::currency/EUR}",
+            MessageError::UnsupportedExpression(10),
+        ),
+        ("{d, date, yyyy}", MessageError::UnsupportedExpression(8)),
+        ("{d, time, full}", MessageError::UnsupportedExpression(8)),
+    ] {
+        assert_eq!(Message::try_from_str(message), Err(error), "{message}");
+    }
+
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let arguments = MessageArguments::new().with("n", 1).with("s", "text");
+    for (message, error) in [
+        (
+            "{missing}",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+        ("{s, plural, other {#}}", MessageError::BadOperand),
+        ("{n, select, other {}}", MessageError::BadOperand),
+    ] {
+        let message = Message::try_from_str(message).unwrap();
+        assert_eq!(
+            formatter.format_mf1(&message, &arguments).map(|_| ()),
+            Err(error)
+        );
+    }
+}
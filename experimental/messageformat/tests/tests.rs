@@ -0,0 +1,397 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu::locid::locale;
+use icu_messageformat::options::BidiIsolation;
+use icu_messageformat::{
+    parts, FunctionOptions, Message, MessageArguments, MessageError, MessageFormatter,
+    MessageFunction, Value,
+};
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+fn format(formatter: &MessageFormatter, message: &str, arguments: &MessageArguments) -> String {
+    let message = Message::try_from_str(message).expect("message should be valid");
+    let formatted = formatter.format(&message, arguments);
+    assert_eq!(formatted.errors(), [], "{message:?}");
+    formatted.to_string()
+}
+
+#[test]
+fn test_number() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+
+    for (message, expected) in [
+        ("{$n}", "1,234.5"),
+        ("{$n :number}", "1,234.5"),
+        ("{$n :integer}", "1,234"),
+        ("{$n :number minimumFractionDigits=2}", "1,234.50"),
+        ("{$n :number maximumFractionDigits=0}", "1,234"),
+        ("{$n :number minimumIntegerDigits=6}", "001,234.5"),
+        ("{$n :string}", "1234.5"),
+        ("{|-42| :number}", "-42"),
+        (
+            ".local $x = {$n :number minimumFractionDigits=3} {{{$x}}}",
+            "1,234.500",
+        ),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new().with("n", "1234.5".parse::<FixedDecimal>().unwrap())
+            ),
+            expected,
+            "{message}"
+        );
+    }
+
+    let formatter = MessageFormatter::try_new(&locale!("bn").into(), Default::default())
+        .expect("locale should be present");
+    assert_eq!(
+        format(
+            &formatter,
+            "{$n :number}",
+            &MessageArguments::new().with("n", 1234567)
+        ),
+        "১২,৩৪,৫৬৭"
+    );
+}
+
+#[test]
+fn test_plural_selection() {
+    let formatter = MessageFormatter::try_new(&locale!("ru").into(), Default::default())
+        .expect("locale should be present");
+    let message = ".match {$count :number}
+        0 {{нет файлов}}
+        one {{{$count} файл}}
+        few {{{$count} файла}}
+        * {{{$count} файлов}}";
+
+    for (count, expected) in [
+        (0, "нет файлов"),
+        (1, "1 файл"),
+        (3, "3 файла"),
+        (11, "11 файлов"),
+        (21, "21 файл"),
+        (1000, "1\u{a0}000 файлов"),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new().with("count", count)
+            ),
+            expected
+        );
+    }
+
+    // Fractional values select on their visible digits
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = ".input {$count :number minimumFractionDigits=1}
+        .match {$count}
+        one {{{$count} mile}}
+        * {{{$count} miles}}";
+    assert_eq!(
+        format(
+            &formatter,
+            message,
+            &MessageArguments::new().with("count", 1)
+        ),
+        "1.0 miles"
+    );
+}
+
+#[test]
+fn test_multiple_selectors() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = ".match {$host :string} {$guests :integer}
+        female 0 {{{$host} does not give a party.}}
+        female 1 {{{$host} invites {$guest} to her party.}}
+        female * {{{$host} invites {$guests} people to her party.}}
+        male * {{{$host} invites {$guests} people to his party.}}
+        * one {{{$host} invites {$guest} to their party.}}
+        * * {{{$host} invites {$guests} people to their party.}}";
+
+    for (host, guests, expected) in [
+        ("female", 0, "female does not give a party."),
+        ("female", 1, "female invites Bob to her party."),
+        ("female", 2, "female invites 2 people to her party."),
+        ("male", 1, "male invites 1 people to his party."),
+        ("other", 1, "other invites Bob to their party."),
+        ("other", 5, "other invites 5 people to their party."),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new()
+                    .with("host", host)
+                    .with("guests", guests)
+                    .with("guest", "Bob")
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_ordinal_and_exact_selection() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+
+    let message = ".match {$n :number select=exact} 1 {{exactly one}} one {{category}} * {{other}}";
+    assert_eq!(
+        format(&formatter, message, &MessageArguments::new().with("n", 1)),
+        "exactly one"
+    );
+    assert_eq!(
+        format(&formatter, message, &MessageArguments::new().with("n", 2)),
+        "other"
+    );
+
+    let message = ".match {$n :number select=ordinal} one {{st}} two {{nd}} few {{rd}} * {{th}}";
+    for (n, expected) in [
+        (1, "st"),
+        (2, "nd"),
+        (3, "rd"),
+        (4, "th"),
+        (11, "th"),
+        (23, "rd"),
+    ] {
+        assert_eq!(
+            format(&formatter, message, &MessageArguments::new().with("n", n)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_parts() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let message = Message::try_from_str("Hello {#b}{$name}{/b}{#br/}").unwrap();
+    // Markup produces no output
+    assert_writeable_parts_eq!(
+        formatter.format(&message, &MessageArguments::new().with("name", "Ada")),
+        "Hello Ada",
+        [(0, 6, parts::LITERAL), (6, 9, parts::PLACEHOLDER),]
+    );
+}
+
+#[test]
+fn test_bidi_isolation() {
+    let formatter = MessageFormatter::try_new(&locale!("he").into(), BidiIsolation::Isolate.into())
+        .expect("locale should be present");
+    let arguments = MessageArguments::new().with("name", "Ada").with("n", 5);
+
+    assert_eq!(
+        format(&formatter, "שלום {$name}", &arguments),
+        "שלום \u{2068}Ada\u{2069}"
+    );
+    assert_eq!(
+        format(&formatter, "שלום {$name :string u:dir=ltr}", &arguments),
+        "שלום \u{2066}Ada\u{2069}"
+    );
+    assert_eq!(
+        format(&formatter, "{$n :number u:dir=rtl}", &arguments),
+        "\u{2067}5\u{2069}"
+    );
+
+    // An invalid direction falls back to the first strong isolate
+    let message = Message::try_from_str("{$name :string u:dir=up}").unwrap();
+    let formatted = formatter.format(&message, &arguments);
+    assert_writeable_eq!(formatted, "\u{2068}Ada\u{2069}");
+    assert_eq!(
+        formatted.errors(),
+        [MessageError::BadOption("u:dir".into())]
+    );
+}
+
+#[derive(Debug)]
+struct Gender;
+
+impl MessageFunction for Gender {
+    fn format(&self, operand: Option<&Value>, _: &FunctionOptions) -> Result<Value, MessageError> {
+        match operand.and_then(Value::as_str) {
+            Some(name) => Ok(Value::from(name)),
+            None => Err(MessageError::BadOperand),
+        }
+    }
+
+    fn select(
+        &self,
+        operand: Option<&Value>,
+        _: &FunctionOptions,
+        keys: &[&str],
+    ) -> Result<Vec<usize>, MessageError> {
+        let gender = match operand.and_then(Value::as_str) {
+            Some("Alice") => "feminine",
+            Some("Bob") => "masculine",
+            _ => "neuter",
+        };
+        Ok(keys
+            .iter()
+            .position(|key| *key == gender)
+            .into_iter()
+            .collect())
+    }
+}
+
+#[test]
+fn test_custom_function() {
+    let mut formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    formatter.register_function("gender", Gender);
+    formatter.register_function(
+        "shout",
+        |operand: Option<&Value>, options: &FunctionOptions| match operand {
+            Some(Value::String(s)) => Ok(Value::String(
+                s.to_uppercase() + options.get_str("suffix").unwrap_or("!"),
+            )),
+            _ => Err(MessageError::BadOperand),
+        },
+    );
+
+    let message = ".input {$name :gender}
+        .local $excited = {$name :shout suffix=|!!|}
+        .match {$name}
+        feminine {{{$excited} ate her lunch.}}
+        masculine {{{$excited} ate his lunch.}}
+        * {{{$excited} ate their lunch.}}";
+    for (name, expected) in [
+        ("Alice", "ALICE!! ate her lunch."),
+        ("Bob", "BOB!! ate his lunch."),
+        ("Sam", "SAM!! ate their lunch."),
+    ] {
+        assert_eq!(
+            format(
+                &formatter,
+                message,
+                &MessageArguments::new().with("name", name)
+            ),
+            expected
+        );
+    }
+
+    // Closures select on their formatted value
+    let message = ".match {$name :shout} |ADA!| {{matched}} * {{fallback}}";
+    assert_eq!(
+        format(
+            &formatter,
+            message,
+            &MessageArguments::new().with("name", "ada")
+        ),
+        "matched"
+    );
+}
+
+#[test]
+fn test_datetime() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let datetime = icu::calendar::DateTime::try_new_iso_datetime(2024, 3, 1, 17, 5, 0).unwrap();
+    let arguments = MessageArguments::new().with("d", datetime);
+
+    for (message, expected) in [
+        ("{$d}", "Mar 1, 2024, 5:05\u{202f}PM"),
+        ("{$d :datetime dateStyle=full}", "Friday, March 1, 2024"),
+        ("{$d :datetime timeStyle=medium}", "5:05:00\u{202f}PM"),
+        ("{$d :date style=short}", "3/1/24"),
+        ("{$d :time}", "5:05\u{202f}PM"),
+        ("{$d :time style=short}", "5:05\u{202f}PM"),
+    ] {
+        assert_eq!(
+            format(&formatter, message, &arguments),
+            expected,
+            "{message}"
+        );
+    }
+
+    // The long and full time styles require a time zone
+    let message = Message::try_from_str("{$d :time style=full}").unwrap();
+    assert_eq!(
+        formatter.format(&message, &arguments).errors(),
+        [MessageError::BadOption("style".into())]
+    );
+}
+
+#[test]
+fn test_errors() {
+    let formatter = MessageFormatter::try_new(&locale!("en").into(), Default::default())
+        .expect("locale should be present");
+    let arguments = MessageArguments::new().with("n", 1).with("s", "text");
+
+    for (message, expected, error) in [
+        (
+            "{$missing}",
+            "{$missing}",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+        (
+            "{$n :unknown}",
+            "{$n}",
+            MessageError::UnknownFunction("unknown".into()),
+        ),
+        (
+            "{:unknown}",
+            "{:unknown}",
+            MessageError::UnknownFunction("unknown".into()),
+        ),
+        ("{$s :number}", "{$s}", MessageError::BadOperand),
+        ("{|a\\|b| :number}", "{|a\\|b|}", MessageError::BadOperand),
+        (
+            "{$n :number minimumFractionDigits=many}",
+            "{$n}",
+            MessageError::BadOption("minimumFractionDigits".into()),
+        ),
+        (
+            ".match {$n :number select=nearest} 1 {{one}} * {{other}}",
+            "other",
+            MessageError::BadOption("select".into()),
+        ),
+        (
+            ".match {$n :datetime} * {{other}}",
+            "other",
+            MessageError::BadOperand,
+        ),
+        (
+            ".input {$missing :number} .local $x = {$missing :integer} {{{$x} and {$n}}}",
+            "{$missing} and 1",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+        (
+            ".match {$missing :number} 1 {{one}} * {{other}}",
+            "other",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+        (
+            // Options that cannot be resolved are ignored
+            "{$n :number minimumFractionDigits=$missing}",
+            "1",
+            MessageError::UnresolvedVariable("missing".into()),
+        ),
+    ] {
+        let message = Message::try_from_str(message).unwrap();
+        let formatted = formatter.format(&message, &arguments);
+        assert_writeable_eq!(formatted, expected, "{message:?}");
+        assert_eq!(formatted.errors(), [error], "{message:?}");
+    }
+
+    let message = Message::try_from_str("\\{literal\\} {|quoted \\| text|}").unwrap();
+    assert_writeable_eq!(
+        formatter.format(&message, &arguments),
+        "{literal} quoted | text"
+    );
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<MessageFormatter>();
+}
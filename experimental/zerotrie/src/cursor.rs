@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::reader::*;
+use crate::ZeroTriePerfectHash;
 use crate::ZeroTrieSimpleAscii;
 
 use core::fmt;
@@ -15,7 +16,8 @@ where
     ///
     /// Useful to query a trie with data that is not a slice.
     ///
-    /// This is currently supported only on `ZeroTrieSimpleAscii`.
+    /// This is currently supported only on `ZeroTrieSimpleAscii`
+    /// and `ZeroTriePerfectHash`.
     ///
     /// # Examples
     ///
@@ -155,6 +157,133 @@ impl<'a> ZeroTrieSimpleAsciiCursor<'a> {
     }
 }
 
+impl<Store> ZeroTriePerfectHash<Store>
+where
+    Store: AsRef<[u8]> + ?Sized,
+{
+    /// Gets a cursor into the current trie.
+    ///
+    /// Unlike [`ZeroTrieSimpleAscii::cursor()`], the cursor can be stepped through
+    /// non-ASCII bytes.
+    ///
+    /// # Examples
+    ///
+    /// Look up a string one char at a time, starting from its end, in a trie whose
+    /// keys are stored reversed:
+    ///
+    /// ```
+    /// use zerotrie::ZeroTriePerfectHash;
+    ///
+    /// // A trie with two values: "г." and "т.е.", each reversed
+    /// let trie: ZeroTriePerfectHash<Vec<u8>> = [(".г", 0), (".е.т", 1)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let mut cursor = trie.cursor();
+    /// for c in "т.е.".chars().rev() {
+    ///     let mut buffer = [0; 4];
+    ///     for b in c.encode_utf8(&mut buffer).bytes() {
+    ///         cursor.step(b);
+    ///     }
+    /// }
+    /// assert_eq!(cursor.take_value(), Some(1));
+    /// ```
+    #[inline]
+    pub fn cursor(&self) -> ZeroTriePerfectHashCursor {
+        ZeroTriePerfectHashCursor {
+            trie: self.as_borrowed_slice(),
+            span: &[],
+        }
+    }
+}
+
+impl<'a> ZeroTriePerfectHash<&'a [u8]> {
+    /// Same as [`ZeroTriePerfectHash::cursor()`] but moves self to avoid
+    /// having to doubly anchor the trie to the stack.
+    #[inline]
+    pub fn into_cursor(self) -> ZeroTriePerfectHashCursor<'a> {
+        ZeroTriePerfectHashCursor {
+            trie: self,
+            span: &[],
+        }
+    }
+}
+
+/// A cursor into a [`ZeroTriePerfectHash`], useful for stepwise lookup.
+///
+/// For examples, see [`ZeroTriePerfectHash::cursor()`].
+// Clone but not Copy: <https://stackoverflow.com/q/32324251/1407170>
+#[derive(Debug, Clone)]
+pub struct ZeroTriePerfectHashCursor<'a> {
+    trie: ZeroTriePerfectHash<&'a [u8]>,
+    /// The rest of a span node that has been partially stepped through.
+    span: &'a [u8],
+}
+
+impl<'a> ZeroTriePerfectHashCursor<'a> {
+    /// Steps the cursor one byte into the trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerotrie::ZeroTriePerfectHash;
+    ///
+    /// // A trie with two values: "abc" and "abçd"
+    /// let trie: ZeroTriePerfectHash<Vec<u8>> =
+    ///     [("abc", 0), ("abçd", 1)].into_iter().collect();
+    ///
+    /// let mut cursor = trie.cursor();
+    /// for b in "abç".bytes() {
+    ///     cursor.step(b);
+    ///     assert_eq!(cursor.take_value(), None);
+    /// }
+    /// assert!(!cursor.is_empty());
+    /// cursor.step(b'd');
+    /// assert_eq!(cursor.take_value(), Some(1)); // "abçd"
+    /// cursor.step(b'x'); // no strings have the prefix "abçdx"
+    /// assert!(cursor.is_empty());
+    /// ```
+    #[inline]
+    pub fn step(&mut self, byte: u8) {
+        step_phf_limited(&mut self.trie.store, &mut self.span, byte)
+    }
+
+    /// Takes the value at the current position.
+    ///
+    /// Calling this function on a new cursor is equivalent to calling `.get()`
+    /// with the empty string (except that it can only be called once).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerotrie::ZeroTriePerfectHash;
+    ///
+    /// // A trie with two values: "" and "abc"
+    /// let trie = ZeroTriePerfectHash::from_bytes(b"\x80abc\x81");
+    ///
+    /// assert_eq!(Some(0), trie.get(""));
+    /// let mut cursor = trie.cursor();
+    /// assert_eq!(Some(0), cursor.take_value());
+    /// assert_eq!(None, cursor.take_value());
+    /// ```
+    #[inline]
+    pub fn take_value(&mut self) -> Option<usize> {
+        if !self.span.is_empty() {
+            // In the middle of a span node
+            return None;
+        }
+        take_value(&mut self.trie.store)
+    }
+
+    /// Checks whether the cursor points to an empty trie.
+    ///
+    /// Use this to determine when to stop iterating.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.trie.is_empty() && self.span.is_empty()
+    }
+}
+
 impl<'a> fmt::Write for ZeroTrieSimpleAsciiCursor<'a> {
     /// Steps the cursor through each ASCII byte of the string.
     ///
@@ -571,6 +571,102 @@ pub(crate) fn step_bsearch_only(trie: &mut &[u8], c: u8) {
     };
 }
 
+/// Steps one byte into the trie assuming branch nodes could be either binary search or PHF.
+///
+/// The input-output argument `trie` starts at the original trie and ends pointing to
+/// the sub-trie reachable by `c`. The input-output argument `span` holds the bytes of
+/// a partially matched span node that have not yet been stepped through; when it is
+/// non-empty, `c` is matched against its first byte instead of against `trie`.
+pub(crate) fn step_phf_limited<'a>(trie: &mut &'a [u8], span: &mut &'a [u8], c: u8) {
+    if let Some((b, rest)) = span.split_first() {
+        if *b == c {
+            // Matched a byte within a span
+            *span = rest;
+        } else {
+            // Byte within a span that doesn't match
+            *span = &[];
+            *trie = &[];
+        }
+        return;
+    }
+    let (mut b, x, i, search);
+    loop {
+        (b, *trie) = match trie.split_first() {
+            Some(v) => v,
+            None => {
+                // Empty trie or only a value node
+                return;
+            }
+        };
+        match byte_type(*b) {
+            NodeType::Ascii if *b == c => {
+                // Matched a byte
+                return;
+            }
+            NodeType::Ascii => {
+                // Byte that doesn't match
+                *trie = &[];
+                return;
+            }
+            NodeType::Branch => {
+                // Proceed to the branch node logic below
+                (x, *trie) = read_varint_meta2(*b, trie);
+                break;
+            }
+            NodeType::Span => {
+                let (x, trie_span);
+                (x, *trie) = read_varint_meta3(*b, trie);
+                (trie_span, *trie) = trie.debug_split_at(x);
+                match trie_span.split_first() {
+                    Some((b, rest)) if *b == c => {
+                        // Matched the first byte of a span
+                        *span = rest;
+                    }
+                    _ => {
+                        // Span that doesn't match
+                        *trie = &[];
+                    }
+                }
+                return;
+            }
+            NodeType::Value => {
+                // Skip the value node and go to the next node
+                (_, *trie) = read_varint_meta3(*b, trie);
+                continue;
+            }
+        };
+    }
+    // Branch node
+    let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
+    // See comment above regarding this assertion
+    debug_assert!(w <= 3, "get: w > 3 but we assume w <= 3");
+    let w = w & 0x3;
+    let x = if x == 0 { 256 } else { x };
+    if x < 16 {
+        // binary search
+        (search, *trie) = trie.debug_split_at(x);
+        i = search.binary_search(&c).ok();
+    } else {
+        // phf
+        (search, *trie) = trie.debug_split_at(x * 2 + 1);
+        i = PerfectByteHashMap::from_store(search).get(c);
+    }
+    match i {
+        Some(i) => {
+            // Matched a byte
+            *trie = if w == 0 {
+                get_branch_w0(trie, i, x)
+            } else {
+                get_branch(trie, i, x, w)
+            };
+        }
+        None => {
+            // Byte that doesn't match
+            *trie = &[]
+        }
+    };
+}
+
 /// Steps one node into the trie if the head node is a value node, returning the value.
 /// If the head node is not a value node, no change is made.
 ///
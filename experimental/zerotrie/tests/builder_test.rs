@@ -45,6 +45,17 @@ where
     assert_eq!(trie.as_bytes(), const_trie.as_bytes());
 }
 
+fn get_with_phf_cursor<S>(trie: &ZeroTriePerfectHash<S>, key: &[u8]) -> Option<usize>
+where
+    S: AsRef<[u8]> + ?Sized,
+{
+    let mut cursor = trie.cursor();
+    for b in key {
+        cursor.step(*b);
+    }
+    cursor.take_value()
+}
+
 fn check_phf_ascii_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTriePerfectHash<S>)
 where
     S: AsRef<[u8]> + ?Sized,
@@ -52,10 +63,12 @@ where
     // Check that each item is in the trie
     for (k, v) in items.iter() {
         assert_eq!(trie.get(k), Some(*v));
+        assert_eq!(get_with_phf_cursor(trie, k), Some(*v));
     }
     // Check that some items are not in the trie
     for s in NON_EXISTENT_STRINGS.iter() {
         assert_eq!(trie.get(s.as_bytes()), None);
+        assert_eq!(get_with_phf_cursor(trie, s.as_bytes()), None);
     }
     // Check that the iterator returns the contents of the LiteMap
     // Note: Since the items might not be in order, we collect them into a new LiteMap
@@ -73,10 +86,12 @@ where
     // Check that each item is in the trie
     for (k, v) in items.iter() {
         assert_eq!(trie.get(k), Some(*v));
+        assert_eq!(get_with_phf_cursor(trie, k), Some(*v));
     }
     // Check that some items are not in the trie
     for s in NON_EXISTENT_STRINGS.iter() {
         assert_eq!(trie.get(s.as_bytes()), None);
+        assert_eq!(get_with_phf_cursor(trie, s.as_bytes()), None);
     }
     // Check that the iterator returns the contents of the LiteMap
     // Note: Since the items might not be in order, we collect them into a new LiteMap
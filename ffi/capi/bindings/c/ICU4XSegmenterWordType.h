@@ -14,6 +14,8 @@ typedef enum ICU4XSegmenterWordType {
   ICU4XSegmenterWordType_None = 0,
   ICU4XSegmenterWordType_Number = 1,
   ICU4XSegmenterWordType_Letter = 2,
+  ICU4XSegmenterWordType_Kana = 3,
+  ICU4XSegmenterWordType_Ideo = 4,
 } ICU4XSegmenterWordType;
 #ifdef __cplusplus
 } // namespace capi
@@ -20,6 +20,8 @@ enum struct ICU4XSegmenterWordType {
   None = 0,
   Number = 1,
   Letter = 2,
+  Kana = 3,
+  Ideo = 4,
 };
 
 #endif
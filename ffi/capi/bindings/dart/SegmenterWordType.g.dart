@@ -11,5 +11,9 @@ enum SegmenterWordType {
 
   number,
 
-  letter;
+  letter,
+
+  kana,
+
+  ideo;
 }
@@ -13,4 +13,10 @@ export enum ICU4XSegmenterWordType {
   /**
    */
   Letter = 'Letter',
+  /**
+   */
+  Kana = 'Kana',
+  /**
+   */
+  Ideo = 'Ideo',
 }
\ No newline at end of file
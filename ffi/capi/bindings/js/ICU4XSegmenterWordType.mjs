@@ -5,16 +5,22 @@ export const ICU4XSegmenterWordType_js_to_rust = {
   "None": 0,
   "Number": 1,
   "Letter": 2,
+  "Kana": 3,
+  "Ideo": 4,
 };
 
 export const ICU4XSegmenterWordType_rust_to_js = {
   [0]: "None",
   [1]: "Number",
   [2]: "Letter",
+  [3]: "Kana",
+  [4]: "Ideo",
 };
 
 export const ICU4XSegmenterWordType = {
   "None": "None",
   "Number": "Number",
   "Letter": "Letter",
+  "Kana": "Kana",
+  "Ideo": "Ideo",
 };
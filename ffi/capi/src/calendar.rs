@@ -96,7 +96,10 @@ pub mod ffi {
             write: &mut diplomat_runtime::DiplomatWriteable,
         ) -> Result<(), ICU4XError> {
             let kind = AnyCalendarKind::from(self);
-            Ok(write.write_str(kind.as_bcp47_string())?)
+            let bcp47 = kind
+                .as_bcp47_string()
+                .ok_or(ICU4XError::CalendarUnknownKindError)?;
+            Ok(write.write_str(bcp47)?)
         }
     }
 
@@ -19,6 +19,8 @@ pub mod ffi {
         None = 0,
         Number = 1,
         Letter = 2,
+        Kana = 3,
+        Ideo = 4,
     }
 
     #[diplomat::opaque]
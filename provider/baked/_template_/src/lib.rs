@@ -4,8 +4,8 @@
 
 //! Data for the icu__component_ crate
 //!
-//! This data was generated with CLDR version _cldr_tag_, ICU version _icuexport_tag_, and
-//! LSTM segmenter version _segmenter_lstm_tag_.
+//! This data was generated with CLDR version _cldr_tag_, ICU version _icuexport_tag_,
+//! LSTM segmenter version _segmenter_lstm_tag_, and tzdata version _tzdata_tag_.
 
 #![no_std]
 // The source is not readable and is massive as HTML.
@@ -45,6 +45,11 @@ mod calendar_japanext_v1;
 #[doc(inline)]
 pub use __impl_calendar_japanext_v1 as impl_calendar_japanext_v1;
 #[macro_use]
+#[path = "macros/calendar_vietnamesecache_v1.rs.data"]
+mod calendar_vietnamesecache_v1;
+#[doc(inline)]
+pub use __impl_calendar_vietnamesecache_v1 as impl_calendar_vietnamesecache_v1;
+#[macro_use]
 #[path = "macros/datetime_week_data_v1.rs.data"]
 mod datetime_week_data_v1;
 #[doc(inline)]
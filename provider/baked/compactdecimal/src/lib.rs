@@ -4,8 +4,8 @@
 
 //! Data for the icu_compactdecimal crate
 //!
-//! This data was generated with CLDR version 44.1.0, ICU version release-74-2, and
-//! LSTM segmenter version v0.1.0.
+//! This data was generated with CLDR version 44.1.0, ICU version release-74-2,
+//! LSTM segmenter version v0.1.0, and tzdata version 2025b.
 
 #![no_std]
 // The source is not readable and is massive as HTML.
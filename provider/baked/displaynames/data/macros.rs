@@ -25,6 +25,11 @@ macro_rules! __make_provider {
 #[doc(inline)]
 pub use __make_provider as make_provider;
 #[macro_use]
+#[path = "macros/displaynames_keys_v1.rs.data"]
+mod displaynames_keys_v1;
+#[doc(inline)]
+pub use __impl_displaynames_keys_v1 as impl_displaynames_keys_v1;
+#[macro_use]
 #[path = "macros/displaynames_languages_v1.rs.data"]
 mod displaynames_languages_v1;
 #[doc(inline)]
@@ -35,6 +40,11 @@ mod displaynames_locales_v1;
 #[doc(inline)]
 pub use __impl_displaynames_locales_v1 as impl_displaynames_locales_v1;
 #[macro_use]
+#[path = "macros/displaynames_patterns_v1.rs.data"]
+mod displaynames_patterns_v1;
+#[doc(inline)]
+pub use __impl_displaynames_patterns_v1 as impl_displaynames_patterns_v1;
+#[macro_use]
 #[path = "macros/displaynames_regions_v1.rs.data"]
 mod displaynames_regions_v1;
 #[doc(inline)]
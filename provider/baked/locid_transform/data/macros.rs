@@ -45,6 +45,16 @@ mod locid_transform_aliases_v2;
 #[doc(inline)]
 pub use __impl_locid_transform_aliases_v2 as impl_locid_transform_aliases_v2;
 #[macro_use]
+#[path = "macros/locid_transform_extension_aliases_v1.rs.data"]
+mod locid_transform_extension_aliases_v1;
+#[doc(inline)]
+pub use __impl_locid_transform_extension_aliases_v1 as impl_locid_transform_extension_aliases_v1;
+#[macro_use]
+#[path = "macros/locid_transform_language_matching_v1.rs.data"]
+mod locid_transform_language_matching_v1;
+#[doc(inline)]
+pub use __impl_locid_transform_language_matching_v1 as impl_locid_transform_language_matching_v1;
+#[macro_use]
 #[path = "macros/locid_transform_likelysubtags_ext_v1.rs.data"]
 mod locid_transform_likelysubtags_ext_v1;
 #[doc(inline)]
@@ -64,3 +74,8 @@ pub use __impl_locid_transform_likelysubtags_sr_v1 as impl_locid_transform_likel
 mod locid_transform_script_dir_v1;
 #[doc(inline)]
 pub use __impl_locid_transform_script_dir_v1 as impl_locid_transform_script_dir_v1;
+#[macro_use]
+#[path = "macros/locid_transform_validity_v1.rs.data"]
+mod locid_transform_validity_v1;
+#[doc(inline)]
+pub use __impl_locid_transform_validity_v1 as impl_locid_transform_validity_v1;
@@ -50,6 +50,11 @@ mod segmenter_lstm_wl_auto_v1;
 #[doc(inline)]
 pub use __impl_segmenter_lstm_wl_auto_v1 as impl_segmenter_lstm_wl_auto_v1;
 #[macro_use]
+#[path = "macros/segmenter_sentence_suppressions_v1.rs.data"]
+mod segmenter_sentence_suppressions_v1;
+#[doc(inline)]
+pub use __impl_segmenter_sentence_suppressions_v1 as impl_segmenter_sentence_suppressions_v1;
+#[macro_use]
 #[path = "macros/segmenter_sentence_v1.rs.data"]
 mod segmenter_sentence_v1;
 #[doc(inline)]
@@ -39,3 +39,18 @@ pub use __impl_time_zone_iana_to_bcp47_v1 as impl_time_zone_iana_to_bcp47_v1;
 mod time_zone_metazone_period_v1;
 #[doc(inline)]
 pub use __impl_time_zone_metazone_period_v1 as impl_time_zone_metazone_period_v1;
+#[macro_use]
+#[path = "macros/time_zone_offset_period_v1.rs.data"]
+mod time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impl_time_zone_offset_period_v1 as impl_time_zone_offset_period_v1;
+#[macro_use]
+#[path = "macros/time_zone_regions_v1.rs.data"]
+mod time_zone_regions_v1;
+#[doc(inline)]
+pub use __impl_time_zone_regions_v1 as impl_time_zone_regions_v1;
+#[macro_use]
+#[path = "macros/time_zone_windows_zones_v1.rs.data"]
+mod time_zone_windows_zones_v1;
+#[doc(inline)]
+pub use __impl_time_zone_windows_zones_v1 as impl_time_zone_windows_zones_v1;
@@ -150,6 +150,21 @@ pub struct Cli {
     )]
     segmenter_lstm_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download compiled IANA time zone database files for this release (https://www.iana.org/time-zones) from the tzdata package on PyPI\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--tzif-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    tzdata_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory of compiled IANA time zone database files in the TZif format, such as /usr/share/zoneinfo."
+    )]
+    tzif_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -258,6 +273,7 @@ impl Cli {
                 &self.segmenter_lstm_tag,
                 "segmenter-lstm",
             )?,
+            tzif: self.make_path(&self.tzif_root, &self.tzdata_tag, "tzif-root")?,
             trie_type: match self.trie_type {
                 TrieType::Fast => config::TrieType::Fast,
                 TrieType::Small => config::TrieType::Small,
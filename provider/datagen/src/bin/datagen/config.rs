@@ -29,6 +29,8 @@ pub struct Config {
     pub icu_export: PathOrTag,
     #[serde(default)]
     pub segmenter_lstm: PathOrTag,
+    #[serde(default)]
+    pub tzif: PathOrTag,
     #[serde(default, skip_serializing_if = "is_default")]
     pub trie_type: TrieType,
     #[serde(default, skip_serializing_if = "is_default")]
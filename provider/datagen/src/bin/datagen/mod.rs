@@ -79,6 +79,19 @@ fn main() -> eyre::Result<()> {
         _ => eyre::bail!("Downloading data from tags requires the `networking` Cargo feature"),
     };
 
+    provider = match config.tzif {
+        config::PathOrTag::Path(path) => provider.with_tzif(path)?,
+        #[cfg(feature = "networking")]
+        config::PathOrTag::Latest => {
+            provider.with_tzif_for_tag(DatagenProvider::LATEST_TESTED_TZDATA_TAG)
+        }
+        #[cfg(feature = "networking")]
+        config::PathOrTag::Tag(tag) => provider.with_tzif_for_tag(&tag),
+        config::PathOrTag::None => provider,
+        #[cfg(not(feature = "networking"))]
+        _ => eyre::bail!("Downloading data from tags requires the `networking` Cargo feature"),
+    };
+
     let mut driver = DatagenDriver::new();
     driver = match config.keys {
         config::KeyInclude::None => driver.with_keys([]),
@@ -438,9 +438,13 @@ impl DatagenDriver {
 
             let transform_duration = instant1.elapsed();
 
-            // segmenter uses hardcoded locales internally, so fallback is not necessary.
+            // segmenter uses hardcoded locales internally, so fallback is not necessary,
+            // except for the sentence break suppressions, which are keyed by CLDR locales.
             // TODO(#4511): Use auxiliary keys for segmenter
-            if fallback == FallbackMode::Runtime && !key.path().get().starts_with("segmenter") {
+            if fallback == FallbackMode::Runtime
+                && (!key.path().get().starts_with("segmenter")
+                    || key == icu_segmenter::provider::SentenceBreakSuppressionsV1Marker::KEY)
+            {
                 sink.flush_with_built_in_fallback(key, BuiltInFallbackMode::Standard)
             } else {
                 sink.flush(key)
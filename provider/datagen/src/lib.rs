@@ -96,7 +96,7 @@
 //!   * enables the `--format dir` CLI argument
 //! * `networking`
 //!   * enables methods on [`DatagenProvider`] that fetch source data from the network
-//!   * enables the `--cldr-tag`, `--icu-export-tag`, and `--segmenter-lstm-tag` CLI arguments that download data
+//!   * enables the `--cldr-tag`, `--icu-export-tag`, `--segmenter-lstm-tag`, and `--tzdata-tag` CLI arguments that download data
 //! * `rayon`
 //!   * enables parallelism during export
 //! * `use_wasm` / `use_icu4c`
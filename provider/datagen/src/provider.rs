@@ -25,6 +25,7 @@ use std::sync::Arc;
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_tzif_error`](Self::is_missing_tzif_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct DatagenProvider {
@@ -42,11 +43,15 @@ impl DatagenProvider {
     /// The latest segmentation LSTM model tag that has been verified to work with this version of `icu_datagen`.
     pub const LATEST_TESTED_SEGMENTER_LSTM_TAG: &'static str = "v0.1.0";
 
+    /// The latest IANA time zone database release that has been verified to work with this version of `icu_datagen`.
+    pub const LATEST_TESTED_TZDATA_TAG: &'static str = "2025b";
+
     /// A provider using the latest data that has been verified to work with this version of `icu_datagen`.
     ///
     /// See [`LATEST_TESTED_CLDR_TAG`](Self::LATEST_TESTED_CLDR_TAG),
     /// [`LATEST_TESTED_ICUEXPORT_TAG`](Self::LATEST_TESTED_ICUEXPORT_TAG),
-    /// [`LATEST_TESTED_SEGMENTER_LSTM_TAG`](Self::LATEST_TESTED_SEGMENTER_LSTM_TAG).
+    /// [`LATEST_TESTED_SEGMENTER_LSTM_TAG`](Self::LATEST_TESTED_SEGMENTER_LSTM_TAG),
+    /// [`LATEST_TESTED_TZDATA_TAG`](Self::LATEST_TESTED_TZDATA_TAG).
     ///
     /// ✨ *Enabled with the `networking` Cargo feature.*
     #[cfg(feature = "networking")]
@@ -60,6 +65,7 @@ impl DatagenProvider {
                     .with_cldr_for_tag(Self::LATEST_TESTED_CLDR_TAG)
                     .with_icuexport_for_tag(Self::LATEST_TESTED_ICUEXPORT_TAG)
                     .with_segmenter_lstm_for_tag(Self::LATEST_TESTED_SEGMENTER_LSTM_TAG)
+                    .with_tzif_for_tag(Self::LATEST_TESTED_TZDATA_TAG)
             })
             .clone()
     }
@@ -68,13 +74,15 @@ impl DatagenProvider {
     /// will return errors.
     ///
     /// Use [`with_cldr`](Self::with_cldr), [`with_icuexport`](Self::with_icuexport),
-    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm) to set data sources.
+    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm), [`with_tzif`](Self::with_tzif)
+    /// to set data sources.
     pub fn new_custom() -> Self {
         Self {
             source: SourceData {
                 cldr_paths: None,
                 icuexport_paths: None,
                 segmenter_lstm_paths: None,
+                tzif_paths: None,
                 trie_type: Default::default(),
                 collation_han_database: Default::default(),
                 #[cfg(feature = "legacy_api")]
@@ -124,6 +132,18 @@ impl DatagenProvider {
         })
     }
 
+    /// Adds time zone offset source data to the provider. The path should point to a local
+    /// directory or ZIP file of compiled IANA time zone database files in the TZif format
+    /// (version 2 or later), such as `/usr/share/zoneinfo`.
+    pub fn with_tzif(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            source: SourceData {
+                tzif_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+                ..self.source
+            },
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -180,6 +200,30 @@ impl DatagenProvider {
         }
     }
 
+    /// Adds time zone offset source data to the provider. The data will be downloaded from PyPI,
+    /// where the [`tzdata`](https://pypi.org/project/tzdata/) package distributes compiled TZif
+    /// files for each release of the [IANA time zone database](https://www.iana.org/time-zones),
+    /// using the given release name (such as `2025b`).
+    ///
+    /// Also see: [`LATEST_TESTED_TZDATA_TAG`](Self::LATEST_TESTED_TZDATA_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_tzif_for_tag(self, tag: &str) -> Self {
+        Self {
+            source: SourceData {
+                tzif_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new_from_url_with_root(
+                    format!(
+                        "https://files.pythonhosted.org/packages/py2.py3/t/tzdata/tzdata-{}-py2.py3-none-any.whl",
+                        tzdata_package_version(tag)
+                    ),
+                    "tzdata/zoneinfo/",
+                )))),
+                ..self.source
+            },
+        }
+    }
+
     const MISSING_CLDR_ERROR: DataError = DataErrorKind::MissingSourceData.with_str_context("cldr");
 
     const MISSING_ICUEXPORT_ERROR: DataError =
@@ -188,6 +232,8 @@ impl DatagenProvider {
     const MISSING_SEGMENTER_LSTM_ERROR: DataError =
         DataErrorKind::MissingSourceData.with_str_context("segmenter");
 
+    const MISSING_TZIF_ERROR: DataError = DataErrorKind::MissingSourceData.with_str_context("tzif");
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.key = None;
@@ -206,6 +252,12 @@ impl DatagenProvider {
         e == Self::MISSING_SEGMENTER_LSTM_ERROR
     }
 
+    /// Identifies errors that are due to missing TZif data.
+    pub fn is_missing_tzif_error(mut e: DataError) -> bool {
+        e.key = None;
+        e == Self::MISSING_TZIF_ERROR
+    }
+
     pub(crate) fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.source
             .cldr_paths
@@ -227,6 +279,13 @@ impl DatagenProvider {
             .ok_or(Self::MISSING_SEGMENTER_LSTM_ERROR)
     }
 
+    pub(crate) fn tzif(&self) -> Result<&SerdeCache, DataError> {
+        self.source
+            .tzif_paths
+            .as_deref()
+            .ok_or(Self::MISSING_TZIF_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -277,6 +336,16 @@ impl DatagenProvider {
     }
 }
 
+/// The `tzdata` package numbers the releases of a year instead of using letters, so that
+/// release `2025b` is version `2025.2`.
+#[cfg(feature = "networking")]
+fn tzdata_package_version(tag: &str) -> String {
+    match tag.char_indices().find(|(_, c)| c.is_ascii_lowercase()) {
+        Some((i, c)) => format!("{}.{}", &tag[..i], u32::from(c) - u32::from('a') + 1),
+        None => tag.to_owned(),
+    }
+}
+
 /// Specifies the trie type to use.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 #[doc(hidden)]
@@ -316,6 +385,7 @@ pub struct SourceData {
     pub(crate) cldr_paths: Option<Arc<CldrCache>>,
     pub(crate) icuexport_paths: Option<Arc<SerdeCache>>,
     pub(crate) segmenter_lstm_paths: Option<Arc<SerdeCache>>,
+    pub(crate) tzif_paths: Option<Arc<SerdeCache>>,
     pub(crate) trie_type: TrieType,
     pub(crate) collation_han_database: CollationHanDatabase,
     #[cfg(feature = "legacy_api")]
@@ -157,11 +157,14 @@ macro_rules! registry {
 registry!(
     #[cfg(test)]
     icu_dimension::provider::CurrencyEssentialsV1Marker = "currency/essentials@1",
+    icu_dimension::provider::GrammaticalFeaturesV1Marker = "grammar/features@1",
+    icu_dimension::provider::UnitsDisplayNamesV1Marker = "units/displaynames@1",
     #[cfg(any(all(), feature = "icu_calendar"))]
     icu_calendar::provider::ChineseCacheV1Marker = "calendar/chinesecache@1",
     icu_calendar::provider::DangiCacheV1Marker = "calendar/dangicache@1",
     icu_calendar::provider::JapaneseErasV1Marker = "calendar/japanese@1",
     icu_calendar::provider::JapaneseExtendedErasV1Marker = "calendar/japanext@1",
+    icu_calendar::provider::VietnameseCacheV1Marker = "calendar/vietnamesecache@1",
     icu_calendar::provider::WeekDataV1Marker = "datetime/week_data@1",
     icu_calendar::provider::WeekDataV2Marker = "datetime/week_data@2",
     #[cfg(any(all(), feature = "icu_casemap"))]
@@ -282,6 +285,8 @@ registry!(
     icu_displaynames::provider::LocaleDisplayNamesV1Marker = "displaynames/locales@1",
     icu_displaynames::provider::ScriptDisplayNamesV1Marker = "displaynames/scripts@1",
     icu_displaynames::provider::VariantDisplayNamesV1Marker = "displaynames/variants@1",
+    icu_displaynames::provider::KeyDisplayNamesV1Marker = "displaynames/keys@1",
+    icu_displaynames::provider::LocaleDisplayNamesPatternsV1Marker = "displaynames/patterns@1",
     #[cfg(any(all(), feature = "icu_list"))]
     icu_list::provider::AndListV1Marker = "list/and@1",
     icu_list::provider::OrListV1Marker = "list/or@1",
@@ -290,6 +295,8 @@ registry!(
     icu_locid_transform::provider::AliasesV1Marker = "locid_transform/aliases@1",
     icu_locid_transform::provider::AliasesV2Marker = "locid_transform/aliases@2",
     icu_locid_transform::provider::CollationFallbackSupplementV1Marker = "fallback/supplement/co@1",
+    icu_locid_transform::provider::ExtensionAliasesV1Marker = "locid_transform/extension_aliases@1",
+    icu_locid_transform::provider::LanguageMatchingV1Marker = "locid_transform/language_matching@1",
     icu_locid_transform::provider::LikelySubtagsV1Marker = "locid_transform/likelysubtags@1",
     icu_locid_transform::provider::LikelySubtagsExtendedV1Marker =
         "locid_transform/likelysubtags_ext@1",
@@ -300,6 +307,7 @@ registry!(
     icu_locid_transform::provider::LocaleFallbackLikelySubtagsV1Marker = "fallback/likelysubtags@1",
     icu_locid_transform::provider::LocaleFallbackParentsV1Marker = "fallback/parents@1",
     icu_locid_transform::provider::ScriptDirectionV1Marker = "locid_transform/script_dir@1",
+    icu_locid_transform::provider::ValidityV1Marker = "locid_transform/validity@1",
     #[cfg(any(all(), feature = "icu_normalizer"))]
     icu_normalizer::provider::CanonicalCompositionsV1Marker = "normalizer/comp@1",
     icu_normalizer::provider::CanonicalDecompositionDataV1Marker = "normalizer/nfd@1",
@@ -494,11 +502,16 @@ registry!(
     icu_segmenter::provider::LineBreakDataV1Marker = "segmenter/line@1",
     icu_segmenter::provider::LstmForWordLineAutoV1Marker = "segmenter/lstm/wl_auto@1",
     icu_segmenter::provider::SentenceBreakDataV1Marker = "segmenter/sentence@1",
+    icu_segmenter::provider::SentenceBreakSuppressionsV1Marker =
+        "segmenter/sentence/suppressions@1",
     icu_segmenter::provider::WordBreakDataV1Marker = "segmenter/word@1",
     #[cfg(any(all(), feature = "icu_timezone"))]
     icu_timezone::provider::MetazonePeriodV1Marker = "time_zone/metazone_period@1",
+    icu_timezone::provider::ZoneOffsetPeriodV1Marker = "time_zone/offset_period@1",
     icu_timezone::provider::names::Bcp47ToIanaMapV1Marker = "time_zone/bcp47_to_iana@1",
     icu_timezone::provider::names::IanaToBcp47MapV1Marker = "time_zone/iana_to_bcp47@1",
+    icu_timezone::provider::names::TimeZoneRegionsV1Marker = "time_zone/regions@1",
+    icu_timezone::provider::names::WindowsZonesMapV1Marker = "time_zone/windows_zones@1",
     #[cfg(feature = "icu_transliterate")]
     icu_transliterate::provider::TransliteratorRulesV1Marker = "transliterator/rules@1",
     #[cfg(feature = "icu_unitsconversion")]
@@ -5,6 +5,7 @@
 use elsa::sync::FrozenMap;
 use icu_provider::prelude::*;
 use std::any::Any;
+use std::any::TypeId;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::fmt::Debug;
@@ -21,7 +22,8 @@ use zip::ZipArchive;
 
 pub(crate) struct SerdeCache {
     pub(crate) root: AbstractFs,
-    cache: FrozenMap<String, Box<dyn Any + Send + Sync>>,
+    /// Keyed by path and type, as some files are parsed into different types by different keys
+    cache: FrozenMap<(String, TypeId), Box<dyn Any + Send + Sync>>,
 }
 
 impl Debug for SerdeCache {
@@ -49,10 +51,11 @@ impl SerdeCache {
     where
         for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
     {
-        match self.cache.get(path) {
+        let key = (path.to_string(), TypeId::of::<S>());
+        match self.cache.get(&key) {
             Some(x) => x,
             None => self.cache.insert(
-                path.to_string(),
+                key,
                 Box::new(
                     parser(&self.root.read_to_buf(path)?)
                         .map_err(|e| e.with_path_context(&path))?,
@@ -83,6 +86,10 @@ impl SerdeCache {
         })
     }
 
+    pub fn read_to_buf(&self, path: &str) -> Result<Vec<u8>, DataError> {
+        self.root.read_to_buf(path)
+    }
+
     pub fn list(&self, path: &str) -> Result<impl Iterator<Item = String>, DataError> {
         self.root.list(path)
     }
@@ -95,11 +102,13 @@ impl SerdeCache {
 pub(crate) struct ZipData {
     archive: ZipArchive<Cursor<Vec<u8>>>,
     file_list: HashSet<String>,
+    /// The directory inside the archive that paths are relative to, either empty or ending in `/`
+    root: &'static str,
 }
 
 pub(crate) enum AbstractFs {
     Fs(PathBuf),
-    Zip(RwLock<Result<ZipData, String>>),
+    Zip(RwLock<Result<ZipData, (String, &'static str)>>),
     Memory(BTreeMap<&'static str, &'static [u8]>),
 }
 
@@ -123,13 +132,24 @@ impl AbstractFs {
                     .with_path_context(&root)
             })?;
             let file_list = archive.file_names().map(String::from).collect();
-            Ok(Self::Zip(RwLock::new(Ok(ZipData { archive, file_list }))))
+            Ok(Self::Zip(RwLock::new(Ok(ZipData {
+                archive,
+                file_list,
+                root: "",
+            }))))
         }
     }
 
     #[cfg(feature = "networking")]
     pub fn new_from_url(path: String) -> Self {
-        Self::Zip(RwLock::new(Err(path)))
+        Self::new_from_url_with_root(path, "")
+    }
+
+    /// Like [`Self::new_from_url`], but paths are relative to the directory `root` inside
+    /// the archive, which has to end in `/`.
+    #[cfg(feature = "networking")]
+    pub fn new_from_url_with_root(path: String, root: &'static str) -> Self {
+        Self::Zip(RwLock::new(Err((path, root))))
     }
 
     fn init(&self) -> Result<(), DataError> {
@@ -139,8 +159,8 @@ impl AbstractFs {
                 return Ok(());
             }
             let mut lock = lock.write().expect("poison");
-            let resource = if let Err(resource) = &*lock {
-                resource
+            let (resource, zip_root) = if let Err((resource, zip_root)) = &*lock {
+                (resource, *zip_root)
             } else {
                 return Ok(());
             };
@@ -170,7 +190,11 @@ impl AbstractFs {
 
             let file_list = archive.file_names().map(String::from).collect();
 
-            *lock = Ok(ZipData { archive, file_list });
+            *lock = Ok(ZipData {
+                archive,
+                file_list,
+                root: zip_root,
+            });
         }
         Ok(())
     }
@@ -186,13 +210,10 @@ impl AbstractFs {
             Self::Zip(zip) => {
                 log::debug!("Reading: <zip>/{}", path);
                 let mut buf = Vec::new();
-                zip.write()
-                    .expect("poison")
-                    .as_mut()
-                    .ok()
-                    .unwrap() // init called
-                    .archive
-                    .by_name(path)
+                let mut zip = zip.write().expect("poison");
+                let zip = zip.as_mut().ok().unwrap(); // init called
+                zip.archive
+                    .by_name(&format!("{}{path}", zip.root))
                     .map_err(|e| {
                         DataErrorKind::Io(std::io::ErrorKind::NotFound)
                             .into_error()
@@ -224,19 +245,17 @@ impl AbstractFs {
                 .map(|e| -> Result<_, DataError> { Ok(e?.file_name().into_string().unwrap()) })
                 .collect::<Result<HashSet<_>, DataError>>()
                 .map(HashSet::into_iter)?,
-            Self::Zip(zip) => zip
-                .read()
-                .expect("poison")
-                .as_ref()
-                .ok()
-                .unwrap() // init called
-                .file_list
-                .iter()
-                .filter_map(|p| p.strip_prefix(path))
-                .filter_map(|suffix| suffix.split('/').find(|s| !s.is_empty()))
-                .map(String::from)
-                .collect::<HashSet<_>>()
-                .into_iter(),
+            Self::Zip(zip) => {
+                let zip = zip.read().expect("poison");
+                let zip = zip.as_ref().ok().unwrap(); // init called
+                zip.file_list
+                    .iter()
+                    .filter_map(|p| p.strip_prefix(zip.root)?.strip_prefix(path))
+                    .filter_map(|suffix| suffix.split('/').find(|s| !s.is_empty()))
+                    .map(String::from)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+            }
             Self::Memory(map) => map
                 .keys()
                 .copied()
@@ -252,14 +271,11 @@ impl AbstractFs {
         self.init()?;
         Ok(match self {
             Self::Fs(root) => root.join(path).is_file(),
-            Self::Zip(zip) => zip
-                .read()
-                .expect("poison")
-                .as_ref()
-                .ok()
-                .unwrap() // init called
-                .file_list
-                .contains(path),
+            Self::Zip(zip) => {
+                let zip = zip.read().expect("poison");
+                let zip = zip.as_ref().ok().unwrap(); // init called
+                zip.file_list.contains(&format!("{}{path}", zip.root))
+            }
             Self::Memory(map) => map.contains_key(path),
         })
     }
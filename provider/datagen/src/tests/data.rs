@@ -21,7 +21,7 @@ impl DatagenProvider {
             .get_or_init(|| Self {
                 source: SourceData {
                     cldr_paths: Some(Arc::new(CldrCache::from_serde_cache(SerdeCache::new(AbstractFs::Memory(
-                        [("cldr-bcp47/bcp47/timezone.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/timezone.json").as_slice()), ("cldr-cal-buddhist-full/main/ar/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ar/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ar-EG/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ar-EG/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/bn/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/bn/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ccp/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ccp/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en-001/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en-001/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en-ZA/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en-ZA/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/es/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/es/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/es-AR/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/es-AR/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/fr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/fr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/fil/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/fil/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ja/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ja/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ru/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ru/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/sr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/sr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/sr-Latn/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/sr-Latn/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/th/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/th/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/tr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/tr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/und/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/und/ca-buddhist.json").as_slice()), ("cldr-cal-chinese-full/main/ar/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ar/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ar-EG/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ar-EG/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/bn/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/bn/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ccp/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ccp/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en-001/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en-001/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en-ZA/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en-ZA/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/es/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/es/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/es-AR/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/es-AR/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/fr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/fr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/fil/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/fil/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ja/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ja/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ru/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ru/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/sr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/sr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/sr-Latn/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/sr-Latn/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/th/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/th/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/tr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/tr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/und/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/und/ca-chinese.json").as_slice()), ("cldr-cal-coptic-full/main/ar/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ar/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ar-EG/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ar-EG/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/bn/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/bn/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ccp/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ccp/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en-001/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en-001/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en-ZA/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en-ZA/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/es/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/es/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/es-AR/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/es-AR/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/fr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/fr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/fil/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/fil/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ja/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ja/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ru/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ru/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/sr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/sr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/sr-Latn/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/sr-Latn/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/th/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/th/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/tr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/tr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/und/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/und/ca-coptic.json").as_slice()), ("cldr-cal-dangi-full/main/ar/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ar/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ar-EG/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ar-EG/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/bn/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/bn/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ccp/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ccp/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en-001/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en-001/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en-ZA/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en-ZA/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/es/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/es/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/es-AR/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/es-AR/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/fr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/fr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/fil/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/fil/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ja/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ja/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ru/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ru/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/sr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/sr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/sr-Latn/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/sr-Latn/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/th/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/th/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/tr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/tr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/und/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/und/ca-dangi.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/bn/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/bn/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ccp/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ccp/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-001/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-001/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/es/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/fr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/fil/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fil/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ja/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ja/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ru/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ru/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/th/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/th/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/tr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/tr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/und/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/und/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/bn/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/bn/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ccp/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ccp/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-001/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-001/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/es/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/fr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/fil/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fil/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ja/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ja/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ru/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ru/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/th/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/th/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/tr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/tr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/und/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/und/ca-ethiopic.json").as_slice()), ("cldr-cal-indian-full/main/ar/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ar/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ar-EG/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ar-EG/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/bn/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/bn/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ccp/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ccp/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en-001/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en-001/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en-ZA/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en-ZA/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/es/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/es/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/es-AR/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/es-AR/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/fr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/fr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/fil/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/fil/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ja/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ja/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ru/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ru/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/sr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/sr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/sr-Latn/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/sr-Latn/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/th/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/th/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/tr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/tr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/und/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/und/ca-indian.json").as_slice()), ("cldr-cal-japanese-full/main/ar/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ar/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ar-EG/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ar-EG/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/bn/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/bn/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ccp/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ccp/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en-001/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en-001/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en-ZA/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en-ZA/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/es/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/es/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/es-AR/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/es-AR/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/fr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/fr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/fil/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/fil/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ja/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ja/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ru/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ru/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/sr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/sr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/sr-Latn/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/sr-Latn/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/th/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/th/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/tr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/tr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/und/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/und/ca-japanese.json").as_slice()), ("cldr-cal-persian-full/main/ar/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ar/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ar-EG/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ar-EG/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/bn/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/bn/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ccp/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ccp/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en-001/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en-001/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en-ZA/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en-ZA/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/es/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/es/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/es-AR/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/es-AR/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/fr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/fr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/fil/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/fil/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ja/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ja/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ru/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ru/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/sr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/sr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/sr-Latn/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/sr-Latn/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/th/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/th/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/tr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/tr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/und/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/und/ca-persian.json").as_slice()), ("cldr-cal-hebrew-full/main/ar/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ar/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ar-EG/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ar-EG/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/bn/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/bn/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ccp/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ccp/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en-001/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en-001/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en-ZA/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en-ZA/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/es/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/es/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/es-AR/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/es-AR/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/fr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/fr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/fil/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/fil/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ja/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ja/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ru/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ru/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/sr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/sr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/sr-Latn/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/sr-Latn/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/th/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/th/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/tr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/tr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/und/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/und/ca-hebrew.json").as_slice()), ("cldr-cal-islamic-full/main/ar/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ar/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ar-EG/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ar-EG/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/bn/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/bn/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ccp/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ccp/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en-001/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en-001/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en-ZA/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en-ZA/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/es/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/es/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/es-AR/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/es-AR/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/fr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/fr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/fil/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/fil/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ja/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ja/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ru/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ru/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/sr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/sr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/sr-Latn/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/sr-Latn/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/th/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/th/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/tr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/tr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/und/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/und/ca-islamic.json").as_slice()), ("cldr-cal-roc-full/main/ar/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ar/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ar-EG/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ar-EG/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/bn/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/bn/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ccp/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ccp/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en-001/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en-001/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en-ZA/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en-ZA/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/es/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/es/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/es-AR/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/es-AR/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/fr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/fr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/fil/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/fil/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ja/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ja/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ru/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ru/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/sr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/sr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/sr-Latn/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/sr-Latn/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/th/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/th/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/tr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/tr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/und/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/und/ca-roc.json").as_slice()), ("cldr-core/coverageLevels.json", include_bytes!("../../tests/data/cldr/cldr-core/coverageLevels.json").as_slice()), ("cldr-core/scriptMetadata.json", include_bytes!("../../tests/data/cldr/cldr-core/scriptMetadata.json").as_slice()), ("cldr-core/supplemental/aliases.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/aliases.json").as_slice()), ("cldr-core/supplemental/calendarData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()), ("cldr-core/supplemental/currencyData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()), ("cldr-core/supplemental/units.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()), ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()), ("cldr-core/supplemental/metaZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()), ("cldr-core/supplemental/numberingSystems.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/numberingSystems.json").as_slice()), ("cldr-core/supplemental/ordinals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/ordinals.json").as_slice()), ("cldr-core/supplemental/parentLocales.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/parentLocales.json").as_slice()), ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()), ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()), ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()), ("cldr-dates-full/main/ar/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ar-EG/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/bn/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ccp/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en-001/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en-ZA/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/es/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/es-AR/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/fr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/fil/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ja/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ru/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/sr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/sr-Latn/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/th/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/tr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/und/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ar/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/dateFields.json").as_slice()), ("cldr-dates-full/main/ar-EG/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/dateFields.json").as_slice()), ("cldr-dates-full/main/bn/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/dateFields.json").as_slice()), ("cldr-dates-full/main/ccp/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/dateFields.json").as_slice()), ("cldr-dates-full/main/en/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/dateFields.json").as_slice()), ("cldr-dates-full/main/en-001/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/dateFields.json").as_slice()), ("cldr-dates-full/main/en-ZA/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/dateFields.json").as_slice()), ("cldr-dates-full/main/es/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/dateFields.json").as_slice()), ("cldr-dates-full/main/es-AR/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/dateFields.json").as_slice()), ("cldr-dates-full/main/fr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/dateFields.json").as_slice()), ("cldr-dates-full/main/fil/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/dateFields.json").as_slice()), ("cldr-dates-full/main/ja/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/dateFields.json").as_slice()), ("cldr-dates-full/main/ru/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/dateFields.json").as_slice()), ("cldr-dates-full/main/sr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/dateFields.json").as_slice()), ("cldr-dates-full/main/sr-Latn/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/dateFields.json").as_slice()), ("cldr-dates-full/main/th/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/dateFields.json").as_slice()), ("cldr-dates-full/main/tr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/dateFields.json").as_slice()), ("cldr-dates-full/main/und/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/dateFields.json").as_slice()), ("cldr-dates-full/main/ar/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ar-EG/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/bn/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ccp/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en-001/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en-ZA/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/es/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/es-AR/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/fr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/fil/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ja/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ru/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/sr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/sr-Latn/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/th/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/tr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/und/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/cs/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/cs/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/cs/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/cs/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/haw/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/haw/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/haw/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/haw/timeZoneNames.json").as_slice()), ("cldr-localenames-full/main/ar/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/languages.json").as_slice()), ("cldr-localenames-full/main/ar-EG/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/languages.json").as_slice()), ("cldr-localenames-full/main/bn/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/languages.json").as_slice()), ("cldr-localenames-full/main/ccp/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/languages.json").as_slice()), ("cldr-localenames-full/main/en/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/languages.json").as_slice()), ("cldr-localenames-full/main/en-001/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/languages.json").as_slice()), ("cldr-localenames-full/main/en-ZA/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/languages.json").as_slice()), ("cldr-localenames-full/main/es/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/languages.json").as_slice()), ("cldr-localenames-full/main/es-AR/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/languages.json").as_slice()), ("cldr-localenames-full/main/fr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/languages.json").as_slice()), ("cldr-localenames-full/main/fil/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/languages.json").as_slice()), ("cldr-localenames-full/main/ja/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/languages.json").as_slice()), ("cldr-localenames-full/main/ru/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/languages.json").as_slice()), ("cldr-localenames-full/main/sr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/languages.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/languages.json").as_slice()), ("cldr-localenames-full/main/th/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/languages.json").as_slice()), ("cldr-localenames-full/main/tr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/languages.json").as_slice()), ("cldr-localenames-full/main/ar/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/scripts.json").as_slice()), ("cldr-localenames-full/main/ar-EG/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/scripts.json").as_slice()), ("cldr-localenames-full/main/bn/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/scripts.json").as_slice()), ("cldr-localenames-full/main/ccp/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/scripts.json").as_slice()), ("cldr-localenames-full/main/en/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/scripts.json").as_slice()), ("cldr-localenames-full/main/en-001/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/scripts.json").as_slice()), ("cldr-localenames-full/main/en-ZA/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/scripts.json").as_slice()), ("cldr-localenames-full/main/es/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/scripts.json").as_slice()), ("cldr-localenames-full/main/es-AR/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/scripts.json").as_slice()), ("cldr-localenames-full/main/fr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/scripts.json").as_slice()), ("cldr-localenames-full/main/fil/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/scripts.json").as_slice()), ("cldr-localenames-full/main/ja/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/scripts.json").as_slice()), ("cldr-localenames-full/main/ru/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/scripts.json").as_slice()), ("cldr-localenames-full/main/sr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/scripts.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/scripts.json").as_slice()), ("cldr-localenames-full/main/th/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/scripts.json").as_slice()), ("cldr-localenames-full/main/tr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/scripts.json").as_slice()), ("cldr-localenames-full/main/ar/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/territories.json").as_slice()), ("cldr-localenames-full/main/ar-EG/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/territories.json").as_slice()), ("cldr-localenames-full/main/bn/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/territories.json").as_slice()), ("cldr-localenames-full/main/ccp/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/territories.json").as_slice()), ("cldr-localenames-full/main/en/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/territories.json").as_slice()), ("cldr-localenames-full/main/en-001/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/territories.json").as_slice()), ("cldr-localenames-full/main/en-ZA/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/territories.json").as_slice()), ("cldr-localenames-full/main/es/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/territories.json").as_slice()), ("cldr-localenames-full/main/es-AR/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/territories.json").as_slice()), ("cldr-localenames-full/main/fr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/territories.json").as_slice()), ("cldr-localenames-full/main/fil/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/territories.json").as_slice()), ("cldr-localenames-full/main/ja/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/territories.json").as_slice()), ("cldr-localenames-full/main/ru/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/territories.json").as_slice()), ("cldr-localenames-full/main/sr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/territories.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/territories.json").as_slice()), ("cldr-localenames-full/main/th/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/territories.json").as_slice()), ("cldr-localenames-full/main/tr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/territories.json").as_slice()), ("cldr-localenames-full/main/ar/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/variants.json").as_slice()), ("cldr-localenames-full/main/ar-EG/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/variants.json").as_slice()), ("cldr-localenames-full/main/en/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/variants.json").as_slice()), ("cldr-localenames-full/main/en-001/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/variants.json").as_slice()), ("cldr-localenames-full/main/en-ZA/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/variants.json").as_slice()), ("cldr-localenames-full/main/es/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/variants.json").as_slice()), ("cldr-localenames-full/main/es-AR/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/variants.json").as_slice()), ("cldr-localenames-full/main/fr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/variants.json").as_slice()), ("cldr-localenames-full/main/fil/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/variants.json").as_slice()), ("cldr-localenames-full/main/ja/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/variants.json").as_slice()), ("cldr-localenames-full/main/ru/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/variants.json").as_slice()), ("cldr-localenames-full/main/sr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/variants.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/variants.json").as_slice()), ("cldr-localenames-full/main/th/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/variants.json").as_slice()), ("cldr-localenames-full/main/tr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/variants.json").as_slice()), ("cldr-misc-full/main/ar/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar/characters.json").as_slice()), ("cldr-misc-full/main/ar-EG/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar-EG/characters.json").as_slice()), ("cldr-misc-full/main/bn/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/bn/characters.json").as_slice()), ("cldr-misc-full/main/ccp/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ccp/characters.json").as_slice()), ("cldr-misc-full/main/en/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en/characters.json").as_slice()), ("cldr-misc-full/main/en-001/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-001/characters.json").as_slice()), ("cldr-misc-full/main/en-ZA/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-ZA/characters.json").as_slice()), ("cldr-misc-full/main/es/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es/characters.json").as_slice()), ("cldr-misc-full/main/es-AR/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es-AR/characters.json").as_slice()), ("cldr-misc-full/main/fr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fr/characters.json").as_slice()), ("cldr-misc-full/main/fil/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fil/characters.json").as_slice()), ("cldr-misc-full/main/ja/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ja/characters.json").as_slice()), ("cldr-misc-full/main/ru/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ru/characters.json").as_slice()), ("cldr-misc-full/main/sr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr/characters.json").as_slice()), ("cldr-misc-full/main/sr-Latn/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr-Latn/characters.json").as_slice()), ("cldr-misc-full/main/th/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/th/characters.json").as_slice()), ("cldr-misc-full/main/tr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/tr/characters.json").as_slice()), ("cldr-misc-full/main/und/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/und/characters.json").as_slice()), ("cldr-misc-full/main/ar/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar/listPatterns.json").as_slice()), ("cldr-misc-full/main/ar-EG/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar-EG/listPatterns.json").as_slice()), ("cldr-misc-full/main/bn/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/bn/listPatterns.json").as_slice()), ("cldr-misc-full/main/ccp/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ccp/listPatterns.json").as_slice()), ("cldr-misc-full/main/en/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en/listPatterns.json").as_slice()), ("cldr-misc-full/main/en-001/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-001/listPatterns.json").as_slice()), ("cldr-misc-full/main/en-ZA/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-ZA/listPatterns.json").as_slice()), ("cldr-misc-full/main/es/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es/listPatterns.json").as_slice()), ("cldr-misc-full/main/es-AR/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es-AR/listPatterns.json").as_slice()), ("cldr-misc-full/main/fr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fr/listPatterns.json").as_slice()), ("cldr-misc-full/main/fil/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fil/listPatterns.json").as_slice()), ("cldr-misc-full/main/ja/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ja/listPatterns.json").as_slice()), ("cldr-misc-full/main/ru/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ru/listPatterns.json").as_slice()), ("cldr-misc-full/main/sr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr/listPatterns.json").as_slice()), ("cldr-misc-full/main/sr-Latn/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr-Latn/listPatterns.json").as_slice()), ("cldr-misc-full/main/th/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/th/listPatterns.json").as_slice()), ("cldr-misc-full/main/tr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/tr/listPatterns.json").as_slice()), ("cldr-misc-full/main/und/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/und/listPatterns.json").as_slice()), ("cldr-numbers-full/main/ar/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar/currencies.json").as_slice()), ("cldr-numbers-full/main/ar-EG/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar-EG/currencies.json").as_slice()), ("cldr-numbers-full/main/bn/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/bn/currencies.json").as_slice()), ("cldr-numbers-full/main/ccp/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ccp/currencies.json").as_slice()), ("cldr-numbers-full/main/en/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en/currencies.json").as_slice()), ("cldr-numbers-full/main/en-001/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-001/currencies.json").as_slice()), ("cldr-numbers-full/main/en-ZA/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-ZA/currencies.json").as_slice()), ("cldr-numbers-full/main/es/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es/currencies.json").as_slice()), ("cldr-numbers-full/main/es-AR/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es-AR/currencies.json").as_slice()), ("cldr-numbers-full/main/fr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fr/currencies.json").as_slice()), ("cldr-numbers-full/main/fil/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fil/currencies.json").as_slice()), ("cldr-numbers-full/main/ja/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ja/currencies.json").as_slice()), ("cldr-numbers-full/main/ru/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ru/currencies.json").as_slice()), ("cldr-numbers-full/main/sr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr/currencies.json").as_slice()), ("cldr-numbers-full/main/sr-Latn/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr-Latn/currencies.json").as_slice()), ("cldr-numbers-full/main/th/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/th/currencies.json").as_slice()), ("cldr-numbers-full/main/tr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/tr/currencies.json").as_slice()), ("cldr-numbers-full/main/und/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/und/currencies.json").as_slice()), ("cldr-numbers-full/main/ar/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar/numbers.json").as_slice()), ("cldr-numbers-full/main/ar-EG/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar-EG/numbers.json").as_slice()), ("cldr-numbers-full/main/bn/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/bn/numbers.json").as_slice()), ("cldr-numbers-full/main/ccp/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ccp/numbers.json").as_slice()), ("cldr-numbers-full/main/en/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en/numbers.json").as_slice()), ("cldr-numbers-full/main/en-001/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-001/numbers.json").as_slice()), ("cldr-numbers-full/main/en-ZA/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-ZA/numbers.json").as_slice()), ("cldr-numbers-full/main/es/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es/numbers.json").as_slice()), ("cldr-numbers-full/main/es-AR/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es-AR/numbers.json").as_slice()), ("cldr-numbers-full/main/fr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fr/numbers.json").as_slice()), ("cldr-numbers-full/main/fil/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fil/numbers.json").as_slice()), ("cldr-numbers-full/main/ja/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ja/numbers.json").as_slice()), ("cldr-numbers-full/main/ru/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ru/numbers.json").as_slice()), ("cldr-numbers-full/main/sr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr/numbers.json").as_slice()), ("cldr-numbers-full/main/sr-Latn/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr-Latn/numbers.json").as_slice()), ("cldr-numbers-full/main/th/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/th/numbers.json").as_slice()), ("cldr-numbers-full/main/tr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/tr/numbers.json").as_slice()), ("cldr-numbers-full/main/und/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/und/numbers.json").as_slice()), ("cldr-person-names-full/main/ar/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ar/personNames.json").as_slice()), ("cldr-person-names-full/main/ar-EG/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ar-EG/personNames.json").as_slice()), ("cldr-person-names-full/main/bn/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/bn/personNames.json").as_slice()), ("cldr-person-names-full/main/ccp/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ccp/personNames.json").as_slice()), ("cldr-person-names-full/main/en/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en/personNames.json").as_slice()), ("cldr-person-names-full/main/en-001/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en-001/personNames.json").as_slice()), ("cldr-person-names-full/main/en-ZA/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en-ZA/personNames.json").as_slice()), ("cldr-person-names-full/main/es/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/es/personNames.json").as_slice()), ("cldr-person-names-full/main/es-AR/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/es-AR/personNames.json").as_slice()), ("cldr-person-names-full/main/fr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/fr/personNames.json").as_slice()), ("cldr-person-names-full/main/fil/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/fil/personNames.json").as_slice()), ("cldr-person-names-full/main/ja/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ja/personNames.json").as_slice()), ("cldr-person-names-full/main/ru/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ru/personNames.json").as_slice()), ("cldr-person-names-full/main/sr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/sr/personNames.json").as_slice()), ("cldr-person-names-full/main/sr-Latn/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/sr-Latn/personNames.json").as_slice()), ("cldr-person-names-full/main/th/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/th/personNames.json").as_slice()), ("cldr-person-names-full/main/tr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/tr/personNames.json").as_slice()), ("cldr-person-names-full/main/und/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/und/personNames.json").as_slice()), ("cldr-transforms-full/main/Latin-ASCII/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Latin-ASCII/metadata.json").as_slice()), ("cldr-transforms-full/main/Latin-ASCII/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Latin-ASCII/source.txt").as_slice()), ("cldr-transforms-full/main/el-el_Latn-BGN/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/el-el_Latn-BGN/metadata.json").as_slice()), ("cldr-transforms-full/main/el-el_Latn-BGN/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/el-el_Latn-BGN/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-HexRustWrapper/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexRustWrapper/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-HexRustWrapper/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexRustWrapper/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-EmptyMatches/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-EmptyMatches/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-EmptyMatches/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-EmptyMatches/source.txt").as_slice()), ("cldr-transforms-full/main/Any-Publishing/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/metadata.json").as_slice()), ("cldr-transforms-full/main/Any-Publishing/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/source.txt").as_slice()), ("cldr-transforms-full/main/Bengali-InterIndic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-InterIndic/metadata.json").as_slice()), ("cldr-transforms-full/main/Bengali-InterIndic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-InterIndic/source.txt").as_slice()), ("cldr-transforms-full/main/de-ASCII/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/de-ASCII/metadata.json").as_slice()), ("cldr-transforms-full/main/de-ASCII/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/de-ASCII/source.txt").as_slice()), ("cldr-transforms-full/main/InterIndic-Arabic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/InterIndic-Arabic/metadata.json").as_slice()), ("cldr-transforms-full/main/InterIndic-Arabic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/InterIndic-Arabic/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteA/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteA/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteA/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteA/source.txt").as_slice()), ("cldr-transforms-full/main/Bengali-Arabic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-Arabic/metadata.json").as_slice()), ("cldr-transforms-full/main/Bengali-Arabic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-Arabic/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-CursorFilters/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-CursorFilters/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-CursorFilters/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-CursorFilters/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/source.txt").as_slice())].into_iter().collect(),
+                        [("cldr-bcp47/bcp47/calendar.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/calendar.json").as_slice()), ("cldr-bcp47/bcp47/collation.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/collation.json").as_slice()), ("cldr-bcp47/bcp47/currency.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/currency.json").as_slice()), ("cldr-bcp47/bcp47/measure.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/measure.json").as_slice()), ("cldr-bcp47/bcp47/number.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/number.json").as_slice()), ("cldr-bcp47/bcp47/segmentation.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/segmentation.json").as_slice()), ("cldr-bcp47/bcp47/timezone.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/timezone.json").as_slice()), ("cldr-bcp47/bcp47/transform-destination.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform-destination.json").as_slice()), ("cldr-bcp47/bcp47/transform.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform.json").as_slice()), ("cldr-bcp47/bcp47/transform_hybrid.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform_hybrid.json").as_slice()), ("cldr-bcp47/bcp47/transform_ime.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform_ime.json").as_slice()), ("cldr-bcp47/bcp47/transform_keyboard.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform_keyboard.json").as_slice()), ("cldr-bcp47/bcp47/transform_mt.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform_mt.json").as_slice()), ("cldr-bcp47/bcp47/transform_private_use.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/transform_private_use.json").as_slice()), ("cldr-bcp47/bcp47/variant.json", include_bytes!("../../tests/data/cldr/cldr-bcp47/bcp47/variant.json").as_slice()), ("cldr-cal-buddhist-full/main/ar/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ar/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ar-EG/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ar-EG/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/bn/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/bn/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ccp/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ccp/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en-001/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en-001/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/en-ZA/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/en-ZA/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/es/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/es/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/es-AR/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/es-AR/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/fr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/fr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/fil/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/fil/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ja/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ja/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/ru/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/ru/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/sr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/sr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/sr-Latn/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/sr-Latn/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/th/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/th/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/tr/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/tr/ca-buddhist.json").as_slice()), ("cldr-cal-buddhist-full/main/und/ca-buddhist.json", include_bytes!("../../tests/data/cldr/cldr-cal-buddhist-full/main/und/ca-buddhist.json").as_slice()), ("cldr-cal-chinese-full/main/ar/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ar/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ar-EG/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ar-EG/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/bn/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/bn/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ccp/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ccp/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en-001/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en-001/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/en-ZA/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/en-ZA/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/es/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/es/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/es-AR/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/es-AR/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/fr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/fr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/fil/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/fil/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ja/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ja/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/ru/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/ru/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/sr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/sr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/sr-Latn/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/sr-Latn/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/th/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/th/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/tr/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/tr/ca-chinese.json").as_slice()), ("cldr-cal-chinese-full/main/und/ca-chinese.json", include_bytes!("../../tests/data/cldr/cldr-cal-chinese-full/main/und/ca-chinese.json").as_slice()), ("cldr-cal-coptic-full/main/ar/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ar/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ar-EG/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ar-EG/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/bn/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/bn/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ccp/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ccp/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en-001/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en-001/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/en-ZA/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/en-ZA/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/es/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/es/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/es-AR/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/es-AR/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/fr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/fr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/fil/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/fil/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ja/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ja/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/ru/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/ru/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/sr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/sr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/sr-Latn/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/sr-Latn/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/th/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/th/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/tr/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/tr/ca-coptic.json").as_slice()), ("cldr-cal-coptic-full/main/und/ca-coptic.json", include_bytes!("../../tests/data/cldr/cldr-cal-coptic-full/main/und/ca-coptic.json").as_slice()), ("cldr-cal-dangi-full/main/ar/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ar/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ar-EG/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ar-EG/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/bn/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/bn/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ccp/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ccp/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en-001/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en-001/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/en-ZA/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/en-ZA/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/es/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/es/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/es-AR/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/es-AR/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/fr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/fr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/fil/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/fil/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ja/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ja/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/ru/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/ru/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/sr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/sr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/sr-Latn/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/sr-Latn/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/th/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/th/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/tr/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/tr/ca-dangi.json").as_slice()), ("cldr-cal-dangi-full/main/und/ca-dangi.json", include_bytes!("../../tests/data/cldr/cldr-cal-dangi-full/main/und/ca-dangi.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/bn/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/bn/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ccp/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ccp/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-001/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-001/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/es/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/fr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/fil/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fil/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ja/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ja/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ru/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ru/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/th/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/th/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/tr/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/tr/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/und/ca-ethiopic-amete-alem.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/und/ca-ethiopic-amete-alem.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ar-EG/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/bn/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/bn/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ccp/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ccp/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-001/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-001/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/en-ZA/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/es/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/es-AR/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/fr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/fil/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/fil/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ja/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ja/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/ru/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/ru/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/sr-Latn/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/th/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/th/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/tr/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/tr/ca-ethiopic.json").as_slice()), ("cldr-cal-ethiopic-full/main/und/ca-ethiopic.json", include_bytes!("../../tests/data/cldr/cldr-cal-ethiopic-full/main/und/ca-ethiopic.json").as_slice()), ("cldr-cal-indian-full/main/ar/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ar/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ar-EG/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ar-EG/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/bn/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/bn/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ccp/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ccp/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en-001/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en-001/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/en-ZA/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/en-ZA/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/es/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/es/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/es-AR/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/es-AR/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/fr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/fr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/fil/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/fil/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ja/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ja/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/ru/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/ru/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/sr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/sr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/sr-Latn/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/sr-Latn/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/th/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/th/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/tr/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/tr/ca-indian.json").as_slice()), ("cldr-cal-indian-full/main/und/ca-indian.json", include_bytes!("../../tests/data/cldr/cldr-cal-indian-full/main/und/ca-indian.json").as_slice()), ("cldr-cal-japanese-full/main/ar/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ar/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ar-EG/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ar-EG/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/bn/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/bn/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ccp/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ccp/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en-001/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en-001/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/en-ZA/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/en-ZA/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/es/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/es/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/es-AR/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/es-AR/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/fr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/fr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/fil/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/fil/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ja/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ja/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/ru/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/ru/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/sr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/sr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/sr-Latn/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/sr-Latn/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/th/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/th/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/tr/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/tr/ca-japanese.json").as_slice()), ("cldr-cal-japanese-full/main/und/ca-japanese.json", include_bytes!("../../tests/data/cldr/cldr-cal-japanese-full/main/und/ca-japanese.json").as_slice()), ("cldr-cal-persian-full/main/ar/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ar/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ar-EG/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ar-EG/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/bn/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/bn/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ccp/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ccp/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en-001/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en-001/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/en-ZA/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/en-ZA/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/es/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/es/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/es-AR/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/es-AR/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/fr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/fr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/fil/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/fil/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ja/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ja/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/ru/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/ru/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/sr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/sr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/sr-Latn/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/sr-Latn/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/th/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/th/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/tr/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/tr/ca-persian.json").as_slice()), ("cldr-cal-persian-full/main/und/ca-persian.json", include_bytes!("../../tests/data/cldr/cldr-cal-persian-full/main/und/ca-persian.json").as_slice()), ("cldr-cal-hebrew-full/main/ar/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ar/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ar-EG/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ar-EG/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/bn/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/bn/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ccp/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ccp/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en-001/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en-001/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/en-ZA/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/en-ZA/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/es/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/es/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/es-AR/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/es-AR/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/fr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/fr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/fil/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/fil/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ja/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ja/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/ru/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/ru/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/sr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/sr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/sr-Latn/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/sr-Latn/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/th/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/th/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/tr/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/tr/ca-hebrew.json").as_slice()), ("cldr-cal-hebrew-full/main/und/ca-hebrew.json", include_bytes!("../../tests/data/cldr/cldr-cal-hebrew-full/main/und/ca-hebrew.json").as_slice()), ("cldr-cal-islamic-full/main/ar/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ar/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ar-EG/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ar-EG/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/bn/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/bn/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ccp/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ccp/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en-001/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en-001/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/en-ZA/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/en-ZA/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/es/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/es/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/es-AR/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/es-AR/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/fr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/fr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/fil/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/fil/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ja/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ja/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/ru/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/ru/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/sr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/sr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/sr-Latn/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/sr-Latn/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/th/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/th/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/tr/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/tr/ca-islamic.json").as_slice()), ("cldr-cal-islamic-full/main/und/ca-islamic.json", include_bytes!("../../tests/data/cldr/cldr-cal-islamic-full/main/und/ca-islamic.json").as_slice()), ("cldr-cal-roc-full/main/ar/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ar/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ar-EG/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ar-EG/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/bn/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/bn/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ccp/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ccp/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en-001/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en-001/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/en-ZA/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/en-ZA/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/es/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/es/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/es-AR/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/es-AR/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/fr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/fr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/fil/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/fil/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ja/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ja/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/ru/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/ru/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/sr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/sr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/sr-Latn/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/sr-Latn/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/th/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/th/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/tr/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/tr/ca-roc.json").as_slice()), ("cldr-cal-roc-full/main/und/ca-roc.json", include_bytes!("../../tests/data/cldr/cldr-cal-roc-full/main/und/ca-roc.json").as_slice()), ("cldr-core/coverageLevels.json", include_bytes!("../../tests/data/cldr/cldr-core/coverageLevels.json").as_slice()), ("cldr-core/scriptMetadata.json", include_bytes!("../../tests/data/cldr/cldr-core/scriptMetadata.json").as_slice()), ("cldr-core/supplemental/aliases.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/aliases.json").as_slice()), ("cldr-core/supplemental/calendarData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()), ("cldr-core/supplemental/currencyData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()), ("cldr-core/supplemental/grammaticalFeatures.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/grammaticalFeatures.json").as_slice()), ("cldr-core/supplemental/units.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()), ("cldr-core/supplemental/languageMatching.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/languageMatching.json").as_slice()), ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()), ("cldr-core/supplemental/metaZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()), ("cldr-core/supplemental/numberingSystems.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/numberingSystems.json").as_slice()), ("cldr-core/supplemental/ordinals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/ordinals.json").as_slice()), ("cldr-core/supplemental/parentLocales.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/parentLocales.json").as_slice()), ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()), ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()), ("cldr-core/supplemental/primaryZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/primaryZones.json").as_slice()), ("cldr-core/supplemental/territoryContainment.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/territoryContainment.json").as_slice()), ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()), ("cldr-core/supplemental/windowsZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/windowsZones.json").as_slice()), ("cldr-core/validity/language.json", include_bytes!("../../tests/data/cldr/cldr-core/validity/language.json").as_slice()), ("cldr-core/validity/region.json", include_bytes!("../../tests/data/cldr/cldr-core/validity/region.json").as_slice()), ("cldr-core/validity/script.json", include_bytes!("../../tests/data/cldr/cldr-core/validity/script.json").as_slice()), ("cldr-core/validity/variant.json", include_bytes!("../../tests/data/cldr/cldr-core/validity/variant.json").as_slice()), ("cldr-dates-full/main/ar/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ar-EG/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/bn/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ccp/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en-001/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/en-ZA/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/es/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/es-AR/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/fr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/fil/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ja/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ru/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/sr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/sr-Latn/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/th/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/tr/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/und/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/ar/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/dateFields.json").as_slice()), ("cldr-dates-full/main/ar-EG/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/dateFields.json").as_slice()), ("cldr-dates-full/main/bn/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/dateFields.json").as_slice()), ("cldr-dates-full/main/ccp/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/dateFields.json").as_slice()), ("cldr-dates-full/main/en/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/dateFields.json").as_slice()), ("cldr-dates-full/main/en-001/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/dateFields.json").as_slice()), ("cldr-dates-full/main/en-ZA/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/dateFields.json").as_slice()), ("cldr-dates-full/main/es/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/dateFields.json").as_slice()), ("cldr-dates-full/main/es-AR/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/dateFields.json").as_slice()), ("cldr-dates-full/main/fr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/dateFields.json").as_slice()), ("cldr-dates-full/main/fil/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/dateFields.json").as_slice()), ("cldr-dates-full/main/ja/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/dateFields.json").as_slice()), ("cldr-dates-full/main/ru/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/dateFields.json").as_slice()), ("cldr-dates-full/main/sr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/dateFields.json").as_slice()), ("cldr-dates-full/main/sr-Latn/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/dateFields.json").as_slice()), ("cldr-dates-full/main/th/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/dateFields.json").as_slice()), ("cldr-dates-full/main/tr/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/dateFields.json").as_slice()), ("cldr-dates-full/main/und/dateFields.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/dateFields.json").as_slice()), ("cldr-dates-full/main/ar/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ar-EG/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/bn/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ccp/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ccp/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en-001/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-001/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/en-ZA/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/en-ZA/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/es/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/es-AR/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/es-AR/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/fr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/fil/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/fil/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ja/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ja/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/ru/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ru/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/sr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/sr-Latn/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/sr-Latn/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/th/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/th/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/tr/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/tr/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/und/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/und/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/cs/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/cs/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/cs/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/cs/timeZoneNames.json").as_slice()), ("cldr-dates-full/main/haw/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/haw/ca-gregorian.json").as_slice()), ("cldr-dates-full/main/haw/timeZoneNames.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/haw/timeZoneNames.json").as_slice()), ("cldr-localenames-full/main/ar/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/languages.json").as_slice()), ("cldr-localenames-full/main/ar-EG/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/languages.json").as_slice()), ("cldr-localenames-full/main/bn/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/languages.json").as_slice()), ("cldr-localenames-full/main/ccp/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/languages.json").as_slice()), ("cldr-localenames-full/main/en/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/languages.json").as_slice()), ("cldr-localenames-full/main/en-001/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/languages.json").as_slice()), ("cldr-localenames-full/main/en-ZA/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/languages.json").as_slice()), ("cldr-localenames-full/main/es/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/languages.json").as_slice()), ("cldr-localenames-full/main/es-AR/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/languages.json").as_slice()), ("cldr-localenames-full/main/fr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/languages.json").as_slice()), ("cldr-localenames-full/main/fil/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/languages.json").as_slice()), ("cldr-localenames-full/main/ja/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/languages.json").as_slice()), ("cldr-localenames-full/main/ru/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/languages.json").as_slice()), ("cldr-localenames-full/main/sr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/languages.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/languages.json").as_slice()), ("cldr-localenames-full/main/th/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/languages.json").as_slice()), ("cldr-localenames-full/main/tr/languages.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/languages.json").as_slice()), ("cldr-localenames-full/main/en/localeDisplayNames.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/localeDisplayNames.json").as_slice()), ("cldr-localenames-full/main/und/localeDisplayNames.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/und/localeDisplayNames.json").as_slice()), ("cldr-localenames-full/main/ar/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/scripts.json").as_slice()), ("cldr-localenames-full/main/ar-EG/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/scripts.json").as_slice()), ("cldr-localenames-full/main/bn/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/scripts.json").as_slice()), ("cldr-localenames-full/main/ccp/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/scripts.json").as_slice()), ("cldr-localenames-full/main/en/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/scripts.json").as_slice()), ("cldr-localenames-full/main/en-001/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/scripts.json").as_slice()), ("cldr-localenames-full/main/en-ZA/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/scripts.json").as_slice()), ("cldr-localenames-full/main/es/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/scripts.json").as_slice()), ("cldr-localenames-full/main/es-AR/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/scripts.json").as_slice()), ("cldr-localenames-full/main/fr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/scripts.json").as_slice()), ("cldr-localenames-full/main/fil/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/scripts.json").as_slice()), ("cldr-localenames-full/main/ja/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/scripts.json").as_slice()), ("cldr-localenames-full/main/ru/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/scripts.json").as_slice()), ("cldr-localenames-full/main/sr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/scripts.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/scripts.json").as_slice()), ("cldr-localenames-full/main/th/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/scripts.json").as_slice()), ("cldr-localenames-full/main/tr/scripts.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/scripts.json").as_slice()), ("cldr-localenames-full/main/ar/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/territories.json").as_slice()), ("cldr-localenames-full/main/ar-EG/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/territories.json").as_slice()), ("cldr-localenames-full/main/bn/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/bn/territories.json").as_slice()), ("cldr-localenames-full/main/ccp/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ccp/territories.json").as_slice()), ("cldr-localenames-full/main/en/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/territories.json").as_slice()), ("cldr-localenames-full/main/en-001/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/territories.json").as_slice()), ("cldr-localenames-full/main/en-ZA/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/territories.json").as_slice()), ("cldr-localenames-full/main/es/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/territories.json").as_slice()), ("cldr-localenames-full/main/es-AR/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/territories.json").as_slice()), ("cldr-localenames-full/main/fr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/territories.json").as_slice()), ("cldr-localenames-full/main/fil/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/territories.json").as_slice()), ("cldr-localenames-full/main/ja/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/territories.json").as_slice()), ("cldr-localenames-full/main/ru/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/territories.json").as_slice()), ("cldr-localenames-full/main/sr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/territories.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/territories.json").as_slice()), ("cldr-localenames-full/main/th/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/territories.json").as_slice()), ("cldr-localenames-full/main/tr/territories.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/territories.json").as_slice()), ("cldr-localenames-full/main/ar/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar/variants.json").as_slice()), ("cldr-localenames-full/main/ar-EG/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ar-EG/variants.json").as_slice()), ("cldr-localenames-full/main/en/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en/variants.json").as_slice()), ("cldr-localenames-full/main/en-001/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-001/variants.json").as_slice()), ("cldr-localenames-full/main/en-ZA/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/en-ZA/variants.json").as_slice()), ("cldr-localenames-full/main/es/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es/variants.json").as_slice()), ("cldr-localenames-full/main/es-AR/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/es-AR/variants.json").as_slice()), ("cldr-localenames-full/main/fr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fr/variants.json").as_slice()), ("cldr-localenames-full/main/fil/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/fil/variants.json").as_slice()), ("cldr-localenames-full/main/ja/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ja/variants.json").as_slice()), ("cldr-localenames-full/main/ru/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/ru/variants.json").as_slice()), ("cldr-localenames-full/main/sr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr/variants.json").as_slice()), ("cldr-localenames-full/main/sr-Latn/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/sr-Latn/variants.json").as_slice()), ("cldr-localenames-full/main/th/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/th/variants.json").as_slice()), ("cldr-localenames-full/main/tr/variants.json", include_bytes!("../../tests/data/cldr/cldr-localenames-full/main/tr/variants.json").as_slice()), ("cldr-misc-full/main/ar/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar/characters.json").as_slice()), ("cldr-misc-full/main/ar-EG/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar-EG/characters.json").as_slice()), ("cldr-misc-full/main/bn/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/bn/characters.json").as_slice()), ("cldr-misc-full/main/ccp/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ccp/characters.json").as_slice()), ("cldr-misc-full/main/en/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en/characters.json").as_slice()), ("cldr-misc-full/main/en-001/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-001/characters.json").as_slice()), ("cldr-misc-full/main/en-ZA/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-ZA/characters.json").as_slice()), ("cldr-misc-full/main/es/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es/characters.json").as_slice()), ("cldr-misc-full/main/es-AR/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es-AR/characters.json").as_slice()), ("cldr-misc-full/main/fr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fr/characters.json").as_slice()), ("cldr-misc-full/main/fil/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fil/characters.json").as_slice()), ("cldr-misc-full/main/ja/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ja/characters.json").as_slice()), ("cldr-misc-full/main/ru/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ru/characters.json").as_slice()), ("cldr-misc-full/main/sr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr/characters.json").as_slice()), ("cldr-misc-full/main/sr-Latn/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr-Latn/characters.json").as_slice()), ("cldr-misc-full/main/th/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/th/characters.json").as_slice()), ("cldr-misc-full/main/tr/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/tr/characters.json").as_slice()), ("cldr-misc-full/main/und/characters.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/und/characters.json").as_slice()), ("cldr-misc-full/main/ar/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar/listPatterns.json").as_slice()), ("cldr-misc-full/main/ar-EG/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ar-EG/listPatterns.json").as_slice()), ("cldr-misc-full/main/bn/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/bn/listPatterns.json").as_slice()), ("cldr-misc-full/main/ccp/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ccp/listPatterns.json").as_slice()), ("cldr-misc-full/main/en/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en/listPatterns.json").as_slice()), ("cldr-misc-full/main/en-001/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-001/listPatterns.json").as_slice()), ("cldr-misc-full/main/en-ZA/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/en-ZA/listPatterns.json").as_slice()), ("cldr-misc-full/main/es/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es/listPatterns.json").as_slice()), ("cldr-misc-full/main/es-AR/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/es-AR/listPatterns.json").as_slice()), ("cldr-misc-full/main/fr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fr/listPatterns.json").as_slice()), ("cldr-misc-full/main/fil/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/fil/listPatterns.json").as_slice()), ("cldr-misc-full/main/ja/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ja/listPatterns.json").as_slice()), ("cldr-misc-full/main/ru/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/ru/listPatterns.json").as_slice()), ("cldr-misc-full/main/sr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr/listPatterns.json").as_slice()), ("cldr-misc-full/main/sr-Latn/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/sr-Latn/listPatterns.json").as_slice()), ("cldr-misc-full/main/th/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/th/listPatterns.json").as_slice()), ("cldr-misc-full/main/tr/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/tr/listPatterns.json").as_slice()), ("cldr-misc-full/main/und/listPatterns.json", include_bytes!("../../tests/data/cldr/cldr-misc-full/main/und/listPatterns.json").as_slice()), ("cldr-numbers-full/main/ar/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar/currencies.json").as_slice()), ("cldr-numbers-full/main/ar-EG/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar-EG/currencies.json").as_slice()), ("cldr-numbers-full/main/bn/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/bn/currencies.json").as_slice()), ("cldr-numbers-full/main/ccp/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ccp/currencies.json").as_slice()), ("cldr-numbers-full/main/en/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en/currencies.json").as_slice()), ("cldr-numbers-full/main/en-001/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-001/currencies.json").as_slice()), ("cldr-numbers-full/main/en-ZA/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-ZA/currencies.json").as_slice()), ("cldr-numbers-full/main/es/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es/currencies.json").as_slice()), ("cldr-numbers-full/main/es-AR/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es-AR/currencies.json").as_slice()), ("cldr-numbers-full/main/fr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fr/currencies.json").as_slice()), ("cldr-numbers-full/main/fil/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fil/currencies.json").as_slice()), ("cldr-numbers-full/main/ja/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ja/currencies.json").as_slice()), ("cldr-numbers-full/main/ru/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ru/currencies.json").as_slice()), ("cldr-numbers-full/main/sr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr/currencies.json").as_slice()), ("cldr-numbers-full/main/sr-Latn/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr-Latn/currencies.json").as_slice()), ("cldr-numbers-full/main/th/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/th/currencies.json").as_slice()), ("cldr-numbers-full/main/tr/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/tr/currencies.json").as_slice()), ("cldr-numbers-full/main/und/currencies.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/und/currencies.json").as_slice()), ("cldr-numbers-full/main/ar/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar/numbers.json").as_slice()), ("cldr-numbers-full/main/ar-EG/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ar-EG/numbers.json").as_slice()), ("cldr-numbers-full/main/bn/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/bn/numbers.json").as_slice()), ("cldr-numbers-full/main/ccp/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ccp/numbers.json").as_slice()), ("cldr-numbers-full/main/en/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en/numbers.json").as_slice()), ("cldr-numbers-full/main/en-001/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-001/numbers.json").as_slice()), ("cldr-numbers-full/main/en-ZA/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/en-ZA/numbers.json").as_slice()), ("cldr-numbers-full/main/es/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es/numbers.json").as_slice()), ("cldr-numbers-full/main/es-AR/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/es-AR/numbers.json").as_slice()), ("cldr-numbers-full/main/fr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fr/numbers.json").as_slice()), ("cldr-numbers-full/main/fil/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/fil/numbers.json").as_slice()), ("cldr-numbers-full/main/ja/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ja/numbers.json").as_slice()), ("cldr-numbers-full/main/ru/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/ru/numbers.json").as_slice()), ("cldr-numbers-full/main/sr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr/numbers.json").as_slice()), ("cldr-numbers-full/main/sr-Latn/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/sr-Latn/numbers.json").as_slice()), ("cldr-numbers-full/main/th/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/th/numbers.json").as_slice()), ("cldr-numbers-full/main/tr/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/tr/numbers.json").as_slice()), ("cldr-numbers-full/main/und/numbers.json", include_bytes!("../../tests/data/cldr/cldr-numbers-full/main/und/numbers.json").as_slice()), ("cldr-person-names-full/main/ar/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ar/personNames.json").as_slice()), ("cldr-person-names-full/main/ar-EG/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ar-EG/personNames.json").as_slice()), ("cldr-person-names-full/main/bn/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/bn/personNames.json").as_slice()), ("cldr-person-names-full/main/ccp/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ccp/personNames.json").as_slice()), ("cldr-person-names-full/main/en/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en/personNames.json").as_slice()), ("cldr-person-names-full/main/en-001/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en-001/personNames.json").as_slice()), ("cldr-person-names-full/main/en-ZA/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/en-ZA/personNames.json").as_slice()), ("cldr-person-names-full/main/es/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/es/personNames.json").as_slice()), ("cldr-person-names-full/main/es-AR/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/es-AR/personNames.json").as_slice()), ("cldr-person-names-full/main/fr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/fr/personNames.json").as_slice()), ("cldr-person-names-full/main/fil/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/fil/personNames.json").as_slice()), ("cldr-person-names-full/main/ja/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ja/personNames.json").as_slice()), ("cldr-person-names-full/main/ru/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/ru/personNames.json").as_slice()), ("cldr-person-names-full/main/sr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/sr/personNames.json").as_slice()), ("cldr-person-names-full/main/sr-Latn/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/sr-Latn/personNames.json").as_slice()), ("cldr-person-names-full/main/th/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/th/personNames.json").as_slice()), ("cldr-person-names-full/main/tr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/tr/personNames.json").as_slice()), ("cldr-person-names-full/main/und/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/und/personNames.json").as_slice()), ("cldr-segments-full/segments/en/suppressions.json", include_bytes!("../../tests/data/cldr/cldr-segments-full/segments/en/suppressions.json").as_slice()), ("cldr-segments-full/segments/es/suppressions.json", include_bytes!("../../tests/data/cldr/cldr-segments-full/segments/es/suppressions.json").as_slice()), ("cldr-segments-full/segments/fr/suppressions.json", include_bytes!("../../tests/data/cldr/cldr-segments-full/segments/fr/suppressions.json").as_slice()), ("cldr-segments-full/segments/ru/suppressions.json", include_bytes!("../../tests/data/cldr/cldr-segments-full/segments/ru/suppressions.json").as_slice()), ("cldr-transforms-full/main/Latin-ASCII/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Latin-ASCII/metadata.json").as_slice()), ("cldr-transforms-full/main/Latin-ASCII/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Latin-ASCII/source.txt").as_slice()), ("cldr-transforms-full/main/el-el_Latn-BGN/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/el-el_Latn-BGN/metadata.json").as_slice()), ("cldr-transforms-full/main/el-el_Latn-BGN/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/el-el_Latn-BGN/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteRoot/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-HexRustWrapper/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexRustWrapper/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-HexRustWrapper/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexRustWrapper/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-EmptyMatches/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-EmptyMatches/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-EmptyMatches/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-EmptyMatches/source.txt").as_slice()), ("cldr-transforms-full/main/Any-Publishing/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/metadata.json").as_slice()), ("cldr-transforms-full/main/Any-Publishing/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-HexUnicodeWrapper/source.txt").as_slice()), ("cldr-transforms-full/main/Bengali-InterIndic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-InterIndic/metadata.json").as_slice()), ("cldr-transforms-full/main/Bengali-InterIndic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-InterIndic/source.txt").as_slice()), ("cldr-transforms-full/main/de-ASCII/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/de-ASCII/metadata.json").as_slice()), ("cldr-transforms-full/main/de-ASCII/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/de-ASCII/source.txt").as_slice()), ("cldr-transforms-full/main/InterIndic-Arabic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/InterIndic-Arabic/metadata.json").as_slice()), ("cldr-transforms-full/main/InterIndic-Arabic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/InterIndic-Arabic/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteA/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteA/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-RecursiveSuiteA/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-RecursiveSuiteA/source.txt").as_slice()), ("cldr-transforms-full/main/Bengali-Arabic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-Arabic/metadata.json").as_slice()), ("cldr-transforms-full/main/Bengali-Arabic/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-Arabic/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-CursorFilters/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-CursorFilters/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-CursorFilters/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-CursorFilters/source.txt").as_slice()), ("cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/metadata.json").as_slice()), ("cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Test-Test-NielsFunctionalityTest/source.txt").as_slice()), ("cldr-units-full/main/de/units.json", include_bytes!("../../tests/data/cldr/cldr-units-full/main/de/units.json").as_slice()), ("cldr-units-full/main/en/units.json", include_bytes!("../../tests/data/cldr/cldr-units-full/main/en/units.json").as_slice()), ("cldr-units-full/main/fr/units.json", include_bytes!("../../tests/data/cldr/cldr-units-full/main/fr/units.json").as_slice()), ("cldr-units-full/main/ru/units.json", include_bytes!("../../tests/data/cldr/cldr-units-full/main/ru/units.json").as_slice()), ("cldr-units-full/main/und/units.json", include_bytes!("../../tests/data/cldr/cldr-units-full/main/und/units.json").as_slice())].into_iter().collect(),
                     ))))),
                     icuexport_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
                         [("collation/implicithan/ar_compat_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_compat_data.toml").as_slice()), ("collation/implicithan/ar_compat_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_compat_meta.toml").as_slice()), ("collation/implicithan/ar_compat_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_compat_reord.toml").as_slice()), ("collation/implicithan/ar_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_standard_data.toml").as_slice()), ("collation/implicithan/bn_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_standard_data.toml").as_slice()), ("collation/implicithan/es_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/es_standard_data.toml").as_slice()), ("collation/implicithan/fil_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/fil_standard_data.toml").as_slice()), ("collation/implicithan/ja_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_standard_data.toml").as_slice()), ("collation/implicithan/sr_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_standard_data.toml").as_slice()), ("collation/implicithan/sr_Latn_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_Latn_standard_data.toml").as_slice()), ("collation/implicithan/th_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/th_standard_data.toml").as_slice()), ("collation/implicithan/tr_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/tr_standard_data.toml").as_slice()), ("collation/implicithan/root_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_standard_data.toml").as_slice()), ("collation/implicithan/root_standard_dia.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_standard_dia.toml").as_slice()), ("collation/implicithan/root_standard_jamo.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_standard_jamo.toml").as_slice()), ("collation/implicithan/ar_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_standard_meta.toml").as_slice()), ("collation/implicithan/bn_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_standard_meta.toml").as_slice()), ("collation/implicithan/es_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/es_standard_meta.toml").as_slice()), ("collation/implicithan/fil_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/fil_standard_meta.toml").as_slice()), ("collation/implicithan/ja_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_standard_meta.toml").as_slice()), ("collation/implicithan/ru_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ru_standard_meta.toml").as_slice()), ("collation/implicithan/sr_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_standard_meta.toml").as_slice()), ("collation/implicithan/sr_Latn_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_Latn_standard_meta.toml").as_slice()), ("collation/implicithan/th_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/th_standard_meta.toml").as_slice()), ("collation/implicithan/tr_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/tr_standard_meta.toml").as_slice()), ("collation/implicithan/root_standard_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_standard_meta.toml").as_slice()), ("collation/implicithan/root_standard_prim.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_standard_prim.toml").as_slice()), ("collation/implicithan/ar_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ar_standard_reord.toml").as_slice()), ("collation/implicithan/bn_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_standard_reord.toml").as_slice()), ("collation/implicithan/ja_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_standard_reord.toml").as_slice()), ("collation/implicithan/ru_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ru_standard_reord.toml").as_slice()), ("collation/implicithan/sr_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_standard_reord.toml").as_slice()), ("collation/implicithan/sr_Latn_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/sr_Latn_standard_reord.toml").as_slice()), ("collation/implicithan/th_standard_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/th_standard_reord.toml").as_slice()), ("collation/implicithan/bn_traditional_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_traditional_data.toml").as_slice()), ("collation/implicithan/es_traditional_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/es_traditional_data.toml").as_slice()), ("collation/implicithan/bn_traditional_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_traditional_meta.toml").as_slice()), ("collation/implicithan/es_traditional_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/es_traditional_meta.toml").as_slice()), ("collation/implicithan/bn_traditional_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/bn_traditional_reord.toml").as_slice()), ("collation/implicithan/ja_unihan_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_unihan_data.toml").as_slice()), ("collation/implicithan/ja_unihan_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_unihan_meta.toml").as_slice()), ("collation/implicithan/ja_unihan_reord.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ja_unihan_reord.toml").as_slice()), ("collation/implicithan/ko_search_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ko_search_data.toml").as_slice()), ("collation/implicithan/ko_searchjl_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ko_searchjl_data.toml").as_slice()), ("collation/implicithan/ko_standard_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ko_standard_data.toml").as_slice()), ("collation/implicithan/ko_unihan_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/ko_unihan_data.toml").as_slice()), ("collation/implicithan/root_emoji_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_emoji_data.toml").as_slice()), ("collation/implicithan/root_emoji_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_emoji_meta.toml").as_slice()), ("collation/implicithan/root_eor_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_eor_data.toml").as_slice()), ("collation/implicithan/root_eor_meta.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/root_eor_meta.toml").as_slice()), ("collation/implicithan/zh_big5han_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_big5han_data.toml").as_slice()), ("collation/implicithan/zh_gb2312han_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_gb2312han_data.toml").as_slice()), ("collation/implicithan/zh_pinyin_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_pinyin_data.toml").as_slice()), ("collation/implicithan/zh_stroke_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_stroke_data.toml").as_slice()), ("collation/implicithan/zh_unihan_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_unihan_data.toml").as_slice()), ("collation/implicithan/zh_zhuyin_data.toml", include_bytes!("../../tests/data/icuexport/collation/implicithan/zh_zhuyin_data.toml").as_slice()), ("norm/small/compositions.toml", include_bytes!("../../tests/data/icuexport/norm/small/compositions.toml").as_slice()), ("norm/small/decompositionex.toml", include_bytes!("../../tests/data/icuexport/norm/small/decompositionex.toml").as_slice()), ("norm/small/nfd.toml", include_bytes!("../../tests/data/icuexport/norm/small/nfd.toml").as_slice()), ("norm/small/nfdex.toml", include_bytes!("../../tests/data/icuexport/norm/small/nfdex.toml").as_slice()), ("norm/small/nfkd.toml", include_bytes!("../../tests/data/icuexport/norm/small/nfkd.toml").as_slice()), ("norm/small/nfkdex.toml", include_bytes!("../../tests/data/icuexport/norm/small/nfkdex.toml").as_slice()), ("norm/small/uts46d.toml", include_bytes!("../../tests/data/icuexport/norm/small/uts46d.toml").as_slice()), ("segmenter/dictionary/burmesedict.toml", include_bytes!("../../tests/data/icuexport/segmenter/dictionary/burmesedict.toml").as_slice()), ("segmenter/dictionary/cjdict.toml", include_bytes!("../../tests/data/icuexport/segmenter/dictionary/cjdict.toml").as_slice()), ("segmenter/dictionary/khmerdict.toml", include_bytes!("../../tests/data/icuexport/segmenter/dictionary/khmerdict.toml").as_slice()), ("segmenter/dictionary/laodict.toml", include_bytes!("../../tests/data/icuexport/segmenter/dictionary/laodict.toml").as_slice()), ("segmenter/dictionary/thaidict.toml", include_bytes!("../../tests/data/icuexport/segmenter/dictionary/thaidict.toml").as_slice()), ("ucase/small/ucase.toml", include_bytes!("../../tests/data/icuexport/ucase/small/ucase.toml").as_slice()), ("uprops/small/AHex.toml", include_bytes!("../../tests/data/icuexport/uprops/small/AHex.toml").as_slice()), ("uprops/small/alnum.toml", include_bytes!("../../tests/data/icuexport/uprops/small/alnum.toml").as_slice()), ("uprops/small/Alpha.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Alpha.toml").as_slice()), ("uprops/small/Basic_Emoji.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Basic_Emoji.toml").as_slice()), ("uprops/small/bc.toml", include_bytes!("../../tests/data/icuexport/uprops/small/bc.toml").as_slice()), ("uprops/small/Bidi_C.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Bidi_C.toml").as_slice()), ("uprops/small/Bidi_M.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Bidi_M.toml").as_slice()), ("uprops/small/blank.toml", include_bytes!("../../tests/data/icuexport/uprops/small/blank.toml").as_slice()), ("uprops/small/bmg.toml", include_bytes!("../../tests/data/icuexport/uprops/small/bmg.toml").as_slice()), ("uprops/small/bpt.toml", include_bytes!("../../tests/data/icuexport/uprops/small/bpt.toml").as_slice()), ("uprops/small/Cased.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Cased.toml").as_slice()), ("uprops/small/ccc.toml", include_bytes!("../../tests/data/icuexport/uprops/small/ccc.toml").as_slice()), ("uprops/small/CI.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CI.toml").as_slice()), ("uprops/small/Comp_Ex.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Comp_Ex.toml").as_slice()), ("uprops/small/CWCF.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWCF.toml").as_slice()), ("uprops/small/CWCM.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWCM.toml").as_slice()), ("uprops/small/CWKCF.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWKCF.toml").as_slice()), ("uprops/small/CWL.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWL.toml").as_slice()), ("uprops/small/CWT.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWT.toml").as_slice()), ("uprops/small/CWU.toml", include_bytes!("../../tests/data/icuexport/uprops/small/CWU.toml").as_slice()), ("uprops/small/Dash.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Dash.toml").as_slice()), ("uprops/small/Dep.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Dep.toml").as_slice()), ("uprops/small/DI.toml", include_bytes!("../../tests/data/icuexport/uprops/small/DI.toml").as_slice()), ("uprops/small/Dia.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Dia.toml").as_slice()), ("uprops/small/ea.toml", include_bytes!("../../tests/data/icuexport/uprops/small/ea.toml").as_slice()), ("uprops/small/EBase.toml", include_bytes!("../../tests/data/icuexport/uprops/small/EBase.toml").as_slice()), ("uprops/small/EComp.toml", include_bytes!("../../tests/data/icuexport/uprops/small/EComp.toml").as_slice()), ("uprops/small/EMod.toml", include_bytes!("../../tests/data/icuexport/uprops/small/EMod.toml").as_slice()), ("uprops/small/Emoji.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Emoji.toml").as_slice()), ("uprops/small/EPres.toml", include_bytes!("../../tests/data/icuexport/uprops/small/EPres.toml").as_slice()), ("uprops/small/Ext.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Ext.toml").as_slice()), ("uprops/small/ExtPict.toml", include_bytes!("../../tests/data/icuexport/uprops/small/ExtPict.toml").as_slice()), ("uprops/small/gc.toml", include_bytes!("../../tests/data/icuexport/uprops/small/gc.toml").as_slice()), ("uprops/small/GCB.toml", include_bytes!("../../tests/data/icuexport/uprops/small/GCB.toml").as_slice()), ("uprops/small/gcm.toml", include_bytes!("../../tests/data/icuexport/uprops/small/gcm.toml").as_slice()), ("uprops/small/Gr_Base.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Gr_Base.toml").as_slice()), ("uprops/small/Gr_Ext.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Gr_Ext.toml").as_slice()), ("uprops/small/Gr_Link.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Gr_Link.toml").as_slice()), ("uprops/small/graph.toml", include_bytes!("../../tests/data/icuexport/uprops/small/graph.toml").as_slice()), ("uprops/small/Hex.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Hex.toml").as_slice()), ("uprops/small/Hyphen.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Hyphen.toml").as_slice()), ("uprops/small/IDC.toml", include_bytes!("../../tests/data/icuexport/uprops/small/IDC.toml").as_slice()), ("uprops/small/Ideo.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Ideo.toml").as_slice()), ("uprops/small/IDS.toml", include_bytes!("../../tests/data/icuexport/uprops/small/IDS.toml").as_slice()), ("uprops/small/IDSB.toml", include_bytes!("../../tests/data/icuexport/uprops/small/IDSB.toml").as_slice()), ("uprops/small/IDST.toml", include_bytes!("../../tests/data/icuexport/uprops/small/IDST.toml").as_slice()), ("uprops/small/InSC.toml", include_bytes!("../../tests/data/icuexport/uprops/small/InSC.toml").as_slice()), ("uprops/small/Join_C.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Join_C.toml").as_slice()), ("uprops/small/lb.toml", include_bytes!("../../tests/data/icuexport/uprops/small/lb.toml").as_slice()), ("uprops/small/LOE.toml", include_bytes!("../../tests/data/icuexport/uprops/small/LOE.toml").as_slice()), ("uprops/small/Lower.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Lower.toml").as_slice()), ("uprops/small/Math.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Math.toml").as_slice()), ("uprops/small/NChar.toml", include_bytes!("../../tests/data/icuexport/uprops/small/NChar.toml").as_slice()), ("uprops/small/nfcinert.toml", include_bytes!("../../tests/data/icuexport/uprops/small/nfcinert.toml").as_slice()), ("uprops/small/nfdinert.toml", include_bytes!("../../tests/data/icuexport/uprops/small/nfdinert.toml").as_slice()), ("uprops/small/nfkcinert.toml", include_bytes!("../../tests/data/icuexport/uprops/small/nfkcinert.toml").as_slice()), ("uprops/small/nfkdinert.toml", include_bytes!("../../tests/data/icuexport/uprops/small/nfkdinert.toml").as_slice()), ("uprops/small/Pat_Syn.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Pat_Syn.toml").as_slice()), ("uprops/small/Pat_WS.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Pat_WS.toml").as_slice()), ("uprops/small/PCM.toml", include_bytes!("../../tests/data/icuexport/uprops/small/PCM.toml").as_slice()), ("uprops/small/print.toml", include_bytes!("../../tests/data/icuexport/uprops/small/print.toml").as_slice()), ("uprops/small/QMark.toml", include_bytes!("../../tests/data/icuexport/uprops/small/QMark.toml").as_slice()), ("uprops/small/Radical.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Radical.toml").as_slice()), ("uprops/small/RI.toml", include_bytes!("../../tests/data/icuexport/uprops/small/RI.toml").as_slice()), ("uprops/small/SB.toml", include_bytes!("../../tests/data/icuexport/uprops/small/SB.toml").as_slice()), ("uprops/small/sc.toml", include_bytes!("../../tests/data/icuexport/uprops/small/sc.toml").as_slice()), ("uprops/small/scx.toml", include_bytes!("../../tests/data/icuexport/uprops/small/scx.toml").as_slice()), ("uprops/small/SD.toml", include_bytes!("../../tests/data/icuexport/uprops/small/SD.toml").as_slice()), ("uprops/small/segstart.toml", include_bytes!("../../tests/data/icuexport/uprops/small/segstart.toml").as_slice()), ("uprops/small/Sensitive.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Sensitive.toml").as_slice()), ("uprops/small/STerm.toml", include_bytes!("../../tests/data/icuexport/uprops/small/STerm.toml").as_slice()), ("uprops/small/Term.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Term.toml").as_slice()), ("uprops/small/UIdeo.toml", include_bytes!("../../tests/data/icuexport/uprops/small/UIdeo.toml").as_slice()), ("uprops/small/Upper.toml", include_bytes!("../../tests/data/icuexport/uprops/small/Upper.toml").as_slice()), ("uprops/small/VS.toml", include_bytes!("../../tests/data/icuexport/uprops/small/VS.toml").as_slice()), ("uprops/small/WB.toml", include_bytes!("../../tests/data/icuexport/uprops/small/WB.toml").as_slice()), ("uprops/small/WSpace.toml", include_bytes!("../../tests/data/icuexport/uprops/small/WSpace.toml").as_slice()), ("uprops/small/xdigit.toml", include_bytes!("../../tests/data/icuexport/uprops/small/xdigit.toml").as_slice()), ("uprops/small/XIDC.toml", include_bytes!("../../tests/data/icuexport/uprops/small/XIDC.toml").as_slice()), ("uprops/small/XIDS.toml", include_bytes!("../../tests/data/icuexport/uprops/small/XIDS.toml").as_slice())].into_iter().collect(),
@@ -29,6 +29,9 @@ impl DatagenProvider {
                     segmenter_lstm_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
                         [("Burmese_codepoints_exclusive_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Burmese_codepoints_exclusive_model4_heavy/weights.json").as_slice()), ("Khmer_codepoints_exclusive_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Khmer_codepoints_exclusive_model4_heavy/weights.json").as_slice()), ("Lao_codepoints_exclusive_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Lao_codepoints_exclusive_model4_heavy/weights.json").as_slice()), ("Thai_codepoints_exclusive_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Thai_codepoints_exclusive_model4_heavy/weights.json").as_slice()), ("Thai_graphclust_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Thai_graphclust_model4_heavy/weights.json").as_slice())].into_iter().collect(),
                     )))),
+                    tzif_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                        [("Africa/Abidjan", include_bytes!("../../tests/data/tzif/Africa/Abidjan").as_slice()), ("Africa/Accra", include_bytes!("../../tests/data/tzif/Africa/Accra").as_slice()), ("Africa/Addis_Ababa", include_bytes!("../../tests/data/tzif/Africa/Addis_Ababa").as_slice()), ("Africa/Algiers", include_bytes!("../../tests/data/tzif/Africa/Algiers").as_slice()), ("Africa/Asmara", include_bytes!("../../tests/data/tzif/Africa/Asmara").as_slice()), ("Africa/Bamako", include_bytes!("../../tests/data/tzif/Africa/Bamako").as_slice()), ("Africa/Bangui", include_bytes!("../../tests/data/tzif/Africa/Bangui").as_slice()), ("Africa/Banjul", include_bytes!("../../tests/data/tzif/Africa/Banjul").as_slice()), ("Africa/Bissau", include_bytes!("../../tests/data/tzif/Africa/Bissau").as_slice()), ("Africa/Blantyre", include_bytes!("../../tests/data/tzif/Africa/Blantyre").as_slice()), ("Africa/Brazzaville", include_bytes!("../../tests/data/tzif/Africa/Brazzaville").as_slice()), ("Africa/Bujumbura", include_bytes!("../../tests/data/tzif/Africa/Bujumbura").as_slice()), ("Africa/Cairo", include_bytes!("../../tests/data/tzif/Africa/Cairo").as_slice()), ("Africa/Casablanca", include_bytes!("../../tests/data/tzif/Africa/Casablanca").as_slice()), ("Africa/Ceuta", include_bytes!("../../tests/data/tzif/Africa/Ceuta").as_slice()), ("Africa/Conakry", include_bytes!("../../tests/data/tzif/Africa/Conakry").as_slice()), ("Africa/Dakar", include_bytes!("../../tests/data/tzif/Africa/Dakar").as_slice()), ("Africa/Dar_es_Salaam", include_bytes!("../../tests/data/tzif/Africa/Dar_es_Salaam").as_slice()), ("Africa/Djibouti", include_bytes!("../../tests/data/tzif/Africa/Djibouti").as_slice()), ("Africa/Douala", include_bytes!("../../tests/data/tzif/Africa/Douala").as_slice()), ("Africa/El_Aaiun", include_bytes!("../../tests/data/tzif/Africa/El_Aaiun").as_slice()), ("Africa/Freetown", include_bytes!("../../tests/data/tzif/Africa/Freetown").as_slice()), ("Africa/Gaborone", include_bytes!("../../tests/data/tzif/Africa/Gaborone").as_slice()), ("Africa/Harare", include_bytes!("../../tests/data/tzif/Africa/Harare").as_slice()), ("Africa/Johannesburg", include_bytes!("../../tests/data/tzif/Africa/Johannesburg").as_slice()), ("Africa/Juba", include_bytes!("../../tests/data/tzif/Africa/Juba").as_slice()), ("Africa/Kampala", include_bytes!("../../tests/data/tzif/Africa/Kampala").as_slice()), ("Africa/Khartoum", include_bytes!("../../tests/data/tzif/Africa/Khartoum").as_slice()), ("Africa/Kigali", include_bytes!("../../tests/data/tzif/Africa/Kigali").as_slice()), ("Africa/Kinshasa", include_bytes!("../../tests/data/tzif/Africa/Kinshasa").as_slice()), ("Africa/Lagos", include_bytes!("../../tests/data/tzif/Africa/Lagos").as_slice()), ("Africa/Libreville", include_bytes!("../../tests/data/tzif/Africa/Libreville").as_slice()), ("Africa/Lome", include_bytes!("../../tests/data/tzif/Africa/Lome").as_slice()), ("Africa/Luanda", include_bytes!("../../tests/data/tzif/Africa/Luanda").as_slice()), ("Africa/Lubumbashi", include_bytes!("../../tests/data/tzif/Africa/Lubumbashi").as_slice()), ("Africa/Lusaka", include_bytes!("../../tests/data/tzif/Africa/Lusaka").as_slice()), ("Africa/Malabo", include_bytes!("../../tests/data/tzif/Africa/Malabo").as_slice()), ("Africa/Maputo", include_bytes!("../../tests/data/tzif/Africa/Maputo").as_slice()), ("Africa/Maseru", include_bytes!("../../tests/data/tzif/Africa/Maseru").as_slice()), ("Africa/Mbabane", include_bytes!("../../tests/data/tzif/Africa/Mbabane").as_slice()), ("Africa/Mogadishu", include_bytes!("../../tests/data/tzif/Africa/Mogadishu").as_slice()), ("Africa/Monrovia", include_bytes!("../../tests/data/tzif/Africa/Monrovia").as_slice()), ("Africa/Nairobi", include_bytes!("../../tests/data/tzif/Africa/Nairobi").as_slice()), ("Africa/Ndjamena", include_bytes!("../../tests/data/tzif/Africa/Ndjamena").as_slice()), ("Africa/Niamey", include_bytes!("../../tests/data/tzif/Africa/Niamey").as_slice()), ("Africa/Nouakchott", include_bytes!("../../tests/data/tzif/Africa/Nouakchott").as_slice()), ("Africa/Ouagadougou", include_bytes!("../../tests/data/tzif/Africa/Ouagadougou").as_slice()), ("Africa/Porto-Novo", include_bytes!("../../tests/data/tzif/Africa/Porto-Novo").as_slice()), ("Africa/Sao_Tome", include_bytes!("../../tests/data/tzif/Africa/Sao_Tome").as_slice()), ("Africa/Tripoli", include_bytes!("../../tests/data/tzif/Africa/Tripoli").as_slice()), ("Africa/Tunis", include_bytes!("../../tests/data/tzif/Africa/Tunis").as_slice()), ("Africa/Windhoek", include_bytes!("../../tests/data/tzif/Africa/Windhoek").as_slice()), ("America/Adak", include_bytes!("../../tests/data/tzif/America/Adak").as_slice()), ("America/Anchorage", include_bytes!("../../tests/data/tzif/America/Anchorage").as_slice()), ("America/Anguilla", include_bytes!("../../tests/data/tzif/America/Anguilla").as_slice()), ("America/Antigua", include_bytes!("../../tests/data/tzif/America/Antigua").as_slice()), ("America/Araguaina", include_bytes!("../../tests/data/tzif/America/Araguaina").as_slice()), ("America/Argentina/Buenos_Aires", include_bytes!("../../tests/data/tzif/America/Argentina/Buenos_Aires").as_slice()), ("America/Argentina/Catamarca", include_bytes!("../../tests/data/tzif/America/Argentina/Catamarca").as_slice()), ("America/Argentina/Cordoba", include_bytes!("../../tests/data/tzif/America/Argentina/Cordoba").as_slice()), ("America/Argentina/Jujuy", include_bytes!("../../tests/data/tzif/America/Argentina/Jujuy").as_slice()), ("America/Argentina/La_Rioja", include_bytes!("../../tests/data/tzif/America/Argentina/La_Rioja").as_slice()), ("America/Argentina/Mendoza", include_bytes!("../../tests/data/tzif/America/Argentina/Mendoza").as_slice()), ("America/Argentina/Rio_Gallegos", include_bytes!("../../tests/data/tzif/America/Argentina/Rio_Gallegos").as_slice()), ("America/Argentina/Salta", include_bytes!("../../tests/data/tzif/America/Argentina/Salta").as_slice()), ("America/Argentina/San_Juan", include_bytes!("../../tests/data/tzif/America/Argentina/San_Juan").as_slice()), ("America/Argentina/San_Luis", include_bytes!("../../tests/data/tzif/America/Argentina/San_Luis").as_slice()), ("America/Argentina/Tucuman", include_bytes!("../../tests/data/tzif/America/Argentina/Tucuman").as_slice()), ("America/Argentina/Ushuaia", include_bytes!("../../tests/data/tzif/America/Argentina/Ushuaia").as_slice()), ("America/Aruba", include_bytes!("../../tests/data/tzif/America/Aruba").as_slice()), ("America/Asuncion", include_bytes!("../../tests/data/tzif/America/Asuncion").as_slice()), ("America/Atikokan", include_bytes!("../../tests/data/tzif/America/Atikokan").as_slice()), ("America/Bahia", include_bytes!("../../tests/data/tzif/America/Bahia").as_slice()), ("America/Bahia_Banderas", include_bytes!("../../tests/data/tzif/America/Bahia_Banderas").as_slice()), ("America/Barbados", include_bytes!("../../tests/data/tzif/America/Barbados").as_slice()), ("America/Belem", include_bytes!("../../tests/data/tzif/America/Belem").as_slice()), ("America/Belize", include_bytes!("../../tests/data/tzif/America/Belize").as_slice()), ("America/Blanc-Sablon", include_bytes!("../../tests/data/tzif/America/Blanc-Sablon").as_slice()), ("America/Boa_Vista", include_bytes!("../../tests/data/tzif/America/Boa_Vista").as_slice()), ("America/Bogota", include_bytes!("../../tests/data/tzif/America/Bogota").as_slice()), ("America/Boise", include_bytes!("../../tests/data/tzif/America/Boise").as_slice()), ("America/Cambridge_Bay", include_bytes!("../../tests/data/tzif/America/Cambridge_Bay").as_slice()), ("America/Campo_Grande", include_bytes!("../../tests/data/tzif/America/Campo_Grande").as_slice()), ("America/Cancun", include_bytes!("../../tests/data/tzif/America/Cancun").as_slice()), ("America/Caracas", include_bytes!("../../tests/data/tzif/America/Caracas").as_slice()), ("America/Cayenne", include_bytes!("../../tests/data/tzif/America/Cayenne").as_slice()), ("America/Cayman", include_bytes!("../../tests/data/tzif/America/Cayman").as_slice()), ("America/Chicago", include_bytes!("../../tests/data/tzif/America/Chicago").as_slice()), ("America/Chihuahua", include_bytes!("../../tests/data/tzif/America/Chihuahua").as_slice()), ("America/Ciudad_Juarez", include_bytes!("../../tests/data/tzif/America/Ciudad_Juarez").as_slice()), ("America/Costa_Rica", include_bytes!("../../tests/data/tzif/America/Costa_Rica").as_slice()), ("America/Creston", include_bytes!("../../tests/data/tzif/America/Creston").as_slice()), ("America/Cuiaba", include_bytes!("../../tests/data/tzif/America/Cuiaba").as_slice()), ("America/Curacao", include_bytes!("../../tests/data/tzif/America/Curacao").as_slice()), ("America/Danmarkshavn", include_bytes!("../../tests/data/tzif/America/Danmarkshavn").as_slice()), ("America/Dawson", include_bytes!("../../tests/data/tzif/America/Dawson").as_slice()), ("America/Dawson_Creek", include_bytes!("../../tests/data/tzif/America/Dawson_Creek").as_slice()), ("America/Denver", include_bytes!("../../tests/data/tzif/America/Denver").as_slice()), ("America/Detroit", include_bytes!("../../tests/data/tzif/America/Detroit").as_slice()), ("America/Dominica", include_bytes!("../../tests/data/tzif/America/Dominica").as_slice()), ("America/Edmonton", include_bytes!("../../tests/data/tzif/America/Edmonton").as_slice()), ("America/Eirunepe", include_bytes!("../../tests/data/tzif/America/Eirunepe").as_slice()), ("America/El_Salvador", include_bytes!("../../tests/data/tzif/America/El_Salvador").as_slice()), ("America/Fort_Nelson", include_bytes!("../../tests/data/tzif/America/Fort_Nelson").as_slice()), ("America/Fortaleza", include_bytes!("../../tests/data/tzif/America/Fortaleza").as_slice()), ("America/Glace_Bay", include_bytes!("../../tests/data/tzif/America/Glace_Bay").as_slice()), ("America/Goose_Bay", include_bytes!("../../tests/data/tzif/America/Goose_Bay").as_slice()), ("America/Grand_Turk", include_bytes!("../../tests/data/tzif/America/Grand_Turk").as_slice()), ("America/Grenada", include_bytes!("../../tests/data/tzif/America/Grenada").as_slice()), ("America/Guadeloupe", include_bytes!("../../tests/data/tzif/America/Guadeloupe").as_slice()), ("America/Guatemala", include_bytes!("../../tests/data/tzif/America/Guatemala").as_slice()), ("America/Guayaquil", include_bytes!("../../tests/data/tzif/America/Guayaquil").as_slice()), ("America/Guyana", include_bytes!("../../tests/data/tzif/America/Guyana").as_slice()), ("America/Halifax", include_bytes!("../../tests/data/tzif/America/Halifax").as_slice()), ("America/Havana", include_bytes!("../../tests/data/tzif/America/Havana").as_slice()), ("America/Hermosillo", include_bytes!("../../tests/data/tzif/America/Hermosillo").as_slice()), ("America/Indiana/Indianapolis", include_bytes!("../../tests/data/tzif/America/Indiana/Indianapolis").as_slice()), ("America/Indiana/Knox", include_bytes!("../../tests/data/tzif/America/Indiana/Knox").as_slice()), ("America/Indiana/Marengo", include_bytes!("../../tests/data/tzif/America/Indiana/Marengo").as_slice()), ("America/Indiana/Petersburg", include_bytes!("../../tests/data/tzif/America/Indiana/Petersburg").as_slice()), ("America/Indiana/Tell_City", include_bytes!("../../tests/data/tzif/America/Indiana/Tell_City").as_slice()), ("America/Indiana/Vevay", include_bytes!("../../tests/data/tzif/America/Indiana/Vevay").as_slice()), ("America/Indiana/Vincennes", include_bytes!("../../tests/data/tzif/America/Indiana/Vincennes").as_slice()), ("America/Indiana/Winamac", include_bytes!("../../tests/data/tzif/America/Indiana/Winamac").as_slice()), ("America/Inuvik", include_bytes!("../../tests/data/tzif/America/Inuvik").as_slice()), ("America/Iqaluit", include_bytes!("../../tests/data/tzif/America/Iqaluit").as_slice()), ("America/Jamaica", include_bytes!("../../tests/data/tzif/America/Jamaica").as_slice()), ("America/Juneau", include_bytes!("../../tests/data/tzif/America/Juneau").as_slice()), ("America/Kentucky/Louisville", include_bytes!("../../tests/data/tzif/America/Kentucky/Louisville").as_slice()), ("America/Kentucky/Monticello", include_bytes!("../../tests/data/tzif/America/Kentucky/Monticello").as_slice()), ("America/Kralendijk", include_bytes!("../../tests/data/tzif/America/Kralendijk").as_slice()), ("America/La_Paz", include_bytes!("../../tests/data/tzif/America/La_Paz").as_slice()), ("America/Lima", include_bytes!("../../tests/data/tzif/America/Lima").as_slice()), ("America/Los_Angeles", include_bytes!("../../tests/data/tzif/America/Los_Angeles").as_slice()), ("America/Lower_Princes", include_bytes!("../../tests/data/tzif/America/Lower_Princes").as_slice()), ("America/Maceio", include_bytes!("../../tests/data/tzif/America/Maceio").as_slice()), ("America/Managua", include_bytes!("../../tests/data/tzif/America/Managua").as_slice()), ("America/Manaus", include_bytes!("../../tests/data/tzif/America/Manaus").as_slice()), ("America/Marigot", include_bytes!("../../tests/data/tzif/America/Marigot").as_slice()), ("America/Martinique", include_bytes!("../../tests/data/tzif/America/Martinique").as_slice()), ("America/Matamoros", include_bytes!("../../tests/data/tzif/America/Matamoros").as_slice()), ("America/Mazatlan", include_bytes!("../../tests/data/tzif/America/Mazatlan").as_slice()), ("America/Menominee", include_bytes!("../../tests/data/tzif/America/Menominee").as_slice()), ("America/Merida", include_bytes!("../../tests/data/tzif/America/Merida").as_slice()), ("America/Metlakatla", include_bytes!("../../tests/data/tzif/America/Metlakatla").as_slice()), ("America/Mexico_City", include_bytes!("../../tests/data/tzif/America/Mexico_City").as_slice()), ("America/Miquelon", include_bytes!("../../tests/data/tzif/America/Miquelon").as_slice()), ("America/Moncton", include_bytes!("../../tests/data/tzif/America/Moncton").as_slice()), ("America/Monterrey", include_bytes!("../../tests/data/tzif/America/Monterrey").as_slice()), ("America/Montevideo", include_bytes!("../../tests/data/tzif/America/Montevideo").as_slice()), ("America/Montserrat", include_bytes!("../../tests/data/tzif/America/Montserrat").as_slice()), ("America/Nassau", include_bytes!("../../tests/data/tzif/America/Nassau").as_slice()), ("America/New_York", include_bytes!("../../tests/data/tzif/America/New_York").as_slice()), ("America/Nome", include_bytes!("../../tests/data/tzif/America/Nome").as_slice()), ("America/Noronha", include_bytes!("../../tests/data/tzif/America/Noronha").as_slice()), ("America/North_Dakota/Beulah", include_bytes!("../../tests/data/tzif/America/North_Dakota/Beulah").as_slice()), ("America/North_Dakota/Center", include_bytes!("../../tests/data/tzif/America/North_Dakota/Center").as_slice()), ("America/North_Dakota/New_Salem", include_bytes!("../../tests/data/tzif/America/North_Dakota/New_Salem").as_slice()), ("America/Nuuk", include_bytes!("../../tests/data/tzif/America/Nuuk").as_slice()), ("America/Ojinaga", include_bytes!("../../tests/data/tzif/America/Ojinaga").as_slice()), ("America/Panama", include_bytes!("../../tests/data/tzif/America/Panama").as_slice()), ("America/Paramaribo", include_bytes!("../../tests/data/tzif/America/Paramaribo").as_slice()), ("America/Phoenix", include_bytes!("../../tests/data/tzif/America/Phoenix").as_slice()), ("America/Port-au-Prince", include_bytes!("../../tests/data/tzif/America/Port-au-Prince").as_slice()), ("America/Port_of_Spain", include_bytes!("../../tests/data/tzif/America/Port_of_Spain").as_slice()), ("America/Porto_Velho", include_bytes!("../../tests/data/tzif/America/Porto_Velho").as_slice()), ("America/Puerto_Rico", include_bytes!("../../tests/data/tzif/America/Puerto_Rico").as_slice()), ("America/Punta_Arenas", include_bytes!("../../tests/data/tzif/America/Punta_Arenas").as_slice()), ("America/Rankin_Inlet", include_bytes!("../../tests/data/tzif/America/Rankin_Inlet").as_slice()), ("America/Recife", include_bytes!("../../tests/data/tzif/America/Recife").as_slice()), ("America/Regina", include_bytes!("../../tests/data/tzif/America/Regina").as_slice()), ("America/Resolute", include_bytes!("../../tests/data/tzif/America/Resolute").as_slice()), ("America/Rio_Branco", include_bytes!("../../tests/data/tzif/America/Rio_Branco").as_slice()), ("America/Santarem", include_bytes!("../../tests/data/tzif/America/Santarem").as_slice()), ("America/Santiago", include_bytes!("../../tests/data/tzif/America/Santiago").as_slice()), ("America/Santo_Domingo", include_bytes!("../../tests/data/tzif/America/Santo_Domingo").as_slice()), ("America/Sao_Paulo", include_bytes!("../../tests/data/tzif/America/Sao_Paulo").as_slice()), ("America/Scoresbysund", include_bytes!("../../tests/data/tzif/America/Scoresbysund").as_slice()), ("America/Sitka", include_bytes!("../../tests/data/tzif/America/Sitka").as_slice()), ("America/St_Barthelemy", include_bytes!("../../tests/data/tzif/America/St_Barthelemy").as_slice()), ("America/St_Johns", include_bytes!("../../tests/data/tzif/America/St_Johns").as_slice()), ("America/St_Kitts", include_bytes!("../../tests/data/tzif/America/St_Kitts").as_slice()), ("America/St_Lucia", include_bytes!("../../tests/data/tzif/America/St_Lucia").as_slice()), ("America/St_Thomas", include_bytes!("../../tests/data/tzif/America/St_Thomas").as_slice()), ("America/St_Vincent", include_bytes!("../../tests/data/tzif/America/St_Vincent").as_slice()), ("America/Swift_Current", include_bytes!("../../tests/data/tzif/America/Swift_Current").as_slice()), ("America/Tegucigalpa", include_bytes!("../../tests/data/tzif/America/Tegucigalpa").as_slice()), ("America/Thule", include_bytes!("../../tests/data/tzif/America/Thule").as_slice()), ("America/Tijuana", include_bytes!("../../tests/data/tzif/America/Tijuana").as_slice()), ("America/Toronto", include_bytes!("../../tests/data/tzif/America/Toronto").as_slice()), ("America/Tortola", include_bytes!("../../tests/data/tzif/America/Tortola").as_slice()), ("America/Vancouver", include_bytes!("../../tests/data/tzif/America/Vancouver").as_slice()), ("America/Whitehorse", include_bytes!("../../tests/data/tzif/America/Whitehorse").as_slice()), ("America/Winnipeg", include_bytes!("../../tests/data/tzif/America/Winnipeg").as_slice()), ("America/Yakutat", include_bytes!("../../tests/data/tzif/America/Yakutat").as_slice()), ("Antarctica/Casey", include_bytes!("../../tests/data/tzif/Antarctica/Casey").as_slice()), ("Antarctica/Davis", include_bytes!("../../tests/data/tzif/Antarctica/Davis").as_slice()), ("Antarctica/DumontDUrville", include_bytes!("../../tests/data/tzif/Antarctica/DumontDUrville").as_slice()), ("Antarctica/Macquarie", include_bytes!("../../tests/data/tzif/Antarctica/Macquarie").as_slice()), ("Antarctica/Mawson", include_bytes!("../../tests/data/tzif/Antarctica/Mawson").as_slice()), ("Antarctica/McMurdo", include_bytes!("../../tests/data/tzif/Antarctica/McMurdo").as_slice()), ("Antarctica/Palmer", include_bytes!("../../tests/data/tzif/Antarctica/Palmer").as_slice()), ("Antarctica/Rothera", include_bytes!("../../tests/data/tzif/Antarctica/Rothera").as_slice()), ("Antarctica/Syowa", include_bytes!("../../tests/data/tzif/Antarctica/Syowa").as_slice()), ("Antarctica/Troll", include_bytes!("../../tests/data/tzif/Antarctica/Troll").as_slice()), ("Antarctica/Vostok", include_bytes!("../../tests/data/tzif/Antarctica/Vostok").as_slice()), ("Arctic/Longyearbyen", include_bytes!("../../tests/data/tzif/Arctic/Longyearbyen").as_slice()), ("Asia/Aden", include_bytes!("../../tests/data/tzif/Asia/Aden").as_slice()), ("Asia/Almaty", include_bytes!("../../tests/data/tzif/Asia/Almaty").as_slice()), ("Asia/Amman", include_bytes!("../../tests/data/tzif/Asia/Amman").as_slice()), ("Asia/Anadyr", include_bytes!("../../tests/data/tzif/Asia/Anadyr").as_slice()), ("Asia/Aqtau", include_bytes!("../../tests/data/tzif/Asia/Aqtau").as_slice()), ("Asia/Aqtobe", include_bytes!("../../tests/data/tzif/Asia/Aqtobe").as_slice()), ("Asia/Ashgabat", include_bytes!("../../tests/data/tzif/Asia/Ashgabat").as_slice()), ("Asia/Atyrau", include_bytes!("../../tests/data/tzif/Asia/Atyrau").as_slice()), ("Asia/Baghdad", include_bytes!("../../tests/data/tzif/Asia/Baghdad").as_slice()), ("Asia/Bahrain", include_bytes!("../../tests/data/tzif/Asia/Bahrain").as_slice()), ("Asia/Baku", include_bytes!("../../tests/data/tzif/Asia/Baku").as_slice()), ("Asia/Bangkok", include_bytes!("../../tests/data/tzif/Asia/Bangkok").as_slice()), ("Asia/Barnaul", include_bytes!("../../tests/data/tzif/Asia/Barnaul").as_slice()), ("Asia/Beirut", include_bytes!("../../tests/data/tzif/Asia/Beirut").as_slice()), ("Asia/Bishkek", include_bytes!("../../tests/data/tzif/Asia/Bishkek").as_slice()), ("Asia/Brunei", include_bytes!("../../tests/data/tzif/Asia/Brunei").as_slice()), ("Asia/Chita", include_bytes!("../../tests/data/tzif/Asia/Chita").as_slice()), ("Asia/Choibalsan", include_bytes!("../../tests/data/tzif/Asia/Choibalsan").as_slice()), ("Asia/Colombo", include_bytes!("../../tests/data/tzif/Asia/Colombo").as_slice()), ("Asia/Damascus", include_bytes!("../../tests/data/tzif/Asia/Damascus").as_slice()), ("Asia/Dhaka", include_bytes!("../../tests/data/tzif/Asia/Dhaka").as_slice()), ("Asia/Dili", include_bytes!("../../tests/data/tzif/Asia/Dili").as_slice()), ("Asia/Dubai", include_bytes!("../../tests/data/tzif/Asia/Dubai").as_slice()), ("Asia/Dushanbe", include_bytes!("../../tests/data/tzif/Asia/Dushanbe").as_slice()), ("Asia/Famagusta", include_bytes!("../../tests/data/tzif/Asia/Famagusta").as_slice()), ("Asia/Gaza", include_bytes!("../../tests/data/tzif/Asia/Gaza").as_slice()), ("Asia/Hebron", include_bytes!("../../tests/data/tzif/Asia/Hebron").as_slice()), ("Asia/Ho_Chi_Minh", include_bytes!("../../tests/data/tzif/Asia/Ho_Chi_Minh").as_slice()), ("Asia/Hong_Kong", include_bytes!("../../tests/data/tzif/Asia/Hong_Kong").as_slice()), ("Asia/Hovd", include_bytes!("../../tests/data/tzif/Asia/Hovd").as_slice()), ("Asia/Irkutsk", include_bytes!("../../tests/data/tzif/Asia/Irkutsk").as_slice()), ("Asia/Jakarta", include_bytes!("../../tests/data/tzif/Asia/Jakarta").as_slice()), ("Asia/Jayapura", include_bytes!("../../tests/data/tzif/Asia/Jayapura").as_slice()), ("Asia/Jerusalem", include_bytes!("../../tests/data/tzif/Asia/Jerusalem").as_slice()), ("Asia/Kabul", include_bytes!("../../tests/data/tzif/Asia/Kabul").as_slice()), ("Asia/Kamchatka", include_bytes!("../../tests/data/tzif/Asia/Kamchatka").as_slice()), ("Asia/Karachi", include_bytes!("../../tests/data/tzif/Asia/Karachi").as_slice()), ("Asia/Kathmandu", include_bytes!("../../tests/data/tzif/Asia/Kathmandu").as_slice()), ("Asia/Khandyga", include_bytes!("../../tests/data/tzif/Asia/Khandyga").as_slice()), ("Asia/Kolkata", include_bytes!("../../tests/data/tzif/Asia/Kolkata").as_slice()), ("Asia/Krasnoyarsk", include_bytes!("../../tests/data/tzif/Asia/Krasnoyarsk").as_slice()), ("Asia/Kuala_Lumpur", include_bytes!("../../tests/data/tzif/Asia/Kuala_Lumpur").as_slice()), ("Asia/Kuching", include_bytes!("../../tests/data/tzif/Asia/Kuching").as_slice()), ("Asia/Kuwait", include_bytes!("../../tests/data/tzif/Asia/Kuwait").as_slice()), ("Asia/Macau", include_bytes!("../../tests/data/tzif/Asia/Macau").as_slice()), ("Asia/Magadan", include_bytes!("../../tests/data/tzif/Asia/Magadan").as_slice()), ("Asia/Makassar", include_bytes!("../../tests/data/tzif/Asia/Makassar").as_slice()), ("Asia/Manila", include_bytes!("../../tests/data/tzif/Asia/Manila").as_slice()), ("Asia/Muscat", include_bytes!("../../tests/data/tzif/Asia/Muscat").as_slice()), ("Asia/Nicosia", include_bytes!("../../tests/data/tzif/Asia/Nicosia").as_slice()), ("Asia/Novokuznetsk", include_bytes!("../../tests/data/tzif/Asia/Novokuznetsk").as_slice()), ("Asia/Novosibirsk", include_bytes!("../../tests/data/tzif/Asia/Novosibirsk").as_slice()), ("Asia/Omsk", include_bytes!("../../tests/data/tzif/Asia/Omsk").as_slice()), ("Asia/Oral", include_bytes!("../../tests/data/tzif/Asia/Oral").as_slice()), ("Asia/Phnom_Penh", include_bytes!("../../tests/data/tzif/Asia/Phnom_Penh").as_slice()), ("Asia/Pontianak", include_bytes!("../../tests/data/tzif/Asia/Pontianak").as_slice()), ("Asia/Pyongyang", include_bytes!("../../tests/data/tzif/Asia/Pyongyang").as_slice()), ("Asia/Qatar", include_bytes!("../../tests/data/tzif/Asia/Qatar").as_slice()), ("Asia/Qostanay", include_bytes!("../../tests/data/tzif/Asia/Qostanay").as_slice()), ("Asia/Qyzylorda", include_bytes!("../../tests/data/tzif/Asia/Qyzylorda").as_slice()), ("Asia/Riyadh", include_bytes!("../../tests/data/tzif/Asia/Riyadh").as_slice()), ("Asia/Sakhalin", include_bytes!("../../tests/data/tzif/Asia/Sakhalin").as_slice()), ("Asia/Samarkand", include_bytes!("../../tests/data/tzif/Asia/Samarkand").as_slice()), ("Asia/Seoul", include_bytes!("../../tests/data/tzif/Asia/Seoul").as_slice()), ("Asia/Shanghai", include_bytes!("../../tests/data/tzif/Asia/Shanghai").as_slice()), ("Asia/Singapore", include_bytes!("../../tests/data/tzif/Asia/Singapore").as_slice()), ("Asia/Srednekolymsk", include_bytes!("../../tests/data/tzif/Asia/Srednekolymsk").as_slice()), ("Asia/Taipei", include_bytes!("../../tests/data/tzif/Asia/Taipei").as_slice()), ("Asia/Tashkent", include_bytes!("../../tests/data/tzif/Asia/Tashkent").as_slice()), ("Asia/Tbilisi", include_bytes!("../../tests/data/tzif/Asia/Tbilisi").as_slice()), ("Asia/Tehran", include_bytes!("../../tests/data/tzif/Asia/Tehran").as_slice()), ("Asia/Thimphu", include_bytes!("../../tests/data/tzif/Asia/Thimphu").as_slice()), ("Asia/Tokyo", include_bytes!("../../tests/data/tzif/Asia/Tokyo").as_slice()), ("Asia/Tomsk", include_bytes!("../../tests/data/tzif/Asia/Tomsk").as_slice()), ("Asia/Ulaanbaatar", include_bytes!("../../tests/data/tzif/Asia/Ulaanbaatar").as_slice()), ("Asia/Urumqi", include_bytes!("../../tests/data/tzif/Asia/Urumqi").as_slice()), ("Asia/Ust-Nera", include_bytes!("../../tests/data/tzif/Asia/Ust-Nera").as_slice()), ("Asia/Vientiane", include_bytes!("../../tests/data/tzif/Asia/Vientiane").as_slice()), ("Asia/Vladivostok", include_bytes!("../../tests/data/tzif/Asia/Vladivostok").as_slice()), ("Asia/Yakutsk", include_bytes!("../../tests/data/tzif/Asia/Yakutsk").as_slice()), ("Asia/Yangon", include_bytes!("../../tests/data/tzif/Asia/Yangon").as_slice()), ("Asia/Yekaterinburg", include_bytes!("../../tests/data/tzif/Asia/Yekaterinburg").as_slice()), ("Asia/Yerevan", include_bytes!("../../tests/data/tzif/Asia/Yerevan").as_slice()), ("Atlantic/Azores", include_bytes!("../../tests/data/tzif/Atlantic/Azores").as_slice()), ("Atlantic/Bermuda", include_bytes!("../../tests/data/tzif/Atlantic/Bermuda").as_slice()), ("Atlantic/Canary", include_bytes!("../../tests/data/tzif/Atlantic/Canary").as_slice()), ("Atlantic/Cape_Verde", include_bytes!("../../tests/data/tzif/Atlantic/Cape_Verde").as_slice()), ("Atlantic/Faroe", include_bytes!("../../tests/data/tzif/Atlantic/Faroe").as_slice()), ("Atlantic/Madeira", include_bytes!("../../tests/data/tzif/Atlantic/Madeira").as_slice()), ("Atlantic/Reykjavik", include_bytes!("../../tests/data/tzif/Atlantic/Reykjavik").as_slice()), ("Atlantic/South_Georgia", include_bytes!("../../tests/data/tzif/Atlantic/South_Georgia").as_slice()), ("Atlantic/St_Helena", include_bytes!("../../tests/data/tzif/Atlantic/St_Helena").as_slice()), ("Atlantic/Stanley", include_bytes!("../../tests/data/tzif/Atlantic/Stanley").as_slice()), ("Australia/Adelaide", include_bytes!("../../tests/data/tzif/Australia/Adelaide").as_slice()), ("Australia/Brisbane", include_bytes!("../../tests/data/tzif/Australia/Brisbane").as_slice()), ("Australia/Broken_Hill", include_bytes!("../../tests/data/tzif/Australia/Broken_Hill").as_slice()), ("Australia/Darwin", include_bytes!("../../tests/data/tzif/Australia/Darwin").as_slice()), ("Australia/Eucla", include_bytes!("../../tests/data/tzif/Australia/Eucla").as_slice()), ("Australia/Hobart", include_bytes!("../../tests/data/tzif/Australia/Hobart").as_slice()), ("Australia/Lindeman", include_bytes!("../../tests/data/tzif/Australia/Lindeman").as_slice()), ("Australia/Lord_Howe", include_bytes!("../../tests/data/tzif/Australia/Lord_Howe").as_slice()), ("Australia/Melbourne", include_bytes!("../../tests/data/tzif/Australia/Melbourne").as_slice()), ("Australia/Perth", include_bytes!("../../tests/data/tzif/Australia/Perth").as_slice()), ("Australia/Sydney", include_bytes!("../../tests/data/tzif/Australia/Sydney").as_slice()), ("CST6CDT", include_bytes!("../../tests/data/tzif/CST6CDT").as_slice()), ("EST5EDT", include_bytes!("../../tests/data/tzif/EST5EDT").as_slice()), ("Etc/GMT", include_bytes!("../../tests/data/tzif/Etc/GMT").as_slice()), ("Etc/GMT+1", include_bytes!("../../tests/data/tzif/Etc/GMT+1").as_slice()), ("Etc/GMT+10", include_bytes!("../../tests/data/tzif/Etc/GMT+10").as_slice()), ("Etc/GMT+11", include_bytes!("../../tests/data/tzif/Etc/GMT+11").as_slice()), ("Etc/GMT+12", include_bytes!("../../tests/data/tzif/Etc/GMT+12").as_slice()), ("Etc/GMT+2", include_bytes!("../../tests/data/tzif/Etc/GMT+2").as_slice()), ("Etc/GMT+3", include_bytes!("../../tests/data/tzif/Etc/GMT+3").as_slice()), ("Etc/GMT+4", include_bytes!("../../tests/data/tzif/Etc/GMT+4").as_slice()), ("Etc/GMT+5", include_bytes!("../../tests/data/tzif/Etc/GMT+5").as_slice()), ("Etc/GMT+6", include_bytes!("../../tests/data/tzif/Etc/GMT+6").as_slice()), ("Etc/GMT+7", include_bytes!("../../tests/data/tzif/Etc/GMT+7").as_slice()), ("Etc/GMT+8", include_bytes!("../../tests/data/tzif/Etc/GMT+8").as_slice()), ("Etc/GMT+9", include_bytes!("../../tests/data/tzif/Etc/GMT+9").as_slice()), ("Etc/GMT-1", include_bytes!("../../tests/data/tzif/Etc/GMT-1").as_slice()), ("Etc/GMT-10", include_bytes!("../../tests/data/tzif/Etc/GMT-10").as_slice()), ("Etc/GMT-11", include_bytes!("../../tests/data/tzif/Etc/GMT-11").as_slice()), ("Etc/GMT-12", include_bytes!("../../tests/data/tzif/Etc/GMT-12").as_slice()), ("Etc/GMT-13", include_bytes!("../../tests/data/tzif/Etc/GMT-13").as_slice()), ("Etc/GMT-14", include_bytes!("../../tests/data/tzif/Etc/GMT-14").as_slice()), ("Etc/GMT-2", include_bytes!("../../tests/data/tzif/Etc/GMT-2").as_slice()), ("Etc/GMT-3", include_bytes!("../../tests/data/tzif/Etc/GMT-3").as_slice()), ("Etc/GMT-4", include_bytes!("../../tests/data/tzif/Etc/GMT-4").as_slice()), ("Etc/GMT-5", include_bytes!("../../tests/data/tzif/Etc/GMT-5").as_slice()), ("Etc/GMT-6", include_bytes!("../../tests/data/tzif/Etc/GMT-6").as_slice()), ("Etc/GMT-7", include_bytes!("../../tests/data/tzif/Etc/GMT-7").as_slice()), ("Etc/GMT-8", include_bytes!("../../tests/data/tzif/Etc/GMT-8").as_slice()), ("Etc/GMT-9", include_bytes!("../../tests/data/tzif/Etc/GMT-9").as_slice()), ("Etc/UTC", include_bytes!("../../tests/data/tzif/Etc/UTC").as_slice()), ("Europe/Amsterdam", include_bytes!("../../tests/data/tzif/Europe/Amsterdam").as_slice()), ("Europe/Andorra", include_bytes!("../../tests/data/tzif/Europe/Andorra").as_slice()), ("Europe/Astrakhan", include_bytes!("../../tests/data/tzif/Europe/Astrakhan").as_slice()), ("Europe/Athens", include_bytes!("../../tests/data/tzif/Europe/Athens").as_slice()), ("Europe/Belgrade", include_bytes!("../../tests/data/tzif/Europe/Belgrade").as_slice()), ("Europe/Berlin", include_bytes!("../../tests/data/tzif/Europe/Berlin").as_slice()), ("Europe/Bratislava", include_bytes!("../../tests/data/tzif/Europe/Bratislava").as_slice()), ("Europe/Brussels", include_bytes!("../../tests/data/tzif/Europe/Brussels").as_slice()), ("Europe/Bucharest", include_bytes!("../../tests/data/tzif/Europe/Bucharest").as_slice()), ("Europe/Budapest", include_bytes!("../../tests/data/tzif/Europe/Budapest").as_slice()), ("Europe/Busingen", include_bytes!("../../tests/data/tzif/Europe/Busingen").as_slice()), ("Europe/Chisinau", include_bytes!("../../tests/data/tzif/Europe/Chisinau").as_slice()), ("Europe/Copenhagen", include_bytes!("../../tests/data/tzif/Europe/Copenhagen").as_slice()), ("Europe/Dublin", include_bytes!("../../tests/data/tzif/Europe/Dublin").as_slice()), ("Europe/Gibraltar", include_bytes!("../../tests/data/tzif/Europe/Gibraltar").as_slice()), ("Europe/Guernsey", include_bytes!("../../tests/data/tzif/Europe/Guernsey").as_slice()), ("Europe/Helsinki", include_bytes!("../../tests/data/tzif/Europe/Helsinki").as_slice()), ("Europe/Isle_of_Man", include_bytes!("../../tests/data/tzif/Europe/Isle_of_Man").as_slice()), ("Europe/Istanbul", include_bytes!("../../tests/data/tzif/Europe/Istanbul").as_slice()), ("Europe/Jersey", include_bytes!("../../tests/data/tzif/Europe/Jersey").as_slice()), ("Europe/Kaliningrad", include_bytes!("../../tests/data/tzif/Europe/Kaliningrad").as_slice()), ("Europe/Kirov", include_bytes!("../../tests/data/tzif/Europe/Kirov").as_slice()), ("Europe/Kyiv", include_bytes!("../../tests/data/tzif/Europe/Kyiv").as_slice()), ("Europe/Lisbon", include_bytes!("../../tests/data/tzif/Europe/Lisbon").as_slice()), ("Europe/Ljubljana", include_bytes!("../../tests/data/tzif/Europe/Ljubljana").as_slice()), ("Europe/London", include_bytes!("../../tests/data/tzif/Europe/London").as_slice()), ("Europe/Luxembourg", include_bytes!("../../tests/data/tzif/Europe/Luxembourg").as_slice()), ("Europe/Madrid", include_bytes!("../../tests/data/tzif/Europe/Madrid").as_slice()), ("Europe/Malta", include_bytes!("../../tests/data/tzif/Europe/Malta").as_slice()), ("Europe/Mariehamn", include_bytes!("../../tests/data/tzif/Europe/Mariehamn").as_slice()), ("Europe/Minsk", include_bytes!("../../tests/data/tzif/Europe/Minsk").as_slice()), ("Europe/Monaco", include_bytes!("../../tests/data/tzif/Europe/Monaco").as_slice()), ("Europe/Moscow", include_bytes!("../../tests/data/tzif/Europe/Moscow").as_slice()), ("Europe/Oslo", include_bytes!("../../tests/data/tzif/Europe/Oslo").as_slice()), ("Europe/Paris", include_bytes!("../../tests/data/tzif/Europe/Paris").as_slice()), ("Europe/Podgorica", include_bytes!("../../tests/data/tzif/Europe/Podgorica").as_slice()), ("Europe/Prague", include_bytes!("../../tests/data/tzif/Europe/Prague").as_slice()), ("Europe/Riga", include_bytes!("../../tests/data/tzif/Europe/Riga").as_slice()), ("Europe/Rome", include_bytes!("../../tests/data/tzif/Europe/Rome").as_slice()), ("Europe/Samara", include_bytes!("../../tests/data/tzif/Europe/Samara").as_slice()), ("Europe/San_Marino", include_bytes!("../../tests/data/tzif/Europe/San_Marino").as_slice()), ("Europe/Sarajevo", include_bytes!("../../tests/data/tzif/Europe/Sarajevo").as_slice()), ("Europe/Saratov", include_bytes!("../../tests/data/tzif/Europe/Saratov").as_slice()), ("Europe/Simferopol", include_bytes!("../../tests/data/tzif/Europe/Simferopol").as_slice()), ("Europe/Skopje", include_bytes!("../../tests/data/tzif/Europe/Skopje").as_slice()), ("Europe/Sofia", include_bytes!("../../tests/data/tzif/Europe/Sofia").as_slice()), ("Europe/Stockholm", include_bytes!("../../tests/data/tzif/Europe/Stockholm").as_slice()), ("Europe/Tallinn", include_bytes!("../../tests/data/tzif/Europe/Tallinn").as_slice()), ("Europe/Tirane", include_bytes!("../../tests/data/tzif/Europe/Tirane").as_slice()), ("Europe/Ulyanovsk", include_bytes!("../../tests/data/tzif/Europe/Ulyanovsk").as_slice()), ("Europe/Vaduz", include_bytes!("../../tests/data/tzif/Europe/Vaduz").as_slice()), ("Europe/Vatican", include_bytes!("../../tests/data/tzif/Europe/Vatican").as_slice()), ("Europe/Vienna", include_bytes!("../../tests/data/tzif/Europe/Vienna").as_slice()), ("Europe/Vilnius", include_bytes!("../../tests/data/tzif/Europe/Vilnius").as_slice()), ("Europe/Volgograd", include_bytes!("../../tests/data/tzif/Europe/Volgograd").as_slice()), ("Europe/Warsaw", include_bytes!("../../tests/data/tzif/Europe/Warsaw").as_slice()), ("Europe/Zagreb", include_bytes!("../../tests/data/tzif/Europe/Zagreb").as_slice()), ("Europe/Zurich", include_bytes!("../../tests/data/tzif/Europe/Zurich").as_slice()), ("Indian/Antananarivo", include_bytes!("../../tests/data/tzif/Indian/Antananarivo").as_slice()), ("Indian/Chagos", include_bytes!("../../tests/data/tzif/Indian/Chagos").as_slice()), ("Indian/Christmas", include_bytes!("../../tests/data/tzif/Indian/Christmas").as_slice()), ("Indian/Cocos", include_bytes!("../../tests/data/tzif/Indian/Cocos").as_slice()), ("Indian/Comoro", include_bytes!("../../tests/data/tzif/Indian/Comoro").as_slice()), ("Indian/Kerguelen", include_bytes!("../../tests/data/tzif/Indian/Kerguelen").as_slice()), ("Indian/Mahe", include_bytes!("../../tests/data/tzif/Indian/Mahe").as_slice()), ("Indian/Maldives", include_bytes!("../../tests/data/tzif/Indian/Maldives").as_slice()), ("Indian/Mauritius", include_bytes!("../../tests/data/tzif/Indian/Mauritius").as_slice()), ("Indian/Mayotte", include_bytes!("../../tests/data/tzif/Indian/Mayotte").as_slice()), ("Indian/Reunion", include_bytes!("../../tests/data/tzif/Indian/Reunion").as_slice()), ("MST7MDT", include_bytes!("../../tests/data/tzif/MST7MDT").as_slice()), ("PST8PDT", include_bytes!("../../tests/data/tzif/PST8PDT").as_slice()), ("Pacific/Apia", include_bytes!("../../tests/data/tzif/Pacific/Apia").as_slice()), ("Pacific/Auckland", include_bytes!("../../tests/data/tzif/Pacific/Auckland").as_slice()), ("Pacific/Bougainville", include_bytes!("../../tests/data/tzif/Pacific/Bougainville").as_slice()), ("Pacific/Chatham", include_bytes!("../../tests/data/tzif/Pacific/Chatham").as_slice()), ("Pacific/Chuuk", include_bytes!("../../tests/data/tzif/Pacific/Chuuk").as_slice()), ("Pacific/Easter", include_bytes!("../../tests/data/tzif/Pacific/Easter").as_slice()), ("Pacific/Efate", include_bytes!("../../tests/data/tzif/Pacific/Efate").as_slice()), ("Pacific/Fakaofo", include_bytes!("../../tests/data/tzif/Pacific/Fakaofo").as_slice()), ("Pacific/Fiji", include_bytes!("../../tests/data/tzif/Pacific/Fiji").as_slice()), ("Pacific/Funafuti", include_bytes!("../../tests/data/tzif/Pacific/Funafuti").as_slice()), ("Pacific/Galapagos", include_bytes!("../../tests/data/tzif/Pacific/Galapagos").as_slice()), ("Pacific/Gambier", include_bytes!("../../tests/data/tzif/Pacific/Gambier").as_slice()), ("Pacific/Guadalcanal", include_bytes!("../../tests/data/tzif/Pacific/Guadalcanal").as_slice()), ("Pacific/Guam", include_bytes!("../../tests/data/tzif/Pacific/Guam").as_slice()), ("Pacific/Honolulu", include_bytes!("../../tests/data/tzif/Pacific/Honolulu").as_slice()), ("Pacific/Kanton", include_bytes!("../../tests/data/tzif/Pacific/Kanton").as_slice()), ("Pacific/Kiritimati", include_bytes!("../../tests/data/tzif/Pacific/Kiritimati").as_slice()), ("Pacific/Kosrae", include_bytes!("../../tests/data/tzif/Pacific/Kosrae").as_slice()), ("Pacific/Kwajalein", include_bytes!("../../tests/data/tzif/Pacific/Kwajalein").as_slice()), ("Pacific/Majuro", include_bytes!("../../tests/data/tzif/Pacific/Majuro").as_slice()), ("Pacific/Marquesas", include_bytes!("../../tests/data/tzif/Pacific/Marquesas").as_slice()), ("Pacific/Midway", include_bytes!("../../tests/data/tzif/Pacific/Midway").as_slice()), ("Pacific/Nauru", include_bytes!("../../tests/data/tzif/Pacific/Nauru").as_slice()), ("Pacific/Niue", include_bytes!("../../tests/data/tzif/Pacific/Niue").as_slice()), ("Pacific/Norfolk", include_bytes!("../../tests/data/tzif/Pacific/Norfolk").as_slice()), ("Pacific/Noumea", include_bytes!("../../tests/data/tzif/Pacific/Noumea").as_slice()), ("Pacific/Pago_Pago", include_bytes!("../../tests/data/tzif/Pacific/Pago_Pago").as_slice()), ("Pacific/Palau", include_bytes!("../../tests/data/tzif/Pacific/Palau").as_slice()), ("Pacific/Pitcairn", include_bytes!("../../tests/data/tzif/Pacific/Pitcairn").as_slice()), ("Pacific/Pohnpei", include_bytes!("../../tests/data/tzif/Pacific/Pohnpei").as_slice()), ("Pacific/Port_Moresby", include_bytes!("../../tests/data/tzif/Pacific/Port_Moresby").as_slice()), ("Pacific/Rarotonga", include_bytes!("../../tests/data/tzif/Pacific/Rarotonga").as_slice()), ("Pacific/Saipan", include_bytes!("../../tests/data/tzif/Pacific/Saipan").as_slice()), ("Pacific/Tahiti", include_bytes!("../../tests/data/tzif/Pacific/Tahiti").as_slice()), ("Pacific/Tarawa", include_bytes!("../../tests/data/tzif/Pacific/Tarawa").as_slice()), ("Pacific/Tongatapu", include_bytes!("../../tests/data/tzif/Pacific/Tongatapu").as_slice()), ("Pacific/Wake", include_bytes!("../../tests/data/tzif/Pacific/Wake").as_slice()), ("Pacific/Wallis", include_bytes!("../../tests/data/tzif/Pacific/Wallis").as_slice())].into_iter().collect(),
+                    )))),
                     ..DatagenProvider::new_custom().source
                 },
             })
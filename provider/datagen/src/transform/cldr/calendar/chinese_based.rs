@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use calendrical_calculations::chinese_based::{Chinese, ChineseBased, Dangi};
+use calendrical_calculations::chinese_based::{Chinese, ChineseBased, Dangi, Vietnamese};
 use icu_calendar::provider::chinese_based::*;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
@@ -38,6 +38,17 @@ impl DataProvider<DangiCacheV1Marker> for crate::DatagenProvider {
     }
 }
 
+impl DataProvider<VietnameseCacheV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<VietnameseCacheV1Marker>, DataError> {
+        self.check_req::<VietnameseCacheV1Marker>(req)?;
+        let cache = load::<Vietnamese>();
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(cache)),
+        })
+    }
+}
+
 impl IterableDataProvider<ChineseCacheV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
         Ok(vec![Default::default()])
@@ -49,3 +60,9 @@ impl IterableDataProvider<DangiCacheV1Marker> for crate::DatagenProvider {
         Ok(vec![Default::default()])
     }
 }
+
+impl IterableDataProvider<VietnameseCacheV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
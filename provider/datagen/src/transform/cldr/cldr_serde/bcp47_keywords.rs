@@ -0,0 +1,51 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON bcp47 keyword files, such as calendar.json.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-bcp47/bcp47/calendar.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct KeyValue {
+    #[serde(rename = "_description")]
+    pub description: Option<String>,
+    #[serde(rename = "_deprecated")]
+    pub deprecated: Option<bool>,
+    #[serde(rename = "_preferred")]
+    pub preferred: Option<String>,
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+    #[serde(rename = "_since")]
+    pub since: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Key {
+    #[serde(rename = "_description")]
+    pub description: String,
+    #[serde(rename = "_deprecated")]
+    pub deprecated: Option<bool>,
+    #[serde(rename = "_preferred")]
+    pub preferred: Option<String>,
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+    #[serde(rename = "_since")]
+    pub since: Option<String>,
+    /// `single` if absent, otherwise `multiple`, `incremental`, or `any`.
+    #[serde(rename = "_valueType")]
+    pub value_type: Option<String>,
+    /// Keyed by the value, or an uppercase name for a class of values such as `CODEPOINTS`.
+    #[serde(flatten)]
+    pub values: BTreeMap<String, KeyValue>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    /// Keyed by the extension (`u` or `t`), then by the key.
+    pub keyword: BTreeMap<char, BTreeMap<String, Key>>,
+}
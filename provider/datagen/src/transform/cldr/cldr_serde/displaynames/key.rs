@@ -0,0 +1,39 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON display name files for locale patterns, keys, and types.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/localeDisplayNames.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocaleDisplayPattern {
+    #[serde(rename = "localePattern")]
+    pub locale_pattern: String,
+    #[serde(rename = "localeSeparator")]
+    pub locale_separator: String,
+    #[serde(rename = "localeKeyTypePattern")]
+    pub locale_key_type_pattern: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Keys {
+    #[serde(rename = "localeDisplayPattern")]
+    pub locale_display_pattern: LocaleDisplayPattern,
+    #[serde(default)]
+    pub keys: HashMap<String, String>,
+    #[serde(default)]
+    pub types: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangDisplayNames {
+    #[serde(rename = "localeDisplayNames")]
+    pub localedisplaynames: Keys,
+}
+
+pub type Resource = super::super::LocaleResource<LangDisplayNames>;
@@ -0,0 +1,31 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON grammaticalFeatures.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/grammaticalFeatures.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct GrammaticalFeatures {
+    #[serde(rename = "grammaticalCase", default)]
+    pub cases: Vec<String>,
+    #[serde(rename = "grammaticalGender", default)]
+    pub genders: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// Keyed by strings of the form `<language>-targets-<target>`, such as `de-targets-nominal`.
+    #[serde(rename = "grammaticalData")]
+    pub grammatical_data: BTreeMap<String, GrammaticalFeatures>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
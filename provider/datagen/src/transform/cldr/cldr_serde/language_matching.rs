@@ -0,0 +1,50 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON languageMatching.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/languageMatching.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LanguageMatchingItem {
+    ParadigmLocales {
+        #[serde(rename = "_locales")]
+        locales: String,
+    },
+    MatchVariable {
+        #[serde(rename = "_value")]
+        value: String,
+    },
+    LanguageMatch {
+        #[serde(rename = "_desired")]
+        desired: String,
+        #[serde(rename = "_distance")]
+        distance: String,
+        #[serde(rename = "_oneway")]
+        oneway: Option<String>,
+    },
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LanguageMatching {
+    /// Single-entry maps, keyed by `paradigmLocales`, the name of a match
+    /// variable, or the supported locale of a match.
+    pub written_new: Vec<HashMap<String, LanguageMatchingItem>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "languageMatching")]
+    pub language_matching: LanguageMatching,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -8,6 +8,7 @@
 //! deserialization support. These structures can be used in the transformers.
 
 pub mod aliases;
+pub mod bcp47_keywords;
 pub mod ca;
 pub mod coverage_levels;
 #[cfg(test)] // keep as test until baked data is needed
@@ -19,7 +20,10 @@ pub mod directionality;
 #[cfg(feature = "icu_displaynames")]
 pub mod displaynames;
 pub mod exemplar_chars;
+#[cfg(test)] // keep as test until baked data is needed
+pub mod grammatical_features;
 pub mod japanese;
+pub mod language_matching;
 pub mod likely_subtags;
 pub mod list_patterns;
 pub mod locale_resource;
@@ -28,11 +32,16 @@ pub mod numbers;
 pub mod parent_locales;
 pub mod plural_ranges;
 pub mod plurals;
+pub mod segments;
+pub mod territory_containment;
 pub mod time_zones;
 #[cfg(feature = "icu_transliterate")]
 pub mod transforms;
+#[cfg(test)] // keep as test until baked data is needed
+pub mod unit_names;
 #[cfg(feature = "icu_unitsconversion")]
 pub mod units;
+pub mod validity;
 pub mod week_data;
 
 use locale_resource::LocaleResource;
@@ -4,4 +4,6 @@
 
 pub mod bcp47_tzid;
 pub mod meta_zones;
+pub mod primary_zones;
 pub mod time_zone_names;
+pub mod windows_zones;
@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON windowsZones.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/windowsZones.json>
+
+use super::meta_zones::MapZone;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct MapTimezone {
+    #[serde(rename = "mapZone")]
+    pub map_zone: MapZone,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WindowsZones {
+    #[serde(rename = "mapTimezones")]
+    pub map_timezones: Vec<MapTimezone>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "windowsZones")]
+    pub windows_zones: WindowsZones,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -0,0 +1,29 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/de/units.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The fields of a unit, such as `displayName`, `gender`, and
+/// `unitPattern-count-one-case-dative`.
+///
+/// Compound patterns such as `per` and `times` use the same representation.
+pub type UnitFields = BTreeMap<String, String>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    pub long: BTreeMap<String, UnitFields>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangUnits {
+    pub units: Units,
+}
+
+pub type Resource = super::LocaleResource<LangUnits>;
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON validity files, such as `validity/language.json`.
+//!
+//! These correspond to CLDR's `common/validity/*.xml` files. Each status lists codes or
+//! ranges of codes, where `aa~d` is short for `aa ab ac ad`.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct IdValidity {
+    #[serde(default)]
+    pub regular: Vec<String>,
+    #[serde(default)]
+    pub special: Vec<String>,
+    #[serde(default)]
+    pub macroregion: Vec<String>,
+    #[serde(default)]
+    pub deprecated: Vec<String>,
+    #[serde(default)]
+    pub private_use: Vec<String>,
+    #[serde(default)]
+    pub reserved: Vec<String>,
+    #[serde(default)]
+    pub unknown: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    /// Keyed by the type of code, such as `language` or `region`.
+    #[serde(rename = "idValidity")]
+    pub id_validity: HashMap<String, IdValidity>,
+}
@@ -0,0 +1,274 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use icu_displaynames::provider::*;
+use icu_locid::extensions::unicode::Value;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use tinystr::TinyAsciiStr;
+use zerovec::ule::UnvalidatedStr;
+
+impl DataProvider<KeyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<KeyDisplayNamesV1Marker>, DataError> {
+        self.check_req::<KeyDisplayNamesV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::key::Resource = self
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "localeDisplayNames.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(KeyDisplayNamesV1::from(data))),
+        })
+    }
+}
+
+impl DataProvider<LocaleDisplayNamesPatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LocaleDisplayNamesPatternsV1Marker>, DataError> {
+        self.check_req::<LocaleDisplayNamesPatternsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::key::Resource = self
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "localeDisplayNames.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(LocaleDisplayNamesPatternsV1::from(
+                data,
+            ))),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<KeyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .displaynames()
+            .list_langs()?
+            .filter(|langid| {
+                // The directory might exist without localeDisplayNames.json
+                self.cldr()
+                    .unwrap()
+                    .displaynames()
+                    .file_exists(langid, "localeDisplayNames.json")
+                    .unwrap_or_default()
+            })
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl IterableDataProviderInternal<LocaleDisplayNamesPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .displaynames()
+            .list_langs()?
+            .filter(|langid| {
+                // The directory might exist without localeDisplayNames.json
+                self.cldr()
+                    .unwrap()
+                    .displaynames()
+                    .file_exists(langid, "localeDisplayNames.json")
+                    .unwrap_or_default()
+            })
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Substring used to denote alternative display names data variants for a given key or type. For example: "gregorian-alt-short".
+const ALT_SUBSTRING: &str = "-alt-";
+
+/// LDML key names that differ from their BCP-47 keys.
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("calendar", "ca"),
+    ("colAlternate", "ka"),
+    ("colBackwards", "kb"),
+    ("colCaseFirst", "kf"),
+    ("colCaseLevel", "kc"),
+    ("colHiraganaQuaternary", "kh"),
+    ("colNormalization", "kk"),
+    ("colNumeric", "kn"),
+    ("colReorder", "kr"),
+    ("colStrength", "ks"),
+    ("collation", "co"),
+    ("currency", "cu"),
+    ("numbers", "nu"),
+    ("timezone", "tz"),
+    ("variableTop", "vt"),
+];
+
+/// LDML type names that differ from their BCP-47 values, by BCP-47 key.
+const TYPE_ALIASES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+    ("ka", "non-ignorable", "noignore"),
+    ("ks", "identical", "identic"),
+    ("ks", "primary", "level1"),
+    ("ks", "quaternary", "level4"),
+    ("ks", "secondary", "level2"),
+    ("ks", "tertiary", "level3"),
+];
+
+/// Returns the BCP-47 key of an LDML key name, such as `ca` for `calendar`.
+fn bcp47_key(key: &str) -> Option<TinyAsciiStr<2>> {
+    let key = KEY_ALIASES
+        .iter()
+        .find(|&&(ldml, _)| ldml == key)
+        .map(|&(_, bcp47)| bcp47)
+        .unwrap_or(key);
+    TinyAsciiStr::from_str(key)
+        .ok()
+        .filter(|k: &TinyAsciiStr<2>| k.is_ascii_alphanumeric() && k.is_ascii_lowercase())
+}
+
+/// Returns the BCP-47 value of an LDML type name, such as `gregory` for `gregorian`.
+fn bcp47_type(key: TinyAsciiStr<2>, ty: &str) -> Option<Cow<str>> {
+    let ty = if let Some(&(_, _, bcp47)) = TYPE_ALIASES
+        .iter()
+        .find(|&&(k, ldml, _)| k == key.as_str() && ldml == ty)
+    {
+        Cow::Borrowed(bcp47)
+    } else if key.starts_with('k') && ty == "yes" {
+        Cow::Borrowed("true")
+    } else if key.starts_with('k') && ty == "no" {
+        Cow::Borrowed("false")
+    } else if ty.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(ty.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(ty)
+    };
+    // Values of transform keys have the same syntax
+    Value::try_from_bytes(ty.as_bytes()).ok().map(|_| ty)
+}
+
+impl From<&cldr_serde::displaynames::key::Resource> for KeyDisplayNamesV1<'static> {
+    fn from(other: &cldr_serde::displaynames::key::Resource) -> Self {
+        let mut names = BTreeMap::new();
+        for (key, name) in other.main.value.localedisplaynames.keys.iter() {
+            if key.contains(ALT_SUBSTRING) {
+                continue;
+            }
+            if let Some(key) = bcp47_key(key) {
+                names.insert(key.to_unvalidated(), name.as_str());
+            }
+        }
+
+        let mut type_names = BTreeMap::new();
+        for (key, types) in other.main.value.localedisplaynames.types.iter() {
+            let Some(key) = bcp47_key(key) else {
+                continue;
+            };
+            for (ty, name) in types.iter() {
+                if ty.contains(ALT_SUBSTRING) {
+                    continue;
+                }
+                if let Some(ty) = bcp47_type(key, ty) {
+                    type_names.insert((key.to_unvalidated(), ty.into_owned()), name.as_str());
+                }
+            }
+        }
+
+        Self {
+            names: names.into_iter().collect(),
+            type_names: type_names
+                .iter()
+                .map(|((key, ty), name)| (key, UnvalidatedStr::from_str(ty), *name))
+                .collect(),
+        }
+    }
+}
+
+impl From<&cldr_serde::displaynames::key::Resource> for LocaleDisplayNamesPatternsV1<'static> {
+    fn from(other: &cldr_serde::displaynames::key::Resource) -> Self {
+        let patterns = &other.main.value.localedisplaynames.locale_display_pattern;
+        Self {
+            locale_pattern: Cow::Owned(patterns.locale_pattern.clone()),
+            locale_separator: Cow::Owned(patterns.locale_separator.clone()),
+            locale_key_type_pattern: Cow::Owned(patterns.locale_key_type_pattern.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::{extensions::unicode::key, locale};
+
+    #[test]
+    fn test_basic_key_display_names() {
+        let provider = crate::DatagenProvider::new_testing();
+
+        let data: DataPayload<KeyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(
+            data.get()
+                .names
+                .get(&key!("ca").into_tinystr().to_unvalidated())
+                .unwrap(),
+            "Calendar"
+        );
+        assert_eq!(
+            data.get()
+                .type_names
+                .get_2d(
+                    &key!("co").into_tinystr().to_unvalidated(),
+                    UnvalidatedStr::from_str("phonebk")
+                )
+                .unwrap(),
+            "Phonebook Sort Order"
+        );
+        assert_eq!(
+            data.get()
+                .type_names
+                .get_2d(
+                    &key!("kn").into_tinystr().to_unvalidated(),
+                    UnvalidatedStr::from_str("true")
+                )
+                .unwrap(),
+            "Sort Digits Numerically"
+        );
+    }
+
+    #[test]
+    fn test_basic_locale_display_names_patterns() {
+        let provider = crate::DatagenProvider::new_testing();
+
+        let data: DataPayload<LocaleDisplayNamesPatternsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("und").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().locale_pattern, "{0} ({1})");
+        assert_eq!(data.get().locale_separator, "{0}, {1}");
+        assert_eq!(data.get().locale_key_type_pattern, "{0}: {1}");
+    }
+}
@@ -0,0 +1,183 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use icu_dimension::grammar::{GrammaticalCase, GrammaticalGender};
+use icu_dimension::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Parses a CLDR grammatical case, such as `dative`.
+pub(in crate::transform::cldr) fn parse_case(case: &str) -> Result<GrammaticalCase, DataError> {
+    use GrammaticalCase::*;
+    Ok(match case {
+        "nominative" => Nominative,
+        "abessive" => Abessive,
+        "ablative" => Ablative,
+        "accusative" => Accusative,
+        "adessive" => Adessive,
+        "allative" => Allative,
+        "causal" => Causal,
+        "comitative" => Comitative,
+        "dative" => Dative,
+        "delative" => Delative,
+        "elative" => Elative,
+        "ergative" => Ergative,
+        "essive" => Essive,
+        "genitive" => Genitive,
+        "illative" => Illative,
+        "inessive" => Inessive,
+        "instrumental" => Instrumental,
+        "locative" => Locative,
+        "locativecopulative" => LocativeCopulative,
+        "oblique" => Oblique,
+        "partitive" => Partitive,
+        "prepositional" => Prepositional,
+        "sociative" => Sociative,
+        "sublative" => Sublative,
+        "superessive" => Superessive,
+        "terminative" => Terminative,
+        "translative" => Translative,
+        "vocative" => Vocative,
+        _ => return Err(DataError::custom("Unknown grammatical case").with_display_context(case)),
+    })
+}
+
+/// Parses a CLDR grammatical gender, such as `feminine`.
+pub(in crate::transform::cldr) fn parse_gender(
+    gender: &str,
+) -> Result<GrammaticalGender, DataError> {
+    use GrammaticalGender::*;
+    Ok(match gender {
+        "animate" => Animate,
+        "inanimate" => Inanimate,
+        "personal" => Personal,
+        "common" => Common,
+        "feminine" => Feminine,
+        "masculine" => Masculine,
+        "neuter" => Neuter,
+        _ => {
+            return Err(DataError::custom("Unknown grammatical gender").with_display_context(gender))
+        }
+    })
+}
+
+impl crate::DatagenProvider {
+    /// Returns the grammatical features of nouns, keyed by language.
+    fn nominal_grammatical_features(
+        &self,
+    ) -> Result<
+        HashMap<LanguageIdentifier, &cldr_serde::grammatical_features::GrammaticalFeatures>,
+        DataError,
+    > {
+        self.cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::grammatical_features::Resource>(
+                "supplemental/grammaticalFeatures.json",
+            )?
+            .supplemental
+            .grammatical_data
+            .iter()
+            .filter_map(|(key, features)| {
+                let lang = key.strip_suffix("-targets-nominal")?;
+                Some(
+                    lang.parse().map(|lang| (lang, features)).map_err(|_| {
+                        DataError::custom("Invalid language").with_display_context(lang)
+                    }),
+                )
+            })
+            .collect()
+    }
+}
+
+impl DataProvider<GrammaticalFeaturesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<GrammaticalFeaturesV1Marker>, DataError> {
+        self.check_req::<GrammaticalFeaturesV1Marker>(req)?;
+
+        let data = if req.locale.is_und() {
+            // `und` has no grammatical features
+            GrammaticalFeaturesV1::default()
+        } else {
+            let features = self
+                .nominal_grammatical_features()?
+                .remove(&req.locale.get_langid())
+                .ok_or(DataErrorKind::MissingLocale.into_error())?;
+            let mut cases = features
+                .cases
+                .iter()
+                .map(|case| parse_case(case))
+                .collect::<Result<Vec<_>, _>>()?;
+            cases.sort();
+            let mut genders = features
+                .genders
+                .iter()
+                .map(|gender| parse_gender(gender))
+                .collect::<Result<Vec<_>, _>>()?;
+            genders.sort();
+            GrammaticalFeaturesV1 {
+                cases: cases.into_iter().collect(),
+                genders: genders.into_iter().collect(),
+            }
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<GrammaticalFeaturesV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .nominal_grammatical_features()?
+            .into_keys()
+            .map(DataLocale::from)
+            .chain([DataLocale::default()]) // `und` is not included in the grammatical data.
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let ru: DataPayload<GrammaticalFeaturesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(ru.get().cases.iter().any(|c| c == GrammaticalCase::Dative));
+    assert_eq!(ru.get().cases.first(), Some(GrammaticalCase::Nominative));
+    assert_eq!(
+        ru.get().genders.iter().collect::<Vec<_>>(),
+        [
+            GrammaticalGender::Feminine,
+            GrammaticalGender::Masculine,
+            GrammaticalGender::Neuter
+        ]
+    );
+
+    let fr: DataPayload<GrammaticalFeaturesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(fr.get().cases.is_empty());
+    assert_eq!(fr.get().genders.len(), 2);
+}
@@ -0,0 +1,112 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_locid_transform::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use tinystr::TinyAsciiStr;
+use zerovec::ule::UnvalidatedStr;
+
+impl DataProvider<ExtensionAliasesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ExtensionAliasesV1Marker>, DataError> {
+        self.check_req::<ExtensionAliasesV1Marker>(req)?;
+
+        let keywords = super::bcp47_keywords(self)?;
+        let aliases = extension_value_aliases(&keywords)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ExtensionAliasesV1 {
+                values: aliases
+                    .iter()
+                    .map(|((key, value), preferred)| {
+                        (key, UnvalidatedStr::from_str(value), preferred.as_str())
+                    })
+                    .collect(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<ExtensionAliasesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Whether `value` is syntactically a `-u-` or `-t-` extension value.
+fn is_extension_value(value: &str) -> bool {
+    value.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+/// Maps deprecated values to their preferred values, and legacy aliases to the values
+/// they are an alias of.
+fn extension_value_aliases(
+    keywords: &[&cldr_serde::bcp47_keywords::Resource],
+) -> Result<BTreeMap<(tinystr::UnvalidatedTinyAsciiStr<2>, String), String>, DataError> {
+    let mut aliases = BTreeMap::new();
+
+    for (extension, extension_keys) in keywords.iter().flat_map(|r| r.keyword.iter()) {
+        if !matches!(extension, 'u' | 't') {
+            continue;
+        }
+        for (key, data) in extension_keys {
+            let key = TinyAsciiStr::<2>::from_str(key)
+                .map_err(|e| DataError::custom("Invalid key").with_display_context(&e))?
+                .to_unvalidated();
+            for (value, value_data) in &data.values {
+                if value_data.deprecated == Some(true) {
+                    if let Some(preferred) = value_data.preferred.as_ref() {
+                        aliases.insert((key, value.clone()), preferred.clone());
+                    }
+                    continue;
+                }
+                for alias in value_data
+                    .alias
+                    .iter()
+                    .flat_map(|a| a.split_ascii_whitespace())
+                    .map(|a| a.to_ascii_lowercase())
+                {
+                    if alias != *value
+                        && is_extension_value(&alias)
+                        && !data.values.contains_key(&alias)
+                    {
+                        aliases.insert((key, alias), value.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(aliases)
+}
+
+#[test]
+fn test_basic() {
+    let provider = crate::DatagenProvider::new_testing();
+    let data: DataPayload<ExtensionAliasesV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let alias = |key: &str, value: &str| {
+        data.get().values.get_2d(
+            &TinyAsciiStr::<2>::from_str(key).unwrap().to_unvalidated(),
+            UnvalidatedStr::from_str(value),
+        )
+    };
+    assert_eq!(alias("ca", "islamicc"), Some("islamic-civil"));
+    assert_eq!(alias("ca", "ethiopic-amete-alem"), Some("ethioaa"));
+    assert_eq!(alias("ms", "imperial"), Some("uksystem"));
+    assert_eq!(alias("tz", "aqams"), Some("nzakl"));
+    assert_eq!(alias("kb", "yes"), Some("true"));
+    assert_eq!(alias("m0", "names"), Some("prprname"));
+    assert_eq!(alias("tz", "eire"), Some("iedub"));
+    assert_eq!(alias("ca", "gregory"), None);
+}
@@ -0,0 +1,271 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::language_matching::LanguageMatchingItem;
+use icu_locid::LanguageIdentifier;
+use icu_locid_transform::provider::*;
+use icu_locid_transform::LocaleExpander;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use tinystr::{TinyAsciiStr, UnvalidatedTinyAsciiStr};
+
+impl DataProvider<LanguageMatchingV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LanguageMatchingV1Marker>, DataError> {
+        self.check_req::<LanguageMatchingV1Marker>(req)?;
+        let language_matching: &cldr_serde::language_matching::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+        let territory_containment: &cldr_serde::territory_containment::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+        let data = transform(
+            language_matching,
+            territory_containment,
+            self.cldr()?.extended_locale_expander()?,
+        )?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+impl IterableDataProvider<LanguageMatchingV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn transform(
+    language_matching: &cldr_serde::language_matching::Resource,
+    territory_containment: &cldr_serde::territory_containment::Resource,
+    expander: &LocaleExpander,
+) -> Result<LanguageMatchingV1<'static>, DataError> {
+    let containment = &territory_containment.supplemental.territory_containment;
+
+    let mut paradigm_locales = vec![];
+    let mut variables = HashMap::new();
+    let mut region_sets = vec![];
+    let mut languages = vec![];
+    let mut scripts = vec![];
+    let mut regions = vec![];
+
+    for item in &language_matching.supplemental.language_matching.written_new {
+        for (key, value) in item {
+            match value {
+                LanguageMatchingItem::ParadigmLocales { locales } => {
+                    for locale in locales.split_whitespace() {
+                        let mut langid = LanguageIdentifier::from_str(locale).map_err(|_| {
+                            DataError::custom("Invalid paradigm locale")
+                                .with_display_context(locale)
+                        })?;
+                        expander.maximize(&mut langid);
+                        paradigm_locales.push(langid.to_string());
+                    }
+                }
+                LanguageMatchingItem::MatchVariable { value } => {
+                    let mut regions = BTreeSet::new();
+                    for region in value.split('+') {
+                        expand_region(region, containment, &mut regions);
+                    }
+                    variables.insert(key.trim_start_matches('$'), regions);
+                }
+                LanguageMatchingItem::LanguageMatch {
+                    desired,
+                    distance,
+                    oneway,
+                } => {
+                    let mut parse = |pattern: &str| {
+                        parse_pattern(pattern, &variables, containment, &mut region_sets)
+                            .ok_or_else(|| {
+                                DataError::custom("Invalid language match pattern")
+                                    .with_display_context(pattern)
+                            })
+                    };
+                    let (desired, desired_len) = parse(desired)?;
+                    let (supported, supported_len) = parse(key)?;
+                    let rule = LanguageMatchRule {
+                        desired,
+                        supported,
+                        distance: distance.parse().map_err(|_| {
+                            DataError::custom("Invalid language match distance")
+                                .with_display_context(distance)
+                        })?,
+                        oneway: oneway.as_deref() == Some("true"),
+                    };
+                    match (desired_len, supported_len) {
+                        (1, 1) => languages.push(rule),
+                        (2, 2) => scripts.push(rule),
+                        (3, 3) => regions.push(rule),
+                        _ => {
+                            return Err(DataError::custom("Inconsistent language match")
+                                .with_display_context(key))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(LanguageMatchingV1 {
+        languages: languages.into_iter().collect(),
+        scripts: scripts.into_iter().collect(),
+        regions: regions.into_iter().collect(),
+        region_sets: (&region_sets).into(),
+        paradigm_locales: paradigm_locales.as_slice().into(),
+    })
+}
+
+/// Parses a pattern such as `en`, `zh_Hant`, or `en_*_$!enUS`, returning it together with
+/// its number of subtags.
+fn parse_pattern(
+    pattern: &str,
+    variables: &HashMap<&str, BTreeSet<UnvalidatedTinyAsciiStr<3>>>,
+    containment: &HashMap<String, cldr_serde::territory_containment::Containment>,
+    region_sets: &mut Vec<Vec<UnvalidatedTinyAsciiStr<3>>>,
+) -> Option<(LanguageMatchPattern, usize)> {
+    let mut subtags = pattern.split('_');
+    let language = TinyAsciiStr::<3>::from_str(subtags.next()?).ok()?;
+    let script = match subtags.next() {
+        Some(script) => Some(TinyAsciiStr::<4>::from_str(script).ok()?),
+        None => None,
+    };
+    let region = subtags.next();
+    if subtags.next().is_some() {
+        return None;
+    }
+
+    let mut negated = false;
+    let region_set = match region {
+        None | Some("*") => LanguageMatchPattern::ANY_REGION,
+        Some(region) => {
+            let set = if let Some(variable) = region.strip_prefix('$') {
+                let variable = if let Some(variable) = variable.strip_prefix('!') {
+                    negated = true;
+                    variable
+                } else {
+                    variable
+                };
+                variables.get(variable)?.clone()
+            } else {
+                let mut set = BTreeSet::new();
+                expand_region(region, containment, &mut set);
+                set
+            };
+            let set = set.into_iter().collect::<Vec<_>>();
+            let index = match region_sets.iter().position(|s| s == &set) {
+                Some(index) => index,
+                None => {
+                    region_sets.push(set);
+                    region_sets.len() - 1
+                }
+            };
+            u8::try_from(index)
+                .ok()
+                .filter(|&i| i != LanguageMatchPattern::ANY_REGION)?
+        }
+    };
+
+    Some((
+        LanguageMatchPattern {
+            language: language.to_unvalidated(),
+            script: script.unwrap_or(tinystr::tinystr!(4, "*")).to_unvalidated(),
+            region_set,
+            negated,
+        },
+        1 + usize::from(script.is_some()) + usize::from(region.is_some()),
+    ))
+}
+
+/// Adds the region and all regions it contains to `regions`, including macroregions from
+/// groupings such as `419`, but not deprecated ones.
+fn expand_region(
+    region: &str,
+    containment: &HashMap<String, cldr_serde::territory_containment::Containment>,
+    regions: &mut BTreeSet<UnvalidatedTinyAsciiStr<3>>,
+) {
+    let Ok(tinystr) = TinyAsciiStr::<3>::from_str(region) else {
+        return;
+    };
+    if !regions.insert(tinystr.to_unvalidated()) {
+        return;
+    }
+    for key in [region.to_string(), format!("{region}-status-grouping")] {
+        for child in containment.get(&key).into_iter().flat_map(|c| &c.contains) {
+            expand_region(child, containment, regions);
+        }
+    }
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr;
+
+    let provider = crate::DatagenProvider::new_testing();
+    let data: DataPayload<LanguageMatchingV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    // {"no": {"_desired": "nb", "_distance": "1"}}
+    assert_eq!(
+        data.get().languages.first(),
+        Some(LanguageMatchRule {
+            desired: LanguageMatchPattern {
+                language: tinystr!(3, "nb").to_unvalidated(),
+                script: tinystr!(4, "*").to_unvalidated(),
+                region_set: LanguageMatchPattern::ANY_REGION,
+                negated: false,
+            },
+            supported: LanguageMatchPattern {
+                language: tinystr!(3, "no").to_unvalidated(),
+                script: tinystr!(4, "*").to_unvalidated(),
+                region_set: LanguageMatchPattern::ANY_REGION,
+                negated: false,
+            },
+            distance: 1,
+            oneway: false,
+        })
+    );
+
+    // $americas is 019, which contains 419 through a grouping
+    let americas = data
+        .get()
+        .regions
+        .iter()
+        .find(|rule| rule.supported.language == tinystr!(3, "es").to_unvalidated())
+        .unwrap()
+        .supported
+        .region_set;
+    let americas = data.get().region_sets.get(usize::from(americas)).unwrap();
+    for region in [
+        tinystr!(3, "019"),
+        tinystr!(3, "419"),
+        tinystr!(3, "MX"),
+        tinystr!(3, "US"),
+    ] {
+        assert!(americas.binary_search(&region.to_unvalidated()).is_ok());
+    }
+    assert!(americas
+        .binary_search(&tinystr!(3, "ES").to_unvalidated())
+        .is_err());
+
+    assert_eq!(
+        data.get().paradigm_locales.iter().collect::<Vec<_>>(),
+        [
+            "en-Latn-US",
+            "en-Latn-GB",
+            "es-Latn-ES",
+            "es-Latn-419",
+            "pt-Latn-BR",
+            "pt-Latn-PT"
+        ]
+    );
+}
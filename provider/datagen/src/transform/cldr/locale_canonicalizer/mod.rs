@@ -4,4 +4,39 @@
 
 pub mod aliases;
 pub mod directionality;
+pub mod extension_aliases;
+pub mod language_matching;
 pub mod likely_subtags;
+pub mod validity;
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::DataError;
+
+/// The bcp47 files that define `-u-` and `-t-` extension keys.
+const BCP47_KEYWORD_FILES: &[&str] = &[
+    "calendar.json",
+    "collation.json",
+    "currency.json",
+    "measure.json",
+    "number.json",
+    "segmentation.json",
+    "timezone.json",
+    "transform.json",
+    "transform-destination.json",
+    "transform_hybrid.json",
+    "transform_ime.json",
+    "transform_keyboard.json",
+    "transform_mt.json",
+    "transform_private_use.json",
+    "variant.json",
+];
+
+fn bcp47_keywords(
+    provider: &crate::DatagenProvider,
+) -> Result<Vec<&cldr_serde::bcp47_keywords::Resource>, DataError> {
+    let bcp47 = provider.cldr()?.bcp47();
+    BCP47_KEYWORD_FILES
+        .iter()
+        .map(|file| bcp47.read_and_parse(file))
+        .collect()
+}
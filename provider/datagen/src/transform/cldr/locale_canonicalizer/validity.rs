@@ -0,0 +1,249 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_locid_transform::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use tinystr::TinyAsciiStr;
+use zerovec::ule::UnvalidatedStr;
+
+impl DataProvider<ValidityV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ValidityV1Marker>, DataError> {
+        self.check_req::<ValidityV1Marker>(req)?;
+
+        let id_validity = |kind: &str| -> Result<_, DataError> {
+            let resource: &cldr_serde::validity::Resource = self
+                .cldr()?
+                .core()
+                .read_and_parse(&format!("validity/{kind}.json"))?;
+            resource.id_validity.get(kind).ok_or_else(|| {
+                DataError::custom("Missing validity data").with_display_context(kind)
+            })
+        };
+
+        let keywords = super::bcp47_keywords(self)?;
+        let (keys, values, multiple_value_keys) = extension_statuses(&keywords)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ValidityV1 {
+                languages: subtag_statuses(id_validity("language")?, |s| s.to_string())?
+                    .into_iter()
+                    .collect(),
+                scripts: subtag_statuses(id_validity("script")?, |s| s.to_string())?
+                    .into_iter()
+                    .collect(),
+                regions: subtag_statuses(id_validity("region")?, |s| s.to_string())?
+                    .into_iter()
+                    .collect(),
+                variants: subtag_statuses(id_validity("variant")?, |s| s.to_ascii_lowercase())?
+                    .into_iter()
+                    .collect(),
+                keys: keys.into_iter().collect(),
+                values: values
+                    .into_iter()
+                    .map(|((key, value), status)| (key, UnvalidatedStr::from_str(value), status))
+                    .collect(),
+                multiple_value_keys: multiple_value_keys.into_iter().collect(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<ValidityV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Expands the codes of each status, where deprecated codes take precedence.
+fn subtag_statuses<const N: usize>(
+    validity: &cldr_serde::validity::IdValidity,
+    normalize: impl Fn(&str) -> String,
+) -> Result<BTreeMap<tinystr::UnvalidatedTinyAsciiStr<N>, IdStatus>, DataError> {
+    let mut statuses = BTreeMap::new();
+    for (codes, status) in [
+        (&validity.regular, IdStatus::Regular),
+        (&validity.special, IdStatus::Special),
+        (&validity.macroregion, IdStatus::Macroregion),
+        (&validity.private_use, IdStatus::PrivateUse),
+        (&validity.unknown, IdStatus::Unknown),
+        (&validity.deprecated, IdStatus::Deprecated),
+    ] {
+        for code in codes.iter().flat_map(|c| c.split_ascii_whitespace()) {
+            for code in expand_range(code)? {
+                let code = TinyAsciiStr::<N>::from_str(&normalize(&code))
+                    .map_err(|e| DataError::custom("Invalid code").with_display_context(&e))?;
+                statuses.insert(code.to_unvalidated(), status);
+            }
+        }
+    }
+    Ok(statuses)
+}
+
+/// Expands a range like `aa~ad`, which is abbreviated as `aa~d`, to `aa ab ac ad`.
+fn expand_range(code: &str) -> Result<Vec<String>, DataError> {
+    let Some((start, end)) = code.split_once('~') else {
+        return Ok(vec![code.to_string()]);
+    };
+    if end.len() > start.len()
+        || !start
+            .bytes()
+            .chain(end.bytes())
+            .all(|b| b.is_ascii_alphanumeric())
+    {
+        return Err(DataError::custom("Invalid range").with_display_context(code));
+    }
+    let end = format!("{}{end}", &start[..start.len() - end.len()]);
+    let mut current = start.as_bytes().to_vec();
+    let mut codes = vec![];
+    loop {
+        codes.push(String::from_utf8(current.clone()).unwrap());
+        if current == end.as_bytes() {
+            return Ok(codes);
+        }
+        // Increment like a counter, where each position wraps around within its alphabet
+        for i in (0..current.len()).rev() {
+            let (first, last) = match current[i] {
+                b'a'..=b'z' => (b'a', b'z'),
+                b'A'..=b'Z' => (b'A', b'Z'),
+                _ => (b'0', b'9'),
+            };
+            if current[i] < last {
+                current[i] += 1;
+                break;
+            }
+            current[i] = first;
+            if i == 0 {
+                return Err(DataError::custom("Invalid range").with_display_context(code));
+            }
+        }
+    }
+}
+
+type UnvalidatedKey = tinystr::UnvalidatedTinyAsciiStr<2>;
+
+/// Collects the statuses of extension keys and values, and the keys with multiple values.
+#[allow(clippy::type_complexity)]
+fn extension_statuses<'a>(
+    keywords: &[&'a cldr_serde::bcp47_keywords::Resource],
+) -> Result<
+    (
+        BTreeMap<UnvalidatedKey, IdStatus>,
+        BTreeMap<(UnvalidatedKey, &'a str), IdStatus>,
+        BTreeSet<UnvalidatedKey>,
+    ),
+    DataError,
+> {
+    let mut keys = BTreeMap::new();
+    let mut values = BTreeMap::new();
+    let mut multiple_value_keys = BTreeSet::new();
+
+    let status = |deprecated: Option<bool>| {
+        if deprecated == Some(true) {
+            IdStatus::Deprecated
+        } else {
+            IdStatus::Regular
+        }
+    };
+
+    for (extension, extension_keys) in keywords.iter().flat_map(|r| r.keyword.iter()) {
+        if !matches!(extension, 'u' | 't') {
+            continue;
+        }
+        for (key, data) in extension_keys {
+            let key = TinyAsciiStr::<2>::from_str(key)
+                .map_err(|e| DataError::custom("Invalid key").with_display_context(&e))?
+                .to_unvalidated();
+            keys.insert(key, status(data.deprecated));
+            if data.value_type.as_deref() == Some("multiple") {
+                multiple_value_keys.insert(key);
+            }
+            for (value, value_data) in &data.values {
+                values.insert((key, value.as_str()), status(value_data.deprecated));
+            }
+        }
+    }
+
+    Ok((keys, values, multiple_value_keys))
+}
+
+#[test]
+fn test_expand_range() {
+    assert_eq!(expand_range("en").unwrap(), ["en"]);
+    assert_eq!(expand_range("aa~d").unwrap(), ["aa", "ab", "ac", "ad"]);
+    assert_eq!(expand_range("Qaaa~Qabx").unwrap().len(), 50);
+    assert_eq!(expand_range("qaa~qtz").unwrap().len(), 520);
+    assert!(expand_range("aa~zzz").is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::subtags::{language, region, script, variant};
+
+    let provider = crate::DatagenProvider::new_testing();
+    let data: DataPayload<ValidityV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let data = data.get();
+
+    let status = |language: icu_locid::subtags::Language| {
+        data.languages
+            .get_copied(&language.into_tinystr().to_unvalidated())
+    };
+    assert_eq!(status(language!("en")), Some(IdStatus::Regular));
+    assert_eq!(status(language!("iw")), Some(IdStatus::Deprecated));
+    assert_eq!(status(language!("mul")), Some(IdStatus::Special));
+    assert_eq!(status(language!("qcd")), Some(IdStatus::PrivateUse));
+    assert_eq!(status(language!("und")), Some(IdStatus::Unknown));
+    assert_eq!(status(language!("xq")), None);
+
+    assert_eq!(
+        data.scripts
+            .get_copied(&script!("Qabx").into_tinystr().to_unvalidated()),
+        Some(IdStatus::PrivateUse)
+    );
+    assert_eq!(
+        data.regions
+            .get_copied(&region!("419").into_tinystr().to_unvalidated()),
+        Some(IdStatus::Macroregion)
+    );
+    assert_eq!(
+        data.regions
+            .get_copied(&region!("XK").into_tinystr().to_unvalidated()),
+        Some(IdStatus::Regular)
+    );
+    assert_eq!(
+        data.variants
+            .get_copied(&variant!("fonipa").into_tinystr().to_unvalidated()),
+        Some(IdStatus::Regular)
+    );
+
+    let ca = tinystr::tinystr!(2, "ca").to_unvalidated();
+    assert_eq!(
+        data.values
+            .get_copied_2d(&ca, UnvalidatedStr::from_str("islamic-civil")),
+        Some(IdStatus::Regular)
+    );
+    assert_eq!(
+        data.values
+            .get_copied_2d(&ca, UnvalidatedStr::from_str("islamicc")),
+        Some(IdStatus::Deprecated)
+    );
+    assert_eq!(
+        data.values.get_copied_2d(
+            &tinystr::tinystr!(2, "tz").to_unvalidated(),
+            UnvalidatedStr::from_str("usnyc")
+        ),
+        Some(IdStatus::Regular)
+    );
+    assert!(data
+        .multiple_value_keys
+        .binary_search(&tinystr::tinystr!(2, "kr").to_unvalidated())
+        .is_ok());
+}
@@ -14,14 +14,19 @@ pub mod decimal;
 #[cfg(feature = "icu_displaynames")]
 pub mod displaynames;
 pub mod fallback;
+#[cfg(test)] // keep as test until bakeddata is needed
+pub mod grammar;
 pub mod list;
 pub mod locale_canonicalizer;
 pub mod plurals;
 #[cfg(feature = "icu_relativetime")]
 pub mod relativetime;
+pub mod segmenter;
 pub mod source;
 pub mod time_zones;
 #[cfg(feature = "icu_transliterate")]
 pub mod transforms;
+#[cfg(test)] // keep as test until bakeddata is needed
+pub mod unit_names;
 #[cfg(feature = "icu_unitsconversion")]
 pub mod units;
@@ -0,0 +1,96 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::*;
+use std::collections::HashSet;
+use zerotrie::ZeroTriePerfectHash;
+
+impl DataProvider<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<SentenceBreakSuppressionsV1Marker>, DataError> {
+        self.check_req::<SentenceBreakSuppressionsV1Marker>(req)?;
+        // CLDR has no suppressions for the root locale
+        let suppressions: &[cldr_serde::segments::Suppression] = if req.locale.is_langid_und() {
+            &[]
+        } else {
+            let resource: &cldr_serde::segments::Resource = self
+                .cldr()?
+                .segments()
+                .read_and_parse(&req.locale.get_langid(), "suppressions.json")?;
+            resource
+                .segments
+                .segmentations
+                .sentence_break
+                .standard
+                .as_slice()
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(transform(suppressions))),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .segments()
+            .list_langs()?
+            .map(DataLocale::from)
+            .chain([Default::default()])
+            .collect())
+    }
+}
+
+fn transform(
+    suppressions: &[cldr_serde::segments::Suppression],
+) -> SentenceBreakSuppressionsV1<'static> {
+    SentenceBreakSuppressionsV1 {
+        suppressions: suppressions
+            .iter()
+            .map(|s| s.suppression.as_str())
+            // The segmenter only matches the last word before a break
+            .filter(|s| !s.contains(char::is_whitespace))
+            // Reversed, as the segmenter matches them backwards from the break
+            .map(|s| (s.chars().rev().collect::<String>(), 0))
+            .collect::<ZeroTriePerfectHash<Vec<u8>>>()
+            .convert_store(),
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<SentenceBreakSuppressionsV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().suppressions.get(".rM"), Some(0));
+    assert_eq!(en.get().suppressions.get("Mr."), None);
+    assert_eq!(en.get().suppressions.get("rM"), None);
+
+    let und: DataPayload<SentenceBreakSuppressionsV1Marker> = provider
+        .load(DataRequest {
+            locale: &Default::default(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(und.get().suppressions.is_empty());
+}
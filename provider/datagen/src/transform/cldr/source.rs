@@ -52,19 +52,28 @@ impl CldrCache {
     }
 
     pub fn numbers(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-numbers".to_owned())
+        CldrDirLang(self, "cldr-numbers".to_owned(), "main")
     }
 
     pub fn misc(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-misc".to_owned())
+        CldrDirLang(self, "cldr-misc".to_owned(), "main")
     }
 
     pub fn bcp47(&self) -> CldrDirNoLang<'_> {
         CldrDirNoLang(self, "cldr-bcp47/bcp47".to_string())
     }
 
+    #[cfg(test)] // keep as test until baked data is needed
+    pub fn units(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, "cldr-units".to_owned(), "main")
+    }
+
     pub fn displaynames(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-localenames".to_owned())
+        CldrDirLang(self, "cldr-localenames".to_owned(), "main")
+    }
+
+    pub fn segments(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, "cldr-segments".to_owned(), "segments")
     }
 
     pub fn dates(&self, cal: &str) -> CldrDirLang<'_> {
@@ -75,6 +84,7 @@ impl CldrCache {
             } else {
                 format!("cldr-cal-{cal}")
             },
+            "main",
         )
     }
 
@@ -109,7 +119,7 @@ impl CldrCache {
             .copied()
     }
 
-    fn extended_locale_expander(&self) -> Result<&LocaleExpander, DataError> {
+    pub(super) fn extended_locale_expander(&self) -> Result<&LocaleExpander, DataError> {
         use super::locale_canonicalizer::likely_subtags::*;
         self.extended_locale_expander.get_or_try_init(|| {
             let common_data =
@@ -224,7 +234,7 @@ impl<'a> CldrDirNoLang<'a> {
     }
 }
 
-pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String);
+pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String, &'static str);
 
 impl<'a> CldrDirLang<'a> {
     pub fn read_and_parse<S>(
@@ -236,7 +246,7 @@ impl<'a> CldrDirLang<'a> {
         for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
     {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main/{lang}/{file_name}", self.1);
+        let path = format!("{}-{dir_suffix}/{}/{lang}/{file_name}", self.1, self.2);
         if self.0.serde_cache.file_exists(&path)? {
             self.0.serde_cache.read_and_parse_json(&path)
         } else if let Some(new_langid) = self.0.add_script_extended(lang)? {
@@ -250,7 +260,7 @@ impl<'a> CldrDirLang<'a> {
 
     pub fn list_langs(&self) -> Result<impl Iterator<Item = LanguageIdentifier> + '_, DataError> {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main", self.1);
+        let path = format!("{}-{dir_suffix}/{}", self.1, self.2);
         Ok(self
             .0
             .serde_cache
@@ -269,7 +279,7 @@ impl<'a> CldrDirLang<'a> {
         file_name: &str,
     ) -> Result<bool, DataError> {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main/{lang}/{file_name}", self.1);
+        let path = format!("{}-{dir_suffix}/{}/{lang}/{file_name}", self.1, self.2);
         if self.0.serde_cache.file_exists(&path)? {
             Ok(true)
         } else if let Some(new_langid) = self.0.add_script_extended(lang)? {
@@ -15,7 +15,7 @@ use icu_timezone::provider::*;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
-mod convert;
+pub(crate) mod convert;
 mod names;
 
 #[derive(Debug, Copy, Clone)]
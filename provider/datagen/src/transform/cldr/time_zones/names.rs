@@ -4,6 +4,7 @@
 
 use super::convert::{compute_bcp47_tzids_btreemap, compute_canonical_tzids_btreemap};
 use crate::transform::cldr::cldr_serde;
+use icu_locid::subtags::{region, Region};
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
 use icu_timezone::provider::names::*;
@@ -11,7 +12,7 @@ use icu_timezone::TimeZoneBcp47Id;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::hash::Hasher;
-use zerotrie::ZeroTriePerfectHash;
+use zerotrie::{ZeroTriePerfectHash, ZeroTrieSimpleAscii};
 use zerovec::{ZeroSlice, ZeroVec};
 
 impl DataProvider<IanaToBcp47MapV1Marker> for crate::DatagenProvider {
@@ -94,6 +95,196 @@ impl IterableDataProvider<Bcp47ToIanaMapV1Marker> for crate::DatagenProvider {
     }
 }
 
+impl DataProvider<WindowsZonesMapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<WindowsZonesMapV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+        let iana2bcp = compute_bcp47_tzids_btreemap(&resource.keyword.u.time_zones.values);
+
+        let resource: &cldr_serde::time_zones::windows_zones::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/windowsZones.json")?;
+
+        // Each map zone lists one or more IANA IDs for a Windows ID and territory, the first
+        // of which is the mapping for that territory.
+        let mut windows2bcp: BTreeMap<Vec<u8>, TimeZoneBcp47Id> = BTreeMap::new();
+        // Every IANA ID appears in a single map zone, but multiple IANA IDs can share a BCP-47 ID,
+        // in which case the default (001) territory mapping wins.
+        let mut bcp2windows: BTreeMap<TimeZoneBcp47Id, &str> = BTreeMap::new();
+        for map_zone in resource
+            .supplemental
+            .windows_zones
+            .map_timezones
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let bcp47_ids = map_zone
+                .zone_type
+                .split(' ')
+                .map(|iana| {
+                    iana2bcp.get(iana).copied().ok_or_else(|| {
+                        DataError::custom("Unknown IANA ID in windowsZones.json")
+                            .with_display_context(iana)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let Some(&first) = bcp47_ids.first() else {
+                continue;
+            };
+            windows2bcp.insert(
+                format!("{}/{}", map_zone.other, map_zone.territory)
+                    .to_ascii_lowercase()
+                    .into_bytes(),
+                first,
+            );
+            for bcp47_id in bcp47_ids {
+                if map_zone.territory == "001" {
+                    bcp2windows.insert(bcp47_id, &map_zone.other);
+                } else {
+                    bcp2windows.entry(bcp47_id).or_insert(&map_zone.other);
+                }
+            }
+        }
+
+        let windows_ids: BTreeSet<&str> = bcp2windows.values().copied().collect();
+        let windows_ids: Vec<&str> = windows_ids.into_iter().collect();
+        let bcp47_ids: ZeroVec<TimeZoneBcp47Id> = bcp2windows.keys().copied().collect();
+
+        #[allow(clippy::unwrap_used)] // structures are derived from each other
+        let bcp47_to_windows = bcp2windows
+            .values()
+            .map(|windows_id| windows_ids.binary_search(windows_id).unwrap() as u16)
+            .collect();
+
+        #[allow(clippy::unwrap_used)] // structures are derived from each other
+        let map: BTreeMap<Vec<u8>, usize> = windows2bcp
+            .into_iter()
+            .map(|(key, bcp)| (key, bcp47_ids.binary_search(&bcp).unwrap()))
+            .collect();
+
+        let data_struct = WindowsZonesMapV1 {
+            map: ZeroTrieSimpleAscii::try_from(&map)
+                .map_err(|e| {
+                    DataError::custom("Could not create ZeroTrie from windowsZones.json data")
+                        .with_display_context(&e)
+                })?
+                .convert_store()
+                .into_zerotrie(),
+            bcp47_ids,
+            windows_ids: windows_ids.as_slice().into(),
+            bcp47_to_windows,
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<WindowsZonesMapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<TimeZoneRegionsV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<TimeZoneRegionsV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+        let iana2bcp = compute_bcp47_tzids_btreemap(&resource.keyword.u.time_zones.values);
+        let bcp2iana = compute_canonical_tzids_btreemap(&resource.keyword.u.time_zones.values);
+
+        // CLDR JSON has no direct mapping from time zones to regions. The windowsZones data
+        // lists the region of almost all time zones, and the remaining ones start with their
+        // region code.
+        let windows_zones: &cldr_serde::time_zones::windows_zones::Resource =
+            self.cldr()?
+                .core()
+                .read_and_parse("supplemental/windowsZones.json")?;
+        let mut iana2region: BTreeMap<&str, Region> = BTreeMap::new();
+        for map_zone in windows_zones
+            .supplemental
+            .windows_zones
+            .map_timezones
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let Ok(region) = map_zone.territory.parse::<Region>() else {
+                continue;
+            };
+            if !region.is_alphabetic() || region == region!("ZZ") {
+                continue;
+            }
+            for iana in map_zone.zone_type.split(' ') {
+                iana2region.insert(iana, region);
+            }
+        }
+
+        let mut regions: BTreeMap<TimeZoneBcp47Id, Region> = BTreeMap::new();
+        for (bcp47_id, iana) in bcp2iana.iter() {
+            // Etc/UTC, EST5EDT, etc. are not associated with a region
+            if iana.starts_with("Etc/") || !iana.contains('/') {
+                continue;
+            }
+            let region = iana2bcp
+                .iter()
+                .filter(|(_, b)| *b == bcp47_id)
+                .find_map(|(alias, _)| iana2region.get(alias.as_str()).copied())
+                .or_else(|| {
+                    bcp47_id
+                        .0
+                        .get(..2)
+                        .and_then(|prefix| prefix.to_ascii_uppercase().parse::<Region>().ok())
+                })
+                .ok_or_else(|| {
+                    DataError::custom("Could not determine the region of time zone")
+                        .with_display_context(iana)
+                })?;
+            regions.insert(*bcp47_id, region);
+        }
+
+        let primary_zones: &cldr_serde::time_zones::primary_zones::Resource =
+            self.cldr()?
+                .core()
+                .read_and_parse("supplemental/primaryZones.json")?;
+        let mut zones_per_region: BTreeMap<Region, usize> = BTreeMap::new();
+        for region in regions.values() {
+            *zones_per_region.entry(*region).or_default() += 1;
+        }
+        let mut primary: BTreeSet<TimeZoneBcp47Id> = regions
+            .iter()
+            .filter(|(_, region)| zones_per_region.get(region) == Some(&1))
+            .map(|(bcp47_id, _)| *bcp47_id)
+            .collect();
+        for (region, iana) in primary_zones.supplemental.primary_zones.iter() {
+            let bcp47_id = iana2bcp.get(iana).ok_or_else(|| {
+                DataError::custom("Unknown IANA ID in primaryZones.json").with_display_context(iana)
+            })?;
+            if regions.get(bcp47_id) != Some(region) {
+                return Err(DataError::custom("Primary zone is not in its region")
+                    .with_display_context(iana));
+            }
+            primary.insert(*bcp47_id);
+        }
+
+        let data_struct = TimeZoneRegionsV1 {
+            regions: regions.into_iter().collect(),
+            primary_zones: primary.into_iter().collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<TimeZoneRegionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
 fn create_hasher() -> impl std::hash::Hasher {
     twox_hash::XxHash64::with_seed(0)
 }
@@ -0,0 +1,178 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::grammar::{parse_case, parse_gender};
+use icu_dimension::grammar::GrammaticalCase;
+use icu_dimension::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use zerovec::ule::UnvalidatedStr;
+use zerovec::ZeroMap2d;
+
+impl DataProvider<UnitsDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsDisplayNamesV1Marker>, DataError> {
+        self.check_req::<UnitsDisplayNamesV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let units_resource: &cldr_serde::unit_names::Resource =
+            self.cldr()?.units().read_and_parse(&langid, "units.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                UnitsDisplayNamesV1::try_from(units_resource)
+                    .map_err(|e| e.with_display_context(&langid))?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<UnitsDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .units()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl TryFrom<&cldr_serde::unit_names::Resource> for UnitsDisplayNamesV1<'static> {
+    type Error = DataError;
+
+    fn try_from(other: &cldr_serde::unit_names::Resource) -> Result<Self, Self::Error> {
+        let mut display_names = BTreeMap::new();
+        let mut genders = BTreeMap::new();
+        // Patterns by plural category, then by unit and case
+        let mut patterns =
+            BTreeMap::<PluralCategory, BTreeMap<(&str, GrammaticalCase), &str>>::new();
+
+        for (unit, fields) in &other.main.value.units.long {
+            // Skip compound patterns such as `per` and `times`, which are not units
+            if !fields.keys().any(|k| k.starts_with("unitPattern-count-")) {
+                continue;
+            }
+            for (field, value) in fields {
+                if field == "displayName" {
+                    display_names.insert(unit.as_str(), value.as_str());
+                } else if field == "gender" {
+                    genders.insert(unit.as_str(), parse_gender(value)?);
+                } else if let Some(count) = field.strip_prefix("unitPattern-count-") {
+                    let (count, case) = match count.split_once("-case-") {
+                        Some((count, case)) => (count, parse_case(case)?),
+                        None => (count, GrammaticalCase::Nominative),
+                    };
+                    let category = PluralCategory::get_for_cldr_string(count).ok_or_else(|| {
+                        DataError::custom("Unknown plural category").with_display_context(count)
+                    })?;
+                    patterns
+                        .entry(category)
+                        .or_default()
+                        .insert((unit.as_str(), case), value.as_str());
+                }
+            }
+        }
+
+        // Only store patterns for other cases if they differ from the nominative
+        for patterns in patterns.values_mut() {
+            let nominative = patterns
+                .iter()
+                .filter(|((_, case), _)| *case == GrammaticalCase::Nominative)
+                .map(|(&(unit, _), &pattern)| (unit, pattern))
+                .collect::<BTreeMap<_, _>>();
+            patterns.retain(|&(unit, case), pattern| {
+                case == GrammaticalCase::Nominative || nominative.get(unit).copied() != Some(*pattern)
+            });
+        }
+
+        let mut patterns_for =
+            |category| -> ZeroMap2d<'static, UnvalidatedStr, GrammaticalCase, str> {
+                patterns
+                    .remove(&category)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|((unit, case), pattern)| (UnvalidatedStr::from_str(unit), case, pattern))
+                    .collect()
+            };
+
+        Ok(Self {
+            display_names: display_names
+                .into_iter()
+                .map(|(unit, name)| (UnvalidatedStr::from_str(unit), name))
+                .collect(),
+            genders: genders
+                .into_iter()
+                .map(|(unit, gender)| (UnvalidatedStr::from_str(unit), gender))
+                .collect(),
+            zero: patterns_for(PluralCategory::Zero),
+            one: patterns_for(PluralCategory::One),
+            two: patterns_for(PluralCategory::Two),
+            few: patterns_for(PluralCategory::Few),
+            many: patterns_for(PluralCategory::Many),
+            other: patterns_for(PluralCategory::Other),
+        })
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let ru: DataPayload<UnitsDisplayNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let hour = UnvalidatedStr::from_str("duration-hour");
+    assert_eq!(ru.get().display_names.get(hour), Some("часы"));
+    assert_eq!(
+        ru.get().genders.get_copied(hour),
+        Some(icu_dimension::grammar::GrammaticalGender::Masculine)
+    );
+    assert_eq!(
+        ru.get().one.get_2d(hour, &GrammaticalCase::Dative),
+        Some("{0} часу")
+    );
+    assert_eq!(
+        ru.get().many.get_2d(hour, &GrammaticalCase::Dative),
+        Some("{0} часам")
+    );
+    // The accusative of inanimate nouns is the same as the nominative
+    assert_eq!(
+        ru.get().one.get_2d(hour, &GrammaticalCase::Accusative),
+        None
+    );
+    // Compound patterns are not units
+    assert_eq!(
+        ru.get().display_names.get(UnvalidatedStr::from_str("per")),
+        None
+    );
+
+    let en: DataPayload<UnitsDisplayNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(en.get().genders.is_empty());
+    assert_eq!(
+        en.get().other.get_2d(
+            UnvalidatedStr::from_str("length-kilometer"),
+            &GrammaticalCase::Nominative
+        ),
+        Some("{0} kilometers")
+    );
+}
@@ -209,17 +209,31 @@ fn generate_rule_break_data(
 
                     if p.name == "SA" {
                         // Word break property doesn't define SA, but we will use non-UAX29 rules.
-                        // SA/CJ property is within 0..U+0x40000
+                        // SA property is within 0..U+0x40000
                         for c in 0..0x40000 {
                             if lb.get32(c) == LineBreak::ComplexContext {
                                 properties_map[c as usize] = property_index
-                            } else if let Some(c) = char::from_u32(c) {
-                                match script.get(c) {
-                                    Script::Han | Script::Hiragana => {
-                                        properties_map[c as usize] = property_index;
-                                    }
+                            }
+                        }
+                        continue;
+                    }
 
-                                    _ => {}
+                    if p.name == "Hiragana" || p.name == "Han" {
+                        // Like SA, CJ uses non-UAX29 rules, but Hiragana and Han have their own
+                        // properties for their word types.
+                        // CJ property is within 0..U+0x40000
+                        let cj_script = if p.name == "Han" {
+                            Script::Han
+                        } else {
+                            Script::Hiragana
+                        };
+                        for c in 0..0x40000 {
+                            if lb.get32(c) == LineBreak::ComplexContext {
+                                continue;
+                            }
+                            if let Some(c) = char::from_u32(c) {
+                                if script.get(c) == cj_script {
+                                    properties_map[c as usize] = property_index;
                                 }
                             }
                         }
@@ -501,9 +515,9 @@ fn generate_rule_break_data(
                 .iter()
                 .map(|p| match &*p.name {
                     "Numeric" => WordType::Number,
-                    "ALetter" | "Hebrew_Letter" | "ExtendNumLet" | "Katakana" | "SA" => {
-                        WordType::Letter
-                    }
+                    "ALetter" | "Hebrew_Letter" | "ExtendNumLet" | "SA" => WordType::Letter,
+                    "Katakana" | "Hiragana" => WordType::Kana,
+                    "Han" => WordType::Ideo,
                     _ => WordType::None,
                 })
                 .collect()
@@ -514,7 +528,8 @@ fn generate_rule_break_data(
         last_codepoint_property: (simple_properties_count - 1).try_into().unwrap(),
         sot_property: (properties_names.len() - 2).try_into().unwrap(),
         eot_property: (properties_names.len() - 1).try_into().unwrap(),
-        // Return 127 if the complex language isn't handled.
+        // Return 127 if the complex language isn't handled. In word data, the simple properties
+        // after SA (Hiragana and Han) are handled as complex as well.
         complex_property: get_index_from_name(&properties_names, "SA")
             .unwrap_or(127)
             .try_into()
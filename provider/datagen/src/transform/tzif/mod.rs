@@ -0,0 +1,480 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by TZif files compiled from the
+//! IANA time zone database.
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::time_zones::convert::compute_canonical_tzids_btreemap;
+use icu_calendar::{DateTime, Iso};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::BTreeSet;
+
+mod parse;
+
+/// The minimum number of consecutive transitions that are replaced by a [`ZoneOffsetRule`].
+const MIN_RULE_TRANSITIONS: usize = 4;
+
+/// Transitions described by a single [`ZoneOffsetRule`] are less than a year apart.
+const MAX_RULE_TRANSITION_GAP_SECONDS: i64 = 366 * 86400;
+
+/// The earliest time that can be represented in the data, in seconds since the Unix epoch. The
+/// offset in effect before the first transition of a time zone applies from this time on.
+const EARLIEST_SECONDS: i64 = i32::MIN as i64 * 60;
+
+impl DataProvider<ZoneOffsetPeriodV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<ZoneOffsetPeriodV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+
+        let mut transitions = Vec::new();
+        let mut rules = Vec::new();
+        for (bcp47, iana) in compute_canonical_tzids_btreemap(&resource.keyword.u.time_zones.values)
+        {
+            // Some time zones, such as Etc/Unknown, are not part of the time zone database.
+            if !self.tzif()?.file_exists(&iana)? {
+                continue;
+            }
+            let tzif = parse::parse_tzif(&self.tzif()?.read_to_buf(&iana)?)
+                .map_err(|e| e.with_display_context(&iana))?;
+            let (zone_transitions, zone_rules) =
+                compute_periods(&tzif).map_err(|e| e.with_display_context(&iana))?;
+            transitions.extend(
+                zone_transitions
+                    .into_iter()
+                    .map(|(seconds, offset)| (bcp47, to_minutes(seconds), offset)),
+            );
+            rules.extend(
+                zone_rules
+                    .into_iter()
+                    .map(|(seconds, rule)| (bcp47, to_minutes(seconds), rule)),
+            );
+        }
+
+        let data_struct = ZoneOffsetPeriodV1 {
+            transitions: transitions.into_iter().collect(),
+            rules: rules.into_iter().collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<ZoneOffsetPeriodV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Converts seconds since the Unix epoch to minutes, rounding down.
+fn to_minutes(seconds: i64) -> i32 {
+    seconds.div_euclid(60) as i32
+}
+
+/// Computes the transitions and rules of a time zone, in seconds since the Unix epoch.
+///
+/// Runs of yearly daylight saving time transitions that follow a rule are replaced by that
+/// rule, which considerably reduces the data size. The rule from the TZif footer applies after
+/// the last transition.
+#[allow(clippy::type_complexity)] // internal function
+fn compute_periods(
+    tzif: &parse::Tzif,
+) -> Result<(Vec<(i64, ZoneOffset)>, Vec<(i64, ZoneOffsetRule)>), DataError> {
+    let entries = compute_entries(tzif)?;
+
+    let footer_rule = match &tzif.footer {
+        Some(footer) => {
+            if entries.last().map(|(_, offset)| offset.offset_seconds)
+                != Some(footer.standard_offset_seconds)
+                && footer.rule.is_none()
+            {
+                return Err(DataError::custom(
+                    "TZif footer does not match last transition",
+                ));
+            }
+            footer.rule.map(normalize_rule)
+        }
+        None => None,
+    };
+
+    // The footer applies after the last transition, which might not start a new entry if it
+    // does not change the offset.
+    let mut footer_start = tzif
+        .transitions
+        .last()
+        .map_or(EARLIEST_SECONDS, |&(seconds, _)| {
+            seconds.max(EARLIEST_SECONDS)
+        });
+    // The footer rule does not always agree with the last transition, in which case it only
+    // applies from its next transition.
+    if let (Some(rule), Some(&(_, last_offset))) = (footer_rule, entries.last()) {
+        if rule_offset_at(&rule, footer_start) != last_offset {
+            footer_start = rule_transitions(
+                &rule,
+                footer_start + 1,
+                footer_start + MAX_RULE_TRANSITION_GAP_SECONDS,
+            )
+            .next()
+            .map_or(footer_start, |(seconds, _)| seconds);
+        }
+    }
+
+    let mut transitions: Vec<(i64, ZoneOffset)> = Vec::new();
+    let mut rules: Vec<(i64, ZoneOffsetRule)> = Vec::new();
+    let mut footer_rule_applied = false;
+    let mut i = 0;
+    while i < entries.len() {
+        let Some((rule, end)) = find_rule(&entries, i, footer_rule) else {
+            transitions.push(entries[i]);
+            i += 1;
+            continue;
+        };
+        rules.push((entries[i].0, rule));
+        let (last, last_offset) = entries[end - 1];
+        // The rule stops applying at the start of the next period. If it would produce a
+        // transition before that, an explicit transition is needed to stop it.
+        let until = if end < entries.len() {
+            entries[end].0
+        } else if footer_rule.is_some() {
+            footer_start
+        } else {
+            i64::MAX
+        };
+        let mut unwanted_transitions = rule_transitions(&rule, last + 1, until.saturating_sub(1));
+        if end == entries.len() && footer_rule == Some(rule) {
+            footer_rule_applied = rule_transitions(&rule, last + 1, footer_start)
+                .next()
+                .is_none();
+        }
+        if !footer_rule_applied && unwanted_transitions.next().is_some() {
+            transitions.push((last, last_offset));
+        }
+        i = end;
+    }
+
+    if let (Some(footer_rule), false) = (footer_rule, footer_rule_applied) {
+        if transitions.last().map(|&(seconds, _)| seconds) == Some(footer_start) {
+            transitions.pop();
+        }
+        rules.push((footer_start, footer_rule));
+    }
+
+    Ok((transitions, rules))
+}
+
+/// Returns the offsets of a time zone, as the number of seconds since the Unix epoch at which
+/// they take effect. The first offset takes effect at [`EARLIEST_SECONDS`].
+///
+/// Negative daylight saving time, where the offset in the winter is marked as daylight saving
+/// time, is normalized so that daylight saving time always has the larger offset.
+fn compute_entries(tzif: &parse::Tzif) -> Result<Vec<(i64, ZoneOffset)>, DataError> {
+    let to_offset = |index: usize| {
+        let (offset_seconds, daylight) = tzif.local_time_types[index];
+        ZoneOffset {
+            offset_seconds,
+            daylight,
+        }
+    };
+    if tzif.local_time_types.is_empty() {
+        return Err(DataError::custom("TZif file without local time types"));
+    }
+
+    // The first local time type is in effect before the first transition.
+    let mut entries = vec![(
+        EARLIEST_SECONDS,
+        to_offset(
+            tzif.transitions
+                .iter()
+                .rev()
+                .find(|&&(seconds, _)| seconds <= EARLIEST_SECONDS)
+                .map(|&(_, index)| index)
+                .unwrap_or_default(),
+        ),
+    )];
+    entries.extend(
+        tzif.transitions
+            .iter()
+            .filter(|&&(seconds, _)| seconds > EARLIEST_SECONDS)
+            .map(|&(seconds, index)| (seconds, to_offset(index))),
+    );
+
+    // A daylight saving time offset is negative if it is between standard time offsets that
+    // are larger than it. Daylight saving time offsets next to each other, as during wars, are
+    // not negative.
+    let is_negative = |i: usize| {
+        let (_, offset) = entries[i];
+        let mut neighbors = [i.checked_sub(1), Some(i + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|j| entries.get(j))
+            .peekable();
+        offset.daylight
+            && neighbors.peek().is_some()
+            && neighbors.all(|(_, neighbor)| {
+                !neighbor.daylight && neighbor.offset_seconds > offset.offset_seconds
+            })
+    };
+    let negative = (0..entries.len()).map(is_negative).collect::<Vec<_>>();
+    for (i, (_, offset)) in entries.iter_mut().enumerate() {
+        if negative[i] {
+            offset.daylight = false;
+        } else if !offset.daylight
+            && i > 0
+            && negative[i - 1]
+            && negative.get(i + 1).copied().unwrap_or(false)
+        {
+            offset.daylight = true;
+        }
+    }
+
+    entries.dedup_by_key(|&mut (_, offset)| offset);
+    Ok(entries)
+}
+
+/// Normalizes negative daylight saving time in a rule, see [`compute_entries`].
+fn normalize_rule(rule: ZoneOffsetRule) -> ZoneOffsetRule {
+    if rule.daylight_offset_seconds < rule.standard_offset_seconds {
+        ZoneOffsetRule {
+            standard_offset_seconds: rule.daylight_offset_seconds,
+            daylight_offset_seconds: rule.standard_offset_seconds,
+            start: rule.end,
+            end: rule.start,
+        }
+    } else {
+        rule
+    }
+}
+
+/// Finds the longest run of transitions starting at `entries[start]` that follows a rule.
+///
+/// Returns the rule and the end index of the run, preferring `preferred_rule` if it matches.
+fn find_rule(
+    entries: &[(i64, ZoneOffset)],
+    start: usize,
+    preferred_rule: Option<ZoneOffsetRule>,
+) -> Option<(ZoneOffsetRule, usize)> {
+    let (_, previous) = *entries.get(start.checked_sub(1)?)?;
+    let (_, first) = entries[start];
+    let (standard, daylight) = match (previous.daylight, first.daylight) {
+        (false, true) => (previous.offset_seconds, first.offset_seconds),
+        (true, false) => (first.offset_seconds, previous.offset_seconds),
+        _ => return None,
+    };
+    if daylight <= standard {
+        return None;
+    }
+
+    let mut starts: Option<BTreeSet<ZoneTransitionRule>> = None;
+    let mut ends: Option<BTreeSet<ZoneTransitionRule>> = None;
+    let mut end = start;
+    while let Some(&(seconds, offset)) = entries.get(end) {
+        let (_, previous) = entries[end - 1];
+        if offset.daylight == previous.daylight
+            || offset.offset_seconds != if offset.daylight { daylight } else { standard }
+            || (end > start && seconds - entries[end - 1].0 >= MAX_RULE_TRANSITION_GAP_SECONDS)
+        {
+            break;
+        }
+        // Daylight saving time starts in local standard time, and ends in local daylight time.
+        let (candidates, local_seconds) = if offset.daylight {
+            (&mut starts, seconds + i64::from(standard))
+        } else {
+            (&mut ends, seconds + i64::from(daylight))
+        };
+        let new_candidates = transition_rule_candidates(local_seconds);
+        let new_candidates = match candidates {
+            Some(candidates) => candidates.intersection(&new_candidates).copied().collect(),
+            None => new_candidates,
+        };
+        if new_candidates.is_empty() {
+            break;
+        }
+        *candidates = Some(new_candidates);
+        end += 1;
+    }
+
+    if end - start < MIN_RULE_TRANSITIONS {
+        return None;
+    }
+    let (starts, ends) = (starts?, ends?);
+    let rule = match preferred_rule {
+        Some(rule)
+            if rule.standard_offset_seconds == standard
+                && rule.daylight_offset_seconds == daylight
+                && starts.contains(&rule.start)
+                && ends.contains(&rule.end) =>
+        {
+            rule
+        }
+        _ => ZoneOffsetRule {
+            standard_offset_seconds: standard,
+            daylight_offset_seconds: daylight,
+            start: *starts.first()?,
+            end: *ends.first()?,
+        },
+    };
+
+    // Make sure the rule produces exactly these transitions, and no others in between.
+    let expected = entries[start..end]
+        .iter()
+        .map(|&(seconds, offset)| (seconds, offset.daylight));
+    if !rule_transitions(&rule, entries[start].0, entries[end - 1].0).eq(expected) {
+        return None;
+    }
+
+    Some((rule, end))
+}
+
+/// Returns the rules that could have produced a transition at the given local time.
+///
+/// A transition can be described relative to the day it takes place on, or relative to the
+/// day before or after, with a time outside of `0..24h`.
+fn transition_rule_candidates(local_seconds: i64) -> BTreeSet<ZoneTransitionRule> {
+    let mut candidates = BTreeSet::new();
+    for day_offset in [-1, 0, 1] {
+        let midnight = (local_seconds.div_euclid(86400) + day_offset) * 86400;
+        let date = to_datetime(midnight).date;
+        let day = date.day_of_month().0 as u8;
+        let mut weeks = vec![(day - 1) / 7 + 1];
+        if day + 7 > date.days_in_month() {
+            weeks.push(5);
+        }
+        for week in weeks {
+            candidates.insert(ZoneTransitionRule {
+                month: date.month().ordinal as u8,
+                week,
+                weekday: date.day_of_week() as u8 % 7,
+                time_seconds: (local_seconds - midnight) as i32,
+            });
+        }
+    }
+    candidates
+}
+
+/// Returns the transitions produced by a rule between `from` and `to` (inclusive), in seconds
+/// since the Unix epoch, together with whether daylight saving time starts.
+fn rule_transitions(
+    rule: &ZoneOffsetRule,
+    from: i64,
+    to: i64,
+) -> impl Iterator<Item = (i64, bool)> {
+    let mut transitions = Vec::new();
+    if from <= to {
+        // Transition times can be more than a day away from the date they are based on.
+        let first_year = to_datetime(from).date.year().number - 1;
+        let last_year = to_datetime(to.min(i64::from(i32::MAX) * 60))
+            .date
+            .year()
+            .number
+            + 1;
+        for year in first_year..=last_year {
+            transitions.push((
+                transition_seconds(&rule.start, year, rule.standard_offset_seconds),
+                true,
+            ));
+            transitions.push((
+                transition_seconds(&rule.end, year, rule.daylight_offset_seconds),
+                false,
+            ));
+        }
+        transitions.retain(|&(seconds, _)| (from..=to).contains(&seconds));
+        transitions.sort();
+    }
+    transitions.into_iter()
+}
+
+/// Returns the offset in effect under a rule at the given number of seconds since the Unix epoch.
+fn rule_offset_at(rule: &ZoneOffsetRule, seconds: i64) -> ZoneOffset {
+    // A rule has two transitions per year.
+    let daylight = rule_transitions(rule, seconds - MAX_RULE_TRANSITION_GAP_SECONDS, seconds)
+        .last()
+        .map_or(false, |(_, daylight)| daylight);
+    ZoneOffset {
+        offset_seconds: if daylight {
+            rule.daylight_offset_seconds
+        } else {
+            rule.standard_offset_seconds
+        },
+        daylight,
+    }
+}
+
+/// The number of seconds since the Unix epoch of the transition described by `rule` in `year`,
+/// where the local time of the transition has the given offset.
+fn transition_seconds(rule: &ZoneTransitionRule, year: i32, offset_seconds: i32) -> i64 {
+    #[allow(clippy::unwrap_used)] // rules are valid
+    let first_of_month = icu_calendar::Date::try_new_iso_date(year, rule.month, 1).unwrap();
+    let first_weekday = first_of_month.day_of_week() as u8 % 7;
+    let mut day = 1 + (7 + rule.weekday - first_weekday) % 7 + 7 * (rule.week - 1);
+    while day > first_of_month.days_in_month() {
+        day -= 7;
+    }
+    #[allow(clippy::unwrap_used)] // day is valid
+    let midnight = DateTime::try_new_iso_datetime(year, rule.month, day, 0, 0, 0).unwrap();
+    i64::from(midnight.minutes_since_local_unix_epoch()) * 60 + i64::from(rule.time_seconds)
+        - i64::from(offset_seconds)
+}
+
+fn to_datetime(seconds: i64) -> DateTime<Iso> {
+    DateTime::from_minutes_since_local_unix_epoch(to_minutes(seconds))
+}
+
+#[test]
+fn test_basic() {
+    use icu_timezone::TimeZoneBcp47Id;
+    use tinystr::tinystr;
+    use zerovec::ule::AsULE;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let offset_period: DataPayload<ZoneOffsetPeriodV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let uslax = offset_period
+        .get()
+        .rules
+        .get0(&TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        .unwrap();
+    // The current rule, in effect since 2007
+    let (minutes, rule) = uslax.iter1_copied().last().unwrap();
+    assert_eq!(i32::from_unaligned(*minutes), 1173607200 / 60);
+    assert_eq!(
+        rule,
+        ZoneOffsetRule {
+            standard_offset_seconds: -8 * 3600,
+            daylight_offset_seconds: -7 * 3600,
+            start: ZoneTransitionRule {
+                month: 3,
+                week: 2,
+                weekday: 0,
+                time_seconds: 7200,
+            },
+            end: ZoneTransitionRule {
+                month: 11,
+                week: 1,
+                weekday: 0,
+                time_seconds: 7200,
+            },
+        }
+    );
+
+    // Europe/Dublin has negative daylight saving time in the time zone database
+    let iedub = offset_period
+        .get()
+        .rules
+        .get0(&TimeZoneBcp47Id(tinystr!(8, "iedub")))
+        .unwrap();
+    let (_, rule) = iedub.iter1_copied().last().unwrap();
+    assert_eq!(rule.standard_offset_seconds, 0);
+    assert_eq!(rule.daylight_offset_seconds, 3600);
+    assert_eq!(rule.start.month, 3);
+    assert_eq!(rule.end.month, 10);
+}
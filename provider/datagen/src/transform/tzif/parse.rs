@@ -0,0 +1,304 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for TZif files, as specified in [RFC 8536](https://www.rfc-editor.org/rfc/rfc8536).
+
+use icu_provider::prelude::*;
+use icu_timezone::provider::{ZoneOffsetRule, ZoneTransitionRule};
+
+/// The version 2+ data of a TZif file.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Tzif {
+    /// The transition times in seconds since the Unix epoch, together with the index of the
+    /// local time type that takes effect.
+    pub(crate) transitions: Vec<(i64, usize)>,
+    /// The local time types, as offset from UTC in seconds and daylight saving time indicator.
+    pub(crate) local_time_types: Vec<(i32, bool)>,
+    /// The footer, which describes local time after the last transition.
+    pub(crate) footer: Option<PosixTz>,
+}
+
+/// A POSIX `TZ` string, as found in the footer of a TZif file.
+#[derive(Debug, PartialEq)]
+pub(crate) struct PosixTz {
+    /// The standard offset from UTC in seconds.
+    pub(crate) standard_offset_seconds: i32,
+    /// The daylight saving time rule, if any.
+    pub(crate) rule: Option<ZoneOffsetRule>,
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DataError> {
+        if n > self.0.len() {
+            return Err(DataError::custom("Truncated TZif file"));
+        }
+        let (taken, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, DataError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i32(&mut self) -> Result<i32, DataError> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, DataError> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn count(&mut self) -> Result<usize, DataError> {
+        usize::try_from(self.i32()?).map_err(|_| DataError::custom("Invalid TZif count"))
+    }
+
+    fn header(&mut self) -> Result<Header, DataError> {
+        if self.take(4)? != b"TZif" {
+            return Err(DataError::custom("Not a TZif file"));
+        }
+        let version = self.u8()?;
+        self.take(15)?;
+        Ok(Header {
+            version,
+            isutcnt: self.count()?,
+            isstdcnt: self.count()?,
+            leapcnt: self.count()?,
+            timecnt: self.count()?,
+            typecnt: self.count()?,
+            charcnt: self.count()?,
+        })
+    }
+}
+
+/// Parses a TZif file of version 2 or later.
+pub(crate) fn parse_tzif(bytes: &[u8]) -> Result<Tzif, DataError> {
+    let mut reader = Reader(bytes);
+
+    // The version 1 data block only contains 32-bit data, skip it.
+    let header = reader.header()?;
+    if header.version < b'2' {
+        return Err(DataError::custom("Unsupported TZif version")
+            .with_display_context(&char::from(header.version)));
+    }
+    reader.take(
+        header.timecnt * 5
+            + header.typecnt * 6
+            + header.charcnt
+            + header.leapcnt * 8
+            + header.isstdcnt
+            + header.isutcnt,
+    )?;
+
+    let header = reader.header()?;
+    let times = (0..header.timecnt)
+        .map(|_| reader.i64())
+        .collect::<Result<Vec<_>, _>>()?;
+    let transitions = times
+        .into_iter()
+        .map(|time| Ok((time, usize::from(reader.u8()?))))
+        .collect::<Result<Vec<_>, DataError>>()?;
+    let local_time_types = (0..header.typecnt)
+        .map(|_| {
+            let offset = reader.i32()?;
+            let is_dst = reader.u8()? != 0;
+            // Skip the abbreviation index
+            reader.u8()?;
+            Ok((offset, is_dst))
+        })
+        .collect::<Result<Vec<_>, DataError>>()?;
+    if transitions
+        .iter()
+        .any(|&(_, index)| index >= local_time_types.len())
+    {
+        return Err(DataError::custom("Invalid TZif local time type index"));
+    }
+    reader.take(header.charcnt + header.leapcnt * 12 + header.isstdcnt + header.isutcnt)?;
+
+    let footer = core::str::from_utf8(reader.0)
+        .map_err(|_| DataError::custom("Invalid TZif footer"))?
+        .trim_matches('\n');
+    let footer = if footer.is_empty() {
+        None
+    } else {
+        Some(parse_posix_tz(footer)?)
+    };
+
+    Ok(Tzif {
+        transitions,
+        local_time_types,
+        footer,
+    })
+}
+
+/// Parses a POSIX `TZ` string such as `PST8PDT,M3.2.0,M11.1.0`.
+///
+/// Only the `Mm.w.d` form of transition dates is supported.
+pub(crate) fn parse_posix_tz(tz: &str) -> Result<PosixTz, DataError> {
+    let error = || DataError::custom("Unsupported POSIX TZ string").with_display_context(tz);
+
+    let mut rest = tz;
+    skip_name(&mut rest).ok_or_else(error)?;
+    // POSIX offsets are positive west of Greenwich
+    let standard_offset_seconds = -parse_time(&mut rest).ok_or_else(error)?;
+    if rest.is_empty() {
+        return Ok(PosixTz {
+            standard_offset_seconds,
+            rule: None,
+        });
+    }
+
+    skip_name(&mut rest).ok_or_else(error)?;
+    let daylight_offset_seconds = if rest.starts_with(',') {
+        standard_offset_seconds + 3600
+    } else {
+        -parse_time(&mut rest).ok_or_else(error)?
+    };
+    rest = rest.strip_prefix(',').ok_or_else(error)?;
+    let (start, end) = rest.split_once(',').ok_or_else(error)?;
+    let start = parse_transition_rule(start).ok_or_else(error)?;
+    let end = parse_transition_rule(end).ok_or_else(error)?;
+
+    Ok(PosixTz {
+        standard_offset_seconds,
+        rule: Some(ZoneOffsetRule {
+            standard_offset_seconds,
+            daylight_offset_seconds,
+            start,
+            end,
+        }),
+    })
+}
+
+/// Skips a time zone abbreviation, either alphabetic or quoted in angle brackets.
+fn skip_name(rest: &mut &str) -> Option<()> {
+    let len = if let Some(quoted) = rest.strip_prefix('<') {
+        quoted.find('>')? + 2
+    } else {
+        rest.find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len())
+    };
+    if len < 3 {
+        return None;
+    }
+    *rest = &rest[len..];
+    Some(())
+}
+
+/// Parses a time of the form `[+-]hh[:mm[:ss]]` into seconds.
+fn parse_time(rest: &mut &str) -> Option<i32> {
+    let len = rest
+        .find(|c: char| !c.is_ascii_digit() && !matches!(c, '+' | '-' | ':'))
+        .unwrap_or(rest.len());
+    let (time, remainder) = rest.split_at(len);
+    *rest = remainder;
+
+    let (sign, time) = match time.strip_prefix('-') {
+        Some(time) => (-1, time),
+        None => (1, time.strip_prefix('+').unwrap_or(time)),
+    };
+    let mut seconds = 0;
+    let mut components = 0;
+    for (component, factor) in time.split(':').zip([3600, 60, 1]) {
+        seconds += component.parse::<i32>().ok()? * factor;
+        components += 1;
+    }
+    if components != time.split(':').count() {
+        return None;
+    }
+    Some(sign * seconds)
+}
+
+/// Parses a transition rule of the form `Mm.w.d[/time]`.
+fn parse_transition_rule(rule: &str) -> Option<ZoneTransitionRule> {
+    let (date, time) = match rule.split_once('/') {
+        Some((date, mut time)) => (date, parse_time(&mut time).filter(|_| time.is_empty())?),
+        // The default transition time is 02:00:00
+        None => (rule, 7200),
+    };
+    let mut date = date.strip_prefix('M')?.split('.');
+    let month = date.next()?.parse().ok().filter(|m| (1..=12).contains(m))?;
+    let week = date.next()?.parse().ok().filter(|w| (1..=5).contains(w))?;
+    let weekday = date.next()?.parse().ok().filter(|d| (0..=6).contains(d))?;
+    if date.next().is_some() {
+        return None;
+    }
+    Some(ZoneTransitionRule {
+        month,
+        week,
+        weekday,
+        time_seconds: time,
+    })
+}
+
+#[test]
+fn test_parse_posix_tz() {
+    assert_eq!(
+        parse_posix_tz("PST8PDT,M3.2.0,M11.1.0"),
+        Ok(PosixTz {
+            standard_offset_seconds: -8 * 3600,
+            rule: Some(ZoneOffsetRule {
+                standard_offset_seconds: -8 * 3600,
+                daylight_offset_seconds: -7 * 3600,
+                start: ZoneTransitionRule {
+                    month: 3,
+                    week: 2,
+                    weekday: 0,
+                    time_seconds: 7200,
+                },
+                end: ZoneTransitionRule {
+                    month: 11,
+                    week: 1,
+                    weekday: 0,
+                    time_seconds: 7200,
+                },
+            }),
+        })
+    );
+
+    assert_eq!(
+        parse_posix_tz("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1"),
+        Ok(PosixTz {
+            standard_offset_seconds: -3 * 3600,
+            rule: Some(ZoneOffsetRule {
+                standard_offset_seconds: -3 * 3600,
+                daylight_offset_seconds: -2 * 3600,
+                start: ZoneTransitionRule {
+                    month: 3,
+                    week: 5,
+                    weekday: 0,
+                    time_seconds: -2 * 3600,
+                },
+                end: ZoneTransitionRule {
+                    month: 10,
+                    week: 5,
+                    weekday: 0,
+                    time_seconds: -3600,
+                },
+            }),
+        })
+    );
+
+    assert_eq!(
+        parse_posix_tz("<+0530>-5:30"),
+        Ok(PosixTz {
+            standard_offset_seconds: 5 * 3600 + 30 * 60,
+            rule: None,
+        })
+    );
+
+    assert!(parse_posix_tz("EST5EDT,J60,J300").is_err());
+}
@@ -126,7 +126,8 @@ fn main() {
                     .replace(
                         "_segmenter_lstm_tag_",
                         DatagenProvider::LATEST_TESTED_SEGMENTER_LSTM_TAG,
-                    ),
+                    )
+                    .replace("_tzdata_tag_", DatagenProvider::LATEST_TESTED_TZDATA_TAG),
             )
             .unwrap();
         }
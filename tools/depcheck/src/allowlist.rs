@@ -89,6 +89,7 @@ pub const EXTRA_DATA_DEPS: &[&str] = &[
 /// This will likely grow when we add experimental crates
 pub const EXTRA_EXPERIMENTAL_DEPS: &[&str] = &[
     "icu_displaynames",
+    "icu_messageformat",
     "icu_personnames",
     "icu_relativetime",
     "icu_compactdecimal",
@@ -142,6 +142,8 @@ lazy_static::lazy_static! {
         "icu::calendar::roc",
         "icu::calendar::persian",
         "icu::calendar::hebrew",
+        "icu::calendar::hindu",
+        "icu::calendar::vietnamese",
         "icu::calendar::any_calendar::IntoAnyCalendar",
         "icu::calendar::Date::try_new_gregorian_date",
         "icu::calendar::Date::try_new_buddhist_date",
@@ -155,12 +157,17 @@ lazy_static::lazy_static! {
         "icu::calendar::Date::try_new_chinese_date_with_calendar",
         "icu::calendar::Date::try_new_dangi_date_with_calendar",
         "icu::calendar::Date::try_new_hebrew_date_with_calendar",
+        "icu::calendar::Date::try_new_hindu_lunar_date_with_calendar",
+        "icu::calendar::Date::try_new_hindu_solar_date_with_calendar",
+        "icu::calendar::Date::is_adhika_day",
+        "icu::calendar::Date::tithi",
         "icu::calendar::Date::try_new_islamic_civil_date_with_calendar",
         "icu::calendar::Date::try_new_islamic_tabular_date_with_calendar",
         "icu::calendar::Date::try_new_observational_islamic_date",
         "icu::calendar::Date::try_new_persian_date",
         "icu::calendar::Date::try_new_roc_date",
         "icu::calendar::Date::try_new_ummalqura_date",
+        "icu::calendar::Date::try_new_vietnamese_date_with_calendar",
         "icu::calendar::DateTime::try_new_gregorian_datetime",
         "icu::calendar::DateTime::try_new_buddhist_datetime",
         "icu::calendar::DateTime::try_new_coptic_datetime",
@@ -179,10 +186,7 @@ lazy_static::lazy_static! {
         "icu::calendar::DateTime::try_new_persian_datetime",
         "icu::calendar::DateTime::try_new_roc_datetime",
         "icu::calendar::DateTime::try_new_ummalqura_datetime",
-
-        // Arithmetic APIs are still experimental/hidden for 1.0
-        "icu::calendar::DateDuration",
-        "icu::calendar::DateDurationUnit",
+        "icu::calendar::DateTime::try_new_vietnamese_datetime_with_calendar",
 
         // mostly used for provider, may in the future be exposed for options
         "icu::datetime::fields",
@@ -259,6 +263,11 @@ lazy_static::lazy_static! {
 
         // experimental
         "icu::normalizer::ComposingNormalizer::new_uts46_without_ignored_and_disallowed",
+        "icu::segmenter::GraphemeClusterSegmenter::try_new_with_rules",
+        "icu::segmenter::LineSegmenter::new_dictionary_with_user_dictionary",
+        "icu::segmenter::SentenceSegmenter::try_new_with_rules",
+        "icu::segmenter::WordSegmenter::new_dictionary_with_user_dictionary",
+        "icu::segmenter::WordSegmenter::try_new_with_rules",
 
         // can't be exposed till Diplomat has Write16
         "icu::normalizer::ComposingNormalizer::normalize_utf16",
@@ -304,6 +313,7 @@ lazy_static::lazy_static! {
         "icu::compactdecimal",
         "icu::relativetime",
         "icu::displaynames",
+        "icu::messageformat",
         "icu::transliterate",
         "icu::plurals::PluralRulesWithRanges",
         "icu::plurals::PluralRulesWithRanges::categories",
@@ -473,6 +483,20 @@ lazy_static::lazy_static! {
         "icu::segmenter::SentenceBreakIteratorUtf8",
         "icu::segmenter::WordBreakIteratorUtf8",
 
+        // Chunked text is passed as a Rust iterator, such as over the leaves of a rope.
+        "icu::segmenter::GraphemeClusterBreakIteratorChunks",
+        "icu::segmenter::GraphemeClusterSegmenter::segment_chunks",
+        "icu::segmenter::GraphemeClusterSegmenter::resegment_chunks",
+        "icu::segmenter::LineBreakIteratorChunks",
+        "icu::segmenter::LineSegmenter::segment_chunks",
+        "icu::segmenter::LineSegmenter::resegment_chunks",
+        "icu::segmenter::SentenceBreakIteratorChunks",
+        "icu::segmenter::SentenceSegmenter::segment_chunks",
+        "icu::segmenter::SentenceSegmenter::resegment_chunks",
+        "icu::segmenter::WordBreakIteratorChunks",
+        "icu::segmenter::WordSegmenter::segment_chunks",
+        "icu::segmenter::WordSegmenter::resegment_chunks",
+
         // Some of the provider adapter types are Rust-specific and not relevant to FFI
         "icu_provider_adapters::either::EitherProvider",
 
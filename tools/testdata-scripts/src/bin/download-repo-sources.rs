@@ -191,6 +191,30 @@ fn main() -> eyre::Result<()> {
         &mut Default::default(),
     )?;
 
+    std::fs::remove_dir_all(out_root.join("tests/data/tzif"))?;
+    // The tzdata package numbers the releases of a year instead of using letters
+    let (tzdata_year, tzdata_letter) = DatagenProvider::LATEST_TESTED_TZDATA_TAG.split_at(4);
+    let tzdata_zip = cached(&format!(
+        "https://files.pythonhosted.org/packages/py2.py3/t/tzdata/tzdata-{tzdata_year}.{}-py2.py3-none-any.whl",
+        tzdata_letter.as_bytes()[0] - b'a' + 1,
+    ))
+    .with_context(|| "Failed to download tzdata ZIP".to_owned())?;
+    let mut tzdata_zip = ZipArchive::new(Cursor::new(std::fs::read(&tzdata_zip)?))
+        .with_context(|| format!("Failed to read zip file {:?}", &tzdata_zip))?;
+    for zone in TZIF_GLOB {
+        let path = out_root.join("tests/data/tzif").join(zone);
+        fs::create_dir_all(path.parent().unwrap())?;
+        // TZif files are binary, so they are copied without line ending fixes
+        io::copy(
+            &mut tzdata_zip
+                .by_name(&format!("tzdata/zoneinfo/{zone}"))
+                .with_context(|| format!("Missing TZif file {zone}"))?,
+            &mut File::create(&path)
+                .with_context(|| format!("Failed to create file {:?}", &path))?,
+        )
+        .with_context(|| format!("Failed to write file {:?}", &path))?;
+    }
+
     let cldr_data = cldr_data
         .iter()
         .map(|path| {
@@ -216,6 +240,14 @@ fn main() -> eyre::Result<()> {
         .collect::<Vec<_>>()
         .join(", ");
 
+    let tzif_data = TZIF_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/tzif/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
     write!(&mut File::create(out_root.join("src/tests/data.rs")).unwrap(), "\
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
@@ -248,6 +280,9 @@ impl DatagenProvider {{
                     segmenter_lstm_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
                         [{lstm_data}].into_iter().collect(),
                     )))),
+                    tzif_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                        [{tzif_data}].into_iter().collect(),
+                    )))),
                     ..DatagenProvider::new_custom().source
                 }},
             }})
@@ -381,6 +381,44 @@ impl Astronomical {
         ))
     }
 
+    /// The standard time of dawn at a given location on a given date, or `None` if there is no
+    /// dawn on that date.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
+    pub fn dawn(date: f64, location: Location, alpha: f64) -> Option<Moment> {
+        let morning = true;
+        let moment_of_depression =
+            Self::moment_of_depression(Moment::new(date + (6.0 / 24.0)), location, alpha, morning)?;
+        Some(Location::standard_from_local(
+            moment_of_depression,
+            location,
+        ))
+    }
+
+    /// Precession of the equinoxes at a given moment, measured from the equinox of J2000.
+    ///
+    /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz,
+    /// originally from _Astronomical Algorithms_ by Jean Meeus, 2nd edn., 1998, pp. 136-137.
+    pub fn precession(moment: Moment) -> f64 {
+        let c = Self::julian_centuries(moment);
+        let eta = poly(
+            c,
+            &[0.0, 47.0029 / 3600.0, -0.03302 / 3600.0, 0.000060 / 3600.0],
+        )
+        .rem_euclid(360.0);
+        let cap_p = poly(c, &[174.876384, -869.8089 / 3600.0, 0.03536 / 3600.0]).rem_euclid(360.0);
+        let p = poly(
+            c,
+            &[0.0, 5029.0966 / 3600.0, 1.11113 / 3600.0, 0.000006 / 3600.0],
+        )
+        .rem_euclid(360.0);
+        let cap_a = eta.to_radians().cos() * cap_p.to_radians().sin();
+        let cap_b = cap_p.to_radians().cos();
+        let arg = cap_a.atan2(cap_b).to_degrees().rem_euclid(360.0);
+
+        (p + cap_p - arg).rem_euclid(360.0)
+    }
+
     /// Calculates the obliquity of the ecliptic at a given moment, meaning the angle of the Earth's
     /// axial tilt with respect to the plane of its orbit around the sun  (currently ~23.4 deg)
     ///
@@ -2595,6 +2633,33 @@ mod tests {
         assert_eq!(long_too_large, LocationOutOfBoundsError::Longitude(-180.1));
     }
 
+    #[test]
+    fn check_precession() {
+        // No precession at the reference epoch
+        let precession = Astronomical::precession(J2000);
+        assert!(!(1e-6..=360.0 - 1e-6).contains(&precession), "{precession}");
+
+        // General precession is about 50.3 arcseconds per year
+        let precession = Astronomical::precession(J2000 + 36525.0);
+        assert!((precession - 1.3970).abs() < 0.001, "{precession}");
+        let precession = Astronomical::precession(J2000 - 36525.0);
+        assert!(
+            (precession - (360.0 - 1.3970)).abs() < 0.001,
+            "{precession}"
+        );
+    }
+
+    #[test]
+    fn check_dawn() {
+        // Sunrise in Greenwich on the March equinox is close to 6:00 local mean time
+        let greenwich = Location::new_unchecked(51.4769, 0.0, 0.0, 0.0);
+        let date = crate::iso::fixed_from_iso(2024, 3, 20).to_f64_date();
+        let dawn = Astronomical::dawn(date, greenwich, 0.0).unwrap();
+        assert!((dawn.inner() - date - 0.25).abs() < 0.01, "{dawn:?}");
+        let dusk = Astronomical::dusk(date, greenwich, 0.0).unwrap();
+        assert!((dusk.inner() - date - 0.75).abs() < 0.01, "{dusk:?}");
+    }
+
     #[test]
     fn check_obliquity() {
         let rd_vals = [
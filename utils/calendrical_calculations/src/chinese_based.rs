@@ -115,6 +115,34 @@ const KOREAN_LOCATION_1961: Location = Location::new_unchecked(
     UTC_OFFSET_1961,
 );
 
+/// The Vietnamese calendar uses the same year numbering as the Chinese calendar, but relies on
+/// knowing the current day at the moment of a new moon in Hanoi. Until 1968, Vietnam followed
+/// the Chinese calendar and its UTC+8h computations; from 1968 onward, new moon calculations
+/// are based on UTC+7h, which is why the two calendars disagree on some new years (e.g. 1985).
+///
+/// Offsets are not given in hours, but in partial days (1 hour = 1 / 24 day).
+const VIETNAMESE_UTC_OFFSET_PRE_1968: f64 = 8.0 / 24.0;
+const VIETNAMESE_UTC_OFFSET_POST_1968: f64 = 7.0 / 24.0;
+
+const FIXED_1968: RataDie = RataDie::new(718432); // Jan 1, 1968
+
+const VIETNAMESE_LATITUDE: f64 = 21.0 + (2.0 / 60.0);
+const VIETNAMESE_LONGITUDE: f64 = 105.0 + (51.0 / 60.0);
+const VIETNAMESE_ELEVATION: f64 = 0.0;
+
+const VIETNAMESE_LOCATION_PRE_1968: Location = Location::new_unchecked(
+    VIETNAMESE_LATITUDE,
+    VIETNAMESE_LONGITUDE,
+    VIETNAMESE_ELEVATION,
+    VIETNAMESE_UTC_OFFSET_PRE_1968,
+);
+const VIETNAMESE_LOCATION_POST_1968: Location = Location::new_unchecked(
+    VIETNAMESE_LATITUDE,
+    VIETNAMESE_LONGITUDE,
+    VIETNAMESE_ELEVATION,
+    VIETNAMESE_UTC_OFFSET_POST_1968,
+);
+
 /// A type implementing [`ChineseBased`] for the Chinese calendar
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 #[allow(clippy::exhaustive_structs)] // newtype
@@ -125,6 +153,11 @@ pub struct Chinese;
 #[allow(clippy::exhaustive_structs)] // newtype
 pub struct Dangi;
 
+/// A type implementing [`ChineseBased`] for the Vietnamese lunar calendar
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct Vietnamese;
+
 impl ChineseBased for Chinese {
     fn location(fixed: RataDie) -> Location {
         let year = crate::iso::iso_year_from_fixed(fixed);
@@ -158,6 +191,19 @@ impl ChineseBased for Dangi {
     const EPOCH_ISO: i32 = KOREAN_EPOCH_ISO;
 }
 
+impl ChineseBased for Vietnamese {
+    fn location(fixed: RataDie) -> Location {
+        if fixed < FIXED_1968 {
+            VIETNAMESE_LOCATION_PRE_1968
+        } else {
+            VIETNAMESE_LOCATION_POST_1968
+        }
+    }
+
+    const EPOCH: RataDie = CHINESE_EPOCH;
+    const EPOCH_ISO: i32 = CHINESE_EPOCH_ISO;
+}
+
 /// Marks the bounds of a lunar year
 #[derive(Debug, Copy, Clone)]
 #[allow(clippy::exhaustive_structs)] // we're comfortable making frequent breaking changes to this crate
@@ -599,6 +645,40 @@ mod test {
         new_year_on_or_before_fixed_date::<Dangi>(fixed, prev_solstice).0
     }
 
+    fn new_year_on_or_before<C: ChineseBased>(fixed: RataDie) -> RataDie {
+        let prev_solstice = winter_solstice_on_or_before::<C>(fixed);
+        new_year_on_or_before_fixed_date::<C>(fixed, prev_solstice).0
+    }
+
+    #[test]
+    fn test_fixed_1968() {
+        assert_eq!(FIXED_1968, crate::iso::fixed_from_iso(1968, 1, 1));
+    }
+
+    #[test]
+    fn test_vietnamese_new_year() {
+        // (ISO year, Vietnamese new year, Chinese new year)
+        let cases = [
+            (1968, (1, 29), (1, 30)),
+            (1985, (1, 21), (2, 20)),
+            (2007, (2, 17), (2, 18)),
+            (2024, (2, 10), (2, 10)),
+        ];
+        for (year, (vn_month, vn_day), (cn_month, cn_day)) in cases {
+            let fixed = crate::iso::fixed_from_iso(year, 3, 1);
+            assert_eq!(
+                new_year_on_or_before::<Vietnamese>(fixed),
+                crate::iso::fixed_from_iso(year, vn_month, vn_day),
+                "Vietnamese new year {year}"
+            );
+            assert_eq!(
+                new_year_on_or_before::<Chinese>(fixed),
+                crate::iso::fixed_from_iso(year, cn_month, cn_day),
+                "Chinese new year {year}"
+            );
+        }
+    }
+
     #[test]
     fn test_month_structure() {
         // Mostly just tests that the assertions aren't hit
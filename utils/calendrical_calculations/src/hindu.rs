@@ -0,0 +1,443 @@
+// This file is part of ICU4X.
+//
+// The contents of this file implement algorithms from Calendrical Calculations
+// by Reingold & Dershowitz, Cambridge University Press, 4th edition (2018),
+// which have been released as Lisp code at <https://github.com/EdReingold/calendar-code2/>
+// under the Apache-2.0 license. Accordingly, this file is released under
+// the Apache License, Version 2.0 which can be found at the calendrical_calculations
+// package root or at http://www.apache.org/licenses/LICENSE-2.0.
+
+//! This file implements the astronomical versions of the modern Hindu calendars
+//! from _Calendrical Calculations_, which use the positions of the true sun and
+//! moon relative to the sidereal zodiac instead of the Surya Siddhanta rules.
+//!
+//! All calculations are done for Ujjain, the traditional Hindu prime meridian.
+
+use crate::astronomy::{Astronomical, Location};
+use crate::helpers::next;
+use crate::rata_die::{Moment, RataDie};
+#[allow(unused_imports)]
+use core_maths::*;
+
+/// The fixed date of the start of the Kali Yuga, February 18, 3102 BCE (Julian).
+///
+/// Based on `hindu-epoch` from _Calendrical Calculations_ by Reingold & Dershowitz.
+pub const HINDU_EPOCH: RataDie = crate::julian::fixed_from_julian(-3101, 2, 18);
+
+/// The number of years between the Kali Yuga and the Vikram Samvat era.
+///
+/// Based on `hindu-lunar-era` from _Calendrical Calculations_ by Reingold & Dershowitz.
+pub const VIKRAM_SAMVAT_ERA: i32 = 3044;
+
+/// The number of years between the Kali Yuga and the Shaka era.
+///
+/// Based on `hindu-solar-era` from _Calendrical Calculations_ by Reingold & Dershowitz.
+pub const SHAKA_ERA: i32 = 3179;
+
+/// The number of years between the Shaka era and the Bengali era (Bangabda).
+pub const BENGALI_ERA_OFFSET: i32 = 515;
+
+/// The mean sidereal year, in days.
+///
+/// Based on `mean-sidereal-year` from _Calendrical Calculations_ by Reingold & Dershowitz.
+const MEAN_SIDEREAL_YEAR: f64 = 365.25636;
+
+/// The sidereal longitude of the vernal equinox of 285 CE, the epoch of the sidereal zodiac
+/// (corresponding to the Lahiri ayanamsha).
+///
+/// Based on `sidereal-start` from _Calendrical Calculations_ by Reingold & Dershowitz.
+const SIDEREAL_START: f64 = 336.1360;
+
+/// The location of Ujjain, using its local mean time as the time zone.
+///
+/// Based on `hindu-location` from _Calendrical Calculations_ by Reingold & Dershowitz.
+const UJJAIN: Location = Location {
+    latitude: 23.0 + 9.0 / 60.0,
+    longitude: 75.0 + 46.0 / 60.0 + 6.0 / 3600.0,
+    elevation: 0.0,
+    zone: (5.0 + 461.0 / 9000.0) / 24.0,
+};
+
+/// The location of Kolkata, using Indian Standard Time as the time zone.
+const KOLKATA: Location = Location {
+    latitude: 22.5726,
+    longitude: 88.3639,
+    elevation: 0.0,
+    zone: 5.5 / 24.0,
+};
+
+/// A date in the Hindu lunisolar calendar, in the amanta scheme (months end at new moon)
+/// and the Vikram Samvat era.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // this is all the information of a Hindu lunar date
+pub struct HinduLunarDate {
+    /// The year in the Vikram Samvat era
+    pub year: i32,
+    /// The month, where 1 is Chaitra and 12 is Phalguna
+    pub month: u8,
+    /// Whether this is an adhika (leap) month, which precedes the regular month of the same name
+    pub leap_month: bool,
+    /// The lunar day (tithi) at sunrise, from 1 to 30
+    pub day: u8,
+    /// Whether this is the second day with the same tithi (adhika day)
+    pub leap_day: bool,
+}
+
+impl HinduLunarDate {
+    /// A key that orders dates chronologically
+    fn sort_key(&self) -> (i32, u8, bool, u8, bool) {
+        (
+            self.year,
+            self.month,
+            !self.leap_month,
+            self.day,
+            self.leap_day,
+        )
+    }
+}
+
+/// The sidereal longitude of the sun at the given moment (in universal time), in degrees.
+///
+/// Based on `sidereal-solar-longitude` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn sidereal_solar_longitude(moment: Moment) -> f64 {
+    (Astronomical::solar_longitude(Astronomical::julian_centuries(moment))
+        - Astronomical::precession(moment)
+        + SIDEREAL_START)
+        .rem_euclid(360.0)
+}
+
+/// The sidereal zodiac sign (rashi) of the sun at the given moment, where 1 is Mesha.
+///
+/// Based on `sidereal-zodiac` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn sidereal_zodiac(moment: Moment) -> u8 {
+    // `rem_euclid` can round a tiny negative longitude up to 360
+    1 + ((sidereal_solar_longitude(moment) / 30.0).floor() as u8).min(11)
+}
+
+/// The Kali Yuga solar year at the given moment.
+///
+/// Based on `astro-hindu-calendar-year` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn astro_hindu_calendar_year(moment: Moment) -> i32 {
+    ((moment - HINDU_EPOCH.as_moment()) / MEAN_SIDEREAL_YEAR
+        - sidereal_solar_longitude(moment) / 360.0)
+        .round() as i32
+}
+
+/// The lunar day (tithi) at the given moment, from 1 to 30.
+///
+/// Based on `astro-lunar-day-from-moment` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn astro_lunar_day_from_moment(moment: Moment) -> u8 {
+    let phase = Astronomical::lunar_phase(moment, Astronomical::julian_centuries(moment));
+    1 + (phase / 12.0).floor() as u8
+}
+
+/// Sunrise in Ujjain on the given date, in universal time.
+///
+/// This is the upper limb of the sun appearing on the horizon taking refraction into
+/// account, rounded to the minute, as specified by Lahiri.
+///
+/// Based on `alt-hindu-sunrise` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn alt_hindu_sunrise(date: RataDie) -> Moment {
+    let date = date.to_f64_date();
+    // Ujjain is in the tropics, so there always is a sunrise
+    let rise = Astronomical::dawn(date, UJJAIN, 47.0 / 60.0).unwrap_or(Moment::new(date + 0.25));
+    let rise = Moment::new((rise.inner() * 24.0 * 60.0).round() / 24.0 / 60.0);
+    Location::universal_from_standard(rise, UJJAIN)
+}
+
+/// Geometrical sunset in Ujjain on the given date, in universal time.
+///
+/// Based on `astro-hindu-sunset` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn astro_hindu_sunset(date: RataDie) -> Moment {
+    let date = date.to_f64_date();
+    // Ujjain is in the tropics, so there always is a sunset
+    let set = Astronomical::dusk(date, UJJAIN, 0.0).unwrap_or(Moment::new(date + 0.75));
+    Location::universal_from_standard(set, UJJAIN)
+}
+
+/// Midnight at the start of the given date in Kolkata, in universal time.
+fn bengali_midnight(date: RataDie) -> Moment {
+    Location::universal_from_standard(date.as_moment(), KOLKATA)
+}
+
+/// The Hindu lunisolar date at the given fixed date.
+///
+/// Based on `astro-hindu-lunar-from-fixed` from _Calendrical Calculations_ by Reingold & Dershowitz.
+pub fn hindu_lunar_from_fixed(date: RataDie) -> HinduLunarDate {
+    let critical = alt_hindu_sunrise(date);
+    let day = astro_lunar_day_from_moment(critical);
+    let leap_day = day == astro_lunar_day_from_moment(alt_hindu_sunrise(date - 1));
+    let last_new_moon = Astronomical::new_moon_before(critical);
+    let next_new_moon = Astronomical::new_moon_at_or_after(critical);
+    let solar_month = sidereal_zodiac(last_new_moon);
+    let leap_month = solar_month == sidereal_zodiac(next_new_moon);
+    let month = solar_month % 12 + 1;
+    let year = astro_hindu_calendar_year(if month <= 2 {
+        // The date might precede the solar new year, so use the year six months later
+        (date + 180).as_moment()
+    } else {
+        date.as_moment()
+    }) - VIKRAM_SAMVAT_ERA;
+    HinduLunarDate {
+        year,
+        month,
+        leap_month,
+        day,
+        leap_day,
+    }
+}
+
+/// The first day of the lunar month containing the given fixed date: the first day
+/// whose sunrise follows the preceding new moon.
+pub fn hindu_lunar_month_start(date: RataDie) -> RataDie {
+    let new_moon = Astronomical::new_moon_before(alt_hindu_sunrise(date));
+    let approx = Location::standard_from_universal(new_moon, UJJAIN).as_rata_die();
+    next(approx, |day| alt_hindu_sunrise(day) > new_moon)
+}
+
+/// The first day of the lunar month following the month containing the given fixed date.
+pub fn hindu_lunar_next_month_start(date: RataDie) -> RataDie {
+    // Lunar months are 29 or 30 days long, so 31 days after the start of a month is
+    // always in the following month
+    hindu_lunar_month_start(hindu_lunar_month_start(date) + 31)
+}
+
+/// The first day of the given Vikram Samvat year, which is the first day of Chaitra
+/// (or of adhika Chaitra, if it exists).
+pub fn hindu_lunar_new_year(year: i32) -> RataDie {
+    // Close to Mesha Samkranti, which is in Chaitra or Vaishakha
+    let approx =
+        HINDU_EPOCH.to_f64_date() + MEAN_SIDEREAL_YEAR * f64::from(year + VIKRAM_SAMVAT_ERA);
+    let mut start = hindu_lunar_month_start(Moment::new(approx).as_rata_die());
+    while hindu_lunar_from_fixed(start).year < year {
+        start = hindu_lunar_next_month_start(start);
+    }
+    loop {
+        let previous = hindu_lunar_month_start(start - 1);
+        if hindu_lunar_from_fixed(previous).year == year {
+            start = previous;
+        } else {
+            return start;
+        }
+    }
+}
+
+/// The fixed date of the given Hindu lunisolar date, or `None` if the date does not exist
+/// (because the month is not a leap month, or because the lunar day is skipped (kshaya) or
+/// not repeated in that month).
+pub fn fixed_from_hindu_lunar(date: HinduLunarDate) -> Option<RataDie> {
+    let mut month_start = hindu_lunar_new_year(date.year);
+    for _ in 0..13 {
+        let first = hindu_lunar_from_fixed(month_start);
+        if first.year != date.year {
+            return None;
+        }
+        if (first.month, first.leap_month) == (date.month, date.leap_month) {
+            // Lunar days advance by at most two per day
+            let mut day = month_start + i64::from(date.day.saturating_sub(first.day)) / 2;
+            loop {
+                let current = hindu_lunar_from_fixed(day);
+                if current.sort_key() >= date.sort_key() {
+                    return (current == date).then_some(day);
+                }
+                day += 1;
+            }
+        }
+        month_start = hindu_lunar_next_month_start(month_start);
+    }
+    None
+}
+
+/// The Hindu solar date at the given fixed date, as (year, month, day) where month 1 is
+/// the month starting at Mesha Samkranti. A day belongs to the sign the sun is in at
+/// its critical moment.
+///
+/// Based on `astro-hindu-solar-from-fixed` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn hindu_solar_from_fixed(date: RataDie, critical: fn(RataDie) -> Moment) -> (i32, u8, u8) {
+    let moment = critical(date);
+    let month = sidereal_zodiac(moment);
+    let year = astro_hindu_calendar_year(moment) - SHAKA_ERA;
+    // 3 days before the start of the mean month
+    let approx = date - 3 - ((sidereal_solar_longitude(moment).floor() as i64).rem_euclid(30));
+    let begin = next(approx, |day| sidereal_zodiac(critical(day)) == month);
+    (year, month, (date - begin + 1) as u8)
+}
+
+/// The fixed date of the given Hindu solar date, without checking that the day is in range.
+///
+/// Based on `fixed-from-astro-hindu-solar` from _Calendrical Calculations_ by Reingold & Dershowitz.
+fn fixed_from_hindu_solar(
+    year: i32,
+    month: u8,
+    day: u8,
+    critical: fn(RataDie) -> Moment,
+) -> RataDie {
+    // 3 days before the start of the mean month
+    let approx = HINDU_EPOCH - 3
+        + (MEAN_SIDEREAL_YEAR * (f64::from(year + SHAKA_ERA) + f64::from(month - 1) / 12.0)).floor()
+            as i64;
+    let begin = next(approx, |day| sidereal_zodiac(critical(day)) == month);
+    begin + i64::from(day) - 1
+}
+
+/// The Tamil solar date at the given fixed date, as (year, month, day) in the Shaka era.
+///
+/// Months begin on the day of the solar transit (samkranti) if it happens before sunset,
+/// and on the following day otherwise.
+pub fn tamil_from_fixed(date: RataDie) -> (i32, u8, u8) {
+    hindu_solar_from_fixed(date, astro_hindu_sunset)
+}
+
+/// The fixed date of the given Tamil solar date in the Shaka era, without checking that the
+/// day is in range.
+pub fn fixed_from_tamil(year: i32, month: u8, day: u8) -> RataDie {
+    fixed_from_hindu_solar(year, month, day, astro_hindu_sunset)
+}
+
+/// The Bengali solar date at the given fixed date, as (year, month, day) in the Bengali era.
+///
+/// Months begin on the day after the solar transit (samkranti), where days are counted
+/// from midnight in Indian Standard Time.
+pub fn bengali_from_fixed(date: RataDie) -> (i32, u8, u8) {
+    let (year, month, day) = hindu_solar_from_fixed(date, bengali_midnight);
+    (year - BENGALI_ERA_OFFSET, month, day)
+}
+
+/// The fixed date of the given Bengali solar date in the Bengali era, without checking
+/// that the day is in range.
+pub fn fixed_from_bengali(year: i32, month: u8, day: u8) -> RataDie {
+    fixed_from_hindu_solar(year + BENGALI_ERA_OFFSET, month, day, bengali_midnight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso::fixed_from_iso;
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(HINDU_EPOCH.to_i64_date(), -1132959);
+    }
+
+    #[test]
+    fn test_lunar_new_years() {
+        // Chaitra Shukla Pratipada (Gudi Padwa, Ugadi)
+        let cases = [
+            (2077, (2020, 3, 25)),
+            (2078, (2021, 4, 13)),
+            (2079, (2022, 4, 2)),
+            (2080, (2023, 3, 22)),
+            (2081, (2024, 4, 9)),
+        ];
+        for (year, (y, m, d)) in cases {
+            let fixed = fixed_from_iso(y, m, d);
+            assert_eq!(hindu_lunar_new_year(year), fixed, "{year}");
+            assert_eq!(
+                hindu_lunar_from_fixed(fixed),
+                HinduLunarDate {
+                    year,
+                    month: 1,
+                    leap_month: false,
+                    day: 1,
+                    leap_day: false
+                },
+                "{year}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_adhika_months() {
+        // Adhika Shravana 2080 (2023)
+        let date = hindu_lunar_from_fixed(fixed_from_iso(2023, 8, 1));
+        assert_eq!((date.year, date.month, date.leap_month), (2080, 5, true));
+        let date = hindu_lunar_from_fixed(fixed_from_iso(2023, 8, 20));
+        assert_eq!((date.year, date.month, date.leap_month), (2080, 5, false));
+        // Adhika Ashvin 2077 (2020)
+        let date = hindu_lunar_from_fixed(fixed_from_iso(2020, 10, 1));
+        assert_eq!((date.year, date.month, date.leap_month), (2077, 7, true));
+        // Adhika Jyeshtha 2083 (2026)
+        let date = hindu_lunar_from_fixed(fixed_from_iso(2026, 6, 1));
+        assert_eq!((date.year, date.month, date.leap_month), (2083, 3, true));
+    }
+
+    #[test]
+    fn test_lunar_roundtrip() {
+        let start = fixed_from_iso(2023, 1, 1);
+        let mut leap_days = 0;
+        let mut skipped_days = 0;
+        let mut previous = hindu_lunar_from_fixed(start - 1);
+        for offset in 0..400 {
+            let fixed = start + offset;
+            let date = hindu_lunar_from_fixed(fixed);
+            assert_eq!(fixed_from_hindu_lunar(date), Some(fixed), "{date:?}");
+            if date.leap_day {
+                leap_days += 1;
+                assert_eq!(date.day, previous.day);
+            } else if date.month == previous.month && date.day == previous.day + 2 {
+                skipped_days += 1;
+                let skipped = HinduLunarDate {
+                    day: previous.day + 1,
+                    ..date
+                };
+                assert_eq!(fixed_from_hindu_lunar(skipped), None, "{skipped:?}");
+            }
+            previous = date;
+        }
+        assert!(leap_days > 0);
+        assert!(skipped_days > 0);
+    }
+
+    #[test]
+    fn test_solar_new_years() {
+        // Puthandu and Pohela Boishakh
+        let cases = [
+            (1942, (2020, 4, 14), (2020, 4, 14)),
+            (1944, (2022, 4, 14), (2022, 4, 15)),
+            (1945, (2023, 4, 14), (2023, 4, 15)),
+            (1946, (2024, 4, 14), (2024, 4, 14)),
+        ];
+        for (year, tamil, bengali) in cases {
+            let tamil = fixed_from_iso(tamil.0, tamil.1, tamil.2);
+            assert_eq!(tamil_from_fixed(tamil), (year, 1, 1));
+            assert_eq!(fixed_from_tamil(year, 1, 1), tamil);
+            let bengali = fixed_from_iso(bengali.0, bengali.1, bengali.2);
+            assert_eq!(bengali_from_fixed(bengali), (year - 515, 1, 1));
+            assert_eq!(fixed_from_bengali(year - 515, 1, 1), bengali);
+        }
+    }
+
+    #[test]
+    fn test_festivals() {
+        // Holi: Phalguna Purnima
+        assert_eq!(
+            hindu_lunar_from_fixed(fixed_from_iso(2024, 3, 25)),
+            HinduLunarDate {
+                year: 2080,
+                month: 12,
+                leap_month: false,
+                day: 15,
+                leap_day: false
+            }
+        );
+        // Thai Pongal: Thai (Makara) 1
+        assert_eq!(tamil_from_fixed(fixed_from_iso(2024, 1, 15)), (1945, 10, 1));
+        // Magh 1, the day after Makar Sankranti (which was after midnight)
+        assert_eq!(
+            bengali_from_fixed(fixed_from_iso(2024, 1, 16)),
+            (1430, 10, 1)
+        );
+    }
+
+    #[test]
+    fn test_solar_roundtrip() {
+        let start = fixed_from_iso(2023, 1, 1);
+        for offset in 0..400 {
+            let fixed = start + offset;
+            let (year, month, day) = tamil_from_fixed(fixed);
+            assert_eq!(fixed_from_tamil(year, month, day), fixed);
+            let (year, month, day) = bengali_from_fixed(fixed);
+            assert_eq!(fixed_from_bengali(year, month, day), fixed);
+        }
+    }
+}
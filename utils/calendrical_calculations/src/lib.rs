@@ -50,6 +50,8 @@ pub mod hebrew;
 pub mod hebrew_keviyah;
 /// Additional math helpers
 pub mod helpers;
+/// The astronomical Hindu lunisolar and solar calendars
+pub mod hindu;
 /// Various islamic lunar calendars
 pub mod islamic;
 /// The ISO calendar (also usable as Gregorian)